@@ -5,5 +5,9 @@
  * @LastEditTime: 2025-01-20 14:40:49
  * @Description: lib
  */
+// `error::Error::Api` carries the whole API error context, its size is negligible next to
+// the network round trip of every call returning it.
+#![allow(clippy::result_large_err)]
+
 pub mod service;
 pub mod volcengine;
@@ -2,118 +2,43 @@
  * @Author: Jerry.Yang
  * @Date: 2024-11-05 10:39:54
  * @LastEditors: Jerry.Yang
- * @LastEditTime: 2025-02-06 16:49:20
+ * @LastEditTime: 2025-03-29 20:50:00
  * @Description: api describe load balancers model
  */
-use crate::volcengine::error::error;
-use crate::volcengine::request::request::RequestVolcengine;
-use crate::volcengine::request::{request, response};
-use std::collections::HashMap;
+use crate::volcengine::paginator::paginator;
 use volcengine_sdk_protobuf::protobuf::lb_instance;
 
-// Implementation of the `ApiRequest` trait for the `DescribeLoadBalancersReq` struct.
-//
-// This implementation allows the `DescribeLoadBalancersReq` struct to be used as part of an API request.
-// The struct needs to be formatted into key-value pairs that can be sent as query parameters in the request URL
-// or as the body of the HTTP request (depending on the API). The methods provided by this implementation
-// allow the struct to be serialized into a `HashMap<String, String>` for query parameters, and a `Vec<u8>`
-// for the body content. The conversion methods are essential for interacting with the external API and
-// sending structured requests.
-//
-// The `DescribeLoadBalancersReq` struct represents a request to describe the load balancers in a given context.
-// This struct will be converted into an appropriate request format for use in API calls.
-//
-// The methods included in this implementation are:
-// - `to_hashmap`: Converts the struct into a key-value map for query parameters.
-// - `to_body`: Prepares the body content of the HTTP request (currently empty).
-//
-// This implementation ensures that the `DescribeLoadBalancersReq` struct can be serialized and transmitted
-// as part of an HTTP request, making it possible to interact with the corresponding API endpoint efficiently.
-impl request::ApiRequest for lb_instance::DescribeLoadBalancersReq {
-    // Converts the `DescribeLoadBalancersReq` struct into a `HashMap<String, String>`.
-    // This method is used to format the struct fields into key-value pairs, which are suitable
-    // for inclusion in an HTTP request's query parameters or body, depending on the context.
-    // The key-value pairs represent the parameters of the API request.
-    //
-    // # Arguments:
-    // - `self`: The `DescribeLoadBalancersReq` struct that contains the parameters to be sent in the request.
-    //
-    // # Returns:
-    // - A `HashMap<String, String>` that contains the request parameters in a key-value format.
-    //   This will be used to construct the query string or body of the HTTP request.
-    fn to_hashmap(&self) -> HashMap<String, String> {
-        // This calls the `format_request_to_hashmap` method from the `Request` trait (presumably)
-        // to convert the struct fields into a proper `HashMap`.
-        // It is likely that the `Request` trait implements the necessary logic for formatting fields
-        // into the correct key-value pairs.
-        request::Request::format_request_to_hashmap(self)
-    }
+/// Implementation of the `Paginated` trait for the `DescribeLoadBalancersReq` structure.
+/// Load balancers are paged with `PageNumber` / `PageSize`.
+impl paginator::Paginated for lb_instance::DescribeLoadBalancersReq {
+    type Response = lb_instance::DescribeLoadBalancersResp;
+    type Item = lb_instance::DescribeLoadBalancersResultLoadBalancerResp;
 
-    // Converts the `DescribeLoadBalancersReq` struct into a byte vector (`Vec<u8>`).
-    // This method is responsible for preparing the body of the HTTP request. It can be useful
-    // when sending POST requests with a body (like JSON or XML), but in this case, it returns an empty body.
-    // You may need to customize it depending on the specific request needs (e.g., if the API expects JSON data).
-    //
-    // # Arguments:
-    // - `self`: The `DescribeLoadBalancersReq` struct that will be sent in the body of the request.
-    //
-    // # Returns:
-    // - A `Vec<u8>`, representing the body of the HTTP request. In this case, it is an empty vector,
-    //   but this can be changed to include data like JSON or XML, depending on what the API expects.
-    fn to_body(&self) -> Vec<u8> {
-        // Initialize an empty vector, which could hold the body content in the future
-        let result = Vec::new(); // Empty body; this might change depending on the request structure.
-        result // Return the empty byte vector.
+    /// Returns the load_balancers of a page.
+    fn items(response: &Self::Response) -> &[Self::Item] {
+        response
+            .result
+            .as_ref()
+            .map_or(&[], |result| result.load_balancers.as_slice())
     }
-}
 
-// Implementation of the `ApiResponse` trait for the `DescribeLoadBalancersResp` struct.
-//
-// This implementation allows the `DescribeLoadBalancersResp` struct to handle the response
-// from an API request. The struct will be populated with data from the HTTP response body,
-// typically in JSON format, and will be converted into the corresponding Rust struct.
-// The method provided by this implementation allows the struct to deserialize the response body
-// into a usable struct that can be processed further in the application.
-//
-// The `DescribeLoadBalancersResp` struct represents the response data from an API call that
-// describes load balancers. This struct is typically populated with various details about
-// the load balancers and is used to relay the response data back to the caller.
-//
-// The methods included in this implementation are:
-// - `to_struct`: This method takes an HTTP response and deserializes it into the `DescribeLoadBalancersResp` struct.
-//   It ensures the response is in the expected format and populates the struct with the data received.
-//
-// This implementation makes the `DescribeLoadBalancersResp` struct suitable for use as an API response handler,
-// allowing for easy transformation of the response into a Rust struct, enabling further processing or usage
-// within the application.
-impl response::ApiResponse for lb_instance::DescribeLoadBalancersResp {
-    // Asynchronously processes the HTTP response and deserializes it into a `DescribeLoadBalancersResp` struct.
-    // This method is called when the HTTP response from the server is received. It is used to parse the
-    // JSON response body into the correct struct format, making it easier to work with the response data
-    // programmatically.
-    //
-    // # Arguments:
-    // - `http_response`: The HTTP response received from the API call, containing the JSON data that will
-    //   be deserialized into the `DescribeLoadBalancersResp` struct.
-    //
-    // # Returns:
-    // - A `Result<(), error::Error>` indicating the success (`Ok(())`) or failure (`Err(error::Error)`)
-    //   of the operation. If successful, the struct is updated with the parsed response.
-    //   If there is an error during parsing, an appropriate error is returned.
-    async fn to_struct(&mut self, http_response: reqwest::Response) -> Result<(), error::Error> {
-        // Attempt to parse the JSON response body into the `DescribeLoadBalancersResp` struct.
-        let parsed_response: volcengine_sdk_protobuf::protobuf::lb_instance::DescribeLoadBalancersResp =
-             http_response
-                 .json() // Deserialize the JSON body of the HTTP response into the struct.
-                 .await
-                 .map_err(|e| error::Error::ErrParseResponse(e))?; // If an error occurs during parsing, return a custom error.
-
-        // After successfully parsing the response, assign the parsed struct to `self`.
-        // This updates the current instance (`self`) with the parsed data.
-        *self = parsed_response;
+    /// Requests the next page until the reported total has been fetched.
+    fn next_request(&self, response: &Self::Response, fetched: usize) -> Option<Self> {
+        let result = response.result.as_ref()?;
+        let page_number = paginator::next_page_number(
+            self.page_number,
+            result.load_balancers.len(),
+            fetched,
+            Some(result.total_count),
+        )?;
+        Some(Self {
+            page_number,
+            ..self.clone()
+        })
+    }
 
-        // Return `Ok(())` to indicate that the parsing and assignment were successful.
-        // If parsing fails, an error will be returned from the `map_err` call.
-        Ok(())
+    /// Counts the items of the pages before the requested page number.
+    fn items_before(&self) -> usize {
+        paginator::items_before_page(self.page_number, Some(self.page_size))
     }
 }
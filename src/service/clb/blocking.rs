@@ -0,0 +1,75 @@
+/*
+ * @Author: Jerry.Yang
+ * @Date: 2025-03-18 11:44:19
+ * @LastEditors: Jerry.Yang
+ * @LastEditTime: 2025-03-30 11:10:00
+ * @Description: Blocking facade for CLB (Cloud Load Balancer) operations
+ */
+use crate::service::clb;
+use crate::service::clb::ServiceClb;
+use crate::volcengine::blocking::blocking;
+use crate::volcengine::error::error;
+use crate::volcengine::session::session;
+use volcengine_sdk_protobuf::protobuf::lb_instance;
+
+/// A synchronous facade over `clb::Clb`.
+///
+/// Exposes the operations of `ServiceClb` as blocking methods, driven by the runtime shared by
+/// the blocking facades. Meant for build scripts and command line tools; asynchronous code
+/// should use `clb::Clb` directly.
+///
+/// # Example
+/// ```rust,no_run
+/// # use volcengine_rust_sdk::volcengine::error::error;
+/// # use volcengine_rust_sdk::volcengine::session::session;
+/// use volcengine_rust_sdk::service::clb;
+///
+/// # fn example(session: session::Session) -> Result<(), error::Error> {
+/// let clb = clb::blocking::Clb::new_clb(session)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Clb {
+    clb: clb::Clb,              // The asynchronous service.
+    runtime: blocking::Runtime, // The runtime driving its requests.
+}
+
+impl Clb {
+    /// Creates a new blocking `Clb` using the provided session.
+    ///
+    /// # Arguments
+    /// - `session`: A `Session` object containing authentication and configuration information.
+    ///
+    /// # Returns
+    /// - `Ok(Clb)`: The blocking service.
+    /// - `Err(error::Error)`: If the service or the runtime could not be created.
+    pub fn new_clb(session: session::Session) -> Result<Clb, error::Error> {
+        Ok(Clb {
+            clb: clb::Clb::new_clb(session)?,
+            runtime: blocking::Runtime::shared()?,
+        })
+    }
+
+    /// Initiates a request to describe load balancers.
+    ///
+    /// Blocks until `ServiceClb::new_describe_load_balancers` completes.
+    pub fn new_describe_load_balancers(
+        &self,
+        request: lb_instance::DescribeLoadBalancersReq,
+    ) -> Result<lb_instance::DescribeLoadBalancersResp, error::Error> {
+        self.runtime
+            .block_on(self.clb.new_describe_load_balancers(request))
+    }
+
+    /// Lists load balancers page by page.
+    ///
+    /// The pages are fetched synchronously as the iterators of the paginator are advanced.
+    pub fn describe_load_balancers_paginator(
+        &self,
+        request: lb_instance::DescribeLoadBalancersReq,
+    ) -> blocking::Paginator<lb_instance::DescribeLoadBalancersReq> {
+        self.runtime
+            .paginator(self.clb.describe_load_balancers_paginator(request))
+    }
+}
@@ -2,19 +2,25 @@
  * @Author: Jerry.Yang
  * @Date: 2024-11-12 17:14:39
  * @LastEditors: Jerry.Yang
- * @LastEditTime: 2024-11-13 10:52:58
+ * @LastEditTime: 2025-03-28 10:14:26
  * @Description: mod
  */
 use crate::volcengine::client::client;
 use crate::volcengine::error::error;
+use crate::volcengine::paginator::paginator;
 use crate::volcengine::session::session;
 use std::future::Future;
 use volcengine_sdk_protobuf::protobuf::lb_instance;
 
-mod api_describe_load_balancers;
 mod api_describe_load_balancers_model;
 pub mod service_clb;
 
+// Blocking facade exposing the service operations synchronously.
+pub mod blocking;
+
+// Test module for CLB operations.
+mod tests;
+
 /**
  * @description: ServiceClb
  * @author: Jerry.Yang
@@ -27,6 +33,20 @@ pub trait ServiceClb {
         &self,
         request: lb_instance::DescribeLoadBalancersReq,
     ) -> impl Future<Output = Result<lb_instance::DescribeLoadBalancersResp, error::Error>>;
+
+    /// Lists load balancers page by page.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current service instance.
+    /// - `request`: The request of the first page; `PageSize` sets the page size.
+    ///
+    /// # Returns
+    /// - `paginator::Paginator<lb_instance::DescribeLoadBalancersReq>`: A paginator turned into a stream over the pages with `pages()`
+    ///   or over the load balancers with `items()`.
+    fn describe_load_balancers_paginator(
+        &self,
+        request: lb_instance::DescribeLoadBalancersReq,
+    ) -> paginator::Paginator<lb_instance::DescribeLoadBalancersReq>;
 }
 
 /**
@@ -2,10 +2,9 @@
  * @Author: Jerry.Yang
  * @Date: 2024-11-12 17:23:37
  * @LastEditors: Jerry.Yang
- * @LastEditTime: 2025-02-05 14:44:03
+ * @LastEditTime: 2025-03-28 10:14:26
  * @Description: service clb
  */
-use crate::service::clb::api_describe_load_balancers;
 use crate::service::clb::Clb;
 use crate::service::clb::ServiceClb;
 use crate::volcengine::client::client;
@@ -13,8 +12,10 @@ use crate::volcengine::client::client_info;
 use crate::volcengine::client::config as client_config;
 use crate::volcengine::common;
 use crate::volcengine::error::error;
-use crate::volcengine::request::handles;
+use crate::volcengine::paginator::paginator;
+use crate::volcengine::registry::registry;
 use crate::volcengine::session::session;
+use volcengine_sdk_protobuf::protobuf::lb_instance;
 
 /**
  * @description: `ServiceClb` trait implementation for `Clb` struct
@@ -24,8 +25,8 @@ use crate::volcengine::session::session;
  * instance and sending requests to the CLB service, such as describing load balancers.
  *
  * - `new_clb`: Creates a new instance of `Clb` with a valid client configuration.
- * - `new_describe_load_balancers`: Sends a request to describe load balancers using the API defined in
- *   `api_describe_load_balancers::ApiDescribeLoadBalancersClb`.
+ * - `new_describe_load_balancers`: Sends a request to describe load balancers through
+ *   `registry::invoke`.
  *
  * The `Clb` struct will use the `client::Client`, `client_info::ClientInfo`, and `handles::Handles`
  * to make requests to the API and parse the responses appropriately.
@@ -48,7 +49,7 @@ impl ServiceClb for Clb {
     /// - `Err(error::Error)`: Returns an error if the configuration or client setup fails.
     fn new_clb(session: session::Session) -> Result<Self, error::Error> {
         // Create a new client configuration from the session.
-        let client_config = session.new_client_config(client_config::ClientServiceName::Clb);
+        let client_config = session.new_client_config(client_config::ClientServiceName::Clb)?;
 
         // Construct client info using the client configuration and common API version.
         let client_info = client_info::ClientInfo::builder()
@@ -57,8 +58,8 @@ impl ServiceClb for Clb {
             .with_signing_region(&client_config.signing_region)
             .build()?;
 
-        // Initialize handles (to be used for request management).
-        let request_handles = handles::Handles {};
+        // Use the request handles registered on the session
+        let request_handles = client_config.handles.clone();
 
         // Build the client using the client info, configuration, and request handles.
         let client = client::Client::builder()
@@ -91,9 +92,30 @@ impl ServiceClb for Clb {
         volcengine_sdk_protobuf::protobuf::lb_instance::DescribeLoadBalancersResp,
         error::Error,
     > {
-        // Call the `ApiDescribeLoadBalancersClb` API method to handle the request.
-        api_describe_load_balancers::ApiDescribeLoadBalancersClb
-            .new_describe_load_balancers_api(self, request)
-            .await
+        registry::invoke(
+            &self.client,
+            &registry::CLB_DESCRIBE_LOAD_BALANCERS,
+            &request,
+        )
+        .await
+    }
+
+    /// Lists load balancers page by page.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current service instance.
+    /// - `request`: The request of the first page.
+    ///
+    /// # Returns
+    /// - `paginator::Paginator<lb_instance::DescribeLoadBalancersReq>`: A paginator sending `new_describe_load_balancers` once per page.
+    fn describe_load_balancers_paginator(
+        &self,
+        request: lb_instance::DescribeLoadBalancersReq,
+    ) -> paginator::Paginator<lb_instance::DescribeLoadBalancersReq> {
+        let clb = self.clone();
+        paginator::Paginator::new(request, move |request| {
+            let clb = clb.clone();
+            Box::pin(async move { clb.new_describe_load_balancers(request).await })
+        })
     }
 }
@@ -0,0 +1,93 @@
+/*
+ * @Author: Jerry.Yang
+ * @Date: 2025-03-19 18:52:13
+ * @LastEditors: Jerry.Yang
+ * @LastEditTime: 2025-03-19 18:52:13
+ * @Description: clb test
+ */
+#[cfg(test)]
+mod tests {
+    use futures::TryStreamExt;
+    use volcengine_sdk_protobuf::protobuf::lb_instance;
+
+    use crate::{
+        service::clb::{self, ServiceClb},
+        volcengine::mock::mock,
+    };
+
+    /// The API version of CLB.
+    const VERSION: &str = "2018-01-01";
+
+    /// Returns a `DescribeLoadBalancers` response listing one load balancer out of `total`.
+    fn describe_load_balancers_response(load_balancer_id: &str, total: i64) -> mock::MockResponse {
+        mock::MockResponse::json(&lb_instance::DescribeLoadBalancersResp {
+            result: Some(lb_instance::DescribeLoadBalancersResultResp {
+                total_count: total,
+                load_balancers: vec![lb_instance::DescribeLoadBalancersResultLoadBalancerResp {
+                    load_balancer_id: load_balancer_id.to_string(),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }),
+            ..Default::default()
+        })
+    }
+
+    #[tokio::test]
+    async fn test_describe_load_balancers() {
+        let server = mock::MockServer::start().await;
+        server.mock(
+            "DescribeLoadBalancers",
+            VERSION,
+            describe_load_balancers_response("clb-1", 1),
+        );
+
+        let clb = clb::Clb::new_clb(server.session()).unwrap();
+        let request = lb_instance::DescribeLoadBalancersReq {
+            vpc_id: "vpc-1".to_string(),
+            ..Default::default()
+        };
+        let result = clb.new_describe_load_balancers(request).await.unwrap();
+
+        let load_balancers = result.result.unwrap().load_balancers;
+        assert_eq!(load_balancers[0].load_balancer_id, "clb-1");
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].signature_valid);
+        assert_eq!(requests[0].service, "clb");
+        assert_eq!(requests[0].query["VpcId"], "vpc-1");
+    }
+
+    #[tokio::test]
+    async fn test_describe_load_balancers_paginator() {
+        // two pages of one load balancer out of two
+        let server = mock::MockServer::start().await;
+        server.mock(
+            "DescribeLoadBalancers",
+            VERSION,
+            describe_load_balancers_response("clb-1", 2),
+        );
+        server.mock(
+            "DescribeLoadBalancers",
+            VERSION,
+            describe_load_balancers_response("clb-2", 2),
+        );
+
+        let clb = clb::Clb::new_clb(server.session()).unwrap();
+        let request = lb_instance::DescribeLoadBalancersReq {
+            page_size: 1,
+            ..Default::default()
+        };
+        let load_balancers: Vec<_> = clb
+            .describe_load_balancers_paginator(request)
+            .items()
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(load_balancers.len(), 2);
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[1].query["PageNumber"], "2");
+    }
+}
@@ -0,0 +1,426 @@
+/*
+ * @Author: Jerry.Yang
+ * @Date: 2025-03-23 09:41:26
+ * @LastEditors: Jerry.Yang
+ * @LastEditTime: 2025-03-29 16:05:00
+ * @Description: instance lifecycle models
+ */
+use crate::volcengine::request::response;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Request of the `StartInstance` API.
+///
+/// # Fields
+/// - `instance_id`: The ID of the stopped instance to start.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct StartInstanceReq {
+    /// The ID of the instance
+    #[serde(rename = "InstanceId")]
+    pub instance_id: String,
+}
+
+/// Response of the `StartInstance` API.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct StartInstanceResp {
+    /// Metadata of the response
+    #[serde(rename = "ResponseMetadata")]
+    pub response_metadata: response::ApiResponseMetadata,
+}
+
+/// Request of the `StartInstances` API.
+///
+/// # Fields
+/// - `instance_ids`: The IDs of the stopped instances to start.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct StartInstancesReq {
+    /// The IDs of the instances
+    #[serde(rename = "InstanceIds")]
+    pub instance_ids: Vec<String>,
+}
+
+/// Response of the `StartInstances` API.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct StartInstancesResp {
+    /// Metadata of the response
+    #[serde(rename = "ResponseMetadata")]
+    pub response_metadata: response::ApiResponseMetadata,
+
+    /// The result of the call
+    #[serde(rename = "Result")]
+    pub result: Option<InstancesOperationResult>,
+}
+
+/// Request of the `RebootInstance` API.
+///
+/// # Fields
+/// - `instance_id`: The ID of the running instance to reboot.
+/// - `force_stop`: Whether to power the instance off instead of shutting it down gracefully.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct RebootInstanceReq {
+    /// The ID of the instance
+    #[serde(rename = "InstanceId")]
+    pub instance_id: String,
+
+    /// Whether to force the instance off
+    #[serde(rename = "ForceStop", skip_serializing_if = "Option::is_none")]
+    pub force_stop: Option<bool>,
+}
+
+/// Response of the `RebootInstance` API.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct RebootInstanceResp {
+    /// Metadata of the response
+    #[serde(rename = "ResponseMetadata")]
+    pub response_metadata: response::ApiResponseMetadata,
+}
+
+/// Request of the `RebootInstances` API.
+///
+/// # Fields
+/// - `instance_ids`: The IDs of the running instances to reboot.
+/// - `force_stop`: Whether to power the instances off instead of shutting them down gracefully.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct RebootInstancesReq {
+    /// The IDs of the instances
+    #[serde(rename = "InstanceIds")]
+    pub instance_ids: Vec<String>,
+
+    /// Whether to force the instances off
+    #[serde(rename = "ForceStop", skip_serializing_if = "Option::is_none")]
+    pub force_stop: Option<bool>,
+}
+
+/// Response of the `RebootInstances` API.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct RebootInstancesResp {
+    /// Metadata of the response
+    #[serde(rename = "ResponseMetadata")]
+    pub response_metadata: response::ApiResponseMetadata,
+
+    /// The result of the call
+    #[serde(rename = "Result")]
+    pub result: Option<InstancesOperationResult>,
+}
+
+/// Request of the `DeleteInstance` API.
+///
+/// # Fields
+/// - `instance_id`: The ID of the instance to release.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DeleteInstanceReq {
+    /// The ID of the instance
+    #[serde(rename = "InstanceId")]
+    pub instance_id: String,
+}
+
+/// Response of the `DeleteInstance` API.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct DeleteInstanceResp {
+    /// Metadata of the response
+    #[serde(rename = "ResponseMetadata")]
+    pub response_metadata: response::ApiResponseMetadata,
+}
+
+/// Request of the `DeleteInstances` API.
+///
+/// # Fields
+/// - `instance_ids`: The IDs of the instances to release.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DeleteInstancesReq {
+    /// The IDs of the instances
+    #[serde(rename = "InstanceIds")]
+    pub instance_ids: Vec<String>,
+}
+
+/// Response of the `DeleteInstances` API.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct DeleteInstancesResp {
+    /// Metadata of the response
+    #[serde(rename = "ResponseMetadata")]
+    pub response_metadata: response::ApiResponseMetadata,
+
+    /// The result of the call
+    #[serde(rename = "Result")]
+    pub result: Option<InstancesOperationResult>,
+}
+
+/// Result of the batch instance operations (`StartInstances`, `RebootInstances` and
+/// `DeleteInstances`).
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct InstancesOperationResult {
+    /// The outcome for each instance
+    #[serde(rename = "OperationDetails")]
+    pub operation_details: Vec<InstanceOperationDetail>,
+}
+
+/// Outcome of a batch operation for one instance.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct InstanceOperationDetail {
+    /// The ID of the instance
+    #[serde(rename = "InstanceId")]
+    pub instance_id: String,
+
+    /// The error of the instance, `None` if the operation succeeded for it
+    #[serde(rename = "Error")]
+    pub error: Option<InstanceOperationError>,
+}
+
+/// Error of a batch operation for one instance.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct InstanceOperationError {
+    /// The error code, e.g. `InvalidInstance.NotFound`
+    #[serde(rename = "Code")]
+    pub code: String,
+
+    /// The error message
+    #[serde(rename = "Message")]
+    pub message: String,
+}
+
+/// Request of the `ModifyInstanceAttribute` API.
+///
+/// Only the attributes that are set are modified.
+///
+/// # Fields
+/// - `instance_id`: The ID of the instance.
+/// - `instance_name`: The new name of the instance.
+/// - `description`: The new description of the instance.
+/// - `password`: The new password of the instance, applied at the next start. Not printed by
+///   `Debug`.
+/// - `user_data`: The new user data of the instance, Base64 encoded.
+#[derive(Serialize, Deserialize, Clone, Default)]
+pub struct ModifyInstanceAttributeReq {
+    /// The ID of the instance
+    #[serde(rename = "InstanceId")]
+    pub instance_id: String,
+
+    /// The name of the instance
+    #[serde(rename = "InstanceName", skip_serializing_if = "Option::is_none")]
+    pub instance_name: Option<String>,
+
+    /// The description of the instance
+    #[serde(rename = "Description", skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// The password of the instance
+    #[serde(rename = "Password", skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+
+    /// The user data of the instance
+    #[serde(rename = "UserData", skip_serializing_if = "Option::is_none")]
+    pub user_data: Option<String>,
+}
+
+impl fmt::Debug for ModifyInstanceAttributeReq {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ModifyInstanceAttributeReq")
+            .field("instance_id", &self.instance_id)
+            .field("instance_name", &self.instance_name)
+            .field("description", &self.description)
+            .field("password", &redacted(&self.password))
+            .field("user_data", &self.user_data)
+            .finish()
+    }
+}
+
+/// Response of the `ModifyInstanceAttribute` API.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct ModifyInstanceAttributeResp {
+    /// Metadata of the response
+    #[serde(rename = "ResponseMetadata")]
+    pub response_metadata: response::ApiResponseMetadata,
+}
+
+/// Request of the `RenewInstance` API.
+///
+/// # Fields
+/// - `instance_id`: The ID of the `PrePaid` instance to renew.
+/// - `period`: The length of the renewal, in `period_unit`.
+/// - `period_unit`: The unit of `period`, `Month` by default.
+/// - `client_token`: A token making the request idempotent.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct RenewInstanceReq {
+    /// The ID of the instance
+    #[serde(rename = "InstanceId")]
+    pub instance_id: String,
+
+    /// The length of the renewal
+    #[serde(rename = "Period", skip_serializing_if = "Option::is_none")]
+    pub period: Option<i32>,
+
+    /// The unit of the renewal
+    #[serde(rename = "PeriodUnit", skip_serializing_if = "Option::is_none")]
+    pub period_unit: Option<String>,
+
+    /// The idempotency token
+    #[serde(rename = "ClientToken", skip_serializing_if = "Option::is_none")]
+    pub client_token: Option<String>,
+}
+
+/// Response of the `RenewInstance` API.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct RenewInstanceResp {
+    /// Metadata of the response
+    #[serde(rename = "ResponseMetadata")]
+    pub response_metadata: response::ApiResponseMetadata,
+
+    /// The result of the call
+    #[serde(rename = "Result")]
+    pub result: Option<InstanceOrderResult>,
+}
+
+/// Request of the `ModifyInstanceChargeType` API.
+///
+/// # Fields
+/// - `instance_ids`: The IDs of the instances.
+/// - `instance_charge_type`: The new billing method, `PrePaid` or `PostPaid`.
+/// - `period`: The length of the subscription when switching to `PrePaid`, in `period_unit`.
+/// - `period_unit`: The unit of `period`, `Month` by default.
+/// - `include_data_volumes`: Whether the attached data volumes switch billing method too.
+/// - `auto_pay`: Whether the order is paid automatically.
+/// - `client_token`: A token making the request idempotent.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ModifyInstanceChargeTypeReq {
+    /// The IDs of the instances
+    #[serde(rename = "InstanceIds")]
+    pub instance_ids: Vec<String>,
+
+    /// The billing method
+    #[serde(rename = "InstanceChargeType")]
+    pub instance_charge_type: String,
+
+    /// The length of the subscription
+    #[serde(rename = "Period", skip_serializing_if = "Option::is_none")]
+    pub period: Option<i32>,
+
+    /// The unit of the subscription
+    #[serde(rename = "PeriodUnit", skip_serializing_if = "Option::is_none")]
+    pub period_unit: Option<String>,
+
+    /// Whether the data volumes switch billing method too
+    #[serde(rename = "IncludeDataVolumes", skip_serializing_if = "Option::is_none")]
+    pub include_data_volumes: Option<bool>,
+
+    /// Whether the order is paid automatically
+    #[serde(rename = "AutoPay", skip_serializing_if = "Option::is_none")]
+    pub auto_pay: Option<bool>,
+
+    /// The idempotency token
+    #[serde(rename = "ClientToken", skip_serializing_if = "Option::is_none")]
+    pub client_token: Option<String>,
+}
+
+/// Response of the `ModifyInstanceChargeType` API.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct ModifyInstanceChargeTypeResp {
+    /// Metadata of the response
+    #[serde(rename = "ResponseMetadata")]
+    pub response_metadata: response::ApiResponseMetadata,
+
+    /// The result of the call
+    #[serde(rename = "Result")]
+    pub result: Option<InstanceOrderResult>,
+}
+
+/// Result of the billing operations (`RenewInstance` and `ModifyInstanceChargeType`).
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct InstanceOrderResult {
+    /// The ID of the order placed
+    #[serde(rename = "OrderId")]
+    pub order_id: String,
+}
+
+/// Request of the `ReplaceSystemVolume` API.
+///
+/// The instance must be stopped. Either `password` or `key_pair_name` sets the credentials of
+/// the new system volume, unless `keep_image_credential` keeps those of the image.
+///
+/// # Fields
+/// - `instance_id`: The ID of the stopped instance.
+/// - `image_id`: The ID of the image of the new system volume.
+/// - `size`: The size of the new system volume in GiB, the current size by default.
+/// - `password`: The password of the instance. Not printed by `Debug`.
+/// - `key_pair_name`: The name of the key pair of the instance.
+/// - `keep_image_credential`: Whether to keep the credentials set in the image.
+/// - `user_data`: The user data of the instance, Base64 encoded.
+/// - `client_token`: A token making the request idempotent.
+#[derive(Serialize, Deserialize, Clone, Default)]
+pub struct ReplaceSystemVolumeReq {
+    /// The ID of the instance
+    #[serde(rename = "InstanceId")]
+    pub instance_id: String,
+
+    /// The ID of the image
+    #[serde(rename = "ImageId")]
+    pub image_id: String,
+
+    /// The size of the system volume in GiB
+    #[serde(rename = "Size", skip_serializing_if = "Option::is_none")]
+    pub size: Option<i32>,
+
+    /// The password of the instance
+    #[serde(rename = "Password", skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+
+    /// The name of the key pair
+    #[serde(rename = "KeyPairName", skip_serializing_if = "Option::is_none")]
+    pub key_pair_name: Option<String>,
+
+    /// Whether to keep the credentials of the image
+    #[serde(
+        rename = "KeepImageCredential",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub keep_image_credential: Option<bool>,
+
+    /// The user data of the instance
+    #[serde(rename = "UserData", skip_serializing_if = "Option::is_none")]
+    pub user_data: Option<String>,
+
+    /// The idempotency token
+    #[serde(rename = "ClientToken", skip_serializing_if = "Option::is_none")]
+    pub client_token: Option<String>,
+}
+
+impl fmt::Debug for ReplaceSystemVolumeReq {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReplaceSystemVolumeReq")
+            .field("instance_id", &self.instance_id)
+            .field("image_id", &self.image_id)
+            .field("size", &self.size)
+            .field("password", &redacted(&self.password))
+            .field("key_pair_name", &self.key_pair_name)
+            .field("keep_image_credential", &self.keep_image_credential)
+            .field("user_data", &self.user_data)
+            .field("client_token", &self.client_token)
+            .finish()
+    }
+}
+
+/// Response of the `ReplaceSystemVolume` API.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct ReplaceSystemVolumeResp {
+    /// Metadata of the response
+    #[serde(rename = "ResponseMetadata")]
+    pub response_metadata: response::ApiResponseMetadata,
+}
+
+/// Hides a password in `Debug` output, showing only whether it is set.
+fn redacted(password: &Option<String>) -> Option<&'static str> {
+    password.as_ref().map(|_| "<redacted>")
+}
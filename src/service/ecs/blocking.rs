@@ -0,0 +1,295 @@
+/*
+ * @Author: Jerry.Yang
+ * @Date: 2025-03-18 10:20:14
+ * @LastEditors: Jerry.Yang
+ * @LastEditTime: 2025-03-30 11:10:00
+ * @Description: Blocking facade for ECS (Elastic Compute Service) operations
+ */
+use crate::service::ecs;
+use crate::service::ecs::api_instance_lifecycle_model;
+use crate::service::ecs::EcsService;
+use crate::volcengine::blocking::blocking;
+use crate::volcengine::error::error;
+use crate::volcengine::session::session;
+use crate::volcengine::waiter::waiter;
+use volcengine_sdk_protobuf::protobuf::ecs_image;
+use volcengine_sdk_protobuf::protobuf::ecs_instance;
+use volcengine_sdk_protobuf::protobuf::ecs_zone;
+
+/// A synchronous facade over `ecs::Ecs`.
+///
+/// Exposes the operations of `EcsService` as blocking methods, driven by the runtime shared by
+/// the blocking facades. Meant for build scripts and command line tools; asynchronous code
+/// should use `ecs::Ecs` directly.
+///
+/// # Example
+/// ```rust,no_run
+/// # use volcengine_rust_sdk::volcengine::error::error;
+/// # use volcengine_rust_sdk::volcengine::session::session;
+/// use volcengine_rust_sdk::service::ecs;
+///
+/// # fn example(session: session::Session) -> Result<(), error::Error> {
+/// let ecs = ecs::blocking::Ecs::new_ecs(session)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Ecs {
+    ecs: ecs::Ecs,              // The asynchronous service.
+    runtime: blocking::Runtime, // The runtime driving its requests.
+}
+
+impl Ecs {
+    /// Creates a new blocking `Ecs` using the provided session.
+    ///
+    /// # Arguments
+    /// - `session`: A `Session` object containing authentication and configuration information.
+    ///
+    /// # Returns
+    /// - `Ok(Ecs)`: The blocking service.
+    /// - `Err(error::Error)`: If the service or the runtime could not be created.
+    pub fn new_ecs(session: session::Session) -> Result<Ecs, error::Error> {
+        Ok(Ecs {
+            ecs: ecs::Ecs::new_ecs(session)?,
+            runtime: blocking::Runtime::shared()?,
+        })
+    }
+
+    /// Initiates a request to run instances in ECS.
+    ///
+    /// Blocks until `EcsService::new_run_instances` completes.
+    pub fn new_run_instances(
+        &self,
+        request: ecs_instance::RunInstancesReq,
+    ) -> Result<ecs_instance::RunInstancesResp, error::Error> {
+        self.runtime.block_on(self.ecs.new_run_instances(request))
+    }
+
+    /// Initiates a request to describe instances in ECS.
+    ///
+    /// Blocks until `EcsService::new_describe_instances` completes.
+    pub fn new_describe_instances(
+        &self,
+        request: ecs_instance::DescribeInstancesReq,
+    ) -> Result<ecs_instance::DescribeInstancesResp, error::Error> {
+        self.runtime
+            .block_on(self.ecs.new_describe_instances(request))
+    }
+
+    /// Lists instances page by page.
+    ///
+    /// The pages are fetched synchronously as the iterators of the paginator are advanced.
+    pub fn describe_instances_paginator(
+        &self,
+        request: ecs_instance::DescribeInstancesReq,
+    ) -> blocking::Paginator<ecs_instance::DescribeInstancesReq> {
+        self.runtime
+            .paginator(self.ecs.describe_instances_paginator(request))
+    }
+
+    /// Initiates a request to stop a single instance in ECS.
+    ///
+    /// Blocks until `EcsService::new_stop_instance` completes.
+    pub fn new_stop_instance(
+        &self,
+        request: ecs_instance::StopInstanceReq,
+    ) -> Result<ecs_instance::StopInstanceResp, error::Error> {
+        self.runtime.block_on(self.ecs.new_stop_instance(request))
+    }
+
+    /// Initiates a request to stop multiple instances in ECS.
+    ///
+    /// Blocks until `EcsService::new_stop_instances` completes.
+    pub fn new_stop_instances(
+        &self,
+        request: ecs_instance::StopInstancesReq,
+    ) -> Result<ecs_instance::StopInstancesResp, error::Error> {
+        self.runtime.block_on(self.ecs.new_stop_instances(request))
+    }
+
+    /// Initiates a request to modify the specifications of an instance in ECS.
+    ///
+    /// Blocks until `EcsService::new_modify_instance_spec` completes.
+    pub fn new_modify_instance_spec(
+        &self,
+        request: ecs_instance::ModifyInstanceSpecReq,
+    ) -> Result<ecs_instance::ModifyInstanceSpecResp, error::Error> {
+        self.runtime
+            .block_on(self.ecs.new_modify_instance_spec(request))
+    }
+
+    /// Initiates a request to start a stopped instance in ECS.
+    ///
+    /// Blocks until `EcsService::new_start_instance` completes.
+    pub fn new_start_instance(
+        &self,
+        request: api_instance_lifecycle_model::StartInstanceReq,
+    ) -> Result<api_instance_lifecycle_model::StartInstanceResp, error::Error> {
+        self.runtime.block_on(self.ecs.new_start_instance(request))
+    }
+
+    /// Initiates a request to start multiple stopped instances in ECS.
+    ///
+    /// Blocks until `EcsService::new_start_instances` completes.
+    pub fn new_start_instances(
+        &self,
+        request: api_instance_lifecycle_model::StartInstancesReq,
+    ) -> Result<api_instance_lifecycle_model::StartInstancesResp, error::Error> {
+        self.runtime.block_on(self.ecs.new_start_instances(request))
+    }
+
+    /// Initiates a request to reboot a running instance in ECS.
+    ///
+    /// Blocks until `EcsService::new_reboot_instance` completes.
+    pub fn new_reboot_instance(
+        &self,
+        request: api_instance_lifecycle_model::RebootInstanceReq,
+    ) -> Result<api_instance_lifecycle_model::RebootInstanceResp, error::Error> {
+        self.runtime.block_on(self.ecs.new_reboot_instance(request))
+    }
+
+    /// Initiates a request to reboot multiple running instances in ECS.
+    ///
+    /// Blocks until `EcsService::new_reboot_instances` completes.
+    pub fn new_reboot_instances(
+        &self,
+        request: api_instance_lifecycle_model::RebootInstancesReq,
+    ) -> Result<api_instance_lifecycle_model::RebootInstancesResp, error::Error> {
+        self.runtime
+            .block_on(self.ecs.new_reboot_instances(request))
+    }
+
+    /// Initiates a request to release an instance in ECS.
+    ///
+    /// Blocks until `EcsService::new_delete_instance` completes.
+    pub fn new_delete_instance(
+        &self,
+        request: api_instance_lifecycle_model::DeleteInstanceReq,
+    ) -> Result<api_instance_lifecycle_model::DeleteInstanceResp, error::Error> {
+        self.runtime.block_on(self.ecs.new_delete_instance(request))
+    }
+
+    /// Initiates a request to release multiple instances in ECS.
+    ///
+    /// Blocks until `EcsService::new_delete_instances` completes.
+    pub fn new_delete_instances(
+        &self,
+        request: api_instance_lifecycle_model::DeleteInstancesReq,
+    ) -> Result<api_instance_lifecycle_model::DeleteInstancesResp, error::Error> {
+        self.runtime
+            .block_on(self.ecs.new_delete_instances(request))
+    }
+
+    /// Initiates a request to modify the name, description, password or user data of an instance in ECS.
+    ///
+    /// Blocks until `EcsService::new_modify_instance_attribute` completes.
+    pub fn new_modify_instance_attribute(
+        &self,
+        request: api_instance_lifecycle_model::ModifyInstanceAttributeReq,
+    ) -> Result<api_instance_lifecycle_model::ModifyInstanceAttributeResp, error::Error> {
+        self.runtime
+            .block_on(self.ecs.new_modify_instance_attribute(request))
+    }
+
+    /// Initiates a request to renew a subscription (`PrePaid`) instance in ECS.
+    ///
+    /// Blocks until `EcsService::new_renew_instance` completes.
+    pub fn new_renew_instance(
+        &self,
+        request: api_instance_lifecycle_model::RenewInstanceReq,
+    ) -> Result<api_instance_lifecycle_model::RenewInstanceResp, error::Error> {
+        self.runtime.block_on(self.ecs.new_renew_instance(request))
+    }
+
+    /// Initiates a request to switch the billing method of instances in ECS.
+    ///
+    /// Blocks until `EcsService::new_modify_instance_charge_type` completes.
+    pub fn new_modify_instance_charge_type(
+        &self,
+        request: api_instance_lifecycle_model::ModifyInstanceChargeTypeReq,
+    ) -> Result<api_instance_lifecycle_model::ModifyInstanceChargeTypeResp, error::Error> {
+        self.runtime
+            .block_on(self.ecs.new_modify_instance_charge_type(request))
+    }
+
+    /// Initiates a request to replace the system volume of a stopped instance with a new image in ECS.
+    ///
+    /// Blocks until `EcsService::new_replace_system_volume` completes.
+    pub fn new_replace_system_volume(
+        &self,
+        request: api_instance_lifecycle_model::ReplaceSystemVolumeReq,
+    ) -> Result<api_instance_lifecycle_model::ReplaceSystemVolumeResp, error::Error> {
+        self.runtime
+            .block_on(self.ecs.new_replace_system_volume(request))
+    }
+
+    /// Initiates a request to describe images in ECS.
+    ///
+    /// Blocks until `EcsService::new_describe_images` completes.
+    pub fn new_describe_images(
+        &self,
+        request: ecs_image::DescribeImagesReq,
+    ) -> Result<ecs_image::DescribeImagesResp, error::Error> {
+        self.runtime.block_on(self.ecs.new_describe_images(request))
+    }
+
+    /// Lists images page by page.
+    ///
+    /// The pages are fetched synchronously as the iterators of the paginator are advanced.
+    pub fn describe_images_paginator(
+        &self,
+        request: ecs_image::DescribeImagesReq,
+    ) -> blocking::Paginator<ecs_image::DescribeImagesReq> {
+        self.runtime
+            .paginator(self.ecs.describe_images_paginator(request))
+    }
+
+    /// Initiates a request to describe regions in ECS.
+    ///
+    /// Blocks until `EcsService::new_describe_regions` completes.
+    pub fn new_describe_regions(
+        &self,
+        request: ecs_zone::DescribeRegionsReq,
+    ) -> Result<ecs_zone::DescribeRegionsResp, error::Error> {
+        self.runtime
+            .block_on(self.ecs.new_describe_regions(request))
+    }
+
+    /// Initiates a request to describe zones in ECS.
+    ///
+    /// Blocks until `EcsService::new_describe_zones` completes.
+    pub fn new_describe_zones(
+        &self,
+        request: ecs_zone::DescribeZonesReq,
+    ) -> Result<ecs_zone::DescribeZonesResp, error::Error> {
+        self.runtime.block_on(self.ecs.new_describe_zones(request))
+    }
+
+    /// Waits until an instance is `RUNNING`, e.g. after `new_run_instances`.
+    ///
+    /// Blocks until `EcsService::wait_until_instance_running` completes.
+    pub fn wait_until_instance_running(
+        &self,
+        instance_id: &str,
+        waiter_config: &waiter::WaiterConfig,
+    ) -> Result<ecs_instance::DescribeInstancesResultInstanceResp, error::Error> {
+        self.runtime.block_on(
+            self.ecs
+                .wait_until_instance_running(instance_id, waiter_config),
+        )
+    }
+
+    /// Waits until an instance is `STOPPED`, e.g. after `new_stop_instances`.
+    ///
+    /// Blocks until `EcsService::wait_until_instance_stopped` completes.
+    pub fn wait_until_instance_stopped(
+        &self,
+        instance_id: &str,
+        waiter_config: &waiter::WaiterConfig,
+    ) -> Result<ecs_instance::DescribeInstancesResultInstanceResp, error::Error> {
+        self.runtime.block_on(
+            self.ecs
+                .wait_until_instance_stopped(instance_id, waiter_config),
+        )
+    }
+}
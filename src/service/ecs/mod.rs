@@ -2,12 +2,14 @@
  * @Author: Jerry.Yang
  * @Date: 2024-10-28 16:35:18
  * @LastEditors: Jerry.Yang
- * @LastEditTime: 2025-02-05 16:56:35
+ * @LastEditTime: 2025-03-28 10:14:26
  * @Description: Module for ECS (Elastic Compute Service) operations
  */
 use crate::volcengine::client::client;
 use crate::volcengine::error::error;
+use crate::volcengine::paginator::paginator;
 use crate::volcengine::session::session;
+use crate::volcengine::waiter::waiter;
 use std::future::Future;
 use volcengine_sdk_protobuf::protobuf::ecs_image;
 use volcengine_sdk_protobuf::protobuf::ecs_instance;
@@ -16,24 +18,16 @@ use volcengine_sdk_protobuf::protobuf::ecs_zone;
 // Public module for ECS service related operations.
 pub mod service_ecs;
 
+// Blocking facade exposing the service operations synchronously.
+pub mod blocking;
+
 // Submodules for various ECS API operations.
 // These modules contain the specific implementations for different ECS operations such as describing images, instances, regions, zones, modifying instance specifications, running instances, stopping single and multiple instances.
-mod api_describe_images;
+// The instance lifecycle models are public because their request and response types are defined in this crate.
 mod api_describe_images_models;
-mod api_describe_instances;
 mod api_describe_instances_model;
-mod api_describe_regions;
-mod api_describe_regions_model;
-mod api_describe_zones;
-mod api_describe_zones_models;
-mod api_modify_instance_spec;
-mod api_modify_instance_spec_model;
-mod api_run_instances;
-mod api_run_instances_model;
-mod api_stop_instance;
-mod api_stop_instance_model;
-mod api_stop_instances;
-mod api_stop_instances_models;
+pub mod api_instance_lifecycle_model;
+mod waiter_instance;
 
 // Test module for ECS operations.
 mod tests;
@@ -75,6 +69,20 @@ pub trait EcsService {
         request: ecs_instance::DescribeInstancesReq,
     ) -> impl Future<Output = Result<ecs_instance::DescribeInstancesResp, error::Error>>;
 
+    /// Lists instances page by page.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current service instance.
+    /// - `request`: The request of the first page; `MaxResults` sets the page size.
+    ///
+    /// # Returns
+    /// - `paginator::Paginator<ecs_instance::DescribeInstancesReq>`: A paginator turned into a stream over the pages with `pages()`
+    ///   or over the instances with `items()`.
+    fn describe_instances_paginator(
+        &self,
+        request: ecs_instance::DescribeInstancesReq,
+    ) -> paginator::Paginator<ecs_instance::DescribeInstancesReq>;
+
     /// Initiates a request to stop a single instance in ECS.
     ///
     /// # Parameters:
@@ -111,6 +119,130 @@ pub trait EcsService {
         request: ecs_instance::ModifyInstanceSpecReq,
     ) -> impl Future<Output = Result<ecs_instance::ModifyInstanceSpecResp, error::Error>>;
 
+    /// Initiates a request to start a stopped instance in ECS.
+    ///
+    /// # Parameters:
+    /// - `request`: A `StartInstanceReq` object containing the parameters of the `StartInstance` API.
+    ///
+    /// # Returns:
+    /// A `Future` that resolves to a `Result` containing a `StartInstanceResp` on success or an `error::Error` on failure.
+    fn new_start_instance(
+        &self,
+        request: api_instance_lifecycle_model::StartInstanceReq,
+    ) -> impl Future<Output = Result<api_instance_lifecycle_model::StartInstanceResp, error::Error>>;
+
+    /// Initiates a request to start multiple stopped instances in ECS.
+    ///
+    /// # Parameters:
+    /// - `request`: A `StartInstancesReq` object containing the parameters of the `StartInstances` API.
+    ///
+    /// # Returns:
+    /// A `Future` that resolves to a `Result` containing a `StartInstancesResp` on success or an `error::Error` on failure.
+    fn new_start_instances(
+        &self,
+        request: api_instance_lifecycle_model::StartInstancesReq,
+    ) -> impl Future<Output = Result<api_instance_lifecycle_model::StartInstancesResp, error::Error>>;
+
+    /// Initiates a request to reboot a running instance in ECS.
+    ///
+    /// # Parameters:
+    /// - `request`: A `RebootInstanceReq` object containing the parameters of the `RebootInstance` API.
+    ///
+    /// # Returns:
+    /// A `Future` that resolves to a `Result` containing a `RebootInstanceResp` on success or an `error::Error` on failure.
+    fn new_reboot_instance(
+        &self,
+        request: api_instance_lifecycle_model::RebootInstanceReq,
+    ) -> impl Future<Output = Result<api_instance_lifecycle_model::RebootInstanceResp, error::Error>>;
+
+    /// Initiates a request to reboot multiple running instances in ECS.
+    ///
+    /// # Parameters:
+    /// - `request`: A `RebootInstancesReq` object containing the parameters of the `RebootInstances` API.
+    ///
+    /// # Returns:
+    /// A `Future` that resolves to a `Result` containing a `RebootInstancesResp` on success or an `error::Error` on failure.
+    fn new_reboot_instances(
+        &self,
+        request: api_instance_lifecycle_model::RebootInstancesReq,
+    ) -> impl Future<Output = Result<api_instance_lifecycle_model::RebootInstancesResp, error::Error>>;
+
+    /// Initiates a request to release an instance in ECS.
+    ///
+    /// # Parameters:
+    /// - `request`: A `DeleteInstanceReq` object containing the parameters of the `DeleteInstance` API.
+    ///
+    /// # Returns:
+    /// A `Future` that resolves to a `Result` containing a `DeleteInstanceResp` on success or an `error::Error` on failure.
+    fn new_delete_instance(
+        &self,
+        request: api_instance_lifecycle_model::DeleteInstanceReq,
+    ) -> impl Future<Output = Result<api_instance_lifecycle_model::DeleteInstanceResp, error::Error>>;
+
+    /// Initiates a request to release multiple instances in ECS.
+    ///
+    /// # Parameters:
+    /// - `request`: A `DeleteInstancesReq` object containing the parameters of the `DeleteInstances` API.
+    ///
+    /// # Returns:
+    /// A `Future` that resolves to a `Result` containing a `DeleteInstancesResp` on success or an `error::Error` on failure.
+    fn new_delete_instances(
+        &self,
+        request: api_instance_lifecycle_model::DeleteInstancesReq,
+    ) -> impl Future<Output = Result<api_instance_lifecycle_model::DeleteInstancesResp, error::Error>>;
+
+    /// Initiates a request to modify the name, description, password or user data of an instance in ECS.
+    ///
+    /// # Parameters:
+    /// - `request`: A `ModifyInstanceAttributeReq` object containing the parameters of the `ModifyInstanceAttribute` API.
+    ///
+    /// # Returns:
+    /// A `Future` that resolves to a `Result` containing a `ModifyInstanceAttributeResp` on success or an `error::Error` on failure.
+    fn new_modify_instance_attribute(
+        &self,
+        request: api_instance_lifecycle_model::ModifyInstanceAttributeReq,
+    ) -> impl Future<
+        Output = Result<api_instance_lifecycle_model::ModifyInstanceAttributeResp, error::Error>,
+    >;
+
+    /// Initiates a request to renew a subscription (`PrePaid`) instance in ECS.
+    ///
+    /// # Parameters:
+    /// - `request`: A `RenewInstanceReq` object containing the parameters of the `RenewInstance` API.
+    ///
+    /// # Returns:
+    /// A `Future` that resolves to a `Result` containing a `RenewInstanceResp` on success or an `error::Error` on failure.
+    fn new_renew_instance(
+        &self,
+        request: api_instance_lifecycle_model::RenewInstanceReq,
+    ) -> impl Future<Output = Result<api_instance_lifecycle_model::RenewInstanceResp, error::Error>>;
+
+    /// Initiates a request to switch the billing method of instances in ECS.
+    ///
+    /// # Parameters:
+    /// - `request`: A `ModifyInstanceChargeTypeReq` object containing the parameters of the `ModifyInstanceChargeType` API.
+    ///
+    /// # Returns:
+    /// A `Future` that resolves to a `Result` containing a `ModifyInstanceChargeTypeResp` on success or an `error::Error` on failure.
+    fn new_modify_instance_charge_type(
+        &self,
+        request: api_instance_lifecycle_model::ModifyInstanceChargeTypeReq,
+    ) -> impl Future<
+        Output = Result<api_instance_lifecycle_model::ModifyInstanceChargeTypeResp, error::Error>,
+    >;
+
+    /// Initiates a request to replace the system volume of a stopped instance with a new image in ECS.
+    ///
+    /// # Parameters:
+    /// - `request`: A `ReplaceSystemVolumeReq` object containing the parameters of the `ReplaceSystemVolume` API.
+    ///
+    /// # Returns:
+    /// A `Future` that resolves to a `Result` containing a `ReplaceSystemVolumeResp` on success or an `error::Error` on failure.
+    fn new_replace_system_volume(
+        &self,
+        request: api_instance_lifecycle_model::ReplaceSystemVolumeReq,
+    ) -> impl Future<Output = Result<api_instance_lifecycle_model::ReplaceSystemVolumeResp, error::Error>>;
+
     /// Initiates a request to describe images in ECS.
     ///
     /// # Parameters:
@@ -123,6 +255,20 @@ pub trait EcsService {
         request: ecs_image::DescribeImagesReq,
     ) -> impl Future<Output = Result<ecs_image::DescribeImagesResp, error::Error>>;
 
+    /// Lists images page by page.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current service instance.
+    /// - `request`: The request of the first page; `MaxResults` sets the page size.
+    ///
+    /// # Returns
+    /// - `paginator::Paginator<ecs_image::DescribeImagesReq>`: A paginator turned into a stream over the pages with `pages()`
+    ///   or over the images with `items()`.
+    fn describe_images_paginator(
+        &self,
+        request: ecs_image::DescribeImagesReq,
+    ) -> paginator::Paginator<ecs_image::DescribeImagesReq>;
+
     /// Initiates a request to describe regions in ECS.
     ///
     /// # Parameters:
@@ -146,6 +292,44 @@ pub trait EcsService {
         &self,
         request: ecs_zone::DescribeZonesReq,
     ) -> impl Future<Output = Result<ecs_zone::DescribeZonesResp, error::Error>>;
+
+    /// Waits until an instance is `RUNNING`, e.g. after `new_run_instances`.
+    ///
+    /// Polls `DescribeInstances` with the delay, backoff and timeout of `waiter_config`, and fails fast
+    /// when the instance reaches a failure status such as `Error`.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current service instance.
+    /// - `instance_id`: The ID of the instance.
+    /// - `waiter_config`: The delay, backoff and timeout, e.g. `waiter::WaiterConfig::default()`.
+    ///
+    /// # Returns
+    /// - `impl Future<Output = Result<ecs_instance::DescribeInstancesResultInstanceResp, error::Error>>`: On success, returns the instance once it is running.
+    ///   On failure, returns `error::Error::ErrWaiterFailure`, `error::Error::ErrWaiterTimeout` or the error of a poll.
+    fn wait_until_instance_running(
+        &self,
+        instance_id: &str,
+        waiter_config: &waiter::WaiterConfig,
+    ) -> impl Future<Output = Result<ecs_instance::DescribeInstancesResultInstanceResp, error::Error>>;
+
+    /// Waits until an instance is `STOPPED`, e.g. after `new_stop_instances`.
+    ///
+    /// Polls `DescribeInstances` with the delay, backoff and timeout of `waiter_config`, and fails fast
+    /// when the instance reaches a failure status such as `Error`.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current service instance.
+    /// - `instance_id`: The ID of the instance.
+    /// - `waiter_config`: The delay, backoff and timeout, e.g. `waiter::WaiterConfig::default()`.
+    ///
+    /// # Returns
+    /// - `impl Future<Output = Result<ecs_instance::DescribeInstancesResultInstanceResp, error::Error>>`: On success, returns the instance once it is stopped.
+    ///   On failure, returns `error::Error::ErrWaiterFailure`, `error::Error::ErrWaiterTimeout` or the error of a poll.
+    fn wait_until_instance_stopped(
+        &self,
+        instance_id: &str,
+        waiter_config: &waiter::WaiterConfig,
+    ) -> impl Future<Output = Result<ecs_instance::DescribeInstancesResultInstanceResp, error::Error>>;
 }
 
 /// The `Ecs` struct represents the client for interacting with the ECS (Elastic Compute Service).
@@ -2,25 +2,23 @@
  * @Author: Jerry.Yang
  * @Date: 2024-10-28 16:45:54
  * @LastEditors: Jerry.Yang
- * @LastEditTime: 2025-02-05 16:59:31
+ * @LastEditTime: 2025-03-28 10:14:26
  * @Description: Service for ECS (Elastic Compute Service)
  */
-use crate::service::ecs::api_describe_images;
-use crate::service::ecs::api_describe_instances;
-use crate::service::ecs::api_describe_regions;
-use crate::service::ecs::api_describe_zones;
-use crate::service::ecs::api_modify_instance_spec;
-use crate::service::ecs::api_run_instances;
-use crate::service::ecs::api_stop_instance;
-use crate::service::ecs::api_stop_instances;
+use crate::service::ecs::api_instance_lifecycle_model;
+use crate::service::ecs::waiter_instance;
 use crate::service::ecs::{Ecs, EcsService};
 use crate::volcengine::client::client;
 use crate::volcengine::client::client_info;
 use crate::volcengine::client::config as client_config;
 use crate::volcengine::common;
 use crate::volcengine::error::error;
-use crate::volcengine::request::handles;
+use crate::volcengine::paginator::paginator;
+use crate::volcengine::registry::registry;
 use crate::volcengine::session::session;
+use crate::volcengine::waiter::waiter;
+use volcengine_sdk_protobuf::protobuf::ecs_image;
+use volcengine_sdk_protobuf::protobuf::ecs_instance;
 
 /// EcsService implementation for Ecs
 /// Implementation of the `EcsService` trait for the `Ecs` struct.
@@ -36,7 +34,7 @@ impl EcsService for Ecs {
     /// A `Result` containing an `Ecs` instance on success or an `error::Error` on failure.
     fn new_ecs(session: session::Session) -> Result<Self, error::Error> {
         // Create a new session and retrieve the client configuration
-        let client_config = session.new_client_config(client_config::ClientServiceName::Ecs);
+        let client_config = session.new_client_config(client_config::ClientServiceName::Ecs)?;
 
         // Set up client information
         let client_info = client_info::ClientInfo::builder()
@@ -45,8 +43,8 @@ impl EcsService for Ecs {
             .with_signing_region(&client_config.signing_region)
             .build()?;
 
-        // Create handles
-        let request_handles = handles::Handles {};
+        // Use the request handles registered on the session
+        let request_handles = client_config.handles.clone();
 
         // Build the client with the provided information and configuration
         let client = client::Client::builder()
@@ -60,7 +58,7 @@ impl EcsService for Ecs {
     }
 
     /// Initiates a request to run instances in ECS.
-    /// This method uses the internal `ApiRunInstance` to handle the request.
+    /// The operation is declared in the registry and sent with `registry::invoke`.
     ///
     /// # Parameters:
     /// - `&self`: A reference to the current instance of `Ecs`.
@@ -73,13 +71,11 @@ impl EcsService for Ecs {
         request: volcengine_sdk_protobuf::protobuf::ecs_instance::RunInstancesReq,
     ) -> Result<volcengine_sdk_protobuf::protobuf::ecs_instance::RunInstancesResp, error::Error>
     {
-        api_run_instances::ApiRunInstance
-            .new_run_instances(self, request)
-            .await
+        registry::invoke(&self.client, &registry::ECS_RUN_INSTANCES, &request).await
     }
 
     /// Initiates a request to describe instances in ECS.
-    /// This method uses the internal `ApiDescribeInstances` to handle the request.
+    /// The operation is declared in the registry and sent with `registry::invoke`.
     ///
     /// # Parameters:
     /// - `&self`: A reference to the current instance of `Ecs`.
@@ -92,13 +88,30 @@ impl EcsService for Ecs {
         request: volcengine_sdk_protobuf::protobuf::ecs_instance::DescribeInstancesReq,
     ) -> Result<volcengine_sdk_protobuf::protobuf::ecs_instance::DescribeInstancesResp, error::Error>
     {
-        api_describe_instances::ApiDescribeInstances
-            .new_describe_instances(self, request)
-            .await
+        registry::invoke(&self.client, &registry::ECS_DESCRIBE_INSTANCES, &request).await
+    }
+
+    /// Lists instances page by page.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current service instance.
+    /// - `request`: The request of the first page.
+    ///
+    /// # Returns
+    /// - `paginator::Paginator<ecs_instance::DescribeInstancesReq>`: A paginator sending `new_describe_instances` once per page.
+    fn describe_instances_paginator(
+        &self,
+        request: ecs_instance::DescribeInstancesReq,
+    ) -> paginator::Paginator<ecs_instance::DescribeInstancesReq> {
+        let ecs = self.clone();
+        paginator::Paginator::new(request, move |request| {
+            let ecs = ecs.clone();
+            Box::pin(async move { ecs.new_describe_instances(request).await })
+        })
     }
 
     /// Initiates a request to stop a single instance in ECS.
-    /// This method uses the internal `ApiStopInstance` to handle the request.
+    /// The operation is declared in the registry and sent with `registry::invoke`.
     ///
     /// # Parameters:
     /// - `&self`: A reference to the current instance of `Ecs`.
@@ -111,13 +124,11 @@ impl EcsService for Ecs {
         request: volcengine_sdk_protobuf::protobuf::ecs_instance::StopInstanceReq,
     ) -> Result<volcengine_sdk_protobuf::protobuf::ecs_instance::StopInstanceResp, error::Error>
     {
-        api_stop_instance::ApiStopInstance
-            .new_stop_instance(self, request)
-            .await
+        registry::invoke(&self.client, &registry::ECS_STOP_INSTANCE, &request).await
     }
 
     /// Initiates a request to stop multiple instances in ECS.
-    /// This method uses the internal `ApiStopInstances` to handle the request.
+    /// The operation is declared in the registry and sent with `registry::invoke`.
     ///
     /// # Parameters:
     /// - `&self`: A reference to the current instance of `Ecs`.
@@ -130,13 +141,11 @@ impl EcsService for Ecs {
         request: volcengine_sdk_protobuf::protobuf::ecs_instance::StopInstancesReq,
     ) -> Result<volcengine_sdk_protobuf::protobuf::ecs_instance::StopInstancesResp, error::Error>
     {
-        api_stop_instances::ApiStopInstances
-            .new_stop_instances(self, request)
-            .await
+        registry::invoke(&self.client, &registry::ECS_STOP_INSTANCES, &request).await
     }
 
     /// Initiates a request to modify the specifications of an instance in ECS.
-    /// This method uses the internal `ApiModifyInstanceSpec` to handle the request.
+    /// The operation is declared in the registry and sent with `registry::invoke`.
     ///
     /// # Parameters:
     /// - `&self`: A reference to the current instance of `Ecs`.
@@ -149,13 +158,181 @@ impl EcsService for Ecs {
         request: volcengine_sdk_protobuf::protobuf::ecs_instance::ModifyInstanceSpecReq,
     ) -> Result<volcengine_sdk_protobuf::protobuf::ecs_instance::ModifyInstanceSpecResp, error::Error>
     {
-        api_modify_instance_spec::ApiModifyInstanceSpec
-            .new_modify_instance_spec(self, request)
-            .await
+        registry::invoke(&self.client, &registry::ECS_MODIFY_INSTANCE_SPEC, &request).await
+    }
+
+    /// Initiates a request to start a stopped instance in ECS.
+    /// The operation is declared in the registry and sent with `registry::invoke`.
+    ///
+    /// # Parameters:
+    /// - `&self`: A reference to the current instance of `Ecs`.
+    /// - `request`: A `StartInstanceReq` object containing the parameters of the `StartInstance` API.
+    ///
+    /// # Returns:
+    /// A `Result` containing a `StartInstanceResp` on success or an `error::Error` on failure.
+    async fn new_start_instance(
+        &self,
+        request: api_instance_lifecycle_model::StartInstanceReq,
+    ) -> Result<api_instance_lifecycle_model::StartInstanceResp, error::Error> {
+        registry::invoke(&self.client, &registry::ECS_START_INSTANCE, &request).await
+    }
+
+    /// Initiates a request to start multiple stopped instances in ECS.
+    /// The operation is declared in the registry and sent with `registry::invoke`.
+    ///
+    /// # Parameters:
+    /// - `&self`: A reference to the current instance of `Ecs`.
+    /// - `request`: A `StartInstancesReq` object containing the parameters of the `StartInstances` API.
+    ///
+    /// # Returns:
+    /// A `Result` containing a `StartInstancesResp` on success or an `error::Error` on failure.
+    async fn new_start_instances(
+        &self,
+        request: api_instance_lifecycle_model::StartInstancesReq,
+    ) -> Result<api_instance_lifecycle_model::StartInstancesResp, error::Error> {
+        registry::invoke(&self.client, &registry::ECS_START_INSTANCES, &request).await
+    }
+
+    /// Initiates a request to reboot a running instance in ECS.
+    /// The operation is declared in the registry and sent with `registry::invoke`.
+    ///
+    /// # Parameters:
+    /// - `&self`: A reference to the current instance of `Ecs`.
+    /// - `request`: A `RebootInstanceReq` object containing the parameters of the `RebootInstance` API.
+    ///
+    /// # Returns:
+    /// A `Result` containing a `RebootInstanceResp` on success or an `error::Error` on failure.
+    async fn new_reboot_instance(
+        &self,
+        request: api_instance_lifecycle_model::RebootInstanceReq,
+    ) -> Result<api_instance_lifecycle_model::RebootInstanceResp, error::Error> {
+        registry::invoke(&self.client, &registry::ECS_REBOOT_INSTANCE, &request).await
+    }
+
+    /// Initiates a request to reboot multiple running instances in ECS.
+    /// The operation is declared in the registry and sent with `registry::invoke`.
+    ///
+    /// # Parameters:
+    /// - `&self`: A reference to the current instance of `Ecs`.
+    /// - `request`: A `RebootInstancesReq` object containing the parameters of the `RebootInstances` API.
+    ///
+    /// # Returns:
+    /// A `Result` containing a `RebootInstancesResp` on success or an `error::Error` on failure.
+    async fn new_reboot_instances(
+        &self,
+        request: api_instance_lifecycle_model::RebootInstancesReq,
+    ) -> Result<api_instance_lifecycle_model::RebootInstancesResp, error::Error> {
+        registry::invoke(&self.client, &registry::ECS_REBOOT_INSTANCES, &request).await
+    }
+
+    /// Initiates a request to release an instance in ECS.
+    /// The operation is declared in the registry and sent with `registry::invoke`.
+    ///
+    /// # Parameters:
+    /// - `&self`: A reference to the current instance of `Ecs`.
+    /// - `request`: A `DeleteInstanceReq` object containing the parameters of the `DeleteInstance` API.
+    ///
+    /// # Returns:
+    /// A `Result` containing a `DeleteInstanceResp` on success or an `error::Error` on failure.
+    async fn new_delete_instance(
+        &self,
+        request: api_instance_lifecycle_model::DeleteInstanceReq,
+    ) -> Result<api_instance_lifecycle_model::DeleteInstanceResp, error::Error> {
+        registry::invoke(&self.client, &registry::ECS_DELETE_INSTANCE, &request).await
+    }
+
+    /// Initiates a request to release multiple instances in ECS.
+    /// The operation is declared in the registry and sent with `registry::invoke`.
+    ///
+    /// # Parameters:
+    /// - `&self`: A reference to the current instance of `Ecs`.
+    /// - `request`: A `DeleteInstancesReq` object containing the parameters of the `DeleteInstances` API.
+    ///
+    /// # Returns:
+    /// A `Result` containing a `DeleteInstancesResp` on success or an `error::Error` on failure.
+    async fn new_delete_instances(
+        &self,
+        request: api_instance_lifecycle_model::DeleteInstancesReq,
+    ) -> Result<api_instance_lifecycle_model::DeleteInstancesResp, error::Error> {
+        registry::invoke(&self.client, &registry::ECS_DELETE_INSTANCES, &request).await
+    }
+
+    /// Initiates a request to modify the name, description, password or user data of an instance in ECS.
+    /// The operation is declared in the registry and sent with `registry::invoke`.
+    ///
+    /// # Parameters:
+    /// - `&self`: A reference to the current instance of `Ecs`.
+    /// - `request`: A `ModifyInstanceAttributeReq` object containing the parameters of the `ModifyInstanceAttribute` API.
+    ///
+    /// # Returns:
+    /// A `Result` containing a `ModifyInstanceAttributeResp` on success or an `error::Error` on failure.
+    async fn new_modify_instance_attribute(
+        &self,
+        request: api_instance_lifecycle_model::ModifyInstanceAttributeReq,
+    ) -> Result<api_instance_lifecycle_model::ModifyInstanceAttributeResp, error::Error> {
+        registry::invoke(
+            &self.client,
+            &registry::ECS_MODIFY_INSTANCE_ATTRIBUTE,
+            &request,
+        )
+        .await
+    }
+
+    /// Initiates a request to renew a subscription (`PrePaid`) instance in ECS.
+    /// The operation is declared in the registry and sent with `registry::invoke`.
+    ///
+    /// # Parameters:
+    /// - `&self`: A reference to the current instance of `Ecs`.
+    /// - `request`: A `RenewInstanceReq` object containing the parameters of the `RenewInstance` API.
+    ///
+    /// # Returns:
+    /// A `Result` containing a `RenewInstanceResp` on success or an `error::Error` on failure.
+    async fn new_renew_instance(
+        &self,
+        request: api_instance_lifecycle_model::RenewInstanceReq,
+    ) -> Result<api_instance_lifecycle_model::RenewInstanceResp, error::Error> {
+        registry::invoke(&self.client, &registry::ECS_RENEW_INSTANCE, &request).await
+    }
+
+    /// Initiates a request to switch the billing method of instances in ECS.
+    /// The operation is declared in the registry and sent with `registry::invoke`.
+    ///
+    /// # Parameters:
+    /// - `&self`: A reference to the current instance of `Ecs`.
+    /// - `request`: A `ModifyInstanceChargeTypeReq` object containing the parameters of the `ModifyInstanceChargeType` API.
+    ///
+    /// # Returns:
+    /// A `Result` containing a `ModifyInstanceChargeTypeResp` on success or an `error::Error` on failure.
+    async fn new_modify_instance_charge_type(
+        &self,
+        request: api_instance_lifecycle_model::ModifyInstanceChargeTypeReq,
+    ) -> Result<api_instance_lifecycle_model::ModifyInstanceChargeTypeResp, error::Error> {
+        registry::invoke(
+            &self.client,
+            &registry::ECS_MODIFY_INSTANCE_CHARGE_TYPE,
+            &request,
+        )
+        .await
+    }
+
+    /// Initiates a request to replace the system volume of a stopped instance with a new image in ECS.
+    /// The operation is declared in the registry and sent with `registry::invoke`.
+    ///
+    /// # Parameters:
+    /// - `&self`: A reference to the current instance of `Ecs`.
+    /// - `request`: A `ReplaceSystemVolumeReq` object containing the parameters of the `ReplaceSystemVolume` API.
+    ///
+    /// # Returns:
+    /// A `Result` containing a `ReplaceSystemVolumeResp` on success or an `error::Error` on failure.
+    async fn new_replace_system_volume(
+        &self,
+        request: api_instance_lifecycle_model::ReplaceSystemVolumeReq,
+    ) -> Result<api_instance_lifecycle_model::ReplaceSystemVolumeResp, error::Error> {
+        registry::invoke(&self.client, &registry::ECS_REPLACE_SYSTEM_VOLUME, &request).await
     }
 
     /// Initiates a request to describe images in ECS.
-    /// This method uses the internal `ApiDescribeImagesEcs` to handle the request.
+    /// The operation is declared in the registry and sent with `registry::invoke`.
     ///
     /// # Parameters:
     /// - `&self`: A reference to the current instance of `Ecs`.
@@ -168,13 +345,30 @@ impl EcsService for Ecs {
         request: volcengine_sdk_protobuf::protobuf::ecs_image::DescribeImagesReq,
     ) -> Result<volcengine_sdk_protobuf::protobuf::ecs_image::DescribeImagesResp, error::Error>
     {
-        api_describe_images::ApiDescribeImagesEcs
-            .new_describe_images(self, request)
-            .await
+        registry::invoke(&self.client, &registry::ECS_DESCRIBE_IMAGES, &request).await
+    }
+
+    /// Lists images page by page.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current service instance.
+    /// - `request`: The request of the first page.
+    ///
+    /// # Returns
+    /// - `paginator::Paginator<ecs_image::DescribeImagesReq>`: A paginator sending `new_describe_images` once per page.
+    fn describe_images_paginator(
+        &self,
+        request: ecs_image::DescribeImagesReq,
+    ) -> paginator::Paginator<ecs_image::DescribeImagesReq> {
+        let ecs = self.clone();
+        paginator::Paginator::new(request, move |request| {
+            let ecs = ecs.clone();
+            Box::pin(async move { ecs.new_describe_images(request).await })
+        })
     }
 
     /// Initiates a request to describe regions in ECS.
-    /// This method uses the internal `ApiDescribeRegionsEcs` to handle the request.
+    /// The operation is declared in the registry and sent with `registry::invoke`.
     ///
     /// # Parameters:
     /// - `&self`: A reference to the current instance of `Ecs`.
@@ -187,13 +381,11 @@ impl EcsService for Ecs {
         request: volcengine_sdk_protobuf::protobuf::ecs_zone::DescribeRegionsReq,
     ) -> Result<volcengine_sdk_protobuf::protobuf::ecs_zone::DescribeRegionsResp, error::Error>
     {
-        api_describe_regions::ApiDescribeRegionsEcs
-            .new_describe_regions(self, request)
-            .await
+        registry::invoke(&self.client, &registry::ECS_DESCRIBE_REGIONS, &request).await
     }
 
     /// Initiates a request to describe zones in ECS.
-    /// This method uses the internal `ApiDescribeZonesEcs` to handle the request.
+    /// The operation is declared in the registry and sent with `registry::invoke`.
     ///
     /// # Parameters:
     /// - `&self`: A reference to the current instance of `Ecs`.
@@ -205,8 +397,56 @@ impl EcsService for Ecs {
         &self,
         request: volcengine_sdk_protobuf::protobuf::ecs_zone::DescribeZonesReq,
     ) -> Result<volcengine_sdk_protobuf::protobuf::ecs_zone::DescribeZonesResp, error::Error> {
-        api_describe_zones::ApiDescribeZonesEcs
-            .new_describe_zones(self, request)
+        registry::invoke(&self.client, &registry::ECS_DESCRIBE_ZONES, &request).await
+    }
+
+    /// Waits until an instance is `RUNNING`.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current service instance.
+    /// - `instance_id`: The ID of the instance.
+    /// - `waiter_config`: The delay, backoff and timeout.
+    ///
+    /// # Returns
+    /// - `Result<ecs_instance::DescribeInstancesResultInstanceResp, error::Error>`: The instance once it reached the status, or the reason the wait ended.
+    async fn wait_until_instance_running(
+        &self,
+        instance_id: &str,
+        waiter_config: &waiter::WaiterConfig,
+    ) -> Result<ecs_instance::DescribeInstancesResultInstanceResp, error::Error> {
+        waiter_instance::WaiterInstanceEcs
+            .wait_until_instance_status(
+                self,
+                "InstanceRunning",
+                instance_id,
+                waiter_instance::INSTANCE_STATUS_RUNNING,
+                waiter_config,
+            )
+            .await
+    }
+
+    /// Waits until an instance is `STOPPED`.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current service instance.
+    /// - `instance_id`: The ID of the instance.
+    /// - `waiter_config`: The delay, backoff and timeout.
+    ///
+    /// # Returns
+    /// - `Result<ecs_instance::DescribeInstancesResultInstanceResp, error::Error>`: The instance once it reached the status, or the reason the wait ended.
+    async fn wait_until_instance_stopped(
+        &self,
+        instance_id: &str,
+        waiter_config: &waiter::WaiterConfig,
+    ) -> Result<ecs_instance::DescribeInstancesResultInstanceResp, error::Error> {
+        waiter_instance::WaiterInstanceEcs
+            .wait_until_instance_status(
+                self,
+                "InstanceStopped",
+                instance_id,
+                waiter_instance::INSTANCE_STATUS_STOPPED,
+                waiter_config,
+            )
             .await
     }
 }
@@ -2,118 +2,660 @@
  * @Author: Jerry.Yang
  * @Date: 2024-10-22 13:52:22
  * @LastEditors: Jerry.Yang
- * @LastEditTime: 2025-02-19 16:03:19
- * @Description: create_user test
+ * @LastEditTime: 2025-03-30 10:05:00
+ * @Description: ecs test
  */
 #[cfg(test)]
 mod tests {
+    use futures::TryStreamExt;
+    use std::time::Duration;
+    use volcengine_sdk_protobuf::protobuf::{ecs_image, ecs_instance, ecs_zone};
+
     use crate::{
         service::ecs,
+        service::ecs::api_instance_lifecycle_model,
         service::ecs::EcsService,
-        volcengine::{config, credentials::credentials, session::session},
+        volcengine::{error::error, mock::mock, waiter::waiter},
     };
 
+    /// The API version of ECS.
+    const VERSION: &str = "2020-04-01";
+
+    /// Starts a mock server answering `action` with `response`, and an `Ecs` calling it.
+    async fn mock_ecs(action: &str, response: mock::MockResponse) -> (mock::MockServer, ecs::Ecs) {
+        let server = mock::MockServer::start().await;
+        server.mock(action, VERSION, response);
+        let ecs = ecs::Ecs::new_ecs(server.session()).unwrap();
+        (server, ecs)
+    }
+
+    /// Returns a `DescribeInstances` response listing one instance in `status`.
+    fn describe_instances_response(
+        instance_id: &str,
+        status: &str,
+        next_token: Option<&str>,
+    ) -> mock::MockResponse {
+        mock::MockResponse::json(&ecs_instance::DescribeInstancesResp {
+            result: Some(ecs_instance::DescribeInstancesResultResp {
+                next_token: next_token.map(str::to_string),
+                instances: vec![ecs_instance::DescribeInstancesResultInstanceResp {
+                    instance_id: Some(instance_id.to_string()),
+                    status: Some(status.to_string()),
+                    ..Default::default()
+                }],
+            }),
+            ..Default::default()
+        })
+    }
+
+    /// Returns a waiter config polling without delay.
+    fn fast_waiter_config() -> waiter::WaiterConfig {
+        waiter::WaiterConfig::builder()
+            .with_delay(Duration::from_millis(1))
+            .with_backoff(1.0)
+            .with_timeout(Duration::from_secs(5))
+            .build()
+    }
+
     #[tokio::test]
     async fn test_get_user() {
-        let access_key_id = ""; // 这里填入实际的 Access Key ID
-        let secret_access_key = ""; // 这里填入实际的 Secret Access Key
-        let region_id = "cn-beijing"; // 这里填入实际的 Region ID
+        let (server, ecs) = mock_ecs(
+            "DescribeRegions",
+            mock::MockResponse::json(&ecs_zone::DescribeRegionsResp {
+                result: Some(ecs_zone::DescribeRegionsResultResp {
+                    regions: vec![ecs_zone::DescribeRegionsResultRegionResp {
+                        region_id: "cn-beijing".to_string(),
+                    }],
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+        )
+        .await;
 
-        let credentials = credentials::Credentials::new(access_key_id, secret_access_key);
+        // describe regions
+        let mut request = ecs_zone::DescribeRegionsReq::default();
+        request.max_results = 1;
+        let result = ecs.new_describe_regions(request).await.unwrap();
 
-        // new config
-        let config = config::Config::builder()
-            .with_region(&region_id)
-            .with_credentials(credentials)
-            .build();
+        assert_eq!(result.result.unwrap().regions[0].region_id, "cn-beijing");
+        let request = server.single_request("DescribeRegions", "ecs");
+        assert_eq!(request.query["MaxResults"], "1");
+    }
 
-        // print config
-        println!("config : {:?}", config);
+    #[tokio::test]
+    async fn test_describe_zones() {
+        let (server, ecs) = mock_ecs(
+            "DescribeZones",
+            mock::MockResponse::json(&ecs_zone::DescribeZonesResp {
+                result: Some(ecs_zone::DescribeZonesResultResp {
+                    zones: vec![ecs_zone::DescribeZonesResultZoneResp {
+                        zone_id: "cn-beijing-a".to_string(),
+                    }],
+                }),
+                ..Default::default()
+            }),
+        )
+        .await;
 
-        // reset config
-        let config = config.unwrap();
+        let request = ecs_zone::DescribeZonesReq {
+            zone_ids: vec!["cn-beijing-a".to_string()],
+        };
+        let result = ecs.new_describe_zones(request).await.unwrap();
 
-        // new session
-        let session = session::Session::builder().with_config(config).build();
+        assert_eq!(result.result.unwrap().zones[0].zone_id, "cn-beijing-a");
+        let request = server.single_request("DescribeZones", "ecs");
+        assert_eq!(request.query["ZoneIds.1"], "cn-beijing-a");
+    }
 
-        // print session
-        println!("session : {:?}", session);
+    #[tokio::test]
+    async fn test_run_instances() {
+        let (server, ecs) = mock_ecs(
+            "RunInstances",
+            mock::MockResponse::json(&ecs_instance::RunInstancesResp {
+                result: Some(ecs_instance::RunInstancesResultResp {
+                    instance_ids: vec!["i-1".to_string()],
+                }),
+                ..Default::default()
+            }),
+        )
+        .await;
 
-        // reset session
-        let session = session.unwrap();
+        let request = ecs_instance::RunInstancesReq {
+            image_id: Some("image-1".to_string()),
+            instance_type_id: Some("ecs.g1.large".to_string()),
+            zone_id: Some("cn-beijing-a".to_string()),
+            count: Some(1),
+            ..Default::default()
+        };
+        let result = ecs.new_run_instances(request).await.unwrap();
 
-        // new ecs
-        let ecs = ecs::Ecs::new_ecs(session);
+        assert_eq!(result.result.unwrap().instance_ids, ["i-1"]);
+        let request = server.single_request("RunInstances", "ecs");
+        assert_eq!(request.query["ImageId"], "image-1");
+        assert_eq!(request.query["InstanceTypeId"], "ecs.g1.large");
+        assert_eq!(request.query["Count"], "1");
+    }
 
-        // print ecs
-        println!("ecs : {:?}", ecs);
+    #[tokio::test]
+    async fn test_describe_instances() {
+        let (server, ecs) = mock_ecs(
+            "DescribeInstances",
+            describe_instances_response("i-1", "RUNNING", None),
+        )
+        .await;
 
-        // reset ecs
-        let ecs = ecs.unwrap();
+        let request = ecs_instance::DescribeInstancesReq {
+            instance_ids: vec!["i-1".to_string()],
+            ..Default::default()
+        };
+        let result = ecs.new_describe_instances(request).await.unwrap();
 
-        // create_user
-        let mut request =
-            volcengine_sdk_protobuf::protobuf::ecs_zone::DescribeRegionsReq::default();
-        request.max_results = 1;
-        // request.auto_renew = Some(true);
-        // request.auto_renew_period = Some(1);
-        // request.count = Some(1);
-        // // request.dry_run = Some(true);
-        // request.image_id = Some("image-yde0q2u36nb6uofymxm5".to_string());
-        // request.instance_charge_type = Some("PrePaid".to_string());
-        // request.instance_name = Some("volcengine-bja-techcenter-pi-oa-api-[8,1]".to_string());
-        // request.instance_type_id = Some("ecs.t2-c2m1.large".to_string());
-        // request.key_pair_name = Some("ops".to_string());
-        // request.period = Some(1);
-        // request.project_name = Some("techcenter-pi-oa-api".to_string());
-        // request.suffix_index = Some(8);
-        // request.unique_suffix = Some(true);
-        // request.zone_id = Some("cn-beijing-a".to_string());
-
-        // // create instance volumes
-        // let mut request_volumes: Vec<
-        //     volcengine_sdk_protobuf::protobuf::ecs_instance::RunInstancesVolumesReq,
-        // > = Vec::new();
-        // let mut request_volume =
-        //     volcengine_sdk_protobuf::protobuf::ecs_instance::RunInstancesVolumesReq::default();
-        // let mut request_volume1 =
-        //     volcengine_sdk_protobuf::protobuf::ecs_instance::RunInstancesVolumesReq::default();
-        // request_volume.size = Some(100);
-        // // request_volume.volume_type = Some("ESSD_PL0".to_string());
-        // request_volume1.size = Some(100);
-        // request_volume1.volume_type = Some("Ultra_Disk".to_string());
-        // request_volumes.push(request_volume);
-        // request_volumes.push(request_volume1);
-        // request.volumes = request_volumes;
-
-        // // create instance tag
-        // let mut request_tags: Vec<
-        //     volcengine_sdk_protobuf::protobuf::ecs_instance::RunInstancesTagsReq,
-        // > = Vec::new();
-        // let mut request_tag =
-        //     volcengine_sdk_protobuf::protobuf::ecs_instance::RunInstancesTagsReq::default();
-        // request_tag.key = Some("cmdb_project_name".to_string());
-        // request_tag.value = Some("techcenter-pi-oa-api".to_string());
-        // request_tags.push(request_tag);
-        // request.tags = request_tags;
-
-        // // create instance networkinterfaces
-        // let mut request_networkinterfaces: Vec<
-        //     volcengine_sdk_protobuf::protobuf::ecs_instance::RunInstancesNetworkInterfacesReq,
-        // > = Vec::new();
-        // let mut request_networkitnerface = volcengine_sdk_protobuf::protobuf::ecs_instance::RunInstancesNetworkInterfacesReq::default();
-        // request_networkitnerface.security_group_ids =
-        //     vec!["sg-rs4s5xddbmyov0x57zbxmf9".to_string()];
-        // request_networkitnerface.subnet_id = Some("subnet-rrag8md0u22ov0x5890j4su".to_string());
-        // request_networkinterfaces.push(request_networkitnerface);
-        // request.network_interfaces = request_networkinterfaces;
-
-        // 运行异步代码的 helper 函数
-        let result = ecs.new_describe_regions(request).await;
-
-        println!("result : {:?}", result);
-
-        // 这里可以添加断言来检查结果
-        assert!(result.is_ok());
+        let instances = result.result.unwrap().instances;
+        assert_eq!(instances[0].status.as_deref(), Some("RUNNING"));
+        let request = server.single_request("DescribeInstances", "ecs");
+        assert_eq!(request.query["InstanceIds.1"], "i-1");
+    }
+
+    #[tokio::test]
+    async fn test_describe_instances_paginator() {
+        // two pages linked by NextToken
+        let server = mock::MockServer::start().await;
+        server.mock(
+            "DescribeInstances",
+            VERSION,
+            describe_instances_response("i-1", "RUNNING", Some("token-2")),
+        );
+        server.mock(
+            "DescribeInstances",
+            VERSION,
+            describe_instances_response("i-2", "RUNNING", None),
+        );
+        let ecs = ecs::Ecs::new_ecs(server.session()).unwrap();
+
+        // walk through every page, 10 instances at a time
+        let request = ecs_instance::DescribeInstancesReq {
+            max_results: Some(10),
+            ..Default::default()
+        };
+        let instances: Vec<_> = ecs
+            .describe_instances_paginator(request)
+            .items()
+            .try_collect()
+            .await
+            .unwrap();
+
+        let instance_ids: Vec<_> = instances
+            .iter()
+            .map(|instance| instance.instance_id.as_deref().unwrap())
+            .collect();
+        assert_eq!(instance_ids, ["i-1", "i-2"]);
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].query["MaxResults"], "10");
+        assert_eq!(requests[1].query["NextToken"], "token-2");
+    }
+
+    #[tokio::test]
+    async fn test_stop_instance() {
+        let (server, ecs) = mock_ecs(
+            "StopInstance",
+            mock::MockResponse::json(&ecs_instance::StopInstanceResp::default()),
+        )
+        .await;
+
+        let request = ecs_instance::StopInstanceReq {
+            instance_id: Some("i-1".to_string()),
+            force_stop: Some(true),
+            ..Default::default()
+        };
+        ecs.new_stop_instance(request).await.unwrap();
+
+        let request = server.single_request("StopInstance", "ecs");
+        assert_eq!(request.query["InstanceId"], "i-1");
+        assert_eq!(request.query["ForceStop"], "true");
+    }
+
+    #[tokio::test]
+    async fn test_stop_instances() {
+        let (server, ecs) = mock_ecs(
+            "StopInstances",
+            mock::MockResponse::json(&ecs_instance::StopInstancesResp::default()),
+        )
+        .await;
+
+        let request = ecs_instance::StopInstancesReq {
+            instance_ids: vec!["i-1".to_string(), "i-2".to_string()],
+            ..Default::default()
+        };
+        ecs.new_stop_instances(request).await.unwrap();
+
+        let request = server.single_request("StopInstances", "ecs");
+        assert_eq!(request.query["InstanceIds.1"], "i-1");
+        assert_eq!(request.query["InstanceIds.2"], "i-2");
+    }
+
+    #[tokio::test]
+    async fn test_modify_instance_spec() {
+        let (server, ecs) = mock_ecs(
+            "ModifyInstanceSpec",
+            mock::MockResponse::json(&ecs_instance::ModifyInstanceSpecResp::default()),
+        )
+        .await;
+
+        let request = ecs_instance::ModifyInstanceSpecReq {
+            instance_id: Some("i-1".to_string()),
+            instance_type_id: Some("ecs.g1.xlarge".to_string()),
+            ..Default::default()
+        };
+        ecs.new_modify_instance_spec(request).await.unwrap();
+
+        let request = server.single_request("ModifyInstanceSpec", "ecs");
+        assert_eq!(request.query["InstanceTypeId"], "ecs.g1.xlarge");
+    }
+
+    #[tokio::test]
+    async fn test_start_instance() {
+        let (server, ecs) = mock_ecs("StartInstance", mock::MockResponse::empty()).await;
+
+        let request = api_instance_lifecycle_model::StartInstanceReq {
+            instance_id: "i-1".to_string(),
+        };
+        ecs.new_start_instance(request).await.unwrap();
+
+        let request = server.single_request("StartInstance", "ecs");
+        assert_eq!(request.query["InstanceId"], "i-1");
+    }
+
+    #[tokio::test]
+    async fn test_start_instances() {
+        let (server, ecs) = mock_ecs(
+            "StartInstances",
+            mock::MockResponse::json(&serde_json::json!({
+                "Result": {
+                    "OperationDetails": [
+                        { "InstanceId": "i-1" },
+                        {
+                            "InstanceId": "i-2",
+                            "Error": { "Code": "InvalidInstance.NotFound", "Message": "not found" },
+                        },
+                    ],
+                },
+            })),
+        )
+        .await;
+
+        let request = api_instance_lifecycle_model::StartInstancesReq {
+            instance_ids: vec!["i-1".to_string(), "i-2".to_string()],
+        };
+        let response = ecs.new_start_instances(request).await.unwrap();
+
+        // the batch succeeds, the failed instances carry their error
+        let details = response.result.unwrap().operation_details;
+        assert!(details[0].error.is_none());
+        assert_eq!(
+            details[1].error.as_ref().unwrap().code,
+            "InvalidInstance.NotFound"
+        );
+
+        let request = server.single_request("StartInstances", "ecs");
+        assert_eq!(request.query["InstanceIds.1"], "i-1");
+        assert_eq!(request.query["InstanceIds.2"], "i-2");
+    }
+
+    #[tokio::test]
+    async fn test_reboot_instances() {
+        let (server, ecs) = mock_ecs("RebootInstances", mock::MockResponse::empty()).await;
+
+        let request = api_instance_lifecycle_model::RebootInstancesReq {
+            instance_ids: vec!["i-1".to_string()],
+            force_stop: Some(true),
+        };
+        ecs.new_reboot_instances(request).await.unwrap();
+
+        let request = server.single_request("RebootInstances", "ecs");
+        assert_eq!(request.query["InstanceIds.1"], "i-1");
+        assert_eq!(request.query["ForceStop"], "true");
+    }
+
+    #[tokio::test]
+    async fn test_delete_instance() {
+        let (server, ecs) = mock_ecs(
+            "DeleteInstance",
+            mock::MockResponse::error(400, "OperationDenied.InstanceLocked", "locked"),
+        )
+        .await;
+
+        let request = api_instance_lifecycle_model::DeleteInstanceReq {
+            instance_id: "i-1".to_string(),
+        };
+        let result = ecs.new_delete_instance(request).await;
+
+        assert!(matches!(
+            result,
+            Err(error::Error::Api { code, .. }) if code == "OperationDenied.InstanceLocked"
+        ));
+        server.single_request("DeleteInstance", "ecs");
+    }
+
+    #[tokio::test]
+    async fn test_modify_instance_attribute() {
+        let (server, ecs) = mock_ecs("ModifyInstanceAttribute", mock::MockResponse::empty()).await;
+
+        let request = api_instance_lifecycle_model::ModifyInstanceAttributeReq {
+            instance_id: "i-1".to_string(),
+            instance_name: Some("worker-1".to_string()),
+            password: Some("Secret-1".to_string()),
+            ..Default::default()
+        };
+        // the password is not printed
+        let debug = format!("{:?}", request);
+        assert!(!debug.contains("Secret-1"));
+        assert!(debug.contains("worker-1"));
+        ecs.new_modify_instance_attribute(request).await.unwrap();
+
+        // only the attributes set are sent, in a form body rather than the URL
+        let request = server.single_request("ModifyInstanceAttribute", "ecs");
+        assert_eq!(request.method, http::Method::POST);
+        assert_eq!(
+            request.headers[http::header::CONTENT_TYPE],
+            "application/x-www-form-urlencoded"
+        );
+        let form = request.form();
+        assert_eq!(form["InstanceId"], "i-1");
+        assert_eq!(form["InstanceName"], "worker-1");
+        assert_eq!(form["Password"], "Secret-1");
+        assert!(!form.contains_key("Description"));
+        assert!(!request.query.contains_key("Password"));
+    }
+
+    #[tokio::test]
+    async fn test_renew_instance() {
+        let (server, ecs) = mock_ecs(
+            "RenewInstance",
+            mock::MockResponse::json(&serde_json::json!({
+                "Result": { "OrderId": "order-1" },
+            })),
+        )
+        .await;
+
+        let request = api_instance_lifecycle_model::RenewInstanceReq {
+            instance_id: "i-1".to_string(),
+            period: Some(3),
+            period_unit: Some("Month".to_string()),
+            ..Default::default()
+        };
+        let response = ecs.new_renew_instance(request).await.unwrap();
+        assert_eq!(response.result.unwrap().order_id, "order-1");
+
+        let request = server.single_request("RenewInstance", "ecs");
+        assert_eq!(request.query["Period"], "3");
+        assert_eq!(request.query["PeriodUnit"], "Month");
+    }
+
+    #[tokio::test]
+    async fn test_modify_instance_charge_type() {
+        let (server, ecs) = mock_ecs(
+            "ModifyInstanceChargeType",
+            mock::MockResponse::json(&serde_json::json!({
+                "Result": { "OrderId": "order-2" },
+            })),
+        )
+        .await;
+
+        let request = api_instance_lifecycle_model::ModifyInstanceChargeTypeReq {
+            instance_ids: vec!["i-1".to_string()],
+            instance_charge_type: "PostPaid".to_string(),
+            include_data_volumes: Some(true),
+            ..Default::default()
+        };
+        let response = ecs.new_modify_instance_charge_type(request).await.unwrap();
+        assert_eq!(response.result.unwrap().order_id, "order-2");
+
+        let request = server.single_request("ModifyInstanceChargeType", "ecs");
+        assert_eq!(request.query["InstanceIds.1"], "i-1");
+        assert_eq!(request.query["InstanceChargeType"], "PostPaid");
+        assert_eq!(request.query["IncludeDataVolumes"], "true");
+    }
+
+    #[tokio::test]
+    async fn test_replace_system_volume() {
+        let (server, ecs) = mock_ecs("ReplaceSystemVolume", mock::MockResponse::empty()).await;
+
+        let request = api_instance_lifecycle_model::ReplaceSystemVolumeReq {
+            instance_id: "i-1".to_string(),
+            image_id: "image-1".to_string(),
+            keep_image_credential: Some(false),
+            password: Some("Secret-2".to_string()),
+            ..Default::default()
+        };
+        assert!(!format!("{:?}", request).contains("Secret-2"));
+        ecs.new_replace_system_volume(request).await.unwrap();
+
+        let request = server.single_request("ReplaceSystemVolume", "ecs");
+        assert_eq!(request.method, http::Method::POST);
+        let form = request.form();
+        assert_eq!(form["ImageId"], "image-1");
+        assert_eq!(form["KeepImageCredential"], "false");
+        assert_eq!(form["Password"], "Secret-2");
+        assert!(!request.query.contains_key("Password"));
+    }
+
+    #[tokio::test]
+    async fn test_describe_images() {
+        let (server, ecs) = mock_ecs(
+            "DescribeImages",
+            mock::MockResponse::json(&ecs_image::DescribeImagesResp {
+                result: Some(ecs_image::DescribeImagesResultResp {
+                    images: vec![ecs_image::DescribeImagesResultImageResp {
+                        image_id: Some("image-1".to_string()),
+                        ..Default::default()
+                    }],
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+        )
+        .await;
+
+        let request = ecs_image::DescribeImagesReq {
+            os_type: Some("Linux".to_string()),
+            ..Default::default()
+        };
+        let result = ecs.new_describe_images(request).await.unwrap();
+
+        let images = result.result.unwrap().images;
+        assert_eq!(images[0].image_id.as_deref(), Some("image-1"));
+        let request = server.single_request("DescribeImages", "ecs");
+        assert_eq!(request.query["OsType"], "Linux");
+    }
+
+    #[tokio::test]
+    async fn test_describe_images_paginator() {
+        let (server, ecs) = mock_ecs(
+            "DescribeImages",
+            mock::MockResponse::json(&ecs_image::DescribeImagesResp {
+                result: Some(ecs_image::DescribeImagesResultResp {
+                    images: vec![ecs_image::DescribeImagesResultImageResp::default(); 2],
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+        )
+        .await;
+
+        let images: Vec<_> = ecs
+            .describe_images_paginator(ecs_image::DescribeImagesReq::default())
+            .items()
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(images.len(), 2);
+        server.single_request("DescribeImages", "ecs");
+    }
+
+    #[tokio::test]
+    async fn test_wait_until_instance_running() {
+        // the instance starts, then runs
+        let server = mock::MockServer::start().await;
+        server.mock(
+            "DescribeInstances",
+            VERSION,
+            describe_instances_response("i-1", "STARTING", None),
+        );
+        server.mock(
+            "DescribeInstances",
+            VERSION,
+            describe_instances_response("i-1", "RUNNING", None),
+        );
+        let ecs = ecs::Ecs::new_ecs(server.session()).unwrap();
+
+        let instance = ecs
+            .wait_until_instance_running("i-1", &fast_waiter_config())
+            .await
+            .unwrap();
+
+        assert_eq!(instance.status.as_deref(), Some("RUNNING"));
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[1].query["InstanceIds.1"], "i-1");
+    }
+
+    #[tokio::test]
+    async fn test_wait_until_instance_stopped() {
+        let (_server, ecs) = mock_ecs(
+            "DescribeInstances",
+            describe_instances_response("i-1", "ERROR", None),
+        )
+        .await;
+
+        // the instance failed, so it never stops
+        let result = ecs
+            .wait_until_instance_stopped("i-1", &fast_waiter_config())
+            .await;
+
+        assert!(matches!(result, Err(error::Error::ErrWaiterFailure { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_wait_until_instance_not_found() {
+        let (server, ecs) = mock_ecs(
+            "DescribeInstances",
+            mock::MockResponse::json(&ecs_instance::DescribeInstancesResp::default()),
+        )
+        .await;
+
+        // the instance is never listed, so the waiter fails after max_not_found polls
+        let result = ecs
+            .wait_until_instance_running("i-1", &fast_waiter_config())
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(error::Error::ErrWaiterFailure { state, .. }) if state == "NOT_FOUND"
+        ));
+        assert_eq!(
+            server.requests().len(),
+            waiter::DEFAULT_MAX_NOT_FOUND as usize
+        );
+    }
+
+    #[tokio::test]
+    async fn test_wait_until_instance_not_found_reset() {
+        // the instance is missing twice, listed, missing twice again, then runs
+        let server = mock::MockServer::start().await;
+        let missing = || mock::MockResponse::json(&ecs_instance::DescribeInstancesResp::default());
+        server.mock("DescribeInstances", VERSION, missing());
+        server.mock("DescribeInstances", VERSION, missing());
+        server.mock(
+            "DescribeInstances",
+            VERSION,
+            describe_instances_response("i-1", "PENDING", None),
+        );
+        server.mock("DescribeInstances", VERSION, missing());
+        server.mock("DescribeInstances", VERSION, missing());
+        server.mock(
+            "DescribeInstances",
+            VERSION,
+            describe_instances_response("i-1", "RUNNING", None),
+        );
+        let ecs = ecs::Ecs::new_ecs(server.session()).unwrap();
+
+        let instance = ecs
+            .wait_until_instance_running("i-1", &fast_waiter_config())
+            .await
+            .unwrap();
+
+        assert_eq!(instance.status.as_deref(), Some("RUNNING"));
+        assert_eq!(server.requests().len(), 6);
+    }
+
+    #[tokio::test]
+    async fn test_wait_until_instance_max_not_found() {
+        let (server, ecs) = mock_ecs(
+            "DescribeInstances",
+            mock::MockResponse::json(&ecs_instance::DescribeInstancesResp::default()),
+        )
+        .await;
+
+        let waiter_config = waiter::WaiterConfig::builder()
+            .with_delay(Duration::from_millis(1))
+            .with_timeout(Duration::from_secs(5))
+            .with_max_not_found(1)
+            .build();
+        let result = ecs.wait_until_instance_running("i-1", &waiter_config).await;
+
+        assert!(matches!(result, Err(error::Error::ErrWaiterFailure { .. })));
+        server.single_request("DescribeInstances", "ecs");
+    }
+
+    #[tokio::test]
+    async fn test_wait_until_instance_spawned() {
+        let (server, ecs) = mock_ecs(
+            "DescribeInstances",
+            describe_instances_response("i-1", "RUNNING", None),
+        )
+        .await;
+
+        // the waiters are `Send`, so that they can run on their own task
+        fn assert_send<T: Send>(future: T) -> T {
+            future
+        }
+        let waiter_config = fast_waiter_config();
+        let stopped = assert_send(ecs.wait_until_instance_stopped("i-1", &waiter_config));
+        drop(stopped);
+        let instance = tokio::spawn(async move {
+            ecs.wait_until_instance_running("i-1", &fast_waiter_config())
+                .await
+        })
+        .await
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(instance.status.as_deref(), Some("RUNNING"));
+        server.single_request("DescribeInstances", "ecs");
+    }
+
+    #[test]
+    fn test_blocking_describe_instances() {
+        // the mock server runs on its own runtime, the facade blocks on the shared one
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let server = runtime.block_on(mock::MockServer::start());
+        server.mock(
+            "DescribeInstances",
+            VERSION,
+            describe_instances_response("i-1", "RUNNING", None),
+        );
+
+        let ecs = ecs::blocking::Ecs::new_ecs(server.session()).unwrap();
+        let instances: Vec<_> = ecs
+            .describe_instances_paginator(ecs_instance::DescribeInstancesReq::default())
+            .items()
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        assert_eq!(instances[0].instance_id.as_deref(), Some("i-1"));
+        server.single_request("DescribeInstances", "ecs");
     }
 }
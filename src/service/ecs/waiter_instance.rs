@@ -0,0 +1,78 @@
+/*
+ * @Author: Jerry.Yang
+ * @Date: 2025-03-12 14:02:37
+ * @LastEditors: Jerry.Yang
+ * @LastEditTime: 2025-03-29 20:05:00
+ * @Description: Waiters for ECS instance state transitions
+ */
+use crate::service::ecs;
+use crate::service::ecs::EcsService;
+use crate::volcengine::error::error;
+use crate::volcengine::waiter::waiter;
+use volcengine_sdk_protobuf::protobuf::ecs_instance;
+
+/// The status of a running instance.
+pub const INSTANCE_STATUS_RUNNING: &str = "RUNNING";
+
+/// The status of a stopped instance.
+pub const INSTANCE_STATUS_STOPPED: &str = "STOPPED";
+
+/// Statuses from which an instance does not reach `RUNNING` or `STOPPED` on its own.
+pub const INSTANCE_FAILURE_STATUSES: [&str; 2] = ["ERROR", "DELETING"];
+
+/// `WaiterInstanceEcs` polls `DescribeInstances` until an instance reaches a status.
+pub struct WaiterInstanceEcs;
+
+impl WaiterInstanceEcs {
+    /// Waits until the instance reaches `target`.
+    ///
+    /// An instance missing from the `DescribeInstances` answer, as happens right after
+    /// `RunInstances`, is treated as still pending for up to `max_not_found` consecutive
+    /// polls, after which the wait fails with the state `NOT_FOUND`.
+    ///
+    /// # Arguments
+    /// - `&self`: A reference to the current instance of `WaiterInstanceEcs`.
+    /// - `ecs`: A reference to the `ecs::Ecs` client.
+    /// - `waiter_name`: The name of the waiter, used in errors.
+    /// - `instance_id`: The ID of the instance.
+    /// - `target`: The status waited for.
+    /// - `waiter_config`: The delay, backoff and timeout.
+    ///
+    /// # Returns
+    /// - `Ok(DescribeInstancesResultInstanceResp)`: The instance once it reached `target`.
+    /// - `Err(error::Error)`: If the instance reached a failure status or stayed missing, the wait timed out or a poll failed.
+    pub async fn wait_until_instance_status(
+        &self,
+        ecs: &ecs::Ecs,
+        waiter_name: &str,
+        instance_id: &str,
+        target: &str,
+        waiter_config: &waiter::WaiterConfig,
+    ) -> Result<ecs_instance::DescribeInstancesResultInstanceResp, error::Error> {
+        let not_found = waiter::NotFoundCounter::default();
+        waiter::wait_until(waiter_name, waiter_config, || async {
+            // Describe the single instance.
+            let request = ecs_instance::DescribeInstancesReq {
+                instance_ids: vec![instance_id.to_string()],
+                ..Default::default()
+            };
+            let response = ecs.new_describe_instances(request).await?;
+
+            // Classify its status.
+            let instance = response
+                .result
+                .and_then(|result| result.instances.into_iter().next());
+            let state = instance.map(|instance| {
+                let status = instance.status.clone().unwrap_or_default();
+                waiter::WaitState::from_status(
+                    &status,
+                    target,
+                    &INSTANCE_FAILURE_STATUSES,
+                    instance,
+                )
+            });
+            Ok(not_found.classify(state, waiter_config))
+        })
+        .await
+    }
+}
@@ -0,0 +1,285 @@
+/*
+ * @Author: Jerry.Yang
+ * @Date: 2025-03-18 10:42:37
+ * @LastEditors: Jerry.Yang
+ * @LastEditTime: 2025-03-30 11:10:00
+ * @Description: Blocking facade for IAM (Identity and Access Management) operations
+ */
+use crate::service::iam;
+use crate::service::iam::IamService;
+use crate::volcengine::blocking::blocking;
+use crate::volcengine::error::error;
+use crate::volcengine::session::session;
+use volcengine_sdk_protobuf::protobuf::iam_policy;
+
+/// A synchronous facade over `iam::Iam`.
+///
+/// Exposes the operations of `IamService` as blocking methods, driven by the runtime shared by
+/// the blocking facades. Meant for build scripts and command line tools; asynchronous code
+/// should use `iam::Iam` directly.
+///
+/// # Example
+/// ```rust,no_run
+/// # use volcengine_rust_sdk::volcengine::error::error;
+/// # use volcengine_rust_sdk::volcengine::session::session;
+/// use volcengine_rust_sdk::service::iam;
+///
+/// # fn example(session: session::Session) -> Result<(), error::Error> {
+/// let iam = iam::blocking::Iam::new_iam(session)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Iam {
+    iam: iam::Iam,              // The asynchronous service.
+    runtime: blocking::Runtime, // The runtime driving its requests.
+}
+
+impl Iam {
+    /// Creates a new blocking `Iam` using the provided session.
+    ///
+    /// # Arguments
+    /// - `session`: A `Session` object containing authentication and configuration information.
+    ///
+    /// # Returns
+    /// - `Ok(Iam)`: The blocking service.
+    /// - `Err(error::Error)`: If the service or the runtime could not be created.
+    pub fn new_iam(session: session::Session) -> Result<Iam, error::Error> {
+        Ok(Iam {
+            iam: iam::Iam::new_iam(session)?,
+            runtime: blocking::Runtime::shared()?,
+        })
+    }
+
+    /// Initiates an API call to create a new user.
+    ///
+    /// Blocks until `IamService::new_create_user` completes.
+    pub fn new_create_user(
+        &self,
+        request: volcengine_sdk_protobuf::protobuf::iam_user::CreateUserReq,
+    ) -> Result<volcengine_sdk_protobuf::protobuf::iam_user::CreateUserResp, error::Error> {
+        self.runtime.block_on(self.iam.new_create_user(request))
+    }
+
+    /// Initiates an API call to retrieve user information.
+    ///
+    /// Blocks until `IamService::new_get_user` completes.
+    pub fn new_get_user(
+        &self,
+        request: volcengine_sdk_protobuf::protobuf::iam_user::GetUserReq,
+    ) -> Result<volcengine_sdk_protobuf::protobuf::iam_user::GetUserResp, error::Error> {
+        self.runtime.block_on(self.iam.new_get_user(request))
+    }
+
+    /// Initiates an asynchronous API call to update user information.
+    ///
+    /// Blocks until `IamService::new_update_user` completes.
+    pub fn new_update_user(
+        &self,
+        request: volcengine_sdk_protobuf::protobuf::iam_user::UpdateUserReq,
+    ) -> Result<volcengine_sdk_protobuf::protobuf::iam_user::UpdateUserResp, error::Error> {
+        self.runtime.block_on(self.iam.new_update_user(request))
+    }
+
+    /// Initiates an asynchronous API call to create a login profile.
+    ///
+    /// Blocks until `IamService::new_create_login_profile` completes.
+    pub fn new_create_login_profile(
+        &self,
+        request: volcengine_sdk_protobuf::protobuf::iam_user::CreateLoginProfileReq,
+    ) -> Result<volcengine_sdk_protobuf::protobuf::iam_user::CreateLoginProfileResp, error::Error>
+    {
+        self.runtime
+            .block_on(self.iam.new_create_login_profile(request))
+    }
+
+    /// Initiates an asynchronous API call to retrieve a login profile.
+    ///
+    /// Blocks until `IamService::new_get_login_profile` completes.
+    pub fn new_get_login_profile(
+        &self,
+        request: volcengine_sdk_protobuf::protobuf::iam_user::GetLoginProfileReq,
+    ) -> Result<volcengine_sdk_protobuf::protobuf::iam_user::GetLoginProfileResp, error::Error>
+    {
+        self.runtime
+            .block_on(self.iam.new_get_login_profile(request))
+    }
+
+    /// Initiates an asynchronous API call to update the login profile.
+    ///
+    /// Blocks until `IamService::new_update_login_profile` completes.
+    pub fn new_update_login_profile(
+        &self,
+        request: volcengine_sdk_protobuf::protobuf::iam_user::UpdateLoginProfileReq,
+    ) -> Result<volcengine_sdk_protobuf::protobuf::iam_user::UpdateLoginProfileResp, error::Error>
+    {
+        self.runtime
+            .block_on(self.iam.new_update_login_profile(request))
+    }
+
+    /// Initiates an asynchronous API call to delete the login profile.
+    ///
+    /// Blocks until `IamService::new_delete_login_profile` completes.
+    pub fn new_delete_login_profile(
+        &self,
+        request: volcengine_sdk_protobuf::protobuf::iam_user::DeleteLoginProfileReq,
+    ) -> Result<volcengine_sdk_protobuf::protobuf::iam_user::DeleteLoginProfileResp, error::Error>
+    {
+        self.runtime
+            .block_on(self.iam.new_delete_login_profile(request))
+    }
+
+    /// Initiates an asynchronous API call to set the security configuration.
+    ///
+    /// Blocks until `IamService::new_set_security_config` completes.
+    pub fn new_set_security_config(
+        &self,
+        request: volcengine_sdk_protobuf::protobuf::iam_user::SetSecurityConfigReq,
+    ) -> Result<volcengine_sdk_protobuf::protobuf::iam_user::SetSecurityConfigResp, error::Error>
+    {
+        self.runtime
+            .block_on(self.iam.new_set_security_config(request))
+    }
+
+    /// Initiates an asynchronous API call to retrieve the security configuration.
+    ///
+    /// Blocks until `IamService::new_get_security_config` completes.
+    pub fn new_get_security_config(
+        &self,
+        request: volcengine_sdk_protobuf::protobuf::iam_user::GetSecurityConfigReq,
+    ) -> Result<volcengine_sdk_protobuf::protobuf::iam_user::GetSecurityConfigResp, error::Error>
+    {
+        self.runtime
+            .block_on(self.iam.new_get_security_config(request))
+    }
+
+    /// Initiates an asynchronous API call to create a new project.
+    ///
+    /// Blocks until `IamService::new_create_project` completes.
+    pub fn new_create_project(
+        &self,
+        request: volcengine_sdk_protobuf::protobuf::iam_project::CreateProjectReq,
+    ) -> Result<volcengine_sdk_protobuf::protobuf::iam_project::CreateProjectResp, error::Error>
+    {
+        self.runtime.block_on(self.iam.new_create_project(request))
+    }
+
+    /// Initiates an asynchronous API call to retrieve a project's information.
+    ///
+    /// Blocks until `IamService::new_get_project` completes.
+    pub fn new_get_project(
+        &self,
+        request: volcengine_sdk_protobuf::protobuf::iam_project::GetProjectReq,
+    ) -> Result<volcengine_sdk_protobuf::protobuf::iam_project::GetProjectResp, error::Error> {
+        self.runtime.block_on(self.iam.new_get_project(request))
+    }
+
+    /// Initiates an asynchronous API call to create a new policy.
+    ///
+    /// Blocks until `IamService::new_create_policy` completes.
+    pub fn new_create_policy(
+        &self,
+        request: volcengine_sdk_protobuf::protobuf::iam_policy::CreatePolicyReq,
+    ) -> Result<volcengine_sdk_protobuf::protobuf::iam_policy::CreatePolicyResp, error::Error> {
+        self.runtime.block_on(self.iam.new_create_policy(request))
+    }
+
+    /// Initiates an asynchronous API call to retrieve a specific policy.
+    ///
+    /// Blocks until `IamService::new_get_policy` completes.
+    pub fn new_get_policy(
+        &self,
+        reqeust: volcengine_sdk_protobuf::protobuf::iam_policy::GetPolicyReq,
+    ) -> Result<volcengine_sdk_protobuf::protobuf::iam_policy::GetPolicyResp, error::Error> {
+        self.runtime.block_on(self.iam.new_get_policy(reqeust))
+    }
+
+    /// Initiates an asynchronous API call to list existing policies.
+    ///
+    /// Blocks until `IamService::new_list_policy` completes.
+    pub fn new_list_policy(
+        &self,
+        request: volcengine_sdk_protobuf::protobuf::iam_policy::ListPoliciesReq,
+    ) -> Result<volcengine_sdk_protobuf::protobuf::iam_policy::ListPoliciesResp, error::Error> {
+        self.runtime.block_on(self.iam.new_list_policy(request))
+    }
+
+    /// Lists policies page by page.
+    ///
+    /// The pages are fetched synchronously as the iterators of the paginator are advanced.
+    pub fn list_policies_paginator(
+        &self,
+        request: iam_policy::ListPoliciesReq,
+    ) -> blocking::Paginator<iam_policy::ListPoliciesReq> {
+        self.runtime
+            .paginator(self.iam.list_policies_paginator(request))
+    }
+
+    /// Initiates an asynchronous API call to update an existing policy.
+    ///
+    /// Blocks until `IamService::new_update_policy` completes.
+    pub fn new_update_policy(
+        &self,
+        request: volcengine_sdk_protobuf::protobuf::iam_policy::UpdatePolicyReq,
+    ) -> Result<volcengine_sdk_protobuf::protobuf::iam_policy::UpdatePolicyResp, error::Error> {
+        self.runtime.block_on(self.iam.new_update_policy(request))
+    }
+
+    /// Initiates an asynchronous API call to delete a specific policy.
+    ///
+    /// Blocks until `IamService::new_delete_policy` completes.
+    pub fn new_delete_policy(
+        &self,
+        request: volcengine_sdk_protobuf::protobuf::iam_policy::DeletePolicyReq,
+    ) -> Result<volcengine_sdk_protobuf::protobuf::iam_policy::DeletePolicyResp, error::Error> {
+        self.runtime.block_on(self.iam.new_delete_policy(request))
+    }
+
+    /// Initiates an asynchronous API call to attach a specific policy to a user.
+    ///
+    /// Blocks until `IamService::new_attach_user_policy` completes.
+    pub fn new_attach_user_policy(
+        &self,
+        request: volcengine_sdk_protobuf::protobuf::iam_policy::AttachUserPolicyReq,
+    ) -> Result<volcengine_sdk_protobuf::protobuf::iam_policy::AttachUserPolicyResp, error::Error>
+    {
+        self.runtime
+            .block_on(self.iam.new_attach_user_policy(request))
+    }
+
+    /// Initiates an asynchronous API call to list all the policies attached to a specific user.
+    ///
+    /// Blocks until `IamService::new_list_attach_user_policy` completes.
+    pub fn new_list_attach_user_policy(
+        &self,
+        request: volcengine_sdk_protobuf::protobuf::iam_policy::ListAttachedUserPoliciesReq,
+    ) -> Result<
+        volcengine_sdk_protobuf::protobuf::iam_policy::ListAttachedUserPoliciesResp,
+        error::Error,
+    > {
+        self.runtime
+            .block_on(self.iam.new_list_attach_user_policy(request))
+    }
+
+    /// Initiates an asynchronous API call to detach a specific policy from a user.
+    ///
+    /// Blocks until `IamService::new_detach_user_policy` completes.
+    pub fn new_detach_user_policy(
+        &self,
+        request: volcengine_sdk_protobuf::protobuf::iam_policy::DetachUserPolicyReq,
+    ) -> Result<volcengine_sdk_protobuf::protobuf::iam_policy::DetachUserPolicyResp, error::Error>
+    {
+        self.runtime
+            .block_on(self.iam.new_detach_user_policy(request))
+    }
+
+    /// Initiates an asynchronous API call to delete a specific user.
+    ///
+    /// Blocks until `IamService::new_delete_user` completes.
+    pub fn new_delete_user(
+        &self,
+        request: volcengine_sdk_protobuf::protobuf::iam_user::DeleteUserReq,
+    ) -> Result<volcengine_sdk_protobuf::protobuf::iam_user::DeleteUserResp, error::Error> {
+        self.runtime.block_on(self.iam.new_delete_user(request))
+    }
+}
@@ -2,56 +2,22 @@
  * @Author: Jerry.Yang
  * @Date: 2024-10-17 14:34:10
  * @LastEditors: Jerry.Yang
- * @LastEditTime: 2025-02-05 19:02:06
+ * @LastEditTime: 2025-03-28 10:14:26
  * @Description: Definition of the IamService module and its related interfaces
  */
 use crate::volcengine::client::client;
 use crate::volcengine::error::error;
+use crate::volcengine::paginator::paginator;
 use crate::volcengine::session::session;
 use std::future::Future;
+use volcengine_sdk_protobuf::protobuf::iam_policy;
 
 // Include API models related to the IAM service
-mod api_attach_user_policy;
-mod api_attach_user_policy_model;
-mod api_create_login_profile;
-mod api_create_login_profile_model;
-mod api_create_policy;
-mod api_create_policy_model;
-mod api_create_project;
-mod api_create_project_model;
-mod api_create_user;
-mod api_create_user_model;
-mod api_delete_login_profile;
-mod api_delete_login_profile_model;
-mod api_delete_policy;
-mod api_delete_policy_model;
-mod api_delete_user;
-mod api_delete_user_model;
-mod api_detach_user_policy;
-mod api_detach_user_policy_model;
-mod api_get_login_profile;
-mod api_get_login_profile_model;
-mod api_get_policy;
-mod api_get_policy_model;
-mod api_get_project;
-mod api_get_project_model;
-mod api_get_security_config;
-mod api_get_security_config_model;
-mod api_get_user;
-mod api_get_user_model;
-mod api_list_attach_user_policy;
-mod api_list_attach_user_policy_model;
-mod api_list_policy;
 mod api_list_policy_model;
-mod api_set_security_config;
-mod api_set_security_config_model;
-mod api_update_login_profile;
-mod api_update_login_profile_model;
-mod api_update_policy;
-mod api_update_policy_model;
-mod api_update_user;
-mod api_update_user_model;
 pub mod service_iam;
+
+// Blocking facade exposing the service operations synchronously.
+pub mod blocking;
 mod test;
 
 // Define the IamService trait, which provides a series of API interfaces related to IAM
@@ -366,6 +332,20 @@ pub trait IamService {
         >,
     >;
 
+    /// Lists policies page by page.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current service instance.
+    /// - `request`: The request of the first page; `Limit` sets the page size.
+    ///
+    /// # Returns
+    /// - `paginator::Paginator<iam_policy::ListPoliciesReq>`: A paginator turned into a stream over the pages with `pages()`
+    ///   or over the policies with `items()`.
+    fn list_policies_paginator(
+        &self,
+        request: iam_policy::ListPoliciesReq,
+    ) -> paginator::Paginator<iam_policy::ListPoliciesReq>;
+
     // API interface for updating a policy
     /// Initiates an asynchronous API call to update an existing policy.
     ///
@@ -2,37 +2,19 @@
  * @Author: Jerry.Yang
  * @Date: 2024-10-17 15:59:58
  * @LastEditors: Jerry.Yang
- * @LastEditTime: 2025-02-05 19:16:39
+ * @LastEditTime: 2025-03-28 10:14:26
  * @Description: IAM (Identity and Access Management) Service
  */
-use crate::service::iam::api_attach_user_policy;
-use crate::service::iam::api_create_login_profile;
-use crate::service::iam::api_create_policy;
-use crate::service::iam::api_create_project;
-use crate::service::iam::api_create_user;
-use crate::service::iam::api_delete_login_profile;
-use crate::service::iam::api_delete_policy;
-use crate::service::iam::api_delete_user;
-use crate::service::iam::api_detach_user_policy;
-use crate::service::iam::api_get_login_profile;
-use crate::service::iam::api_get_policy;
-use crate::service::iam::api_get_project;
-use crate::service::iam::api_get_security_config;
-use crate::service::iam::api_get_user;
-use crate::service::iam::api_list_attach_user_policy;
-use crate::service::iam::api_list_policy;
-use crate::service::iam::api_set_security_config;
-use crate::service::iam::api_update_login_profile;
-use crate::service::iam::api_update_policy;
-use crate::service::iam::api_update_user;
 use crate::service::iam::{Iam, IamService};
 use crate::volcengine::client::client;
 use crate::volcengine::client::client_info;
 use crate::volcengine::client::config as client_config;
 use crate::volcengine::common;
 use crate::volcengine::error::error;
-use crate::volcengine::request::handles;
+use crate::volcengine::paginator::paginator;
+use crate::volcengine::registry::registry;
 use crate::volcengine::session::session;
+use volcengine_sdk_protobuf::protobuf::iam_policy;
 
 /// Implementation of the `IamService` trait for the `Iam` struct.
 /// This implementation provides concrete functionality for all the methods defined in the `IamService` trait.
@@ -51,7 +33,7 @@ impl IamService for Iam {
     /// Returns a result containing the new `Iam` service instance or an error.
     fn new_iam(session: session::Session) -> Result<Self, error::Error> {
         // Create new client configuration using the session
-        let client_config = session.new_client_config(client_config::ClientServiceName::Iam);
+        let client_config = session.new_client_config(client_config::ClientServiceName::Iam)?;
 
         // Set up the client information, including service name and API version
         let client_info = client_info::ClientInfo::builder()
@@ -60,8 +42,8 @@ impl IamService for Iam {
             .with_signing_region(&client_config.signing_region)
             .build()?;
 
-        // Create request handles (for managing requests and responses)
-        let request_handles = handles::Handles {};
+        // Use the request handles registered on the session
+        let request_handles = client_config.handles.clone();
 
         // Build the client with the necessary configuration and client info
         let client = client::Client::builder()
@@ -87,10 +69,7 @@ impl IamService for Iam {
         &self,
         request: volcengine_sdk_protobuf::protobuf::iam_user::CreateUserReq,
     ) -> Result<volcengine_sdk_protobuf::protobuf::iam_user::CreateUserResp, error::Error> {
-        // Delegate to the API for creating the user
-        api_create_user::ApiCreateUserIam
-            .new_create_user(self, request)
-            .await
+        registry::invoke(&self.client, &registry::IAM_CREATE_USER, &request).await
     }
 
     /// new_get_user
@@ -106,10 +85,7 @@ impl IamService for Iam {
         &self,
         request: volcengine_sdk_protobuf::protobuf::iam_user::GetUserReq,
     ) -> Result<volcengine_sdk_protobuf::protobuf::iam_user::GetUserResp, error::Error> {
-        // Delegate to the API for retrieving the user
-        api_get_user::ApiGetUserIam
-            .new_get_user(self, request)
-            .await
+        registry::invoke(&self.client, &registry::IAM_GET_USER, &request).await
     }
 
     /// new_update_user
@@ -125,10 +101,7 @@ impl IamService for Iam {
         &self,
         request: volcengine_sdk_protobuf::protobuf::iam_user::UpdateUserReq,
     ) -> Result<volcengine_sdk_protobuf::protobuf::iam_user::UpdateUserResp, error::Error> {
-        // Delegate to the API for updating the user
-        api_update_user::ApiUpdateUserIam
-            .new_update_user(self, request)
-            .await
+        registry::invoke(&self.client, &registry::IAM_UPDATE_USER, &request).await
     }
 
     /// new_create_login_profile
@@ -145,10 +118,7 @@ impl IamService for Iam {
         request: volcengine_sdk_protobuf::protobuf::iam_user::CreateLoginProfileReq,
     ) -> Result<volcengine_sdk_protobuf::protobuf::iam_user::CreateLoginProfileResp, error::Error>
     {
-        // Delegate to the API for creating the login profile
-        api_create_login_profile::ApiCreateLoginProfileIam
-            .new_create_login_profile(self, request)
-            .await
+        registry::invoke(&self.client, &registry::IAM_CREATE_LOGIN_PROFILE, &request).await
     }
 
     /// new_get_login_profile
@@ -165,10 +135,7 @@ impl IamService for Iam {
         request: volcengine_sdk_protobuf::protobuf::iam_user::GetLoginProfileReq,
     ) -> Result<volcengine_sdk_protobuf::protobuf::iam_user::GetLoginProfileResp, error::Error>
     {
-        // Delegate to the API for retrieving the login profile
-        api_get_login_profile::ApiGetLoginProfileIam
-            .new_get_login_profile(self, request)
-            .await
+        registry::invoke(&self.client, &registry::IAM_GET_LOGIN_PROFILE, &request).await
     }
 
     /// new_update_login_profile
@@ -185,10 +152,7 @@ impl IamService for Iam {
         request: volcengine_sdk_protobuf::protobuf::iam_user::UpdateLoginProfileReq,
     ) -> Result<volcengine_sdk_protobuf::protobuf::iam_user::UpdateLoginProfileResp, error::Error>
     {
-        // Delegate to the API for updating the login profile
-        api_update_login_profile::ApiUpdateLoginProfileIam
-            .new_update_login_profile(self, request)
-            .await
+        registry::invoke(&self.client, &registry::IAM_UPDATE_LOGIN_PROFILE, &request).await
     }
 
     /// new_delete_login_profile
@@ -205,10 +169,7 @@ impl IamService for Iam {
         request: volcengine_sdk_protobuf::protobuf::iam_user::DeleteLoginProfileReq,
     ) -> Result<volcengine_sdk_protobuf::protobuf::iam_user::DeleteLoginProfileResp, error::Error>
     {
-        // Delegate to the API for deleting the login profile
-        api_delete_login_profile::ApiDeleteLoginProfileIam
-            .new_delete_login_profile(self, request)
-            .await
+        registry::invoke(&self.client, &registry::IAM_DELETE_LOGIN_PROFILE, &request).await
     }
 
     /// new_set_security_config
@@ -225,10 +186,7 @@ impl IamService for Iam {
         request: volcengine_sdk_protobuf::protobuf::iam_user::SetSecurityConfigReq,
     ) -> Result<volcengine_sdk_protobuf::protobuf::iam_user::SetSecurityConfigResp, error::Error>
     {
-        // Delegate to the API for setting the security configuration
-        api_set_security_config::ApiSetSecurityConfigIam
-            .new_set_security_config(self, request)
-            .await
+        registry::invoke(&self.client, &registry::IAM_SET_SECURITY_CONFIG, &request).await
     }
 
     /// new_get_security_config
@@ -245,9 +203,7 @@ impl IamService for Iam {
         request: volcengine_sdk_protobuf::protobuf::iam_user::GetSecurityConfigReq,
     ) -> Result<volcengine_sdk_protobuf::protobuf::iam_user::GetSecurityConfigResp, error::Error>
     {
-        api_get_security_config::ApiGetSecurityConfigIam
-            .new_get_security_config(self, request)
-            .await
+        registry::invoke(&self.client, &registry::IAM_GET_SECURITY_CONFIG, &request).await
     }
 
     /// new_create_project
@@ -264,9 +220,7 @@ impl IamService for Iam {
         request: volcengine_sdk_protobuf::protobuf::iam_project::CreateProjectReq,
     ) -> Result<volcengine_sdk_protobuf::protobuf::iam_project::CreateProjectResp, error::Error>
     {
-        api_create_project::ApiCreateProjectIam
-            .new_create_project(self, request)
-            .await
+        registry::invoke(&self.client, &registry::IAM_CREATE_PROJECT, &request).await
     }
 
     /// new_get_project
@@ -282,9 +236,7 @@ impl IamService for Iam {
         &self,
         request: volcengine_sdk_protobuf::protobuf::iam_project::GetProjectReq,
     ) -> Result<volcengine_sdk_protobuf::protobuf::iam_project::GetProjectResp, error::Error> {
-        api_get_project::ApiGetProjectIam
-            .new_get_project(self, request)
-            .await
+        registry::invoke(&self.client, &registry::IAM_GET_PROJECT, &request).await
     }
 
     /// new_create_policy
@@ -300,9 +252,7 @@ impl IamService for Iam {
         &self,
         request: volcengine_sdk_protobuf::protobuf::iam_policy::CreatePolicyReq,
     ) -> Result<volcengine_sdk_protobuf::protobuf::iam_policy::CreatePolicyResp, error::Error> {
-        api_create_policy::ApiCreatePolicyIam
-            .new_create_policy(self, request)
-            .await
+        registry::invoke(&self.client, &registry::IAM_CREATE_POLICY, &request).await
     }
 
     /// new_get_policy
@@ -318,9 +268,7 @@ impl IamService for Iam {
         &self,
         request: volcengine_sdk_protobuf::protobuf::iam_policy::GetPolicyReq,
     ) -> Result<volcengine_sdk_protobuf::protobuf::iam_policy::GetPolicyResp, error::Error> {
-        api_get_policy::ApiGetPolicyIam
-            .new_get_policy(self, request)
-            .await
+        registry::invoke(&self.client, &registry::IAM_GET_POLICY, &request).await
     }
 
     /// new_list_policy
@@ -336,9 +284,26 @@ impl IamService for Iam {
         &self,
         request: volcengine_sdk_protobuf::protobuf::iam_policy::ListPoliciesReq,
     ) -> Result<volcengine_sdk_protobuf::protobuf::iam_policy::ListPoliciesResp, error::Error> {
-        api_list_policy::ApiListPolicyIam
-            .new_list_policy(self, request)
-            .await
+        registry::invoke(&self.client, &registry::IAM_LIST_POLICIES, &request).await
+    }
+
+    /// Lists policies page by page.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current service instance.
+    /// - `request`: The request of the first page.
+    ///
+    /// # Returns
+    /// - `paginator::Paginator<iam_policy::ListPoliciesReq>`: A paginator sending `new_list_policy` once per page.
+    fn list_policies_paginator(
+        &self,
+        request: iam_policy::ListPoliciesReq,
+    ) -> paginator::Paginator<iam_policy::ListPoliciesReq> {
+        let iam = self.clone();
+        paginator::Paginator::new(request, move |request| {
+            let iam = iam.clone();
+            Box::pin(async move { iam.new_list_policy(request).await })
+        })
     }
 
     /// new_update_policy
@@ -354,9 +319,7 @@ impl IamService for Iam {
         &self,
         request: volcengine_sdk_protobuf::protobuf::iam_policy::UpdatePolicyReq,
     ) -> Result<volcengine_sdk_protobuf::protobuf::iam_policy::UpdatePolicyResp, error::Error> {
-        api_update_policy::ApiUpdatePolicyIam
-            .new_update_policy(self, request)
-            .await
+        registry::invoke(&self.client, &registry::IAM_UPDATE_POLICY, &request).await
     }
 
     /// new_delete_policy
@@ -372,9 +335,7 @@ impl IamService for Iam {
         &self,
         request: volcengine_sdk_protobuf::protobuf::iam_policy::DeletePolicyReq,
     ) -> Result<volcengine_sdk_protobuf::protobuf::iam_policy::DeletePolicyResp, error::Error> {
-        api_delete_policy::ApiDeletePolicyIam
-            .new_delete_policy(self, request)
-            .await
+        registry::invoke(&self.client, &registry::IAM_DELETE_POLICY, &request).await
     }
 
     /// new_attach_user_policy
@@ -391,9 +352,7 @@ impl IamService for Iam {
         request: volcengine_sdk_protobuf::protobuf::iam_policy::AttachUserPolicyReq,
     ) -> Result<volcengine_sdk_protobuf::protobuf::iam_policy::AttachUserPolicyResp, error::Error>
     {
-        api_attach_user_policy::ApiAttachUserPolicyIam
-            .new_attach_user_policy(self, request)
-            .await
+        registry::invoke(&self.client, &registry::IAM_ATTACH_USER_POLICY, &request).await
     }
 
     /// new_list_attach_user_policy
@@ -412,9 +371,12 @@ impl IamService for Iam {
         volcengine_sdk_protobuf::protobuf::iam_policy::ListAttachedUserPoliciesResp,
         error::Error,
     > {
-        api_list_attach_user_policy::ApiListAttachUserPolicyIam
-            .new_list_attach_user_policy(self, request)
-            .await
+        registry::invoke(
+            &self.client,
+            &registry::IAM_LIST_ATTACHED_USER_POLICIES,
+            &request,
+        )
+        .await
     }
 
     /// new_detach_user_policy
@@ -431,9 +393,7 @@ impl IamService for Iam {
         request: volcengine_sdk_protobuf::protobuf::iam_policy::DetachUserPolicyReq,
     ) -> Result<volcengine_sdk_protobuf::protobuf::iam_policy::DetachUserPolicyResp, error::Error>
     {
-        api_detach_user_policy::ApiDetachUserPolicyIam
-            .new_detach_user_policy(self, request)
-            .await
+        registry::invoke(&self.client, &registry::IAM_DETACH_USER_POLICY, &request).await
     }
 
     /// new_delete_user
@@ -449,8 +409,6 @@ impl IamService for Iam {
         &self,
         request: volcengine_sdk_protobuf::protobuf::iam_user::DeleteUserReq,
     ) -> Result<volcengine_sdk_protobuf::protobuf::iam_user::DeleteUserResp, error::Error> {
-        api_delete_user::ApiDeleteUserIam
-            .new_delete_user(self, request)
-            .await
+        registry::invoke(&self.client, &registry::IAM_DELETE_USER, &request).await
     }
 }
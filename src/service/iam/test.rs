@@ -2,66 +2,248 @@
  * @Author: Jerry.Yang
  * @Date: 2024-10-22 13:52:22
  * @LastEditors: Jerry.Yang
- * @LastEditTime: 2025-02-19 16:03:28
- * @Description: create_user test
+ * @LastEditTime: 2025-03-19 17:41:09
+ * @Description: iam test
  */
 #[cfg(test)]
 mod tests {
+    use futures::TryStreamExt;
+    use volcengine_sdk_protobuf::protobuf::{iam_policy, iam_project, iam_user};
+
     use crate::{
         service::iam::{self, IamService},
-        volcengine::{config, credentials::credentials, session::session},
+        volcengine::mock::mock,
     };
 
+    /// The API version of IAM.
+    const VERSION: &str = "2018-01-01";
+
     #[tokio::test]
     async fn test_get_user() {
-        let access_key_id = ""; // 这里填入实际的 Access Key ID
-        let secret_access_key = ""; // 这里填入实际的 Secret Access Key
-        let region_id = "cn-beijing"; // 这里填入实际的 Region ID
-
-        let credentials = credentials::Credentials::new(access_key_id, secret_access_key);
+        // start the mock server
+        let server = mock::MockServer::start().await;
+        server.mock(
+            "CreatePolicy",
+            VERSION,
+            mock::MockResponse::json(&iam_policy::CreatePolicyResp {
+                result: Some(iam_policy::CreatePolicyResultResp {
+                    policy: Some(iam_policy::CreatePolicyResultPolicyResp {
+                        policy_name: Some("policy_write".to_string()),
+                        ..Default::default()
+                    }),
+                }),
+                ..Default::default()
+            }),
+        );
 
-        // new config
-        let config = config::Config::builder()
-            .with_region(&region_id)
-            .with_credentials(credentials)
-            .build();
+        // new iam
+        let iam = iam::Iam::new_iam(server.session()).unwrap();
 
-        // print config
-        println!("config : {:?}", config);
+        // create policy
+        let mut request = iam_policy::CreatePolicyReq::default();
+        request.description = "policy_write".to_string();
+        request.policy_document =
+            "{\"Statement\":[{\"Effect\":\"Allow\",\"Action\":[\"ecs:*\"],\"Resource\":[\"*\"]}]}"
+                .to_string();
+        request.policy_name = "policy_write".to_string();
+        let result = iam.new_create_policy(request.clone()).await.unwrap();
 
-        // reset config
-        let config = config.unwrap();
+        assert_eq!(
+            result
+                .result
+                .unwrap()
+                .policy
+                .unwrap()
+                .policy_name
+                .as_deref(),
+            Some("policy_write")
+        );
 
-        // new session
-        let session = session::Session::builder().with_config(config).build();
+        // the request is signed and carries the parameters
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].signature_valid);
+        assert_eq!(requests[0].service, "iam");
+        assert_eq!(requests[0].query["PolicyName"], "policy_write");
+        assert_eq!(requests[0].query["PolicyDocument"], request.policy_document);
+    }
 
-        // print session
-        println!("session : {:?}", session);
+    #[tokio::test]
+    async fn test_create_user() {
+        let server = mock::MockServer::start().await;
+        server.mock(
+            "CreateUser",
+            VERSION,
+            mock::MockResponse::json(&iam_user::CreateUserResp {
+                result: Some(iam_user::CreateUserResultResp {
+                    user: Some(iam_user::CreateUserResultUserResp {
+                        user_name: Some("jerry".to_string()),
+                        ..Default::default()
+                    }),
+                }),
+                ..Default::default()
+            }),
+        );
 
-        // reset session
-        let session = session.unwrap();
+        let iam = iam::Iam::new_iam(server.session()).unwrap();
+        let request = iam_user::CreateUserReq {
+            user_name: Some("jerry".to_string()),
+            display_name: Some("Jerry".to_string()),
+            ..Default::default()
+        };
+        let result = iam.new_create_user(request).await.unwrap();
 
-        // new iam
-        let iam = iam::Iam::new_iam(session);
+        let user = result.result.unwrap().user.unwrap();
+        assert_eq!(user.user_name.as_deref(), Some("jerry"));
+        let requests = server.requests();
+        assert!(requests[0].signature_valid);
+        assert_eq!(requests[0].query["UserName"], "jerry");
+        assert_eq!(requests[0].query["DisplayName"], "Jerry");
+    }
 
-        // print iam
-        println!("iam : {:?}", iam);
+    #[tokio::test]
+    async fn test_list_policies_paginator() {
+        // two pages of one policy out of two
+        let server = mock::MockServer::start().await;
+        for policy_name in ["policy-1", "policy-2"] {
+            server.mock(
+                "ListPolicies",
+                VERSION,
+                mock::MockResponse::json(&iam_policy::ListPoliciesResp {
+                    result: Some(iam_policy::ListPoliciesResultResp {
+                        total: 2,
+                        policy_metadata: vec![iam_policy::ListPoliciesResultPolicyResp {
+                            policy_name: policy_name.to_string(),
+                            ..Default::default()
+                        }],
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                }),
+            );
+        }
 
-        // reset iam
-        let iam = iam.unwrap();
+        let iam = iam::Iam::new_iam(server.session()).unwrap();
+        let request = iam_policy::ListPoliciesReq {
+            limit: 1,
+            ..Default::default()
+        };
+        let policies: Vec<_> = iam
+            .list_policies_paginator(request)
+            .items()
+            .try_collect()
+            .await
+            .unwrap();
 
-        // create_user
-        let mut request = volcengine_sdk_protobuf::protobuf::iam_policy::CreatePolicyReq::default();
-        request.description = "policy_write_yangjie04".to_string();
-        request.policy_document = "{\"Statement\":[{\"Effect\":\"Allow\",\"Action\":[\"ecs:*\"],\"Resource\":[\"trn:ecs:cn-beijing:2101531441:instance/:i-ydgp6rkiyoqc6iks6om4\"]}]}".to_string();
-        request.policy_name = "policy_write_yangjie04".to_string();
+        let policy_names: Vec<_> = policies
+            .iter()
+            .map(|policy| policy.policy_name.as_str())
+            .collect();
+        assert_eq!(policy_names, ["policy-1", "policy-2"]);
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[1].query["Offset"], "1");
+    }
 
-        // 运行异步代码的 helper 函数
-        let result = iam.new_create_policy(request).await;
+    #[tokio::test]
+    async fn test_every_operation() {
+        // answer every action with an empty result
+        let actions = [
+            "CreateUser",
+            "GetUser",
+            "UpdateUser",
+            "CreateLoginProfile",
+            "GetLoginProfile",
+            "UpdateLoginProfile",
+            "DeleteLoginProfile",
+            "SetSecurityConfig",
+            "GetSecurityConfig",
+            "CreateProject",
+            "GetProject",
+            "CreatePolicy",
+            "GetPolicy",
+            "ListPolicies",
+            "UpdatePolicy",
+            "DeletePolicy",
+            "AttachUserPolicy",
+            "ListAttachedUserPolicies",
+            "DetachUserPolicy",
+            "DeleteUser",
+        ];
+        let server = mock::MockServer::start().await;
+        for action in actions {
+            server.mock(action, VERSION, mock::MockResponse::empty());
+        }
 
-        println!("result : {:?}", result);
+        let iam = iam::Iam::new_iam(server.session()).unwrap();
+        iam.new_create_user(iam_user::CreateUserReq::default())
+            .await
+            .unwrap();
+        iam.new_get_user(iam_user::GetUserReq::default())
+            .await
+            .unwrap();
+        iam.new_update_user(iam_user::UpdateUserReq::default())
+            .await
+            .unwrap();
+        iam.new_create_login_profile(iam_user::CreateLoginProfileReq::default())
+            .await
+            .unwrap();
+        iam.new_get_login_profile(iam_user::GetLoginProfileReq::default())
+            .await
+            .unwrap();
+        iam.new_update_login_profile(iam_user::UpdateLoginProfileReq::default())
+            .await
+            .unwrap();
+        iam.new_delete_login_profile(iam_user::DeleteLoginProfileReq::default())
+            .await
+            .unwrap();
+        iam.new_set_security_config(iam_user::SetSecurityConfigReq::default())
+            .await
+            .unwrap();
+        iam.new_get_security_config(iam_user::GetSecurityConfigReq::default())
+            .await
+            .unwrap();
+        iam.new_create_project(iam_project::CreateProjectReq::default())
+            .await
+            .unwrap();
+        iam.new_get_project(iam_project::GetProjectReq::default())
+            .await
+            .unwrap();
+        iam.new_create_policy(iam_policy::CreatePolicyReq::default())
+            .await
+            .unwrap();
+        iam.new_get_policy(iam_policy::GetPolicyReq::default())
+            .await
+            .unwrap();
+        iam.new_list_policy(iam_policy::ListPoliciesReq::default())
+            .await
+            .unwrap();
+        iam.new_update_policy(iam_policy::UpdatePolicyReq::default())
+            .await
+            .unwrap();
+        iam.new_delete_policy(iam_policy::DeletePolicyReq::default())
+            .await
+            .unwrap();
+        iam.new_attach_user_policy(iam_policy::AttachUserPolicyReq::default())
+            .await
+            .unwrap();
+        iam.new_list_attach_user_policy(iam_policy::ListAttachedUserPoliciesReq::default())
+            .await
+            .unwrap();
+        iam.new_detach_user_policy(iam_policy::DetachUserPolicyReq::default())
+            .await
+            .unwrap();
+        iam.new_delete_user(iam_user::DeleteUserReq::default())
+            .await
+            .unwrap();
 
-        // 这里可以添加断言来检查结果
-        assert!(result.is_ok());
+        // one signed request per operation, in order
+        let requests = server.requests();
+        let received_actions: Vec<_> = requests
+            .iter()
+            .map(|request| request.action.as_str())
+            .collect();
+        assert_eq!(received_actions, actions);
+        assert!(requests.iter().all(|request| request.signature_valid));
     }
 }
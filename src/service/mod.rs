@@ -2,12 +2,14 @@
  * @Author: Jerry.Yang
  * @Date: 2024-10-17 14:33:22
  * @LastEditors: Jerry.Yang
- * @LastEditTime: 2024-11-12 17:21:07
+ * @LastEditTime: 2025-03-27 10:36:52
  * @Description: mod
  */
 pub mod clb;
 pub mod ecs;
 pub mod iam;
+pub mod nat_gateway;
 pub mod rds;
 pub mod redis;
+pub mod sts;
 pub mod vpc;
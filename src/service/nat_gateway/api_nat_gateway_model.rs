@@ -0,0 +1,676 @@
+/*
+ * @Author: Jerry.Yang
+ * @Date: 2025-03-27 10:40:18
+ * @LastEditors: Jerry.Yang
+ * @LastEditTime: 2025-03-29 20:50:00
+ * @Description: nat gateway, snat entry and dnat entry models
+ */
+use crate::service::vpc::api_tag_model;
+use crate::volcengine::paginator::paginator;
+use crate::volcengine::request::response;
+use serde::{Deserialize, Serialize};
+
+/// Request of the `CreateNatGateway` API.
+///
+/// # Fields
+/// - `vpc_id`: The ID of the VPC of the NAT gateway.
+/// - `subnet_id`: The ID of the subnet holding the NAT gateway, in the VPC.
+/// - `spec`: The specification of the NAT gateway, e.g. `Small` (the default), `Medium` or `Large`.
+/// - `nat_gateway_name`: The name of the NAT gateway.
+/// - `description`: The description of the NAT gateway.
+/// - `billing_type`: The billing method, `1` for subscription or `2` for pay-as-you-go (the default).
+/// - `period`: The length of the subscription, in `period_unit`.
+/// - `period_unit`: The unit of `period`, `Month` (the default) or `Year`.
+/// - `project_name`: The project of the NAT gateway.
+/// - `tags`: The tags of the NAT gateway.
+/// - `client_token`: A token making the request idempotent.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct CreateNatGatewayReq {
+    /// The ID of the VPC
+    #[serde(rename = "VpcId")]
+    pub vpc_id: String,
+
+    /// The ID of the subnet
+    #[serde(rename = "SubnetId")]
+    pub subnet_id: String,
+
+    /// The specification of the NAT gateway
+    #[serde(rename = "Spec", skip_serializing_if = "Option::is_none")]
+    pub spec: Option<String>,
+
+    /// The name of the NAT gateway
+    #[serde(rename = "NatGatewayName", skip_serializing_if = "Option::is_none")]
+    pub nat_gateway_name: Option<String>,
+
+    /// The description of the NAT gateway
+    #[serde(rename = "Description", skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// The billing method
+    #[serde(rename = "BillingType", skip_serializing_if = "Option::is_none")]
+    pub billing_type: Option<i32>,
+
+    /// The length of the subscription
+    #[serde(rename = "Period", skip_serializing_if = "Option::is_none")]
+    pub period: Option<i32>,
+
+    /// The unit of the subscription
+    #[serde(rename = "PeriodUnit", skip_serializing_if = "Option::is_none")]
+    pub period_unit: Option<String>,
+
+    /// The project of the NAT gateway
+    #[serde(rename = "ProjectName", skip_serializing_if = "Option::is_none")]
+    pub project_name: Option<String>,
+
+    /// The tags of the NAT gateway
+    #[serde(rename = "Tags")]
+    pub tags: Vec<api_tag_model::Tag>,
+
+    /// The idempotency token
+    #[serde(rename = "ClientToken", skip_serializing_if = "Option::is_none")]
+    pub client_token: Option<String>,
+}
+
+/// Response of the `CreateNatGateway` API.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct CreateNatGatewayResp {
+    /// Metadata of the response
+    #[serde(rename = "ResponseMetadata")]
+    pub response_metadata: response::ApiResponseMetadata,
+
+    /// The result of the call
+    #[serde(rename = "Result")]
+    pub result: Option<CreateNatGatewayResult>,
+}
+
+/// Result of the `CreateNatGateway` API.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct CreateNatGatewayResult {
+    /// The ID of the NAT gateway created
+    #[serde(rename = "NatGatewayId")]
+    pub nat_gateway_id: String,
+
+    /// The ID of the order, for a subscription
+    #[serde(rename = "OrderId")]
+    pub order_id: String,
+}
+
+/// Request of the `DescribeNatGateways` API.
+///
+/// # Fields
+/// - `nat_gateway_ids`: Lists these NAT gateways.
+/// - `nat_gateway_name`: Lists the NAT gateways with this name.
+/// - `vpc_id`: Lists the NAT gateways of this VPC.
+/// - `subnet_id`: Lists the NAT gateways of this subnet.
+/// - `spec`: Lists the NAT gateways of this specification.
+/// - `project_name`: Lists the NAT gateways of this project.
+/// - `page_number`: The page to list, from 1.
+/// - `page_size`: The number of NAT gateways per page.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DescribeNatGatewaysReq {
+    /// The IDs of the NAT gateways
+    #[serde(rename = "NatGatewayIds")]
+    pub nat_gateway_ids: Vec<String>,
+
+    /// The name of the NAT gateways
+    #[serde(rename = "NatGatewayName", skip_serializing_if = "Option::is_none")]
+    pub nat_gateway_name: Option<String>,
+
+    /// The ID of the VPC
+    #[serde(rename = "VpcId", skip_serializing_if = "Option::is_none")]
+    pub vpc_id: Option<String>,
+
+    /// The ID of the subnet
+    #[serde(rename = "SubnetId", skip_serializing_if = "Option::is_none")]
+    pub subnet_id: Option<String>,
+
+    /// The specification of the NAT gateways
+    #[serde(rename = "Spec", skip_serializing_if = "Option::is_none")]
+    pub spec: Option<String>,
+
+    /// The project of the NAT gateways
+    #[serde(rename = "ProjectName", skip_serializing_if = "Option::is_none")]
+    pub project_name: Option<String>,
+
+    /// The page number
+    #[serde(rename = "PageNumber", skip_serializing_if = "Option::is_none")]
+    pub page_number: Option<i64>,
+
+    /// The page size
+    #[serde(rename = "PageSize", skip_serializing_if = "Option::is_none")]
+    pub page_size: Option<i64>,
+}
+
+/// Response of the `DescribeNatGateways` API.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct DescribeNatGatewaysResp {
+    /// Metadata of the response
+    #[serde(rename = "ResponseMetadata")]
+    pub response_metadata: response::ApiResponseMetadata,
+
+    /// The result of the call
+    #[serde(rename = "Result")]
+    pub result: Option<DescribeNatGatewaysResult>,
+}
+
+/// Result of the `DescribeNatGateways` API.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct DescribeNatGatewaysResult {
+    /// The NAT gateways of the page
+    #[serde(rename = "NatGateways")]
+    pub nat_gateways: Vec<NatGatewayInfo>,
+
+    /// The number of NAT gateways listed
+    #[serde(rename = "TotalCount")]
+    pub total_count: Option<i64>,
+
+    /// The page number
+    #[serde(rename = "PageNumber")]
+    pub page_number: Option<i64>,
+
+    /// The page size
+    #[serde(rename = "PageSize")]
+    pub page_size: Option<i64>,
+}
+
+/// A NAT gateway listed by `DescribeNatGateways`.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct NatGatewayInfo {
+    /// The ID of the NAT gateway
+    #[serde(rename = "NatGatewayId")]
+    pub nat_gateway_id: String,
+
+    /// The name of the NAT gateway
+    #[serde(rename = "NatGatewayName")]
+    pub nat_gateway_name: String,
+
+    /// The description of the NAT gateway
+    #[serde(rename = "Description")]
+    pub description: String,
+
+    /// The specification of the NAT gateway
+    #[serde(rename = "Spec")]
+    pub spec: String,
+
+    /// The status of the NAT gateway, e.g. `Creating` or `Available`
+    #[serde(rename = "Status")]
+    pub status: String,
+
+    /// The billing method of the NAT gateway
+    #[serde(rename = "BillingType")]
+    pub billing_type: i32,
+
+    /// The ID of the VPC
+    #[serde(rename = "VpcId")]
+    pub vpc_id: String,
+
+    /// The ID of the subnet
+    #[serde(rename = "SubnetId")]
+    pub subnet_id: String,
+
+    /// The ID of the network interface of the NAT gateway
+    #[serde(rename = "NetworkInterfaceId")]
+    pub network_interface_id: String,
+
+    /// The EIPs associated with the NAT gateway
+    #[serde(rename = "EipAddresses")]
+    pub eip_addresses: Vec<NatGatewayEipAddress>,
+
+    /// The project of the NAT gateway
+    #[serde(rename = "ProjectName")]
+    pub project_name: String,
+
+    /// The creation time of the NAT gateway
+    #[serde(rename = "CreationTime")]
+    pub creation_time: String,
+
+    /// The tags of the NAT gateway
+    #[serde(rename = "Tags")]
+    pub tags: Vec<api_tag_model::Tag>,
+}
+
+/// An EIP associated with a NAT gateway.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct NatGatewayEipAddress {
+    /// The ID of the EIP
+    #[serde(rename = "AllocationId")]
+    pub allocation_id: String,
+
+    /// The public IP address of the EIP
+    #[serde(rename = "EipAddress")]
+    pub eip_address: String,
+
+    /// What the EIP is used for, e.g. `ForSnat` or `ForDnat`
+    #[serde(rename = "UsingStatus")]
+    pub using_status: String,
+}
+
+/// Request of the `DeleteNatGateway` API.
+///
+/// # Fields
+/// - `nat_gateway_id`: The ID of the NAT gateway, which must hold no SNAT or DNAT entry.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DeleteNatGatewayReq {
+    /// The ID of the NAT gateway
+    #[serde(rename = "NatGatewayId")]
+    pub nat_gateway_id: String,
+}
+
+/// The private addresses an SNAT entry gives outbound access to.
+///
+/// Sent as `SubnetId` or `SourceCidr`, which are mutually exclusive.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(try_from = "RawSnatSource", into = "RawSnatSource")]
+pub enum SnatSource {
+    /// Every address of a subnet, by its ID
+    Subnet(String),
+    /// The addresses of a CIDR block within the VPC, e.g. `172.16.1.0/24` or `172.16.1.5/32`
+    Cidr(String),
+}
+
+/// The fields of an SNAT source as sent by the API.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+struct RawSnatSource {
+    #[serde(rename = "SubnetId", skip_serializing_if = "Option::is_none")]
+    subnet_id: Option<String>,
+    #[serde(rename = "SourceCidr", skip_serializing_if = "Option::is_none")]
+    source_cidr: Option<String>,
+}
+
+impl From<SnatSource> for RawSnatSource {
+    fn from(source: SnatSource) -> Self {
+        match source {
+            SnatSource::Subnet(subnet_id) => RawSnatSource {
+                subnet_id: Some(subnet_id),
+                source_cidr: None,
+            },
+            SnatSource::Cidr(source_cidr) => RawSnatSource {
+                subnet_id: None,
+                source_cidr: Some(source_cidr),
+            },
+        }
+    }
+}
+
+impl TryFrom<RawSnatSource> for SnatSource {
+    type Error = String;
+
+    fn try_from(raw: RawSnatSource) -> Result<Self, Self::Error> {
+        // Empty strings stand for unset fields in the responses.
+        let subnet_id = raw.subnet_id.filter(|subnet_id| !subnet_id.is_empty());
+        let source_cidr = raw
+            .source_cidr
+            .filter(|source_cidr| !source_cidr.is_empty());
+        match (subnet_id, source_cidr) {
+            (Some(subnet_id), _) => Ok(SnatSource::Subnet(subnet_id)),
+            (None, Some(source_cidr)) => Ok(SnatSource::Cidr(source_cidr)),
+            (None, None) => Err("the SNAT entry has neither SubnetId nor SourceCidr".to_string()),
+        }
+    }
+}
+
+/// Request of the `CreateSnatEntry` API.
+///
+/// # Fields
+/// - `nat_gateway_id`: The ID of the NAT gateway.
+/// - `eip_id`: The ID of the EIP the traffic goes out from, associated with the NAT gateway.
+/// - `source`: The private addresses given outbound access.
+/// - `snat_entry_name`: The name of the SNAT entry.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CreateSnatEntryReq {
+    /// The ID of the NAT gateway
+    #[serde(rename = "NatGatewayId")]
+    pub nat_gateway_id: String,
+
+    /// The ID of the EIP
+    #[serde(rename = "EipId")]
+    pub eip_id: String,
+
+    /// The private addresses
+    #[serde(flatten)]
+    pub source: SnatSource,
+
+    /// The name of the SNAT entry
+    #[serde(rename = "SnatEntryName", skip_serializing_if = "Option::is_none")]
+    pub snat_entry_name: Option<String>,
+}
+
+/// Response of the `CreateSnatEntry` API.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct CreateSnatEntryResp {
+    /// Metadata of the response
+    #[serde(rename = "ResponseMetadata")]
+    pub response_metadata: response::ApiResponseMetadata,
+
+    /// The result of the call
+    #[serde(rename = "Result")]
+    pub result: Option<CreateSnatEntryResult>,
+}
+
+/// Result of the `CreateSnatEntry` API.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct CreateSnatEntryResult {
+    /// The ID of the SNAT entry created
+    #[serde(rename = "SnatEntryId")]
+    pub snat_entry_id: String,
+}
+
+/// Request of the `DescribeSnatEntries` API.
+///
+/// # Fields
+/// - `snat_entry_ids`: Lists these SNAT entries.
+/// - `nat_gateway_id`: Lists the SNAT entries of this NAT gateway.
+/// - `snat_entry_name`: Lists the SNAT entries with this name.
+/// - `subnet_id`: Lists the SNAT entries of this subnet.
+/// - `source_cidr`: Lists the SNAT entries of this CIDR block.
+/// - `eip_id`: Lists the SNAT entries going out from this EIP.
+/// - `page_number`: The page to list, from 1.
+/// - `page_size`: The number of SNAT entries per page.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DescribeSnatEntriesReq {
+    /// The IDs of the SNAT entries
+    #[serde(rename = "SnatEntryIds")]
+    pub snat_entry_ids: Vec<String>,
+
+    /// The ID of the NAT gateway
+    #[serde(rename = "NatGatewayId", skip_serializing_if = "Option::is_none")]
+    pub nat_gateway_id: Option<String>,
+
+    /// The name of the SNAT entries
+    #[serde(rename = "SnatEntryName", skip_serializing_if = "Option::is_none")]
+    pub snat_entry_name: Option<String>,
+
+    /// The ID of the subnet
+    #[serde(rename = "SubnetId", skip_serializing_if = "Option::is_none")]
+    pub subnet_id: Option<String>,
+
+    /// The CIDR block
+    #[serde(rename = "SourceCidr", skip_serializing_if = "Option::is_none")]
+    pub source_cidr: Option<String>,
+
+    /// The ID of the EIP
+    #[serde(rename = "EipId", skip_serializing_if = "Option::is_none")]
+    pub eip_id: Option<String>,
+
+    /// The page number
+    #[serde(rename = "PageNumber", skip_serializing_if = "Option::is_none")]
+    pub page_number: Option<i64>,
+
+    /// The page size
+    #[serde(rename = "PageSize", skip_serializing_if = "Option::is_none")]
+    pub page_size: Option<i64>,
+}
+
+/// Response of the `DescribeSnatEntries` API.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct DescribeSnatEntriesResp {
+    /// Metadata of the response
+    #[serde(rename = "ResponseMetadata")]
+    pub response_metadata: response::ApiResponseMetadata,
+
+    /// The result of the call
+    #[serde(rename = "Result")]
+    pub result: Option<DescribeSnatEntriesResult>,
+}
+
+/// Result of the `DescribeSnatEntries` API.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct DescribeSnatEntriesResult {
+    /// The SNAT entries of the page
+    #[serde(rename = "SnatEntries")]
+    pub snat_entries: Vec<SnatEntry>,
+
+    /// The number of SNAT entries listed
+    #[serde(rename = "TotalCount")]
+    pub total_count: Option<i64>,
+
+    /// The page number
+    #[serde(rename = "PageNumber")]
+    pub page_number: Option<i64>,
+
+    /// The page size
+    #[serde(rename = "PageSize")]
+    pub page_size: Option<i64>,
+}
+
+/// An SNAT entry listed by `DescribeSnatEntries`.
+///
+/// The source is kept as the `SubnetId` / `SourceCidr` sent by the API, so that an entry with
+/// neither does not fail the whole response; `source` reads them into a `SnatSource`.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct SnatEntry {
+    /// The ID of the SNAT entry
+    #[serde(rename = "SnatEntryId")]
+    pub snat_entry_id: String,
+
+    /// The name of the SNAT entry
+    #[serde(rename = "SnatEntryName")]
+    pub snat_entry_name: String,
+
+    /// The ID of the NAT gateway
+    #[serde(rename = "NatGatewayId")]
+    pub nat_gateway_id: String,
+
+    /// The ID of the EIP
+    #[serde(rename = "EipId")]
+    pub eip_id: String,
+
+    /// The public IP address of the EIP
+    #[serde(rename = "EipAddress")]
+    pub eip_address: String,
+
+    /// The ID of the subnet given outbound access, empty for a CIDR block
+    #[serde(rename = "SubnetId")]
+    pub subnet_id: String,
+
+    /// The CIDR block given outbound access, empty for a subnet
+    #[serde(rename = "SourceCidr")]
+    pub source_cidr: String,
+
+    /// The status of the SNAT entry, e.g. `Creating` or `Available`
+    #[serde(rename = "Status")]
+    pub status: String,
+}
+
+impl SnatEntry {
+    /// Returns the private addresses the SNAT entry gives outbound access to.
+    ///
+    /// # Returns
+    /// - `Some(SnatSource)`: The subnet or the CIDR block.
+    /// - `None`: If the entry has neither.
+    pub fn source(&self) -> Option<SnatSource> {
+        SnatSource::try_from(RawSnatSource {
+            subnet_id: Some(self.subnet_id.clone()),
+            source_cidr: Some(self.source_cidr.clone()),
+        })
+        .ok()
+    }
+}
+
+/// Request of the `DeleteSnatEntry` API.
+///
+/// # Fields
+/// - `snat_entry_id`: The ID of the SNAT entry.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DeleteSnatEntryReq {
+    /// The ID of the SNAT entry
+    #[serde(rename = "SnatEntryId")]
+    pub snat_entry_id: String,
+}
+
+/// The protocol of a DNAT entry.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum DnatProtocol {
+    /// `tcp`
+    #[default]
+    Tcp,
+    /// `udp`
+    Udp,
+}
+
+/// Request of the `CreateDnatEntry` API.
+///
+/// # Fields
+/// - `nat_gateway_id`: The ID of the NAT gateway.
+/// - `external_ip`: The public IP address of an EIP associated with the NAT gateway.
+/// - `external_port`: The public port, e.g. `80`, or a range of ports, e.g. `8000-8010`.
+/// - `internal_ip`: The private IP address the traffic is forwarded to.
+/// - `internal_port`: The private port, or a range of ports as long as `external_port`.
+/// - `protocol`: The protocol forwarded.
+/// - `dnat_entry_name`: The name of the DNAT entry.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct CreateDnatEntryReq {
+    /// The ID of the NAT gateway
+    #[serde(rename = "NatGatewayId")]
+    pub nat_gateway_id: String,
+
+    /// The public IP address
+    #[serde(rename = "ExternalIp")]
+    pub external_ip: String,
+
+    /// The public port
+    #[serde(rename = "ExternalPort")]
+    pub external_port: String,
+
+    /// The private IP address
+    #[serde(rename = "InternalIp")]
+    pub internal_ip: String,
+
+    /// The private port
+    #[serde(rename = "InternalPort")]
+    pub internal_port: String,
+
+    /// The protocol
+    #[serde(rename = "Protocol")]
+    pub protocol: DnatProtocol,
+
+    /// The name of the DNAT entry
+    #[serde(rename = "DnatEntryName", skip_serializing_if = "Option::is_none")]
+    pub dnat_entry_name: Option<String>,
+}
+
+/// Response of the `CreateDnatEntry` API.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct CreateDnatEntryResp {
+    /// Metadata of the response
+    #[serde(rename = "ResponseMetadata")]
+    pub response_metadata: response::ApiResponseMetadata,
+
+    /// The result of the call
+    #[serde(rename = "Result")]
+    pub result: Option<CreateDnatEntryResult>,
+}
+
+/// Result of the `CreateDnatEntry` API.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct CreateDnatEntryResult {
+    /// The ID of the DNAT entry created
+    #[serde(rename = "DnatEntryId")]
+    pub dnat_entry_id: String,
+}
+
+/// Request of the `DeleteDnatEntry` API.
+///
+/// # Fields
+/// - `dnat_entry_id`: The ID of the DNAT entry.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DeleteDnatEntryReq {
+    /// The ID of the DNAT entry
+    #[serde(rename = "DnatEntryId")]
+    pub dnat_entry_id: String,
+}
+
+/// Response of the NAT gateway APIs returning no result (`DeleteNatGateway`,
+/// `DeleteSnatEntry` and `DeleteDnatEntry`).
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct NatGatewayOperationResp {
+    /// Metadata of the response
+    #[serde(rename = "ResponseMetadata")]
+    pub response_metadata: response::ApiResponseMetadata,
+}
+
+/// Implementation of the `Paginated` trait for the `DescribeNatGatewaysReq` structure.
+/// NAT gateways are paged with `PageNumber` / `PageSize`.
+impl paginator::Paginated for DescribeNatGatewaysReq {
+    type Response = DescribeNatGatewaysResp;
+    type Item = NatGatewayInfo;
+
+    /// Returns the NAT gateways of a page.
+    fn items(response: &Self::Response) -> &[Self::Item] {
+        response
+            .result
+            .as_ref()
+            .map_or(&[], |result| result.nat_gateways.as_slice())
+    }
+
+    /// Requests the page following the page number of the response.
+    fn next_request(&self, response: &Self::Response, fetched: usize) -> Option<Self> {
+        let result = response.result.as_ref()?;
+        let page_number = paginator::next_page_number(
+            self.page_number.unwrap_or_default(),
+            result.nat_gateways.len(),
+            fetched,
+            result.total_count,
+        )?;
+        Some(Self {
+            page_number: Some(page_number),
+            ..self.clone()
+        })
+    }
+
+    /// Counts the items of the pages before the requested page number.
+    fn items_before(&self) -> usize {
+        paginator::items_before_page(self.page_number.unwrap_or_default(), self.page_size)
+    }
+}
+
+/// Implementation of the `Paginated` trait for the `DescribeSnatEntriesReq` structure.
+/// SNAT entries are paged with `PageNumber` / `PageSize`.
+impl paginator::Paginated for DescribeSnatEntriesReq {
+    type Response = DescribeSnatEntriesResp;
+    type Item = SnatEntry;
+
+    /// Returns the SNAT entries of a page.
+    fn items(response: &Self::Response) -> &[Self::Item] {
+        response
+            .result
+            .as_ref()
+            .map_or(&[], |result| result.snat_entries.as_slice())
+    }
+
+    /// Requests the page following the page number of the response.
+    fn next_request(&self, response: &Self::Response, fetched: usize) -> Option<Self> {
+        let result = response.result.as_ref()?;
+        let page_number = paginator::next_page_number(
+            self.page_number.unwrap_or_default(),
+            result.snat_entries.len(),
+            fetched,
+            result.total_count,
+        )?;
+        Some(Self {
+            page_number: Some(page_number),
+            ..self.clone()
+        })
+    }
+
+    /// Counts the items of the pages before the requested page number.
+    fn items_before(&self) -> usize {
+        paginator::items_before_page(self.page_number.unwrap_or_default(), self.page_size)
+    }
+}
@@ -0,0 +1,163 @@
+/*
+ * @Author: Jerry.Yang
+ * @Date: 2025-03-27 11:18:05
+ * @LastEditors: Jerry.Yang
+ * @LastEditTime: 2025-03-30 11:10:00
+ * @Description: Blocking facade for NAT gateway operations
+ */
+use crate::service::nat_gateway;
+use crate::service::nat_gateway::api_nat_gateway_model;
+use crate::service::nat_gateway::NatGatewayService;
+use crate::volcengine::blocking::blocking;
+use crate::volcengine::error::error;
+use crate::volcengine::session::session;
+
+/// A synchronous facade over `nat_gateway::NatGateway`.
+///
+/// Exposes the operations of `NatGatewayService` as blocking methods, driven by the runtime
+/// shared by the blocking facades. Meant for build scripts and command line tools; asynchronous
+/// code should use `nat_gateway::NatGateway` directly.
+///
+/// # Example
+/// ```rust,no_run
+/// # use volcengine_rust_sdk::volcengine::error::error;
+/// # use volcengine_rust_sdk::volcengine::session::session;
+/// use volcengine_rust_sdk::service::nat_gateway;
+///
+/// # fn example(session: session::Session) -> Result<(), error::Error> {
+/// let nat_gateway = nat_gateway::blocking::NatGateway::new_nat_gateway(session)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct NatGateway {
+    nat_gateway: nat_gateway::NatGateway, // The asynchronous service.
+    runtime: blocking::Runtime,           // The runtime driving its requests.
+}
+
+impl NatGateway {
+    /// Creates a new blocking `NatGateway` using the provided session.
+    ///
+    /// # Arguments
+    /// - `session`: A `Session` object containing authentication and configuration information.
+    ///
+    /// # Returns
+    /// - `Ok(NatGateway)`: The blocking service.
+    /// - `Err(error::Error)`: If the service or the runtime could not be created.
+    pub fn new_nat_gateway(session: session::Session) -> Result<NatGateway, error::Error> {
+        Ok(NatGateway {
+            nat_gateway: nat_gateway::NatGateway::new_nat_gateway(session)?,
+            runtime: blocking::Runtime::shared()?,
+        })
+    }
+
+    /// Creates a NAT gateway in a subnet of a VPC.
+    ///
+    /// Blocks until `NatGatewayService::new_create_nat_gateway` completes.
+    pub fn new_create_nat_gateway(
+        &self,
+        request: api_nat_gateway_model::CreateNatGatewayReq,
+    ) -> Result<api_nat_gateway_model::CreateNatGatewayResp, error::Error> {
+        self.runtime
+            .block_on(self.nat_gateway.new_create_nat_gateway(request))
+    }
+
+    /// Describes NAT gateways.
+    ///
+    /// Blocks until `NatGatewayService::new_describe_nat_gateways` completes.
+    pub fn new_describe_nat_gateways(
+        &self,
+        request: api_nat_gateway_model::DescribeNatGatewaysReq,
+    ) -> Result<api_nat_gateway_model::DescribeNatGatewaysResp, error::Error> {
+        self.runtime
+            .block_on(self.nat_gateway.new_describe_nat_gateways(request))
+    }
+
+    /// Deletes a NAT gateway.
+    ///
+    /// Blocks until `NatGatewayService::new_delete_nat_gateway` completes.
+    pub fn new_delete_nat_gateway(
+        &self,
+        request: api_nat_gateway_model::DeleteNatGatewayReq,
+    ) -> Result<api_nat_gateway_model::NatGatewayOperationResp, error::Error> {
+        self.runtime
+            .block_on(self.nat_gateway.new_delete_nat_gateway(request))
+    }
+
+    /// Adds an SNAT entry to a NAT gateway, giving a subnet or a CIDR block outbound access through an EIP.
+    ///
+    /// Blocks until `NatGatewayService::new_create_snat_entry` completes.
+    pub fn new_create_snat_entry(
+        &self,
+        request: api_nat_gateway_model::CreateSnatEntryReq,
+    ) -> Result<api_nat_gateway_model::CreateSnatEntryResp, error::Error> {
+        self.runtime
+            .block_on(self.nat_gateway.new_create_snat_entry(request))
+    }
+
+    /// Describes SNAT entries.
+    ///
+    /// Blocks until `NatGatewayService::new_describe_snat_entries` completes.
+    pub fn new_describe_snat_entries(
+        &self,
+        request: api_nat_gateway_model::DescribeSnatEntriesReq,
+    ) -> Result<api_nat_gateway_model::DescribeSnatEntriesResp, error::Error> {
+        self.runtime
+            .block_on(self.nat_gateway.new_describe_snat_entries(request))
+    }
+
+    /// Deletes an SNAT entry.
+    ///
+    /// Blocks until `NatGatewayService::new_delete_snat_entry` completes.
+    pub fn new_delete_snat_entry(
+        &self,
+        request: api_nat_gateway_model::DeleteSnatEntryReq,
+    ) -> Result<api_nat_gateway_model::NatGatewayOperationResp, error::Error> {
+        self.runtime
+            .block_on(self.nat_gateway.new_delete_snat_entry(request))
+    }
+
+    /// Adds a DNAT entry to a NAT gateway, forwarding a public port of an EIP to a private address.
+    ///
+    /// Blocks until `NatGatewayService::new_create_dnat_entry` completes.
+    pub fn new_create_dnat_entry(
+        &self,
+        request: api_nat_gateway_model::CreateDnatEntryReq,
+    ) -> Result<api_nat_gateway_model::CreateDnatEntryResp, error::Error> {
+        self.runtime
+            .block_on(self.nat_gateway.new_create_dnat_entry(request))
+    }
+
+    /// Deletes a DNAT entry.
+    ///
+    /// Blocks until `NatGatewayService::new_delete_dnat_entry` completes.
+    pub fn new_delete_dnat_entry(
+        &self,
+        request: api_nat_gateway_model::DeleteDnatEntryReq,
+    ) -> Result<api_nat_gateway_model::NatGatewayOperationResp, error::Error> {
+        self.runtime
+            .block_on(self.nat_gateway.new_delete_dnat_entry(request))
+    }
+
+    /// Lists NAT gateways page by page.
+    ///
+    /// The pages are fetched synchronously as the iterators of the paginator are advanced.
+    pub fn describe_nat_gateways_paginator(
+        &self,
+        request: api_nat_gateway_model::DescribeNatGatewaysReq,
+    ) -> blocking::Paginator<api_nat_gateway_model::DescribeNatGatewaysReq> {
+        self.runtime
+            .paginator(self.nat_gateway.describe_nat_gateways_paginator(request))
+    }
+
+    /// Lists SNAT entries page by page.
+    ///
+    /// The pages are fetched synchronously as the iterators of the paginator are advanced.
+    pub fn describe_snat_entries_paginator(
+        &self,
+        request: api_nat_gateway_model::DescribeSnatEntriesReq,
+    ) -> blocking::Paginator<api_nat_gateway_model::DescribeSnatEntriesReq> {
+        self.runtime
+            .paginator(self.nat_gateway.describe_snat_entries_paginator(request))
+    }
+}
@@ -0,0 +1,182 @@
+/*
+ * @Author: Jerry.Yang
+ * @Date: 2025-03-27 10:36:52
+ * @LastEditors: Jerry.Yang
+ * @LastEditTime: 2025-03-27 15:22:07
+ * @Description: Module for managing NAT gateways and their SNAT and DNAT entries.
+ */
+use crate::volcengine::client::client;
+use crate::volcengine::error::error;
+use crate::volcengine::paginator::paginator;
+use crate::volcengine::session::session;
+use std::future::Future;
+
+// The model module is public because the NAT gateway request and response types are defined in this crate.
+pub mod api_nat_gateway_model;
+pub mod service_nat_gateway;
+
+// Blocking facade exposing the service operations synchronously.
+pub mod blocking;
+mod tests;
+
+/// Defines the NatGatewayService trait, providing methods for the NAT gateway operations.
+/// A NAT gateway gives the instances of private subnets outbound access (SNAT entries) and
+/// forwards public ports to them (DNAT entries), through the EIPs associated with it.
+pub trait NatGatewayService {
+    /// Creates a new NAT gateway service instance from a given session.
+    ///
+    /// # Arguments
+    /// - `session`: The session object containing the necessary configuration and credentials.
+    ///
+    /// # Returns
+    /// - `Result<NatGateway, error::Error>`: On success, returns a new instance of the NatGateway struct.
+    ///   On failure, returns an error indicating the cause of the failure.
+    fn new_nat_gateway(session: session::Session) -> Result<NatGateway, error::Error>;
+
+    /// Creates a NAT gateway in a subnet of a VPC.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current NAT gateway service instance.
+    /// - `request`: The request structure containing the VPC, the subnet and the specification of the NAT gateway.
+    ///
+    /// # Returns
+    /// - `impl Future<Output = Result<api_nat_gateway_model::CreateNatGatewayResp, error::Error>>`: On success, returns a future that resolves to the response from the NAT gateway service.
+    ///   On failure, returns an error indicating the cause of the failure.
+    fn new_create_nat_gateway(
+        &self,
+        request: api_nat_gateway_model::CreateNatGatewayReq,
+    ) -> impl Future<Output = Result<api_nat_gateway_model::CreateNatGatewayResp, error::Error>>;
+
+    /// Describes NAT gateways.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current NAT gateway service instance.
+    /// - `request`: The request structure containing the parameters for describing NAT gateways.
+    ///
+    /// # Returns
+    /// - `impl Future<Output = Result<api_nat_gateway_model::DescribeNatGatewaysResp, error::Error>>`: On success, returns a future that resolves to the response from the NAT gateway service.
+    ///   On failure, returns an error indicating the cause of the failure.
+    fn new_describe_nat_gateways(
+        &self,
+        request: api_nat_gateway_model::DescribeNatGatewaysReq,
+    ) -> impl Future<Output = Result<api_nat_gateway_model::DescribeNatGatewaysResp, error::Error>>;
+
+    /// Deletes a NAT gateway.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current NAT gateway service instance.
+    /// - `request`: The request structure containing the ID of the NAT gateway.
+    ///
+    /// # Returns
+    /// - `impl Future<Output = Result<api_nat_gateway_model::NatGatewayOperationResp, error::Error>>`: On success, returns a future that resolves to the response from the NAT gateway service.
+    ///   On failure, returns an error indicating the cause of the failure.
+    fn new_delete_nat_gateway(
+        &self,
+        request: api_nat_gateway_model::DeleteNatGatewayReq,
+    ) -> impl Future<Output = Result<api_nat_gateway_model::NatGatewayOperationResp, error::Error>>;
+
+    /// Adds an SNAT entry to a NAT gateway, giving a subnet or a CIDR block outbound access through an EIP.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current NAT gateway service instance.
+    /// - `request`: The request structure containing the NAT gateway, the EIP and the private addresses.
+    ///
+    /// # Returns
+    /// - `impl Future<Output = Result<api_nat_gateway_model::CreateSnatEntryResp, error::Error>>`: On success, returns a future that resolves to the response from the NAT gateway service.
+    ///   On failure, returns an error indicating the cause of the failure.
+    fn new_create_snat_entry(
+        &self,
+        request: api_nat_gateway_model::CreateSnatEntryReq,
+    ) -> impl Future<Output = Result<api_nat_gateway_model::CreateSnatEntryResp, error::Error>>;
+
+    /// Describes SNAT entries.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current NAT gateway service instance.
+    /// - `request`: The request structure containing the parameters for describing SNAT entries.
+    ///
+    /// # Returns
+    /// - `impl Future<Output = Result<api_nat_gateway_model::DescribeSnatEntriesResp, error::Error>>`: On success, returns a future that resolves to the response from the NAT gateway service.
+    ///   On failure, returns an error indicating the cause of the failure.
+    fn new_describe_snat_entries(
+        &self,
+        request: api_nat_gateway_model::DescribeSnatEntriesReq,
+    ) -> impl Future<Output = Result<api_nat_gateway_model::DescribeSnatEntriesResp, error::Error>>;
+
+    /// Deletes an SNAT entry.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current NAT gateway service instance.
+    /// - `request`: The request structure containing the ID of the SNAT entry.
+    ///
+    /// # Returns
+    /// - `impl Future<Output = Result<api_nat_gateway_model::NatGatewayOperationResp, error::Error>>`: On success, returns a future that resolves to the response from the NAT gateway service.
+    ///   On failure, returns an error indicating the cause of the failure.
+    fn new_delete_snat_entry(
+        &self,
+        request: api_nat_gateway_model::DeleteSnatEntryReq,
+    ) -> impl Future<Output = Result<api_nat_gateway_model::NatGatewayOperationResp, error::Error>>;
+
+    /// Adds a DNAT entry to a NAT gateway, forwarding a public port of an EIP to a private address.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current NAT gateway service instance.
+    /// - `request`: The request structure containing the NAT gateway, the public and the private addresses and ports.
+    ///
+    /// # Returns
+    /// - `impl Future<Output = Result<api_nat_gateway_model::CreateDnatEntryResp, error::Error>>`: On success, returns a future that resolves to the response from the NAT gateway service.
+    ///   On failure, returns an error indicating the cause of the failure.
+    fn new_create_dnat_entry(
+        &self,
+        request: api_nat_gateway_model::CreateDnatEntryReq,
+    ) -> impl Future<Output = Result<api_nat_gateway_model::CreateDnatEntryResp, error::Error>>;
+
+    /// Deletes a DNAT entry.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current NAT gateway service instance.
+    /// - `request`: The request structure containing the ID of the DNAT entry.
+    ///
+    /// # Returns
+    /// - `impl Future<Output = Result<api_nat_gateway_model::NatGatewayOperationResp, error::Error>>`: On success, returns a future that resolves to the response from the NAT gateway service.
+    ///   On failure, returns an error indicating the cause of the failure.
+    fn new_delete_dnat_entry(
+        &self,
+        request: api_nat_gateway_model::DeleteDnatEntryReq,
+    ) -> impl Future<Output = Result<api_nat_gateway_model::NatGatewayOperationResp, error::Error>>;
+
+    /// Lists NAT gateways page by page.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current service instance.
+    /// - `request`: The request of the first page; `PageSize` sets the page size.
+    ///
+    /// # Returns
+    /// - `paginator::Paginator<api_nat_gateway_model::DescribeNatGatewaysReq>`: A paginator turned into a stream over the pages with `pages()`
+    ///   or over the NAT gateways with `items()`.
+    fn describe_nat_gateways_paginator(
+        &self,
+        request: api_nat_gateway_model::DescribeNatGatewaysReq,
+    ) -> paginator::Paginator<api_nat_gateway_model::DescribeNatGatewaysReq>;
+
+    /// Lists SNAT entries page by page.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current service instance.
+    /// - `request`: The request of the first page; `PageSize` sets the page size.
+    ///
+    /// # Returns
+    /// - `paginator::Paginator<api_nat_gateway_model::DescribeSnatEntriesReq>`: A paginator turned into a stream over the pages with `pages()`
+    ///   or over the SNAT entries with `items()`.
+    fn describe_snat_entries_paginator(
+        &self,
+        request: api_nat_gateway_model::DescribeSnatEntriesReq,
+    ) -> paginator::Paginator<api_nat_gateway_model::DescribeSnatEntriesReq>;
+}
+
+/// Represents the NAT gateway service, encapsulating the client information required to interact with the Volcengine NAT gateway service.
+#[derive(Debug, Clone)]
+pub struct NatGateway {
+    /// The client used to make requests to the Volcengine NAT gateway service.
+    client: client::Client,
+}
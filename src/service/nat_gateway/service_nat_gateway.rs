@@ -0,0 +1,277 @@
+/*
+ * @Author: Jerry.Yang
+ * @Date: 2025-03-27 10:52:33
+ * @LastEditors: Jerry.Yang
+ * @LastEditTime: 2025-03-27 15:22:07
+ * @Description: Implementation of the NAT gateway service.
+ */
+use super::{NatGateway, NatGatewayService};
+use crate::service::nat_gateway::api_nat_gateway_model;
+use crate::volcengine::client::client;
+use crate::volcengine::client::client_info;
+use crate::volcengine::client::config as client_config;
+use crate::volcengine::common;
+use crate::volcengine::error::error;
+use crate::volcengine::paginator::paginator;
+use crate::volcengine::registry::registry;
+use crate::volcengine::session::session;
+
+/// Implementation of the NatGatewayService trait for the NatGateway struct.
+/// Every operation is declared in the registry and sent with `registry::invoke`.
+impl NatGatewayService for NatGateway {
+    /// Creates a new NAT gateway service instance from a given session.
+    ///
+    /// # Arguments
+    /// - `session`: The session object containing the necessary configuration and credentials.
+    ///
+    /// # Returns
+    /// - `Result<Self, error::Error>`: On success, returns a new instance of the NatGateway struct.
+    ///   On failure, returns an error indicating the cause of the failure.
+    fn new_nat_gateway(session: session::Session) -> Result<Self, error::Error> {
+        // Create a new client configuration for the NAT gateway service.
+        let client_config =
+            session.new_client_config(client_config::ClientServiceName::NatGateway)?;
+
+        // Build the client information with the required parameters.
+        let client_info = client_info::ClientInfo::builder()
+            .with_service_name(client_config::ClientServiceName::NatGateway)
+            .with_api_version(common::COMMON_VERSION_2020_04_01)
+            .with_signing_region(&client_config.signing_region)
+            .build()?;
+
+        // Use the request handles registered on the session
+        let request_handles = client_config.handles.clone();
+
+        // Build the client with the provided information.
+        let client = client::Client::builder()
+            .with_client_info(&client_info)
+            .with_config(&client_config)
+            .with_handles(&request_handles)
+            .build()?;
+
+        // Return the new NAT gateway service instance.
+        Ok(NatGateway { client })
+    }
+
+    /// Creates a NAT gateway in a subnet of a VPC.
+    ///
+    /// The operation is declared in the registry and sent with `registry::invoke`.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current NAT gateway service instance.
+    /// - `request`: The request structure containing the VPC, the subnet and the specification of the NAT gateway.
+    ///
+    /// # Returns
+    /// - `Result<api_nat_gateway_model::CreateNatGatewayResp, error::Error>`: On success, returns the response from the NAT gateway service.
+    ///   On failure, returns an error indicating the cause of the failure.
+    async fn new_create_nat_gateway(
+        &self,
+        request: api_nat_gateway_model::CreateNatGatewayReq,
+    ) -> Result<api_nat_gateway_model::CreateNatGatewayResp, error::Error> {
+        registry::invoke(
+            &self.client,
+            &registry::NAT_GATEWAY_CREATE_NAT_GATEWAY,
+            &request,
+        )
+        .await
+    }
+
+    /// Describes NAT gateways.
+    ///
+    /// The operation is declared in the registry and sent with `registry::invoke`.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current NAT gateway service instance.
+    /// - `request`: The request structure containing the parameters for describing NAT gateways.
+    ///
+    /// # Returns
+    /// - `Result<api_nat_gateway_model::DescribeNatGatewaysResp, error::Error>`: On success, returns the response from the NAT gateway service.
+    ///   On failure, returns an error indicating the cause of the failure.
+    async fn new_describe_nat_gateways(
+        &self,
+        request: api_nat_gateway_model::DescribeNatGatewaysReq,
+    ) -> Result<api_nat_gateway_model::DescribeNatGatewaysResp, error::Error> {
+        registry::invoke(
+            &self.client,
+            &registry::NAT_GATEWAY_DESCRIBE_NAT_GATEWAYS,
+            &request,
+        )
+        .await
+    }
+
+    /// Deletes a NAT gateway.
+    ///
+    /// The operation is declared in the registry and sent with `registry::invoke`.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current NAT gateway service instance.
+    /// - `request`: The request structure containing the ID of the NAT gateway.
+    ///
+    /// # Returns
+    /// - `Result<api_nat_gateway_model::NatGatewayOperationResp, error::Error>`: On success, returns the response from the NAT gateway service.
+    ///   On failure, returns an error indicating the cause of the failure.
+    async fn new_delete_nat_gateway(
+        &self,
+        request: api_nat_gateway_model::DeleteNatGatewayReq,
+    ) -> Result<api_nat_gateway_model::NatGatewayOperationResp, error::Error> {
+        registry::invoke(
+            &self.client,
+            &registry::NAT_GATEWAY_DELETE_NAT_GATEWAY,
+            &request,
+        )
+        .await
+    }
+
+    /// Adds an SNAT entry to a NAT gateway, giving a subnet or a CIDR block outbound access through an EIP.
+    ///
+    /// The operation is declared in the registry and sent with `registry::invoke`.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current NAT gateway service instance.
+    /// - `request`: The request structure containing the NAT gateway, the EIP and the private addresses.
+    ///
+    /// # Returns
+    /// - `Result<api_nat_gateway_model::CreateSnatEntryResp, error::Error>`: On success, returns the response from the NAT gateway service.
+    ///   On failure, returns an error indicating the cause of the failure.
+    async fn new_create_snat_entry(
+        &self,
+        request: api_nat_gateway_model::CreateSnatEntryReq,
+    ) -> Result<api_nat_gateway_model::CreateSnatEntryResp, error::Error> {
+        registry::invoke(
+            &self.client,
+            &registry::NAT_GATEWAY_CREATE_SNAT_ENTRY,
+            &request,
+        )
+        .await
+    }
+
+    /// Describes SNAT entries.
+    ///
+    /// The operation is declared in the registry and sent with `registry::invoke`.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current NAT gateway service instance.
+    /// - `request`: The request structure containing the parameters for describing SNAT entries.
+    ///
+    /// # Returns
+    /// - `Result<api_nat_gateway_model::DescribeSnatEntriesResp, error::Error>`: On success, returns the response from the NAT gateway service.
+    ///   On failure, returns an error indicating the cause of the failure.
+    async fn new_describe_snat_entries(
+        &self,
+        request: api_nat_gateway_model::DescribeSnatEntriesReq,
+    ) -> Result<api_nat_gateway_model::DescribeSnatEntriesResp, error::Error> {
+        registry::invoke(
+            &self.client,
+            &registry::NAT_GATEWAY_DESCRIBE_SNAT_ENTRIES,
+            &request,
+        )
+        .await
+    }
+
+    /// Deletes an SNAT entry.
+    ///
+    /// The operation is declared in the registry and sent with `registry::invoke`.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current NAT gateway service instance.
+    /// - `request`: The request structure containing the ID of the SNAT entry.
+    ///
+    /// # Returns
+    /// - `Result<api_nat_gateway_model::NatGatewayOperationResp, error::Error>`: On success, returns the response from the NAT gateway service.
+    ///   On failure, returns an error indicating the cause of the failure.
+    async fn new_delete_snat_entry(
+        &self,
+        request: api_nat_gateway_model::DeleteSnatEntryReq,
+    ) -> Result<api_nat_gateway_model::NatGatewayOperationResp, error::Error> {
+        registry::invoke(
+            &self.client,
+            &registry::NAT_GATEWAY_DELETE_SNAT_ENTRY,
+            &request,
+        )
+        .await
+    }
+
+    /// Adds a DNAT entry to a NAT gateway, forwarding a public port of an EIP to a private address.
+    ///
+    /// The operation is declared in the registry and sent with `registry::invoke`.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current NAT gateway service instance.
+    /// - `request`: The request structure containing the NAT gateway, the public and the private addresses and ports.
+    ///
+    /// # Returns
+    /// - `Result<api_nat_gateway_model::CreateDnatEntryResp, error::Error>`: On success, returns the response from the NAT gateway service.
+    ///   On failure, returns an error indicating the cause of the failure.
+    async fn new_create_dnat_entry(
+        &self,
+        request: api_nat_gateway_model::CreateDnatEntryReq,
+    ) -> Result<api_nat_gateway_model::CreateDnatEntryResp, error::Error> {
+        registry::invoke(
+            &self.client,
+            &registry::NAT_GATEWAY_CREATE_DNAT_ENTRY,
+            &request,
+        )
+        .await
+    }
+
+    /// Deletes a DNAT entry.
+    ///
+    /// The operation is declared in the registry and sent with `registry::invoke`.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current NAT gateway service instance.
+    /// - `request`: The request structure containing the ID of the DNAT entry.
+    ///
+    /// # Returns
+    /// - `Result<api_nat_gateway_model::NatGatewayOperationResp, error::Error>`: On success, returns the response from the NAT gateway service.
+    ///   On failure, returns an error indicating the cause of the failure.
+    async fn new_delete_dnat_entry(
+        &self,
+        request: api_nat_gateway_model::DeleteDnatEntryReq,
+    ) -> Result<api_nat_gateway_model::NatGatewayOperationResp, error::Error> {
+        registry::invoke(
+            &self.client,
+            &registry::NAT_GATEWAY_DELETE_DNAT_ENTRY,
+            &request,
+        )
+        .await
+    }
+
+    /// Lists NAT gateways page by page.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current service instance.
+    /// - `request`: The request of the first page.
+    ///
+    /// # Returns
+    /// - `paginator::Paginator<api_nat_gateway_model::DescribeNatGatewaysReq>`: A paginator sending `new_describe_nat_gateways` once per page.
+    fn describe_nat_gateways_paginator(
+        &self,
+        request: api_nat_gateway_model::DescribeNatGatewaysReq,
+    ) -> paginator::Paginator<api_nat_gateway_model::DescribeNatGatewaysReq> {
+        let nat_gateway = self.clone();
+        paginator::Paginator::new(request, move |request| {
+            let nat_gateway = nat_gateway.clone();
+            Box::pin(async move { nat_gateway.new_describe_nat_gateways(request).await })
+        })
+    }
+
+    /// Lists SNAT entries page by page.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current service instance.
+    /// - `request`: The request of the first page.
+    ///
+    /// # Returns
+    /// - `paginator::Paginator<api_nat_gateway_model::DescribeSnatEntriesReq>`: A paginator sending `new_describe_snat_entries` once per page.
+    fn describe_snat_entries_paginator(
+        &self,
+        request: api_nat_gateway_model::DescribeSnatEntriesReq,
+    ) -> paginator::Paginator<api_nat_gateway_model::DescribeSnatEntriesReq> {
+        let nat_gateway = self.clone();
+        paginator::Paginator::new(request, move |request| {
+            let nat_gateway = nat_gateway.clone();
+            Box::pin(async move { nat_gateway.new_describe_snat_entries(request).await })
+        })
+    }
+}
@@ -0,0 +1,266 @@
+/*
+ * @Author: Jerry.Yang
+ * @Date: 2025-03-27 13:40:11
+ * @LastEditors: Jerry.Yang
+ * @LastEditTime: 2025-03-29 15:20:00
+ * @Description: nat gateway test
+ */
+#[cfg(test)]
+mod tests {
+    use futures::TryStreamExt;
+
+    use crate::{
+        service::nat_gateway::{self, api_nat_gateway_model, NatGatewayService},
+        volcengine::{error::error, mock::mock},
+    };
+
+    /// Starts a mock server answering `action` with `response`, and a `NatGateway` calling it.
+    async fn mock_nat_gateway(
+        action: &str,
+        response: mock::MockResponse,
+    ) -> (mock::MockServer, nat_gateway::NatGateway) {
+        let server = mock::MockServer::start().await;
+        server.mock(action, "2020-04-01", response);
+        let nat_gateway = nat_gateway::NatGateway::new_nat_gateway(server.session()).unwrap();
+        (server, nat_gateway)
+    }
+
+    #[tokio::test]
+    async fn test_create_nat_gateway() {
+        let (server, nat_gateway) = mock_nat_gateway(
+            "CreateNatGateway",
+            mock::MockResponse::json(&serde_json::json!({
+                "Result": { "NatGatewayId": "ngw-1" },
+            })),
+        )
+        .await;
+
+        let request = api_nat_gateway_model::CreateNatGatewayReq {
+            vpc_id: "vpc-1".to_string(),
+            subnet_id: "subnet-1".to_string(),
+            spec: Some("Small".to_string()),
+            ..Default::default()
+        };
+        let response = nat_gateway.new_create_nat_gateway(request).await.unwrap();
+        assert_eq!(response.result.unwrap().nat_gateway_id, "ngw-1");
+
+        let request = server.single_request("CreateNatGateway", "natgateway");
+        assert_eq!(request.version, "2020-04-01");
+        assert_eq!(request.query["VpcId"], "vpc-1");
+        assert_eq!(request.query["SubnetId"], "subnet-1");
+        assert_eq!(request.query["Spec"], "Small");
+    }
+
+    #[tokio::test]
+    async fn test_describe_nat_gateways_paginator() {
+        // two pages numbered 1 and 2
+        let server = mock::MockServer::start().await;
+        for nat_gateway_id in ["ngw-1", "ngw-2"] {
+            server.mock(
+                "DescribeNatGateways",
+                "2020-04-01",
+                mock::MockResponse::json(&serde_json::json!({
+                    "Result": {
+                        "TotalCount": 2,
+                        "NatGateways": [{
+                            "NatGatewayId": nat_gateway_id,
+                            "Status": "Available",
+                            "EipAddresses": [{ "AllocationId": "eip-1", "UsingStatus": "ForSnat" }],
+                        }],
+                    },
+                })),
+            );
+        }
+
+        let nat_gateway = nat_gateway::NatGateway::new_nat_gateway(server.session()).unwrap();
+        let request = api_nat_gateway_model::DescribeNatGatewaysReq {
+            vpc_id: Some("vpc-1".to_string()),
+            page_size: Some(1),
+            ..Default::default()
+        };
+        let nat_gateways: Vec<_> = nat_gateway
+            .describe_nat_gateways_paginator(request)
+            .items()
+            .try_collect()
+            .await
+            .unwrap();
+
+        let nat_gateway_ids: Vec<_> = nat_gateways
+            .iter()
+            .map(|nat_gateway| nat_gateway.nat_gateway_id.as_str())
+            .collect();
+        assert_eq!(nat_gateway_ids, ["ngw-1", "ngw-2"]);
+        assert_eq!(nat_gateways[0].eip_addresses[0].using_status, "ForSnat");
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[1].query["PageNumber"], "2");
+        assert_eq!(requests[1].query["VpcId"], "vpc-1");
+    }
+
+    #[tokio::test]
+    async fn test_create_snat_entry_for_subnet() {
+        let (server, nat_gateway) = mock_nat_gateway(
+            "CreateSnatEntry",
+            mock::MockResponse::json(&serde_json::json!({
+                "Result": { "SnatEntryId": "snat-1" },
+            })),
+        )
+        .await;
+
+        let request = api_nat_gateway_model::CreateSnatEntryReq {
+            nat_gateway_id: "ngw-1".to_string(),
+            eip_id: "eip-1".to_string(),
+            source: api_nat_gateway_model::SnatSource::Subnet("subnet-1".to_string()),
+            snat_entry_name: None,
+        };
+        let response = nat_gateway.new_create_snat_entry(request).await.unwrap();
+        assert_eq!(response.result.unwrap().snat_entry_id, "snat-1");
+
+        // the source is sent as one of SubnetId and SourceCidr
+        let request = server.single_request("CreateSnatEntry", "natgateway");
+        assert_eq!(request.query["NatGatewayId"], "ngw-1");
+        assert_eq!(request.query["EipId"], "eip-1");
+        assert_eq!(request.query["SubnetId"], "subnet-1");
+        assert!(!request.query.contains_key("SourceCidr"));
+    }
+
+    #[tokio::test]
+    async fn test_create_snat_entry_for_cidr() {
+        let (server, nat_gateway) =
+            mock_nat_gateway("CreateSnatEntry", mock::MockResponse::empty()).await;
+
+        let request = api_nat_gateway_model::CreateSnatEntryReq {
+            nat_gateway_id: "ngw-1".to_string(),
+            eip_id: "eip-1".to_string(),
+            source: api_nat_gateway_model::SnatSource::Cidr("172.16.1.5/32".to_string()),
+            snat_entry_name: Some("db".to_string()),
+        };
+        nat_gateway.new_create_snat_entry(request).await.unwrap();
+
+        let request = server.single_request("CreateSnatEntry", "natgateway");
+        assert_eq!(request.query["SourceCidr"], "172.16.1.5/32");
+        assert_eq!(request.query["SnatEntryName"], "db");
+        assert!(!request.query.contains_key("SubnetId"));
+    }
+
+    #[tokio::test]
+    async fn test_describe_snat_entries() {
+        let (server, nat_gateway) = mock_nat_gateway(
+            "DescribeSnatEntries",
+            mock::MockResponse::json(&serde_json::json!({
+                "Result": {
+                    "TotalCount": 3,
+                    "SnatEntries": [
+                        { "SnatEntryId": "snat-1", "SubnetId": "subnet-1", "SourceCidr": "" },
+                        { "SnatEntryId": "snat-2", "SubnetId": "", "SourceCidr": "172.16.1.5/32" },
+                        { "SnatEntryId": "snat-3", "Status": "Deleting" },
+                    ],
+                },
+            })),
+        )
+        .await;
+
+        let request = api_nat_gateway_model::DescribeSnatEntriesReq {
+            nat_gateway_id: Some("ngw-1".to_string()),
+            ..Default::default()
+        };
+        let response = nat_gateway
+            .new_describe_snat_entries(request)
+            .await
+            .unwrap();
+
+        // empty fields of the source are left out, an entry without source does not fail
+        let snat_entries = response.result.unwrap().snat_entries;
+        assert_eq!(
+            snat_entries[0].source(),
+            Some(api_nat_gateway_model::SnatSource::Subnet(
+                "subnet-1".to_string()
+            ))
+        );
+        assert_eq!(
+            snat_entries[1].source(),
+            Some(api_nat_gateway_model::SnatSource::Cidr(
+                "172.16.1.5/32".to_string()
+            ))
+        );
+        assert_eq!(snat_entries[2].snat_entry_id, "snat-3");
+        assert_eq!(snat_entries[2].source(), None);
+        let request = server.single_request("DescribeSnatEntries", "natgateway");
+        assert_eq!(request.query["NatGatewayId"], "ngw-1");
+    }
+
+    #[tokio::test]
+    async fn test_create_dnat_entry() {
+        let (server, nat_gateway) = mock_nat_gateway(
+            "CreateDnatEntry",
+            mock::MockResponse::json(&serde_json::json!({
+                "Result": { "DnatEntryId": "dnat-1" },
+            })),
+        )
+        .await;
+
+        let request = api_nat_gateway_model::CreateDnatEntryReq {
+            nat_gateway_id: "ngw-1".to_string(),
+            external_ip: "203.0.113.10".to_string(),
+            external_port: "8080".to_string(),
+            internal_ip: "172.16.1.5".to_string(),
+            internal_port: "80".to_string(),
+            protocol: api_nat_gateway_model::DnatProtocol::Udp,
+            ..Default::default()
+        };
+        let response = nat_gateway.new_create_dnat_entry(request).await.unwrap();
+        assert_eq!(response.result.unwrap().dnat_entry_id, "dnat-1");
+
+        let request = server.single_request("CreateDnatEntry", "natgateway");
+        assert_eq!(request.query["ExternalIp"], "203.0.113.10");
+        assert_eq!(request.query["ExternalPort"], "8080");
+        assert_eq!(request.query["InternalIp"], "172.16.1.5");
+        assert_eq!(request.query["InternalPort"], "80");
+        assert_eq!(request.query["Protocol"], "udp");
+    }
+
+    #[tokio::test]
+    async fn test_delete_entries() {
+        let (server, nat_gateway) =
+            mock_nat_gateway("DeleteSnatEntry", mock::MockResponse::empty()).await;
+        let request = api_nat_gateway_model::DeleteSnatEntryReq {
+            snat_entry_id: "snat-1".to_string(),
+        };
+        nat_gateway.new_delete_snat_entry(request).await.unwrap();
+        let request = server.single_request("DeleteSnatEntry", "natgateway");
+        assert_eq!(request.query["SnatEntryId"], "snat-1");
+
+        let (server, nat_gateway) =
+            mock_nat_gateway("DeleteDnatEntry", mock::MockResponse::empty()).await;
+        let request = api_nat_gateway_model::DeleteDnatEntryReq {
+            dnat_entry_id: "dnat-1".to_string(),
+        };
+        nat_gateway.new_delete_dnat_entry(request).await.unwrap();
+        let request = server.single_request("DeleteDnatEntry", "natgateway");
+        assert_eq!(request.query["DnatEntryId"], "dnat-1");
+    }
+
+    #[tokio::test]
+    async fn test_delete_nat_gateway_api_error() {
+        let (server, nat_gateway) = mock_nat_gateway(
+            "DeleteNatGateway",
+            mock::MockResponse::error(
+                400,
+                "DependencyViolation.SnatEntry",
+                "the nat gateway has snat entries",
+            ),
+        )
+        .await;
+
+        let request = api_nat_gateway_model::DeleteNatGatewayReq {
+            nat_gateway_id: "ngw-1".to_string(),
+        };
+        let result = nat_gateway.new_delete_nat_gateway(request).await;
+
+        assert!(matches!(
+            result,
+            Err(error::Error::Api { code, .. }) if code == "DependencyViolation.SnatEntry"
+        ));
+        server.single_request("DeleteNatGateway", "natgateway");
+    }
+}
@@ -0,0 +1,204 @@
+/*
+ * @Author: Jerry.Yang
+ * @Date: 2025-03-18 11:18:06
+ * @LastEditors: Jerry.Yang
+ * @LastEditTime: 2025-03-30 11:10:00
+ * @Description: Blocking facade for RDS (Relational Database Service) operations
+ */
+use crate::service::rds;
+use crate::service::rds::RdsService;
+use crate::volcengine::blocking::blocking;
+use crate::volcengine::error::error;
+use crate::volcengine::session::session;
+use crate::volcengine::waiter::waiter;
+use volcengine_sdk_protobuf::protobuf::rds_account;
+use volcengine_sdk_protobuf::protobuf::rds_allow;
+use volcengine_sdk_protobuf::protobuf::rds_database;
+use volcengine_sdk_protobuf::protobuf::rds_endpoint;
+use volcengine_sdk_protobuf::protobuf::rds_instance;
+
+/// A synchronous facade over `rds::Rds`.
+///
+/// Exposes the operations of `RdsService` as blocking methods, driven by the runtime shared by
+/// the blocking facades. Meant for build scripts and command line tools; asynchronous code
+/// should use `rds::Rds` directly.
+///
+/// # Example
+/// ```rust,no_run
+/// # use volcengine_rust_sdk::volcengine::error::error;
+/// # use volcengine_rust_sdk::volcengine::session::session;
+/// use volcengine_rust_sdk::service::rds;
+///
+/// # fn example(session: session::Session) -> Result<(), error::Error> {
+/// let rds = rds::blocking::Rds::new_rds(session)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Rds {
+    rds: rds::Rds,              // The asynchronous service.
+    runtime: blocking::Runtime, // The runtime driving its requests.
+}
+
+impl Rds {
+    /// Creates a new blocking `Rds` using the provided session.
+    ///
+    /// # Arguments
+    /// - `session`: A `Session` object containing authentication and configuration information.
+    ///
+    /// # Returns
+    /// - `Ok(Rds)`: The blocking service.
+    /// - `Err(error::Error)`: If the service or the runtime could not be created.
+    pub fn new_rds(session: session::Session) -> Result<Rds, error::Error> {
+        Ok(Rds {
+            rds: rds::Rds::new_rds(session)?,
+            runtime: blocking::Runtime::shared()?,
+        })
+    }
+
+    /// Creates a new database instance.
+    ///
+    /// Blocks until `RdsService::new_create_db_instance` completes.
+    pub fn new_create_db_instance(
+        &self,
+        request: rds_instance::CreateDbInstanceReq,
+    ) -> Result<rds_instance::CreateDbInstanceResp, error::Error> {
+        self.runtime
+            .block_on(self.rds.new_create_db_instance(request))
+    }
+
+    /// Describes the details of a specific database instance.
+    ///
+    /// Blocks until `RdsService::new_describe_db_instance_detail` completes.
+    pub fn new_describe_db_instance_detail(
+        &self,
+        request: rds_instance::DescribeDbInstanceDetailReq,
+    ) -> Result<rds_instance::DescribeDbInstanceDetailResp, error::Error> {
+        self.runtime
+            .block_on(self.rds.new_describe_db_instance_detail(request))
+    }
+
+    /// Creates a new database endpoint.
+    ///
+    /// Blocks until `RdsService::new_create_db_endpoint` completes.
+    pub fn new_create_db_endpoint(
+        &self,
+        request: rds_endpoint::CreateDbEndpointReq,
+    ) -> Result<rds_endpoint::CreateDbEndpointResp, error::Error> {
+        self.runtime
+            .block_on(self.rds.new_create_db_endpoint(request))
+    }
+
+    /// Creates a new database account.
+    ///
+    /// Blocks until `RdsService::new_create_db_account` completes.
+    pub fn new_create_db_account(
+        &self,
+        request: rds_account::CreateDbAccountReq,
+    ) -> Result<rds_account::CreateDbAccountResp, error::Error> {
+        self.runtime
+            .block_on(self.rds.new_create_db_account(request))
+    }
+
+    /// Creates a new database.
+    ///
+    /// Blocks until `RdsService::new_create_db_database` completes.
+    pub fn new_create_db_database(
+        &self,
+        request: rds_database::CreateDatabaseReq,
+    ) -> Result<rds_database::CreateDatabaseResp, error::Error> {
+        self.runtime
+            .block_on(self.rds.new_create_db_database(request))
+    }
+
+    /// Modifies the allow list of a database instance.
+    ///
+    /// Blocks until `RdsService::new_modify_allow_list` completes.
+    pub fn new_modify_allow_list(
+        &self,
+        request: rds_allow::ModifyAllowListReq,
+    ) -> Result<rds_allow::ModifyAllowListResp, error::Error> {
+        self.runtime
+            .block_on(self.rds.new_modify_allow_list(request))
+    }
+
+    /// Modifies the specifications of a database instance.
+    ///
+    /// Blocks until `RdsService::new_modify_db_instance_spec` completes.
+    pub fn new_modify_db_instance_spec(
+        &self,
+        request: rds_instance::ModifyDbInstanceSpecReq,
+    ) -> Result<rds_instance::ModifyDbInstanceSpecResp, error::Error> {
+        self.runtime
+            .block_on(self.rds.new_modify_db_instance_spec(request))
+    }
+
+    /// Describes the databases of a database instance.
+    ///
+    /// Blocks until `RdsService::new_describe_db_databases` completes.
+    pub fn new_describe_db_databases(
+        &self,
+        request: rds_database::DescribeDatabasesReq,
+    ) -> Result<rds_database::DescribeDatabasesResp, error::Error> {
+        self.runtime
+            .block_on(self.rds.new_describe_db_databases(request))
+    }
+
+    /// Describes the accounts of a database instance.
+    ///
+    /// Blocks until `RdsService::new_describe_db_accounts` completes.
+    pub fn new_describe_db_accounts(
+        &self,
+        request: rds_account::DescribeDbAccountsReq,
+    ) -> Result<rds_account::DescribeDbAccountsResp, error::Error> {
+        self.runtime
+            .block_on(self.rds.new_describe_db_accounts(request))
+    }
+
+    /// Modifies a database endpoint.
+    ///
+    /// Blocks until `RdsService::new_modify_db_endpoint` completes.
+    pub fn new_modify_db_endpoint(
+        &self,
+        request: rds_endpoint::ModifyDbEndpointReq,
+    ) -> Result<rds_endpoint::ModifyDbEndpointResp, error::Error> {
+        self.runtime
+            .block_on(self.rds.new_modify_db_endpoint(request))
+    }
+
+    /// Describes the database instances.
+    ///
+    /// Blocks until `RdsService::new_describe_db_instances` completes.
+    pub fn new_describe_db_instances(
+        &self,
+        request: rds_instance::DescribeDbInstancesReq,
+    ) -> Result<rds_instance::DescribeDbInstancesResp, error::Error> {
+        self.runtime
+            .block_on(self.rds.new_describe_db_instances(request))
+    }
+
+    /// Lists DB instances page by page.
+    ///
+    /// The pages are fetched synchronously as the iterators of the paginator are advanced.
+    pub fn describe_db_instances_paginator(
+        &self,
+        request: rds_instance::DescribeDbInstancesReq,
+    ) -> blocking::Paginator<rds_instance::DescribeDbInstancesReq> {
+        self.runtime
+            .paginator(self.rds.describe_db_instances_paginator(request))
+    }
+
+    /// Waits until a DB instance is `Running`, e.g. after `new_create_db_instance` or `new_modify_db_instance_spec`.
+    ///
+    /// Blocks until `RdsService::wait_until_db_instance_running` completes.
+    pub fn wait_until_db_instance_running(
+        &self,
+        instance_id: &str,
+        waiter_config: &waiter::WaiterConfig,
+    ) -> Result<rds_instance::DescribeDbInstanceDetailResp, error::Error> {
+        self.runtime.block_on(
+            self.rds
+                .wait_until_db_instance_running(instance_id, waiter_config),
+        )
+    }
+}
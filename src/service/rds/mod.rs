@@ -2,12 +2,14 @@
  * @Author: Jerry.Yang
  * @Date: 2024-11-05 10:36:11
  * @LastEditors: Jerry.Yang
- * @LastEditTime: 2025-02-06 11:36:03
+ * @LastEditTime: 2025-03-28 10:14:26
  * @Description: Module for managing RDS (Relational Database Service) instances, databases, endpoints, and accounts using Volcengine SDK.
  */
 use crate::volcengine::client::client;
 use crate::volcengine::error::error;
+use crate::volcengine::paginator::paginator;
 use crate::volcengine::session::session;
+use crate::volcengine::waiter::waiter;
 use std::future::Future;
 use volcengine_sdk_protobuf::protobuf::rds_account;
 use volcengine_sdk_protobuf::protobuf::rds_allow;
@@ -16,30 +18,13 @@ use volcengine_sdk_protobuf::protobuf::rds_endpoint;
 use volcengine_sdk_protobuf::protobuf::rds_instance;
 
 // Import modules for various RDS operations
-mod api_create_db_account;
-mod api_create_db_account_model;
-mod api_create_db_database;
-mod api_create_db_database_model;
-mod api_create_db_endpoint;
-mod api_create_db_endpoint_model;
-mod api_create_db_instance;
-mod api_create_db_instance_model;
-mod api_describe_db_accounts;
-mod api_describe_db_accounts_model;
-mod api_describe_db_database;
-mod api_describe_db_databases_model;
-mod api_describe_db_instance_detail;
-mod api_describe_db_instance_detail_model;
-mod api_describe_db_instances;
 mod api_describe_db_instances_models;
-mod api_modify_allow_list;
-mod api_modify_allow_list_model;
-mod api_modify_db_endpoint;
-mod api_modify_db_endpoint_model;
-mod api_modify_db_instance_spec;
-mod api_modify_db_instance_spec_model;
 pub mod service_rds;
+
+// Blocking facade exposing the service operations synchronously.
+pub mod blocking;
 mod tests;
+mod waiter_db_instance;
 
 /**
  * Defines the RDS service interface, providing methods for various RDS operations.
@@ -128,6 +113,39 @@ pub trait RdsService {
         &self,
         request: rds_instance::DescribeDbInstancesReq,
     ) -> impl Future<Output = Result<rds_instance::DescribeDbInstancesResp, error::Error>>;
+
+    /// Lists DB instances page by page.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current service instance.
+    /// - `request`: The request of the first page; `PageSize` sets the page size.
+    ///
+    /// # Returns
+    /// - `paginator::Paginator<rds_instance::DescribeDbInstancesReq>`: A paginator turned into a stream over the pages with `pages()`
+    ///   or over the DB instances with `items()`.
+    fn describe_db_instances_paginator(
+        &self,
+        request: rds_instance::DescribeDbInstancesReq,
+    ) -> paginator::Paginator<rds_instance::DescribeDbInstancesReq>;
+
+    /// Waits until a DB instance is `Running`, e.g. after `new_create_db_instance` or `new_modify_db_instance_spec`.
+    ///
+    /// Polls `DescribeDBInstanceDetail` with the delay, backoff and timeout of `waiter_config`, and fails fast
+    /// when the instance reaches a failure status such as `Error`.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current service instance.
+    /// - `instance_id`: The ID of the instance.
+    /// - `waiter_config`: The delay, backoff and timeout, e.g. `waiter::WaiterConfig::default()`.
+    ///
+    /// # Returns
+    /// - `impl Future<Output = Result<rds_instance::DescribeDbInstanceDetailResp, error::Error>>`: On success, returns the instance detail once it is running.
+    ///   On failure, returns `error::Error::ErrWaiterFailure`, `error::Error::ErrWaiterTimeout` or the error of a poll.
+    fn wait_until_db_instance_running(
+        &self,
+        instance_id: &str,
+        waiter_config: &waiter::WaiterConfig,
+    ) -> impl Future<Output = Result<rds_instance::DescribeDbInstanceDetailResp, error::Error>>;
 }
 
 /**
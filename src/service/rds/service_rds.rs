@@ -2,28 +2,21 @@
  * @Author: Jerry.Yang
  * @Date: 2024-11-05 10:36:29
  * @LastEditors: Jerry.Yang
- * @LastEditTime: 2025-02-06 11:38:48
+ * @LastEditTime: 2025-03-28 10:14:26
  * @Description: Implementation of the RDS service, providing methods for managing RDS instances, databases, endpoints, and accounts.
  */
-use crate::service::rds::api_create_db_account;
-use crate::service::rds::api_create_db_database;
-use crate::service::rds::api_create_db_endpoint;
-use crate::service::rds::api_create_db_instance;
-use crate::service::rds::api_describe_db_accounts;
-use crate::service::rds::api_describe_db_database;
-use crate::service::rds::api_describe_db_instance_detail;
-use crate::service::rds::api_describe_db_instances;
-use crate::service::rds::api_modify_allow_list;
-use crate::service::rds::api_modify_db_endpoint;
-use crate::service::rds::api_modify_db_instance_spec;
+use crate::service::rds::waiter_db_instance;
 use crate::service::rds::{Rds, RdsService};
 use crate::volcengine::client::client;
 use crate::volcengine::client::client_info;
 use crate::volcengine::client::config as client_config;
 use crate::volcengine::common;
 use crate::volcengine::error::error;
-use crate::volcengine::request::handles;
+use crate::volcengine::paginator::paginator;
+use crate::volcengine::registry::registry;
 use crate::volcengine::session::session;
+use crate::volcengine::waiter::waiter;
+use volcengine_sdk_protobuf::protobuf::rds_instance;
 
 /// Implementation of the RdsService trait for the Rds struct.
 /// This implementation provides the necessary logic to interact with the Volcengine RDS service,
@@ -39,7 +32,7 @@ impl RdsService for Rds {
     ///   On failure, returns an error indicating the cause of the failure.
     fn new_rds(session: session::Session) -> Result<Self, error::Error> {
         // Create a new client configuration for the RDS service.
-        let client_config = session.new_client_config(client_config::ClientServiceName::Rds);
+        let client_config = session.new_client_config(client_config::ClientServiceName::Rds)?;
 
         // Build the client information with the required parameters.
         let client_info = client_info::ClientInfo::builder()
@@ -48,8 +41,8 @@ impl RdsService for Rds {
             .with_signing_region(&client_config.signing_region)
             .build()?;
 
-        // Initialize the request handles.
-        let request_handles = handles::Handles {};
+        // Use the request handles registered on the session
+        let request_handles = client_config.handles.clone();
 
         // Build the client with the provided information.
         let client = client::Client::builder()
@@ -76,9 +69,7 @@ impl RdsService for Rds {
         request: volcengine_sdk_protobuf::protobuf::rds_instance::CreateDbInstanceReq,
     ) -> Result<volcengine_sdk_protobuf::protobuf::rds_instance::CreateDbInstanceResp, error::Error>
     {
-        api_create_db_instance::ApiCreateDbInstanceRds
-            .new_create_db_instance(self, request)
-            .await
+        registry::invoke(&self.client, &registry::RDS_CREATE_DB_INSTANCE, &request).await
     }
 
     /// Describes the details of a specific database instance.
@@ -97,9 +88,12 @@ impl RdsService for Rds {
         volcengine_sdk_protobuf::protobuf::rds_instance::DescribeDbInstanceDetailResp,
         error::Error,
     > {
-        api_describe_db_instance_detail::ApiDescribeDbInstanceDetailRds
-            .new_describe_db_instance_detail(self, request)
-            .await
+        registry::invoke(
+            &self.client,
+            &registry::RDS_DESCRIBE_DB_INSTANCE_DETAIL,
+            &request,
+        )
+        .await
     }
 
     /// Creates a new database endpoint.
@@ -116,9 +110,7 @@ impl RdsService for Rds {
         request: volcengine_sdk_protobuf::protobuf::rds_endpoint::CreateDbEndpointReq,
     ) -> Result<volcengine_sdk_protobuf::protobuf::rds_endpoint::CreateDbEndpointResp, error::Error>
     {
-        api_create_db_endpoint::ApiCreateDbEndpointRds
-            .new_create_db_endpoint(self, request)
-            .await
+        registry::invoke(&self.client, &registry::RDS_CREATE_DB_ENDPOINT, &request).await
     }
 
     /// Creates a new database account.
@@ -135,9 +127,7 @@ impl RdsService for Rds {
         request: volcengine_sdk_protobuf::protobuf::rds_account::CreateDbAccountReq,
     ) -> Result<volcengine_sdk_protobuf::protobuf::rds_account::CreateDbAccountResp, error::Error>
     {
-        api_create_db_account::ApiCreateDbAccountRds
-            .new_create_db_account(self, request)
-            .await
+        registry::invoke(&self.client, &registry::RDS_CREATE_DB_ACCOUNT, &request).await
     }
 
     /// Creates a new database.
@@ -154,9 +144,7 @@ impl RdsService for Rds {
         request: volcengine_sdk_protobuf::protobuf::rds_database::CreateDatabaseReq,
     ) -> Result<volcengine_sdk_protobuf::protobuf::rds_database::CreateDatabaseResp, error::Error>
     {
-        api_create_db_database::ApiCreateDbDatabaseRds
-            .new_create_db_database(self, request)
-            .await
+        registry::invoke(&self.client, &registry::RDS_CREATE_DATABASE, &request).await
     }
 
     /// Modifies the allow list of a database instance.
@@ -173,9 +161,7 @@ impl RdsService for Rds {
         request: volcengine_sdk_protobuf::protobuf::rds_allow::ModifyAllowListReq,
     ) -> Result<volcengine_sdk_protobuf::protobuf::rds_allow::ModifyAllowListResp, error::Error>
     {
-        api_modify_allow_list::ApiModifyAllowListRds
-            .new_modify_allow_list(self, request)
-            .await
+        registry::invoke(&self.client, &registry::RDS_MODIFY_ALLOW_LIST, &request).await
     }
 
     /// Modifies the specifications of a database instance.
@@ -194,9 +180,12 @@ impl RdsService for Rds {
         volcengine_sdk_protobuf::protobuf::rds_instance::ModifyDbInstanceSpecResp,
         error::Error,
     > {
-        api_modify_db_instance_spec::ApiModifyDBInstanceSpecRds
-            .new_modify_db_instance_spec(self, request)
-            .await
+        registry::invoke(
+            &self.client,
+            &registry::RDS_MODIFY_DB_INSTANCE_SPEC,
+            &request,
+        )
+        .await
     }
 
     /// Describes the databases of a specific database instance.
@@ -213,9 +202,7 @@ impl RdsService for Rds {
         request: volcengine_sdk_protobuf::protobuf::rds_database::DescribeDatabasesReq,
     ) -> Result<volcengine_sdk_protobuf::protobuf::rds_database::DescribeDatabasesResp, error::Error>
     {
-        api_describe_db_database::ApiDescribeDbDatabasesRds
-            .new_describe_db_databases(self, request)
-            .await
+        registry::invoke(&self.client, &registry::RDS_DESCRIBE_DATABASES, &request).await
     }
 
     /// Describes the accounts of a specific database instance.
@@ -232,9 +219,7 @@ impl RdsService for Rds {
         request: volcengine_sdk_protobuf::protobuf::rds_account::DescribeDbAccountsReq,
     ) -> Result<volcengine_sdk_protobuf::protobuf::rds_account::DescribeDbAccountsResp, error::Error>
     {
-        api_describe_db_accounts::ApiDescribeDBAccountsRds
-            .new_describe_db_accounts(self, request)
-            .await
+        registry::invoke(&self.client, &registry::RDS_DESCRIBE_DB_ACCOUNTS, &request).await
     }
 
     /// Modifies a database endpoint.
@@ -251,9 +236,7 @@ impl RdsService for Rds {
         request: volcengine_sdk_protobuf::protobuf::rds_endpoint::ModifyDbEndpointReq,
     ) -> Result<volcengine_sdk_protobuf::protobuf::rds_endpoint::ModifyDbEndpointResp, error::Error>
     {
-        api_modify_db_endpoint::ApiModifyDBEndpointRds
-            .new_modify_db_endpoint(self, request)
-            .await
+        registry::invoke(&self.client, &registry::RDS_MODIFY_DB_ENDPOINT, &request).await
     }
 
     /// Describes the database instances.
@@ -272,8 +255,50 @@ impl RdsService for Rds {
         volcengine_sdk_protobuf::protobuf::rds_instance::DescribeDbInstancesResp,
         error::Error,
     > {
-        api_describe_db_instances::ApiDescribeDBInstancesRds
-            .new_describe_db_instances(self, request)
+        registry::invoke(&self.client, &registry::RDS_DESCRIBE_DB_INSTANCES, &request).await
+    }
+
+    /// Lists DB instances page by page.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current service instance.
+    /// - `request`: The request of the first page.
+    ///
+    /// # Returns
+    /// - `paginator::Paginator<rds_instance::DescribeDbInstancesReq>`: A paginator sending `new_describe_db_instances` once per page.
+    fn describe_db_instances_paginator(
+        &self,
+        request: rds_instance::DescribeDbInstancesReq,
+    ) -> paginator::Paginator<rds_instance::DescribeDbInstancesReq> {
+        let rds = self.clone();
+        paginator::Paginator::new(request, move |request| {
+            let rds = rds.clone();
+            Box::pin(async move { rds.new_describe_db_instances(request).await })
+        })
+    }
+
+    /// Waits until a DB instance is `Running`.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current service instance.
+    /// - `instance_id`: The ID of the instance.
+    /// - `waiter_config`: The delay, backoff and timeout.
+    ///
+    /// # Returns
+    /// - `Result<rds_instance::DescribeDbInstanceDetailResp, error::Error>`: The instance once it reached the status, or the reason the wait ended.
+    async fn wait_until_db_instance_running(
+        &self,
+        instance_id: &str,
+        waiter_config: &waiter::WaiterConfig,
+    ) -> Result<rds_instance::DescribeDbInstanceDetailResp, error::Error> {
+        waiter_db_instance::WaiterDBInstanceRds
+            .wait_until_db_instance_status(
+                self,
+                "DBInstanceRunning",
+                instance_id,
+                waiter_db_instance::DB_INSTANCE_STATUS_RUNNING,
+                waiter_config,
+            )
             .await
     }
 }
@@ -2,66 +2,269 @@
  * @Author: Jerry.Yang
  * @Date: 2024-10-22 13:52:22
  * @LastEditors: Jerry.Yang
- * @LastEditTime: 2025-02-19 16:03:36
- * @Description: create_user test
+ * @LastEditTime: 2025-03-30 10:05:00
+ * @Description: rds test
  */
 #[cfg(test)]
 mod tests {
+    use futures::TryStreamExt;
+    use std::time::Duration;
+    use volcengine_sdk_protobuf::protobuf::{
+        rds_account, rds_allow, rds_database, rds_endpoint, rds_instance,
+    };
 
     use crate::{
         service::rds::{self, RdsService},
-        volcengine::{config, credentials::credentials, session::session},
+        volcengine::{error::error, mock::mock, waiter::waiter},
     };
 
+    /// The API version of RDS.
+    const VERSION: &str = "2022-01-01";
+
+    /// Returns a `DescribeDBInstanceDetail` response for an instance in `status`.
+    fn describe_db_instance_detail_response(instance_id: &str, status: &str) -> mock::MockResponse {
+        mock::MockResponse::json(&rds_instance::DescribeDbInstanceDetailResp {
+            result: Some(rds_instance::DescribeDbInstanceDetailResultResp {
+                basic_info: Some(rds_instance::DescribeDbInstanceDetailResultBasicInfoResp {
+                    instance_id: Some(instance_id.to_string()),
+                    instance_status: Some(status.to_string()),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        })
+    }
+
     #[tokio::test]
     async fn test_create_mysql() {
-        let access_key_id = ""; // 这里填入实际的 Access Key ID
-        let secret_access_key = ""; // 这里填入实际的 Secret Access Key
-        let region_id = "cn-beijing"; // 这里填入实际的 Region ID
+        // start the mock server
+        let server = mock::MockServer::start().await;
+        server.mock(
+            "DescribeDBInstanceDetail",
+            VERSION,
+            describe_db_instance_detail_response("mysql-04529706be3d", "Running"),
+        );
 
-        let credentials = credentials::Credentials::new(access_key_id, secret_access_key);
+        // new rds
+        let rds = rds::Rds::new_rds(server.session()).unwrap();
 
-        // new config
-        let config = config::Config::builder()
-            .with_region(&region_id)
-            .with_credentials(credentials)
-            .build();
+        // describe the instance
+        let mut request = rds_instance::DescribeDbInstanceDetailReq::default();
+        request.instance_id = Some("mysql-04529706be3d".to_string());
+        let result = rds.new_describe_db_instance_detail(request).await.unwrap();
 
-        // print config
-        println!("config : {:?}", config);
+        let basic_info = result.result.unwrap().basic_info.unwrap();
+        assert_eq!(basic_info.instance_status.as_deref(), Some("Running"));
 
-        // reset config
-        let config = config.unwrap();
+        // the request is signed and carries the parameters
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].signature_valid);
+        assert_eq!(requests[0].service, "rds_mysql");
+        assert_eq!(requests[0].query["InstanceId"], "mysql-04529706be3d");
+    }
 
-        // new session
-        let session = session::Session::builder().with_config(config).build();
+    #[tokio::test]
+    async fn test_create_db_instance() {
+        let server = mock::MockServer::start().await;
+        server.mock(
+            "CreateDBInstance",
+            VERSION,
+            mock::MockResponse::json(&rds_instance::CreateDbInstanceResp {
+                result: Some(rds_instance::CreateDbInstanceResultResp {
+                    instance_id: Some("mysql-1".to_string()),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+        );
 
-        // print session
-        println!("session : {:?}", session);
+        let rds = rds::Rds::new_rds(server.session()).unwrap();
+        let request = rds_instance::CreateDbInstanceReq {
+            db_engine_version: Some("MySQL_8_0".to_string()),
+            vpc_id: Some("vpc-1".to_string()),
+            ..Default::default()
+        };
+        let result = rds.new_create_db_instance(request).await.unwrap();
 
-        // reset session
-        let session = session.unwrap();
+        assert_eq!(
+            result.result.unwrap().instance_id.as_deref(),
+            Some("mysql-1")
+        );
 
-        // new rds
-        let rds = rds::Rds::new_rds(session);
+        // the parameters are sent as a signed JSON body
+        let requests = server.requests();
+        assert!(requests[0].signature_valid);
+        assert_eq!(requests[0].method, http::Method::POST);
+        let body: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+        assert_eq!(body["DbEngineVersion"], "MySQL_8_0");
+        assert_eq!(body["VpcId"], "vpc-1");
+    }
 
-        // print rds
-        println!("rds : {:?}", rds);
+    #[tokio::test]
+    async fn test_describe_db_instances_paginator() {
+        // two pages of one instance out of two
+        let server = mock::MockServer::start().await;
+        for instance_id in ["mysql-1", "mysql-2"] {
+            server.mock(
+                "DescribeDBInstances",
+                VERSION,
+                mock::MockResponse::json(&rds_instance::DescribeDbInstancesResp {
+                    result: Some(rds_instance::DescribeDbInstancesResultResp {
+                        total: Some(2),
+                        instances: vec![rds_instance::DescribeDbInstancesResultInstanceResp {
+                            instance_id: Some(instance_id.to_string()),
+                            ..Default::default()
+                        }],
+                    }),
+                    ..Default::default()
+                }),
+            );
+        }
 
-        // reset rds
-        let rds = rds.unwrap();
+        let rds = rds::Rds::new_rds(server.session()).unwrap();
+        let request = rds_instance::DescribeDbInstancesReq {
+            page_size: Some(1),
+            ..Default::default()
+        };
+        let instances: Vec<_> = rds
+            .describe_db_instances_paginator(request)
+            .items()
+            .try_collect()
+            .await
+            .unwrap();
 
-        // create mysql
-        let mut request =
-            volcengine_sdk_protobuf::protobuf::rds_instance::DescribeDbInstanceDetailReq::default();
-        request.instance_id = Some("mysql-04529706be3d".to_string());
+        assert_eq!(instances.len(), 2);
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        let body: serde_json::Value = serde_json::from_slice(&requests[1].body).unwrap();
+        assert_eq!(body["PageNumber"], 2);
+    }
+
+    #[tokio::test]
+    async fn test_wait_until_db_instance_running() {
+        // the instance is created, then runs
+        let server = mock::MockServer::start().await;
+        server.mock(
+            "DescribeDBInstanceDetail",
+            VERSION,
+            describe_db_instance_detail_response("mysql-1", "Creating"),
+        );
+        server.mock(
+            "DescribeDBInstanceDetail",
+            VERSION,
+            describe_db_instance_detail_response("mysql-1", "Running"),
+        );
+
+        let rds = rds::Rds::new_rds(server.session()).unwrap();
+        let waiter_config = waiter::WaiterConfig::builder()
+            .with_delay(Duration::from_millis(1))
+            .with_timeout(Duration::from_secs(5))
+            .build();
+        rds.wait_until_db_instance_running("mysql-1", &waiter_config)
+            .await
+            .unwrap();
+
+        assert_eq!(server.requests().len(), 2);
+    }
 
-        // 运行异步代码的 helper 函数
-        let result = rds.new_describe_db_instance_detail(request).await;
+    #[tokio::test]
+    async fn test_wait_until_db_instance_not_found() {
+        // the detail never carries the instance, so the waiter fails after max_not_found polls
+        let server = mock::MockServer::start().await;
+        server.mock(
+            "DescribeDBInstanceDetail",
+            VERSION,
+            mock::MockResponse::json(&rds_instance::DescribeDbInstanceDetailResp::default()),
+        );
+
+        // the waiter runs on its own task
+        let rds = rds::Rds::new_rds(server.session()).unwrap();
+        let result = tokio::spawn(async move {
+            let waiter_config = waiter::WaiterConfig::builder()
+                .with_delay(Duration::from_millis(1))
+                .with_timeout(Duration::from_secs(5))
+                .build();
+            rds.wait_until_db_instance_running("mysql-1", &waiter_config)
+                .await
+        })
+        .await
+        .unwrap();
+
+        assert!(matches!(
+            result,
+            Err(error::Error::ErrWaiterFailure { state, .. }) if state == "NOT_FOUND"
+        ));
+        assert_eq!(
+            server.requests().len(),
+            waiter::DEFAULT_MAX_NOT_FOUND as usize
+        );
+    }
+
+    #[tokio::test]
+    async fn test_every_operation() {
+        // answer every action with an empty result
+        let actions = [
+            "CreateDBInstance",
+            "DescribeDBInstanceDetail",
+            "CreateDBEndpoint",
+            "CreateDBAccount",
+            "CreateDatabase",
+            "ModifyAllowList",
+            "ModifyDBInstanceSpec",
+            "DescribeDatabases",
+            "DescribeDBAccounts",
+            "ModifyDBEndpoint",
+            "DescribeDBInstances",
+        ];
+        let server = mock::MockServer::start().await;
+        for action in actions {
+            server.mock(action, VERSION, mock::MockResponse::empty());
+        }
 
-        println!("result : {:?}", result);
+        let rds = rds::Rds::new_rds(server.session()).unwrap();
+        rds.new_create_db_instance(rds_instance::CreateDbInstanceReq::default())
+            .await
+            .unwrap();
+        rds.new_describe_db_instance_detail(rds_instance::DescribeDbInstanceDetailReq::default())
+            .await
+            .unwrap();
+        rds.new_create_db_endpoint(rds_endpoint::CreateDbEndpointReq::default())
+            .await
+            .unwrap();
+        rds.new_create_db_account(rds_account::CreateDbAccountReq::default())
+            .await
+            .unwrap();
+        rds.new_create_db_database(rds_database::CreateDatabaseReq::default())
+            .await
+            .unwrap();
+        rds.new_modify_allow_list(rds_allow::ModifyAllowListReq::default())
+            .await
+            .unwrap();
+        rds.new_modify_db_instance_spec(rds_instance::ModifyDbInstanceSpecReq::default())
+            .await
+            .unwrap();
+        rds.new_describe_db_databases(rds_database::DescribeDatabasesReq::default())
+            .await
+            .unwrap();
+        rds.new_describe_db_accounts(rds_account::DescribeDbAccountsReq::default())
+            .await
+            .unwrap();
+        rds.new_modify_db_endpoint(rds_endpoint::ModifyDbEndpointReq::default())
+            .await
+            .unwrap();
+        rds.new_describe_db_instances(rds_instance::DescribeDbInstancesReq::default())
+            .await
+            .unwrap();
 
-        // 这里可以添加断言来检查结果
-        assert!(result.is_ok());
+        // one signed request per operation, in order
+        let requests = server.requests();
+        let received_actions: Vec<_> = requests
+            .iter()
+            .map(|request| request.action.as_str())
+            .collect();
+        assert_eq!(received_actions, actions);
+        assert!(requests.iter().all(|request| request.signature_valid));
     }
 }
@@ -0,0 +1,76 @@
+/*
+ * @Author: Jerry.Yang
+ * @Date: 2025-03-12 14:40:15
+ * @LastEditors: Jerry.Yang
+ * @LastEditTime: 2025-03-30 10:05:00
+ * @Description: Waiters for RDS instance state transitions
+ */
+use crate::service::rds;
+use crate::service::rds::RdsService;
+use crate::volcengine::error::error;
+use crate::volcengine::waiter::waiter;
+use volcengine_sdk_protobuf::protobuf::rds_instance;
+
+/// The status of a running instance.
+pub const DB_INSTANCE_STATUS_RUNNING: &str = "Running";
+
+/// Statuses from which an instance does not reach `Running` on its own.
+pub const DB_INSTANCE_FAILURE_STATUSES: [&str; 4] = ["Error", "Deleting", "Released", "Closed"];
+
+/// `WaiterDBInstanceRds` polls `DescribeDBInstanceDetail` until an instance reaches a status.
+pub struct WaiterDBInstanceRds;
+
+impl WaiterDBInstanceRds {
+    /// Waits until the instance reaches `target`.
+    ///
+    /// A detail answer without the status of the instance, as happens right after
+    /// `CreateDBInstance`, is treated as still pending for up to `max_not_found` consecutive
+    /// polls, after which the wait fails with the state `NOT_FOUND`. An API error, such as
+    /// the one returned for an unknown instance ID, ends the wait immediately.
+    ///
+    /// # Arguments
+    /// - `&self`: A reference to the current instance of `WaiterDBInstanceRds`.
+    /// - `rds`: A reference to the `rds::Rds` client.
+    /// - `waiter_name`: The name of the waiter, used in errors.
+    /// - `instance_id`: The ID of the instance.
+    /// - `target`: The status waited for.
+    /// - `waiter_config`: The delay, backoff and timeout.
+    ///
+    /// # Returns
+    /// - `Ok(DescribeDbInstanceDetailResp)`: The instance detail once it reached `target`.
+    /// - `Err(error::Error)`: If the instance reached a failure status or stayed missing, the wait timed out or a poll failed.
+    pub async fn wait_until_db_instance_status(
+        &self,
+        rds: &rds::Rds,
+        waiter_name: &str,
+        instance_id: &str,
+        target: &str,
+        waiter_config: &waiter::WaiterConfig,
+    ) -> Result<rds_instance::DescribeDbInstanceDetailResp, error::Error> {
+        let not_found = waiter::NotFoundCounter::default();
+        waiter::wait_until(waiter_name, waiter_config, || async {
+            let request = rds_instance::DescribeDbInstanceDetailReq {
+                instance_id: Some(instance_id.to_string()),
+            };
+            let response = rds.new_describe_db_instance_detail(request).await?;
+
+            // Classify the status of the instance.
+            let status = response
+                .result
+                .as_ref()
+                .and_then(|result| result.basic_info.as_ref())
+                .and_then(|basic_info| basic_info.instance_status.clone())
+                .filter(|status| !status.is_empty());
+            let state = status.map(|status| {
+                waiter::WaitState::from_status(
+                    &status,
+                    target,
+                    &DB_INSTANCE_FAILURE_STATUSES,
+                    response,
+                )
+            });
+            Ok(not_found.classify(state, waiter_config))
+        })
+        .await
+    }
+}
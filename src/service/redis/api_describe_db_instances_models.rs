@@ -2,84 +2,45 @@
  * @Author: Jerry.Yang
  * @Date: 2024-10-29 17:33:27
  * @LastEditors: Jerry.Yang
- * @LastEditTime: 2025-02-19 15:58:59
+ * @LastEditTime: 2025-03-29 20:50:00
  * @Description: API for describing the details of a Redis database instance.
  */
 
-use crate::volcengine::error::error;
+use crate::volcengine::paginator::paginator;
 //  use crate::volcengine::request::request::RequestVolcengine;
-use crate::volcengine::request::{request, response};
-use std::collections::HashMap;
 use volcengine_sdk_protobuf::protobuf::redis_instance;
 
-/// Implementation of the `ApiRequest` trait for the `RedisDescribeDbInstancesReq` structure.
-/// This implementation provides the necessary methods to convert the request into a format suitable for sending over HTTP.
-impl request::ApiRequest for redis_instance::RedisDescribeDbInstancesReq {
-    /// Converts the request into a `HashMap` of headers.
-    /// This method formats the request parameters into a `HashMap` that can be used as query parameters in the HTTP request.
-    /// The `Request::format_request_to_hashmap` method is used to handle the conversion.
-    fn to_hashmap(&self) -> HashMap<String, String> {
-        //  request::Request::format_request_to_hashmap(self)
-        HashMap::new()
+/// Implementation of the `Paginated` trait for the `RedisDescribeDbInstancesReq` structure.
+/// Instances are paged with `PageNumber` / `PageSize`.
+impl paginator::Paginated for redis_instance::RedisDescribeDbInstancesReq {
+    type Response = redis_instance::RedisDescribeDbInstancesResp;
+    type Item = redis_instance::RedisDescribeDbInstancesResultInstanceResp;
+
+    /// Returns the instances of a page.
+    fn items(response: &Self::Response) -> &[Self::Item] {
+        response
+            .result
+            .as_ref()
+            .map_or(&[], |result| result.instances.as_slice())
     }
 
-    /// Serializes the request into a byte vector to be sent as the request body.
-    /// For this specific request, no request body is required, so an empty byte vector is returned.
-    fn to_body(&self) -> Vec<u8> {
-        serde_json::to_vec(self).unwrap()
+    /// Requests the next page until the reported total has been fetched.
+    fn next_request(&self, response: &Self::Response, fetched: usize) -> Option<Self> {
+        let result = response.result.as_ref()?;
+        let page_number = paginator::next_page_number(
+            self.page_number.unwrap_or_default(),
+            result.instances.len(),
+            fetched,
+            result.total_instances_num,
+        )?;
+        Some(Self {
+            page_number: Some(page_number),
+            ..self.clone()
+        })
     }
-}
-
-/// Implementation of the `ApiResponse` trait for the `RedisDescribeDbInstancesResp` structure.
-/// This implementation provides the necessary methods to parse the HTTP response into a structured response object.
-impl response::ApiResponse for redis_instance::RedisDescribeDbInstancesResp {
-    /// Deserializes the HTTP response into a structured response object.
-    /// This method takes an `http_response` object, parses its JSON body, and updates the current response object with the parsed data.
-    ///
-    /// # Arguments
-    /// * `http_response` - The HTTP response object received from the server.
-    ///
-    /// # Returns
-    /// * `Result<(), error::Error>` - Returns `Ok(())` if the response is successfully parsed, or an error if parsing fails.
-    async fn to_struct(&mut self, http_response: reqwest::Response) -> Result<(), error::Error> {
-        // Get the HTTP status code from the response.
-        let http_status = http_response.status();
-
-        // Convert the response body to a text string.
-        let txt_response = http_response
-            .text()
-            .await
-            .map_err(|e| error::Error::ErrParseResponse(e))?;
-
-        // Sanitize the response text by replacing `null` values with empty arrays.
-        // This is necessary because the response may contain `null` values that need to be handled properly.
-        let sanitized_response = txt_response.replace("\"Tags\":null", "\"Tags\":[]"); // Replace `null` with an empty array
-
-        // Parse the sanitized JSON response body into the expected structure.
-        let parsed_response: volcengine_sdk_protobuf::protobuf::redis_instance::RedisDescribeDbInstancesResp =
-            serde_json::from_str(&sanitized_response).map_err(|e| error::Error::ErrParseJson(e))?;
-
-        // Update the current response object with the parsed data.
-        *self = parsed_response;
-
-        // Check if the HTTP status code indicates an error.
-        if !http_status.is_success() {
-            // Check if `response_metadata` exists in the response.
-            if let Some(mut response_metadata) = self.response_metadata.take() {
-                // Ensure the `error` field exists in `response_metadata`.
-                let response_metadata_error = response_metadata.error.get_or_insert_with(
-                    volcengine_sdk_protobuf::protobuf::redis_instance::ResponseMetadataErr::default,
-                );
-
-                // Set the `code_n` field to the HTTP status code.
-                response_metadata_error.code_n = Some(http_status.as_u16().into());
-
-                // Update `response_metadata` with the error information.
-                self.response_metadata = Some(response_metadata);
-            }
-        }
 
-        // Return successfully if no errors occurred.
-        Ok(())
+    /// Counts the items of the pages before the requested page number.
+    fn items_before(&self) -> usize {
+        paginator::items_before_page(self.page_number.unwrap_or_default(), self.page_size)
     }
 }
@@ -0,0 +1,178 @@
+/*
+ * @Author: Jerry.Yang
+ * @Date: 2025-03-18 11:31:45
+ * @LastEditors: Jerry.Yang
+ * @LastEditTime: 2025-03-30 11:10:00
+ * @Description: Blocking facade for Redis operations
+ */
+use crate::service::redis;
+use crate::service::redis::RedisService;
+use crate::volcengine::blocking::blocking;
+use crate::volcengine::error::error;
+use crate::volcengine::session::session;
+use crate::volcengine::waiter::waiter;
+use volcengine_sdk_protobuf::protobuf::{redis_allow, redis_instance};
+
+/// A synchronous facade over `redis::Redis`.
+///
+/// Exposes the operations of `RedisService` as blocking methods, driven by the runtime shared by
+/// the blocking facades. Meant for build scripts and command line tools; asynchronous code
+/// should use `redis::Redis` directly.
+///
+/// # Example
+/// ```rust,no_run
+/// # use volcengine_rust_sdk::volcengine::error::error;
+/// # use volcengine_rust_sdk::volcengine::session::session;
+/// use volcengine_rust_sdk::service::redis;
+///
+/// # fn example(session: session::Session) -> Result<(), error::Error> {
+/// let redis = redis::blocking::Redis::new_redis(session)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Redis {
+    redis: redis::Redis,        // The asynchronous service.
+    runtime: blocking::Runtime, // The runtime driving its requests.
+}
+
+impl Redis {
+    /// Creates a new blocking `Redis` using the provided session.
+    ///
+    /// # Arguments
+    /// - `session`: A `Session` object containing authentication and configuration information.
+    ///
+    /// # Returns
+    /// - `Ok(Redis)`: The blocking service.
+    /// - `Err(error::Error)`: If the service or the runtime could not be created.
+    pub fn new_redis(session: session::Session) -> Result<Redis, error::Error> {
+        Ok(Redis {
+            redis: redis::Redis::new_redis(session)?,
+            runtime: blocking::Runtime::shared()?,
+        })
+    }
+
+    /// Creates a new Redis database instance.
+    ///
+    /// Blocks until `RedisService::new_create_db_instance` completes.
+    pub fn new_create_db_instance(
+        &self,
+        request: redis_instance::RedisCreateDbInstanceReq,
+    ) -> Result<redis_instance::RedisCreateDbInstanceResp, error::Error> {
+        self.runtime
+            .block_on(self.redis.new_create_db_instance(request))
+    }
+
+    /// Describes the details of a specific Redis database instance.
+    ///
+    /// Blocks until `RedisService::new_describe_db_instance_detail` completes.
+    pub fn new_describe_db_instance_detail(
+        &self,
+        request: redis_instance::RedisDescribeDbInstanceDetailReq,
+    ) -> Result<redis_instance::RedisDescribeDbInstanceDetailResp, error::Error> {
+        self.runtime
+            .block_on(self.redis.new_describe_db_instance_detail(request))
+    }
+
+    /// Modifies the allow list of a Redis database instance.
+    ///
+    /// Blocks until `RedisService::new_modify_allow_list` completes.
+    pub fn new_modify_allow_list(
+        &self,
+        request: redis_allow::RedisModifyAllowListReq,
+    ) -> Result<redis_allow::RedisModifyAllowListResp, error::Error> {
+        self.runtime
+            .block_on(self.redis.new_modify_allow_list(request))
+    }
+
+    /// Increase the number of nodes in a Redis instance.
+    ///
+    /// Blocks until `RedisService::new_increase_db_instance_node_number` completes.
+    pub fn new_increase_db_instance_node_number(
+        &self,
+        request: redis_instance::RedisIncreaseDbInstanceNodeNumberReq,
+    ) -> Result<redis_instance::RedisIncreaseDbInstanceNodeNumberResp, error::Error> {
+        self.runtime
+            .block_on(self.redis.new_increase_db_instance_node_number(request))
+    }
+
+    /// Decreases the node number of a Redis database instance.
+    ///
+    /// Blocks until `RedisService::new_decrease_db_instance_node_number` completes.
+    pub fn new_decrease_db_instance_node_number(
+        &self,
+        request: redis_instance::RedisDecreaseDbInstanceNodeNumberReq,
+    ) -> Result<redis_instance::RedisDecreaseDbInstanceNodeNumberResp, error::Error> {
+        self.runtime
+            .block_on(self.redis.new_decrease_db_instance_node_number(request))
+    }
+
+    /// Modifies the shard capacity of a Redis database instance.
+    ///
+    /// Blocks until `RedisService::new_modify_db_instance_shard_capacity` completes.
+    pub fn new_modify_db_instance_shard_capacity(
+        &self,
+        request: redis_instance::RedisModifyDbInstanceShardCapacityReq,
+    ) -> Result<redis_instance::RedisModifyDbInstanceShardCapacityResp, error::Error> {
+        self.runtime
+            .block_on(self.redis.new_modify_db_instance_shard_capacity(request))
+    }
+
+    /// Modifies the shard number of a Redis database instance.
+    ///
+    /// Blocks until `RedisService::new_modify_db_instance_shard_number` completes.
+    pub fn new_modify_db_instance_shard_number(
+        &self,
+        request: redis_instance::RedisModifyDbInstanceShardNumberReq,
+    ) -> Result<redis_instance::RedisModifyDbInstanceShardNumberResp, error::Error> {
+        self.runtime
+            .block_on(self.redis.new_modify_db_instance_shard_number(request))
+    }
+
+    /// Enables sharded cluster mode for a Redis database instance.
+    ///
+    /// Blocks until `RedisService::new_enable_sharded_cluster` completes.
+    pub fn new_enable_sharded_cluster(
+        &self,
+        request: redis_instance::RedisEnableShardedClusterReq,
+    ) -> Result<redis_instance::RedisEnableShardedClusterResp, error::Error> {
+        self.runtime
+            .block_on(self.redis.new_enable_sharded_cluster(request))
+    }
+
+    /// Public method to initiate a request for describing the details of a Redis database instance.
+    ///
+    /// Blocks until `RedisService::new_describe_db_instances` completes.
+    pub fn new_describe_db_instances(
+        &self,
+        request: redis_instance::RedisDescribeDbInstancesReq,
+    ) -> Result<redis_instance::RedisDescribeDbInstancesResp, error::Error> {
+        self.runtime
+            .block_on(self.redis.new_describe_db_instances(request))
+    }
+
+    /// Lists Redis instances page by page.
+    ///
+    /// The pages are fetched synchronously as the iterators of the paginator are advanced.
+    pub fn describe_db_instances_paginator(
+        &self,
+        request: redis_instance::RedisDescribeDbInstancesReq,
+    ) -> blocking::Paginator<redis_instance::RedisDescribeDbInstancesReq> {
+        self.runtime
+            .paginator(self.redis.describe_db_instances_paginator(request))
+    }
+
+    /// Waits until a Redis instance is `Running`, e.g. after `new_create_db_instance` or `new_modify_db_instance_shard_number`.
+    ///
+    /// Blocks until `RedisService::wait_until_redis_instance_running` completes.
+    pub fn wait_until_redis_instance_running(
+        &self,
+        instance_id: &str,
+        waiter_config: &waiter::WaiterConfig,
+    ) -> Result<redis_instance::RedisDescribeDbInstanceDetailResp, error::Error> {
+        self.runtime.block_on(
+            self.redis
+                .wait_until_redis_instance_running(instance_id, waiter_config),
+        )
+    }
+}
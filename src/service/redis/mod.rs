@@ -2,36 +2,25 @@
  * @Author: Jerry.Yang
  * @Date: 2024-11-07 10:40:08
  * @LastEditors: Jerry.Yang
- * @LastEditTime: 2025-02-19 10:59:03
+ * @LastEditTime: 2025-03-28 10:14:26
  * @Description: Module for managing Redis database instances using the Volcengine SDK.
  */
 use crate::volcengine::client::client;
 use crate::volcengine::error::error;
+use crate::volcengine::paginator::paginator;
 use crate::volcengine::session::session;
+use crate::volcengine::waiter::waiter;
 use std::future::Future;
 use volcengine_sdk_protobuf::protobuf::{redis_allow, redis_instance};
 
 // Import modules for various Redis operations
-mod api_create_db_instance;
-mod api_create_db_instance_model;
-mod api_decrease_db_instance_node_number;
-mod api_decrease_db_instance_node_number_model;
-mod api_describe_db_instance_detail;
-mod api_describe_db_instance_detail_model;
-mod api_describe_db_instances;
 mod api_describe_db_instances_models;
-mod api_enable_sharded_cluster;
-mod api_enable_sharded_cluster_model;
-mod api_increase_db_instance_node_number;
-mod api_increase_db_instance_node_number_model;
-mod api_modify_allow_list;
-mod api_modify_allow_list_model;
-mod api_modify_db_instance_shard_capacity;
-mod api_modify_db_instance_shard_capacity_model;
-mod api_modify_db_instance_shard_number;
-mod api_modify_db_instance_shard_number_model;
 pub mod service_redis;
+
+// Blocking facade exposing the service operations synchronously.
+pub mod blocking;
 mod tests;
+mod waiter_db_instance;
 
 /// Defines the RedisService trait, providing methods for various Redis operations.
 /// This trait encapsulates the functionality required to interact with the Volcengine Redis service.
@@ -190,6 +179,39 @@ pub trait RedisService {
         &self,
         request: redis_instance::RedisDescribeDbInstancesReq,
     ) -> impl Future<Output = Result<redis_instance::RedisDescribeDbInstancesResp, error::Error>>;
+
+    /// Lists Redis instances page by page.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current service instance.
+    /// - `request`: The request of the first page; `PageSize` sets the page size.
+    ///
+    /// # Returns
+    /// - `paginator::Paginator<redis_instance::RedisDescribeDbInstancesReq>`: A paginator turned into a stream over the pages with `pages()`
+    ///   or over the Redis instances with `items()`.
+    fn describe_db_instances_paginator(
+        &self,
+        request: redis_instance::RedisDescribeDbInstancesReq,
+    ) -> paginator::Paginator<redis_instance::RedisDescribeDbInstancesReq>;
+
+    /// Waits until a Redis instance is `Running`, e.g. after `new_create_db_instance` or `new_modify_db_instance_shard_number`.
+    ///
+    /// Polls `DescribeDBInstanceDetail` with the delay, backoff and timeout of `waiter_config`, and fails fast
+    /// when the instance reaches a failure status such as `Error`.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current service instance.
+    /// - `instance_id`: The ID of the instance.
+    /// - `waiter_config`: The delay, backoff and timeout, e.g. `waiter::WaiterConfig::default()`.
+    ///
+    /// # Returns
+    /// - `impl Future<Output = Result<redis_instance::RedisDescribeDbInstanceDetailResp, error::Error>>`: On success, returns the instance detail once it is running.
+    ///   On failure, returns `error::Error::ErrWaiterFailure`, `error::Error::ErrWaiterTimeout` or the error of a poll.
+    fn wait_until_redis_instance_running(
+        &self,
+        instance_id: &str,
+        waiter_config: &waiter::WaiterConfig,
+    ) -> impl Future<Output = Result<redis_instance::RedisDescribeDbInstanceDetailResp, error::Error>>;
 }
 
 /// Represents the Redis service, encapsulating the client information required to interact with the Volcengine Redis service.
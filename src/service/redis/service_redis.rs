@@ -2,26 +2,21 @@
  * @Author: Jerry.Yang
  * @Date: 2024-11-07 14:39:42
  * @LastEditors: Jerry.Yang
- * @LastEditTime: 2025-02-19 11:03:29
+ * @LastEditTime: 2025-03-28 10:14:26
  * @Description: Implementation of the Redis service, providing methods for managing Redis database instances.
  */
-use crate::service::redis::api_create_db_instance;
-use crate::service::redis::api_decrease_db_instance_node_number;
-use crate::service::redis::api_describe_db_instance_detail;
-use crate::service::redis::api_describe_db_instances;
-use crate::service::redis::api_enable_sharded_cluster;
-use crate::service::redis::api_increase_db_instance_node_number;
-use crate::service::redis::api_modify_allow_list;
-use crate::service::redis::api_modify_db_instance_shard_capacity;
-use crate::service::redis::api_modify_db_instance_shard_number;
+use crate::service::redis::waiter_db_instance;
 use crate::service::redis::{Redis, RedisService};
 use crate::volcengine::client::client;
 use crate::volcengine::client::client_info;
 use crate::volcengine::client::config as client_config;
 use crate::volcengine::common;
 use crate::volcengine::error::error;
-use crate::volcengine::request::handles;
+use crate::volcengine::paginator::paginator;
+use crate::volcengine::registry::registry;
 use crate::volcengine::session::session;
+use crate::volcengine::waiter::waiter;
+use volcengine_sdk_protobuf::protobuf::redis_instance;
 
 /// Implementation of RedisService trait for Redis struct.
 /// Provides core functionality for interacting with Volcengine Redis service,
@@ -43,7 +38,7 @@ impl RedisService for Redis {
     /// - `Err(error::Error)`: Configuration errors during client setup
     fn new_redis(session: session::Session) -> Result<Self, error::Error> {
         // Initialize Redis-specific client configuration from session
-        let client_config = session.new_client_config(client_config::ClientServiceName::Redis);
+        let client_config = session.new_client_config(client_config::ClientServiceName::Redis)?;
 
         // Build service metadata for API requests
         let client_info = client_info::ClientInfo::builder()
@@ -52,8 +47,8 @@ impl RedisService for Redis {
             .with_signing_region(&client_config.signing_region)
             .build()?;
 
-        // Initialize network connection pool and TLS configuration
-        let request_handles = handles::Handles {};
+        // Use the request handles registered on the session
+        let request_handles = client_config.handles.clone();
 
         // Construct complete client instance
         let client = client::Client::builder()
@@ -83,10 +78,7 @@ impl RedisService for Redis {
         volcengine_sdk_protobuf::protobuf::redis_instance::RedisCreateDbInstanceResp,
         error::Error,
     > {
-        // Delegate to API implementation module
-        api_create_db_instance::ApiCreateDBInstanceRedis
-            .new_create_db_instance(self, request)
-            .await
+        registry::invoke(&self.client, &registry::REDIS_CREATE_DB_INSTANCE, &request).await
     }
 
     /// Retrieves detailed configuration and status of a Redis instance.
@@ -107,9 +99,12 @@ impl RedisService for Redis {
         volcengine_sdk_protobuf::protobuf::redis_instance::RedisDescribeDbInstanceDetailResp,
         error::Error,
     > {
-        api_describe_db_instance_detail::ApiDescribeDBInstanceDetailRedis
-            .new_describe_db_instance(self, request)
-            .await
+        registry::invoke(
+            &self.client,
+            &registry::REDIS_DESCRIBE_DB_INSTANCE_DETAIL,
+            &request,
+        )
+        .await
     }
 
     /// Updates network access rules (allow list) for a Redis instance.
@@ -133,16 +128,14 @@ impl RedisService for Redis {
         volcengine_sdk_protobuf::protobuf::redis_allow::RedisModifyAllowListResp,
         error::Error,
     > {
-        api_modify_allow_list::ApiModifyAllowListRedis
-            .new_modify_allow_list(self, request)
-            .await
+        registry::invoke(&self.client, &registry::REDIS_MODIFY_ALLOW_LIST, &request).await
     }
 
     /// Increases the node number of a Redis database instance.
     ///
     /// This function sends a request to the Volcengine Redis service to increase the number of nodes
-    /// in a Redis database instance. It delegates the actual request handling to the
-    /// `ApiIncreaseDbInstanceNodeNumber` struct.
+    /// in a Redis database instance. The operation is sent with
+    /// `registry::invoke`.
     ///
     /// # Arguments
     /// * `request` - A `IncreaseDbInstanceNodeNumberReq` structure containing the request parameters.
@@ -157,16 +150,19 @@ impl RedisService for Redis {
         volcengine_sdk_protobuf::protobuf::redis_instance::RedisIncreaseDbInstanceNodeNumberResp,
         error::Error,
     > {
-        api_increase_db_instance_node_number::ApiIncreaseDbInstanceNodeNumber
-            .new_increase_db_instance_node_number(self, request)
-            .await
+        registry::invoke(
+            &self.client,
+            &registry::REDIS_INCREASE_DB_INSTANCE_NODE_NUMBER,
+            &request,
+        )
+        .await
     }
 
     /// Decreases the node number of a Redis database instance.
     ///
     /// This function sends a request to the Volcengine Redis service to decrease the number of nodes
-    /// in a Redis database instance. It delegates the actual request handling to the
-    /// `ApiDecreaseDBInstanceNodeNumber` struct.
+    /// in a Redis database instance. The operation is sent with
+    /// `registry::invoke`.
     ///
     /// # Arguments
     /// * `request` - A `DecreaseDbInstanceNodeNumberReq` structure containing the request parameters.
@@ -181,16 +177,19 @@ impl RedisService for Redis {
         volcengine_sdk_protobuf::protobuf::redis_instance::RedisDecreaseDbInstanceNodeNumberResp,
         error::Error,
     > {
-        api_decrease_db_instance_node_number::ApiDecreaseDBInstanceNodeNumber
-            .new_decrease_db_instance_node_number(self, request)
-            .await
+        registry::invoke(
+            &self.client,
+            &registry::REDIS_DECREASE_DB_INSTANCE_NODE_NUMBER,
+            &request,
+        )
+        .await
     }
 
     /// Modifies the shard capacity of a Redis database instance.
     ///
     /// This function sends a request to the Volcengine Redis service to modify the shard capacity
-    /// of a Redis database instance. It delegates the actual request handling to the
-    /// `ApiModifyDBInstanceShardCapacity` struct.
+    /// of a Redis database instance. The operation is sent with
+    /// `registry::invoke`.
     ///
     /// # Arguments
     /// * `request` - A `ModifyDbInstanceShardCapacityReq` structure containing the request parameters.
@@ -205,16 +204,19 @@ impl RedisService for Redis {
         volcengine_sdk_protobuf::protobuf::redis_instance::RedisModifyDbInstanceShardCapacityResp,
         error::Error,
     > {
-        api_modify_db_instance_shard_capacity::ApiModifyDBInstanceShardCapacity
-            .new_modify_db_instance_shard_capacity(self, request)
-            .await
+        registry::invoke(
+            &self.client,
+            &registry::REDIS_MODIFY_DB_INSTANCE_SHARD_CAPACITY,
+            &request,
+        )
+        .await
     }
 
     /// Modifies the shard number of a Redis database instance.
     ///
     /// This function sends a request to the Volcengine Redis service to modify the shard number
-    /// of a Redis database instance. It delegates the actual request handling to the
-    /// `ApiModifyDBInstanceShardNumber` struct.
+    /// of a Redis database instance. The operation is sent with
+    /// `registry::invoke`.
     ///
     /// # Arguments
     /// * `request` - A `ModifyDbInstanceShardNumberReq` structure containing the request parameters.
@@ -229,16 +231,19 @@ impl RedisService for Redis {
         volcengine_sdk_protobuf::protobuf::redis_instance::RedisModifyDbInstanceShardNumberResp,
         error::Error,
     > {
-        api_modify_db_instance_shard_number::ApiModifyDBInstanceShardNumber
-            .new_modify_db_instance_shard_number(self, request)
-            .await
+        registry::invoke(
+            &self.client,
+            &registry::REDIS_MODIFY_DB_INSTANCE_SHARD_NUMBER,
+            &request,
+        )
+        .await
     }
 
     /// Enables sharded cluster mode for a Redis database instance.
     ///
     /// This function sends a request to the Volcengine Redis service to enable sharded cluster mode
-    /// for a Redis database instance. It delegates the actual request handling to the
-    /// `ApiEnableShardedCluster` struct.
+    /// for a Redis database instance. The operation is sent with
+    /// `registry::invoke`.
     ///
     /// # Arguments
     /// * `request` - A `EnableShardedClusterReq` structure containing the request parameters.
@@ -253,14 +258,17 @@ impl RedisService for Redis {
         volcengine_sdk_protobuf::protobuf::redis_instance::RedisEnableShardedClusterResp,
         error::Error,
     > {
-        api_enable_sharded_cluster::ApiEnableShardedCluster
-            .new_enable_sharded_cluster(self, request)
-            .await
+        registry::invoke(
+            &self.client,
+            &registry::REDIS_ENABLE_SHARDED_CLUSTER,
+            &request,
+        )
+        .await
     }
 
     /// Public method to describe the details of a Redis database instance.
     ///
-    /// This method acts as a wrapper to call the `new_describe_db_instances` method of the `ApiDescribeDBInstancesRedis` struct.
+    /// The operation is declared in the registry and sent with `registry::invoke`.
     /// It is used to send a request to the Volcengine Redis service to retrieve detailed information about Redis database instances.
     ///
     /// # Arguments
@@ -277,8 +285,55 @@ impl RedisService for Redis {
         volcengine_sdk_protobuf::protobuf::redis_instance::RedisDescribeDbInstancesResp,
         error::Error,
     > {
-        api_describe_db_instances::ApiDescribeDBInstancesRedis
-            .new_describe_db_instances(self, request)
+        registry::invoke(
+            &self.client,
+            &registry::REDIS_DESCRIBE_DB_INSTANCES,
+            &request,
+        )
+        .await
+    }
+
+    /// Lists Redis instances page by page.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current service instance.
+    /// - `request`: The request of the first page.
+    ///
+    /// # Returns
+    /// - `paginator::Paginator<redis_instance::RedisDescribeDbInstancesReq>`: A paginator sending `new_describe_db_instances` once per page.
+    fn describe_db_instances_paginator(
+        &self,
+        request: redis_instance::RedisDescribeDbInstancesReq,
+    ) -> paginator::Paginator<redis_instance::RedisDescribeDbInstancesReq> {
+        let redis = self.clone();
+        paginator::Paginator::new(request, move |request| {
+            let redis = redis.clone();
+            Box::pin(async move { redis.new_describe_db_instances(request).await })
+        })
+    }
+
+    /// Waits until a Redis instance is `Running`.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current service instance.
+    /// - `instance_id`: The ID of the instance.
+    /// - `waiter_config`: The delay, backoff and timeout.
+    ///
+    /// # Returns
+    /// - `Result<redis_instance::RedisDescribeDbInstanceDetailResp, error::Error>`: The instance once it reached the status, or the reason the wait ended.
+    async fn wait_until_redis_instance_running(
+        &self,
+        instance_id: &str,
+        waiter_config: &waiter::WaiterConfig,
+    ) -> Result<redis_instance::RedisDescribeDbInstanceDetailResp, error::Error> {
+        waiter_db_instance::WaiterDBInstanceRedis
+            .wait_until_db_instance_status(
+                self,
+                "RedisInstanceRunning",
+                instance_id,
+                waiter_db_instance::REDIS_INSTANCE_STATUS_RUNNING,
+                waiter_config,
+            )
             .await
     }
 }
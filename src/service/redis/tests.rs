@@ -2,120 +2,252 @@
  * @Author: Jerry.Yang
  * @Date: 2024-10-22 13:52:22
  * @LastEditors: Jerry.Yang
- * @LastEditTime: 2025-02-19 16:02:11
- * @Description: create_user test
+ * @LastEditTime: 2025-03-30 10:05:00
+ * @Description: redis test
  */
 #[cfg(test)]
 mod tests {
+    use futures::TryStreamExt;
+    use std::time::Duration;
+    use volcengine_sdk_protobuf::protobuf::{redis_allow, redis_instance};
+
     use crate::{
         service::redis,
         service::redis::RedisService,
-        volcengine::{config, credentials::credentials, session::session},
+        volcengine::{error::error, mock::mock, waiter::waiter},
     };
 
+    /// The API version of Redis.
+    const VERSION: &str = "2020-12-07";
+
+    /// Returns a `DescribeDBInstances` response listing one instance out of `total`.
+    fn describe_db_instances_response(instance_id: &str, total: i64) -> mock::MockResponse {
+        mock::MockResponse::json(&redis_instance::RedisDescribeDbInstancesResp {
+            result: Some(redis_instance::RedisDescribeDbInstancesResultResp {
+                total_instances_num: Some(total),
+                instances: vec![redis_instance::RedisDescribeDbInstancesResultInstanceResp {
+                    instance_id: Some(instance_id.to_string()),
+                    ..Default::default()
+                }],
+            }),
+            ..Default::default()
+        })
+    }
+
+    /// Returns a `DescribeDBInstanceDetail` response for an instance in `status`.
+    fn describe_db_instance_detail_response(instance_id: &str, status: &str) -> mock::MockResponse {
+        mock::MockResponse::json(&redis_instance::RedisDescribeDbInstanceDetailResp {
+            result: Some(redis_instance::RedisDescribeDbInstanceDetailResultResp {
+                instance_id: instance_id.to_string(),
+                status: status.to_string(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        })
+    }
+
     #[tokio::test]
     async fn test_get_user() {
-        let access_key_id = ""; // 这里填入实际的 Access Key ID
-        let secret_access_key = ""; // 这里填入实际的 Secret Access Key
-        let region_id = "cn-beijing"; // 这里填入实际的 Region ID
+        // start the mock server
+        let server = mock::MockServer::start().await;
+        server.mock(
+            "DescribeDBInstances",
+            VERSION,
+            describe_db_instances_response("redis-1", 1),
+        );
+
+        // new redis
+        let redis = redis::Redis::new_redis(server.session()).unwrap();
+
+        // describe instances
+        let mut request = redis_instance::RedisDescribeDbInstancesReq::default();
+        request.page_number = Some(10);
+        request.page_size = Some(100);
+        let result = redis.new_describe_db_instances(request).await.unwrap();
+
+        let instances = result.result.unwrap().instances;
+        assert_eq!(instances[0].instance_id.as_deref(), Some("redis-1"));
+
+        // the request is signed and carries the parameters in its body
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].signature_valid);
+        assert_eq!(requests[0].service, "redis");
+        let body: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+        assert_eq!(body["PageNumber"], 10);
+        assert_eq!(body["PageSize"], 100);
+    }
 
-        let credentials = credentials::Credentials::new(access_key_id, secret_access_key);
+    #[tokio::test]
+    async fn test_describe_db_instances_paginator() {
+        // two pages of one instance out of two
+        let server = mock::MockServer::start().await;
+        server.mock(
+            "DescribeDBInstances",
+            VERSION,
+            describe_db_instances_response("redis-1", 2),
+        );
+        server.mock(
+            "DescribeDBInstances",
+            VERSION,
+            describe_db_instances_response("redis-2", 2),
+        );
+
+        let redis = redis::Redis::new_redis(server.session()).unwrap();
+        let request = redis_instance::RedisDescribeDbInstancesReq {
+            page_size: Some(1),
+            ..Default::default()
+        };
+        let instances: Vec<_> = redis
+            .describe_db_instances_paginator(request)
+            .items()
+            .try_collect()
+            .await
+            .unwrap();
+
+        let instance_ids: Vec<_> = instances
+            .iter()
+            .map(|instance| instance.instance_id.as_deref().unwrap())
+            .collect();
+        assert_eq!(instance_ids, ["redis-1", "redis-2"]);
+        assert_eq!(server.requests().len(), 2);
+    }
 
-        // new config
-        let config = config::Config::builder()
-            .with_region(&region_id)
-            .with_credentials(credentials)
+    #[tokio::test]
+    async fn test_wait_until_redis_instance_running() {
+        // the instance failed, so it never runs
+        let server = mock::MockServer::start().await;
+        server.mock(
+            "DescribeDBInstanceDetail",
+            VERSION,
+            describe_db_instance_detail_response("redis-1", "Creating"),
+        );
+        server.mock(
+            "DescribeDBInstanceDetail",
+            VERSION,
+            describe_db_instance_detail_response("redis-1", "Error"),
+        );
+
+        let redis = redis::Redis::new_redis(server.session()).unwrap();
+        let waiter_config = waiter::WaiterConfig::builder()
+            .with_delay(Duration::from_millis(1))
+            .with_timeout(Duration::from_secs(5))
             .build();
+        let result = redis
+            .wait_until_redis_instance_running("redis-1", &waiter_config)
+            .await;
 
-        // print config
-        println!("config : {:?}", config);
-
-        // reset config
-        let config = config.unwrap();
-
-        // new session
-        let session = session::Session::builder().with_config(config).build();
-
-        // print session
-        println!("session : {:?}", session);
-
-        // reset session
-        let session = session.unwrap();
-
-        // new ecs
-        let redis = redis::Redis::new_redis(session);
-
-        // print ecs
-        println!("redis : {:?}", redis);
+        assert!(matches!(result, Err(error::Error::ErrWaiterFailure { .. })));
+        assert_eq!(server.requests().len(), 2);
+    }
 
-        // reset ecs
-        let redis = redis.unwrap();
+    #[tokio::test]
+    async fn test_wait_until_redis_instance_not_found() {
+        // the instance is missing once, then runs
+        let server = mock::MockServer::start().await;
+        server.mock(
+            "DescribeDBInstanceDetail",
+            VERSION,
+            mock::MockResponse::json(&redis_instance::RedisDescribeDbInstanceDetailResp::default()),
+        );
+        server.mock(
+            "DescribeDBInstanceDetail",
+            VERSION,
+            describe_db_instance_detail_response("redis-1", "Running"),
+        );
+
+        // the waiter runs on its own task
+        let redis = redis::Redis::new_redis(server.session()).unwrap();
+        tokio::spawn(async move {
+            let waiter_config = waiter::WaiterConfig::builder()
+                .with_delay(Duration::from_millis(1))
+                .with_timeout(Duration::from_secs(5))
+                .build();
+            redis
+                .wait_until_redis_instance_running("redis-1", &waiter_config)
+                .await
+        })
+        .await
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(server.requests().len(), 2);
+    }
 
-        // create_user
-        let mut request =
-            volcengine_sdk_protobuf::protobuf::redis_instance::RedisDescribeDbInstancesReq::default(
-            );
-        request.page_number = Some(10);
-        request.page_size = Some(100);
-        // request.auto_renew = Some(true);
-        // request.auto_renew_period = Some(1);
-        // request.count = Some(1);
-        // // request.dry_run = Some(true);
-        // request.image_id = Some("image-yde0q2u36nb6uofymxm5".to_string());
-        // request.instance_charge_type = Some("PrePaid".to_string());
-        // request.instance_name = Some("volcengine-bja-techcenter-pi-oa-api-[8,1]".to_string());
-        // request.instance_type_id = Some("ecs.t2-c2m1.large".to_string());
-        // request.key_pair_name = Some("ops".to_string());
-        // request.period = Some(1);
-        // request.project_name = Some("techcenter-pi-oa-api".to_string());
-        // request.suffix_index = Some(8);
-        // request.unique_suffix = Some(true);
-        // request.zone_id = Some("cn-beijing-a".to_string());
-
-        // // create instance volumes
-        // let mut request_volumes: Vec<
-        //     volcengine_sdk_protobuf::protobuf::ecs_instance::RunInstancesVolumesReq,
-        // > = Vec::new();
-        // let mut request_volume =
-        //     volcengine_sdk_protobuf::protobuf::ecs_instance::RunInstancesVolumesReq::default();
-        // let mut request_volume1 =
-        //     volcengine_sdk_protobuf::protobuf::ecs_instance::RunInstancesVolumesReq::default();
-        // request_volume.size = Some(100);
-        // // request_volume.volume_type = Some("ESSD_PL0".to_string());
-        // request_volume1.size = Some(100);
-        // request_volume1.volume_type = Some("Ultra_Disk".to_string());
-        // request_volumes.push(request_volume);
-        // request_volumes.push(request_volume1);
-        // request.volumes = request_volumes;
-
-        // // create instance tag
-        // let mut request_tags: Vec<
-        //     volcengine_sdk_protobuf::protobuf::ecs_instance::RunInstancesTagsReq,
-        // > = Vec::new();
-        // let mut request_tag =
-        //     volcengine_sdk_protobuf::protobuf::ecs_instance::RunInstancesTagsReq::default();
-        // request_tag.key = Some("cmdb_project_name".to_string());
-        // request_tag.value = Some("techcenter-pi-oa-api".to_string());
-        // request_tags.push(request_tag);
-        // request.tags = request_tags;
-
-        // // create instance networkinterfaces
-        // let mut request_networkinterfaces: Vec<
-        //     volcengine_sdk_protobuf::protobuf::ecs_instance::RunInstancesNetworkInterfacesReq,
-        // > = Vec::new();
-        // let mut request_networkitnerface = volcengine_sdk_protobuf::protobuf::ecs_instance::RunInstancesNetworkInterfacesReq::default();
-        // request_networkitnerface.security_group_ids =
-        //     vec!["sg-rs4s5xddbmyov0x57zbxmf9".to_string()];
-        // request_networkitnerface.subnet_id = Some("subnet-rrag8md0u22ov0x5890j4su".to_string());
-        // request_networkinterfaces.push(request_networkitnerface);
-        // request.network_interfaces = request_networkinterfaces;
-
-        // 运行异步代码的 helper 函数
-        let result = redis.new_describe_db_instances(request).await;
-
-        println!("result : {:?}", result);
-
-        // 这里可以添加断言来检查结果
-        assert!(result.is_ok());
+    #[tokio::test]
+    async fn test_every_operation() {
+        // answer every action with an empty result
+        let actions = [
+            "CreateDBInstance",
+            "DescribeDBInstanceDetail",
+            "ModifyAllowList",
+            "IncreaseDBInstanceNodeNumber",
+            "DecreaseDBInstanceNodeNumber",
+            "ModifyDBInstanceShardCapacity",
+            "ModifyDBInstanceShardNumber",
+            "EnableShardedCluster",
+            "DescribeDBInstances",
+        ];
+        let server = mock::MockServer::start().await;
+        for action in actions {
+            server.mock(action, VERSION, mock::MockResponse::empty());
+        }
+
+        let redis = redis::Redis::new_redis(server.session()).unwrap();
+        redis
+            .new_create_db_instance(redis_instance::RedisCreateDbInstanceReq::default())
+            .await
+            .unwrap();
+        redis
+            .new_describe_db_instance_detail(
+                redis_instance::RedisDescribeDbInstanceDetailReq::default(),
+            )
+            .await
+            .unwrap();
+        redis
+            .new_modify_allow_list(redis_allow::RedisModifyAllowListReq::default())
+            .await
+            .unwrap();
+        redis
+            .new_increase_db_instance_node_number(
+                redis_instance::RedisIncreaseDbInstanceNodeNumberReq::default(),
+            )
+            .await
+            .unwrap();
+        redis
+            .new_decrease_db_instance_node_number(
+                redis_instance::RedisDecreaseDbInstanceNodeNumberReq::default(),
+            )
+            .await
+            .unwrap();
+        redis
+            .new_modify_db_instance_shard_capacity(
+                redis_instance::RedisModifyDbInstanceShardCapacityReq::default(),
+            )
+            .await
+            .unwrap();
+        redis
+            .new_modify_db_instance_shard_number(
+                redis_instance::RedisModifyDbInstanceShardNumberReq::default(),
+            )
+            .await
+            .unwrap();
+        redis
+            .new_enable_sharded_cluster(redis_instance::RedisEnableShardedClusterReq::default())
+            .await
+            .unwrap();
+        redis
+            .new_describe_db_instances(redis_instance::RedisDescribeDbInstancesReq::default())
+            .await
+            .unwrap();
+
+        // one signed request per operation, in order
+        let requests = server.requests();
+        let received_actions: Vec<_> = requests
+            .iter()
+            .map(|request| request.action.as_str())
+            .collect();
+        assert_eq!(received_actions, actions);
+        assert!(requests.iter().all(|request| request.signature_valid));
     }
 }
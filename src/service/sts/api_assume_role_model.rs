@@ -0,0 +1,99 @@
+/*
+ * @Author: Jerry.Yang
+ * @Date: 2025-03-10 10:47:03
+ * @LastEditors: Jerry.Yang
+ * @LastEditTime: 2025-03-28 10:14:26
+ * @Description: assume role model
+ */
+use crate::volcengine::request::response;
+use serde::{Deserialize, Serialize};
+
+/// Request of the `AssumeRole` API.
+///
+/// # Fields
+/// - `role_trn`: The TRN of the role to assume, e.g. `trn:iam::2100000000:role/ops`.
+/// - `role_session_name`: A name identifying the session, recorded in audit logs.
+/// - `duration_seconds`: Validity of the temporary credentials in seconds (900 to 43200, default 3600).
+/// - `policy`: An optional policy further restricting the permissions of the session.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct AssumeRoleReq {
+    /// The TRN of the role to assume
+    #[serde(rename = "RoleTrn")]
+    pub role_trn: String,
+
+    /// The name of the role session
+    #[serde(rename = "RoleSessionName")]
+    pub role_session_name: String,
+
+    /// The validity of the temporary credentials in seconds
+    #[serde(rename = "DurationSeconds", skip_serializing_if = "Option::is_none")]
+    pub duration_seconds: Option<i32>,
+
+    /// An inline policy restricting the permissions of the session
+    #[serde(rename = "Policy", skip_serializing_if = "Option::is_none")]
+    pub policy: Option<String>,
+}
+
+/// Response of the `AssumeRole` API.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct AssumeRoleResp {
+    /// Metadata of the response
+    #[serde(rename = "ResponseMetadata")]
+    pub response_metadata: response::ApiResponseMetadata,
+
+    /// The result of the call
+    #[serde(rename = "Result")]
+    pub result: Option<AssumeRoleResult>,
+}
+
+/// Result of the `AssumeRole` API.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct AssumeRoleResult {
+    /// The temporary credentials
+    #[serde(rename = "Credentials")]
+    pub credentials: AssumeRoleCredentials,
+
+    /// The identity of the assumed role
+    #[serde(rename = "AssumedRoleUser")]
+    pub assumed_role_user: AssumedRoleUser,
+}
+
+/// Temporary credentials returned by the `AssumeRole` API.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct AssumeRoleCredentials {
+    /// The time the credentials were issued (RFC 3339)
+    #[serde(rename = "CurrentTime")]
+    pub current_time: String,
+
+    /// The time the credentials expire (RFC 3339)
+    #[serde(rename = "ExpiredTime")]
+    pub expired_time: String,
+
+    /// The temporary access key id
+    #[serde(rename = "AccessKeyId")]
+    pub access_key_id: String,
+
+    /// The temporary secret access key
+    #[serde(rename = "SecretAccessKey")]
+    pub secret_access_key: String,
+
+    /// The session token to send with every request
+    #[serde(rename = "SessionToken")]
+    pub session_token: String,
+}
+
+/// Identity of the role assumed by the `AssumeRole` API.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct AssumedRoleUser {
+    /// The TRN of the assumed role session
+    #[serde(rename = "Trn")]
+    pub trn: String,
+
+    /// The id of the assumed role session
+    #[serde(rename = "AssumedRoleId")]
+    pub assumed_role_id: String,
+}
@@ -0,0 +1,63 @@
+/*
+ * @Author: Jerry.Yang
+ * @Date: 2025-03-18 11:52:08
+ * @LastEditors: Jerry.Yang
+ * @LastEditTime: 2025-03-30 11:10:00
+ * @Description: Blocking facade for STS (Security Token Service) operations
+ */
+use crate::service::sts;
+use crate::service::sts::api_assume_role_model;
+use crate::service::sts::StsService;
+use crate::volcengine::blocking::blocking;
+use crate::volcengine::error::error;
+use crate::volcengine::session::session;
+
+/// A synchronous facade over `sts::Sts`.
+///
+/// Exposes the operations of `StsService` as blocking methods, driven by the runtime shared by
+/// the blocking facades. Meant for build scripts and command line tools; asynchronous code
+/// should use `sts::Sts` directly.
+///
+/// # Example
+/// ```rust,no_run
+/// # use volcengine_rust_sdk::volcengine::error::error;
+/// # use volcengine_rust_sdk::volcengine::session::session;
+/// use volcengine_rust_sdk::service::sts;
+///
+/// # fn example(session: session::Session) -> Result<(), error::Error> {
+/// let sts = sts::blocking::Sts::new_sts(session)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Sts {
+    sts: sts::Sts,              // The asynchronous service.
+    runtime: blocking::Runtime, // The runtime driving its requests.
+}
+
+impl Sts {
+    /// Creates a new blocking `Sts` using the provided session.
+    ///
+    /// # Arguments
+    /// - `session`: A `Session` object containing authentication and configuration information.
+    ///
+    /// # Returns
+    /// - `Ok(Sts)`: The blocking service.
+    /// - `Err(error::Error)`: If the service or the runtime could not be created.
+    pub fn new_sts(session: session::Session) -> Result<Sts, error::Error> {
+        Ok(Sts {
+            sts: sts::Sts::new_sts(session)?,
+            runtime: blocking::Runtime::shared()?,
+        })
+    }
+
+    /// Initiates a request to assume a role and obtain temporary credentials.
+    ///
+    /// Blocks until `StsService::new_assume_role` completes.
+    pub fn new_assume_role(
+        &self,
+        request: api_assume_role_model::AssumeRoleReq,
+    ) -> Result<api_assume_role_model::AssumeRoleResp, error::Error> {
+        self.runtime.block_on(self.sts.new_assume_role(request))
+    }
+}
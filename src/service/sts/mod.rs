@@ -0,0 +1,57 @@
+/*
+ * @Author: Jerry.Yang
+ * @Date: 2025-03-10 10:30:12
+ * @LastEditors: Jerry.Yang
+ * @LastEditTime: 2025-03-28 10:14:26
+ * @Description: Module for STS (Security Token Service) operations
+ */
+use crate::volcengine::client::client;
+use crate::volcengine::error::error;
+use crate::volcengine::session::session;
+use std::future::Future;
+
+// Public module for STS service related operations.
+pub mod service_sts;
+
+// Blocking facade exposing the service operations synchronously.
+pub mod blocking;
+
+// Submodules for the STS API operations.
+// The model module is public because the STS request and response types are defined in this crate.
+pub mod api_assume_role_model;
+
+// Test module for STS operations.
+mod tests;
+
+/// The `StsService` trait defines the interface for interacting with the STS (Security Token Service).
+/// STS issues temporary credentials (access key, secret key and session token) for a role.
+pub trait StsService {
+    /// Creates a new instance of the `Sts` struct using the provided session.
+    ///
+    /// # Parameters:
+    /// - `session`: A `Session` object containing authentication and configuration information.
+    ///
+    /// # Returns:
+    /// A `Result` containing an `Sts` instance on success or an `error::Error` on failure.
+    fn new_sts(session: session::Session) -> Result<Sts, error::Error>;
+
+    /// Initiates a request to assume a role and obtain temporary credentials.
+    ///
+    /// # Parameters:
+    /// - `request`: An `AssumeRoleReq` object containing the role TRN and session name.
+    ///
+    /// # Returns:
+    /// A `Future` that resolves to a `Result` containing an `AssumeRoleResp` on success or an `error::Error` on failure.
+    fn new_assume_role(
+        &self,
+        request: api_assume_role_model::AssumeRoleReq,
+    ) -> impl Future<Output = Result<api_assume_role_model::AssumeRoleResp, error::Error>>;
+}
+
+/// The `Sts` struct represents the client for interacting with the STS (Security Token Service).
+/// It encapsulates the client configuration and provides methods for STS operations.
+#[derive(Debug, Clone)]
+pub struct Sts {
+    /// The client configuration used for making requests to the STS service.
+    client: client::Client,
+}
@@ -0,0 +1,70 @@
+/*
+ * @Author: Jerry.Yang
+ * @Date: 2025-03-10 10:34:51
+ * @LastEditors: Jerry.Yang
+ * @LastEditTime: 2025-03-28 10:14:26
+ * @Description: service sts
+ */
+use crate::service::sts::api_assume_role_model;
+use crate::service::sts::{Sts, StsService};
+use crate::volcengine::client::client;
+use crate::volcengine::client::client_info;
+use crate::volcengine::client::config as client_config;
+use crate::volcengine::common;
+use crate::volcengine::error::error;
+use crate::volcengine::registry::registry;
+use crate::volcengine::session::session;
+
+/// Implementation of the `StsService` trait for the `Sts` struct.
+/// This implementation provides the functionality to initialize the STS client and to
+/// obtain temporary credentials by assuming a role.
+impl StsService for Sts {
+    /// Creates a new `Sts` instance using the provided session.
+    ///
+    /// # Arguments
+    /// - `session`: The session used to retrieve the client configuration for the STS service.
+    ///
+    /// # Returns
+    /// - `Ok(Sts)`: A new `Sts` instance if the initialization succeeds.
+    /// - `Err(error::Error)`: Returns an error if the configuration or client setup fails.
+    fn new_sts(session: session::Session) -> Result<Self, error::Error> {
+        // Create a new client configuration from the session.
+        let client_config = session.new_client_config(client_config::ClientServiceName::Sts)?;
+
+        // Construct client info using the client configuration and common API version.
+        let client_info = client_info::ClientInfo::builder()
+            .with_service_name(client_config::ClientServiceName::Sts)
+            .with_api_version(common::COMMON_VERSION)
+            .with_signing_region(&client_config.signing_region)
+            .build()?;
+
+        // Use the request handles registered on the session
+        let request_handles = client_config.handles.clone();
+
+        // Build the client using the client info, configuration, and request handles.
+        let client = client::Client::builder()
+            .with_client_info(&client_info)
+            .with_config(&client_config)
+            .with_handles(&request_handles)
+            .build()?;
+
+        // Return the initialized `Sts` instance.
+        Ok(Sts { client })
+    }
+
+    /// Sends a request to the STS service to assume a role.
+    ///
+    /// # Arguments
+    /// - `&self`: The reference to the `Sts` instance calling this method.
+    /// - `request`: The `AssumeRoleReq` containing the role TRN and session name.
+    ///
+    /// # Returns
+    /// - `Ok(AssumeRoleResp)`: The temporary credentials of the assumed role.
+    /// - `Err(error::Error)`: An error if the request fails or the response cannot be parsed.
+    async fn new_assume_role(
+        &self,
+        request: api_assume_role_model::AssumeRoleReq,
+    ) -> Result<api_assume_role_model::AssumeRoleResp, error::Error> {
+        registry::invoke(&self.client, &registry::STS_ASSUME_ROLE, &request).await
+    }
+}
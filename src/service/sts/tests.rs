@@ -0,0 +1,56 @@
+/*
+ * @Author: Jerry.Yang
+ * @Date: 2025-03-19 19:04:37
+ * @LastEditors: Jerry.Yang
+ * @LastEditTime: 2025-03-19 19:04:37
+ * @Description: sts test
+ */
+#[cfg(test)]
+mod tests {
+    use crate::{
+        service::sts::{self, api_assume_role_model, StsService},
+        volcengine::mock::mock,
+    };
+
+    #[tokio::test]
+    async fn test_assume_role() {
+        let server = mock::MockServer::start().await;
+        let response = api_assume_role_model::AssumeRoleResp {
+            result: Some(api_assume_role_model::AssumeRoleResult {
+                credentials: api_assume_role_model::AssumeRoleCredentials {
+                    access_key_id: "AKTPtemporary".to_string(),
+                    secret_access_key: "temporarysecret".to_string(),
+                    session_token: "token".to_string(),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        server.mock(
+            "AssumeRole",
+            "2018-01-01",
+            mock::MockResponse::json(&response),
+        );
+
+        let sts = sts::Sts::new_sts(server.session()).unwrap();
+        let request = api_assume_role_model::AssumeRoleReq {
+            role_trn: "trn:iam::2100000000:role/ops".to_string(),
+            role_session_name: "session".to_string(),
+            duration_seconds: Some(900),
+            policy: None,
+        };
+        let result = sts.new_assume_role(request).await.unwrap();
+
+        let credentials = result.result.unwrap().credentials;
+        assert_eq!(credentials.access_key_id, "AKTPtemporary");
+        assert_eq!(credentials.session_token, "token");
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].signature_valid);
+        assert_eq!(requests[0].service, "sts");
+        assert_eq!(requests[0].query["RoleTrn"], "trn:iam::2100000000:role/ops");
+        assert_eq!(requests[0].query["DurationSeconds"], "900");
+    }
+}
@@ -0,0 +1,556 @@
+/*
+ * @Author: Jerry.Yang
+ * @Date: 2025-03-26 09:58:14
+ * @LastEditors: Jerry.Yang
+ * @LastEditTime: 2025-03-29 20:50:00
+ * @Description: elastic ip models
+ */
+use crate::service::vpc::api_tag_model;
+use crate::volcengine::paginator::paginator;
+use crate::volcengine::request::response;
+use serde::{Deserialize, Serialize};
+
+/// The billing method of an EIP, sent as `BillingType`.
+///
+/// Billing methods the SDK does not know yet are kept as `Other`.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(from = "i32", into = "i32")]
+pub enum EipBillingType {
+    /// `1`, subscription billed by bandwidth
+    PrePaid,
+    /// `2`, pay-as-you-go billed by bandwidth
+    PostPaidByBandwidth,
+    /// `3`, pay-as-you-go billed by traffic
+    PostPaidByTraffic,
+    /// Any other billing method, verbatim
+    Other(i32),
+}
+
+impl From<i32> for EipBillingType {
+    fn from(billing_type: i32) -> Self {
+        match billing_type {
+            1 => EipBillingType::PrePaid,
+            2 => EipBillingType::PostPaidByBandwidth,
+            3 => EipBillingType::PostPaidByTraffic,
+            _ => EipBillingType::Other(billing_type),
+        }
+    }
+}
+
+impl From<EipBillingType> for i32 {
+    fn from(billing_type: EipBillingType) -> Self {
+        match billing_type {
+            EipBillingType::PrePaid => 1,
+            EipBillingType::PostPaidByBandwidth => 2,
+            EipBillingType::PostPaidByTraffic => 3,
+            EipBillingType::Other(billing_type) => billing_type,
+        }
+    }
+}
+
+/// The resource an EIP is associated with.
+///
+/// Sent as `InstanceType` / `InstanceId`, plus `PrivateIpAddress` for a network interface.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(try_from = "RawEipTarget", into = "RawEipTarget")]
+pub enum EipTarget {
+    /// An ECS instance, by its ID, sent as `EcsInstance`
+    EcsInstance(String),
+    /// A CLB instance, by its ID, sent as `ClbInstance`
+    ClbInstance(String),
+    /// A NAT gateway, by its ID, sent as `Nat`
+    NatGateway(String),
+    /// A network interface, by its ID, sent as `NetworkInterface`; the EIP is bound to
+    /// `private_ip_address`, the primary private IP of the interface by default
+    NetworkInterface {
+        network_interface_id: String,
+        private_ip_address: Option<String>,
+    },
+}
+
+impl EipTarget {
+    /// Returns the `InstanceType` of the target.
+    ///
+    /// # Returns
+    /// - `&str`: The type, e.g. `EcsInstance`.
+    pub fn instance_type(&self) -> &str {
+        match self {
+            EipTarget::EcsInstance(_) => "EcsInstance",
+            EipTarget::ClbInstance(_) => "ClbInstance",
+            EipTarget::NatGateway(_) => "Nat",
+            EipTarget::NetworkInterface { .. } => "NetworkInterface",
+        }
+    }
+
+    /// Returns the `InstanceId` of the target.
+    ///
+    /// # Returns
+    /// - `&str`: The ID of the resource.
+    pub fn instance_id(&self) -> &str {
+        match self {
+            EipTarget::EcsInstance(instance_id)
+            | EipTarget::ClbInstance(instance_id)
+            | EipTarget::NatGateway(instance_id) => instance_id,
+            EipTarget::NetworkInterface {
+                network_interface_id,
+                ..
+            } => network_interface_id,
+        }
+    }
+}
+
+/// The fields of an EIP target as sent by the API.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+struct RawEipTarget {
+    #[serde(rename = "InstanceType")]
+    instance_type: String,
+    #[serde(rename = "InstanceId")]
+    instance_id: String,
+    #[serde(rename = "PrivateIpAddress", skip_serializing_if = "Option::is_none")]
+    private_ip_address: Option<String>,
+}
+
+impl From<EipTarget> for RawEipTarget {
+    fn from(target: EipTarget) -> Self {
+        let instance_type = target.instance_type().to_string();
+        match target {
+            EipTarget::EcsInstance(instance_id)
+            | EipTarget::ClbInstance(instance_id)
+            | EipTarget::NatGateway(instance_id) => RawEipTarget {
+                instance_type,
+                instance_id,
+                private_ip_address: None,
+            },
+            EipTarget::NetworkInterface {
+                network_interface_id,
+                private_ip_address,
+            } => RawEipTarget {
+                instance_type,
+                instance_id: network_interface_id,
+                private_ip_address,
+            },
+        }
+    }
+}
+
+impl TryFrom<RawEipTarget> for EipTarget {
+    type Error = String;
+
+    fn try_from(raw: RawEipTarget) -> Result<Self, Self::Error> {
+        match raw.instance_type.as_str() {
+            "EcsInstance" => Ok(EipTarget::EcsInstance(raw.instance_id)),
+            "ClbInstance" => Ok(EipTarget::ClbInstance(raw.instance_id)),
+            "Nat" => Ok(EipTarget::NatGateway(raw.instance_id)),
+            "NetworkInterface" => Ok(EipTarget::NetworkInterface {
+                network_interface_id: raw.instance_id,
+                private_ip_address: raw.private_ip_address.filter(|ip| !ip.is_empty()),
+            }),
+            instance_type => Err(format!("unknown EIP instance type {}", instance_type)),
+        }
+    }
+}
+
+/// Request of the `AllocateEipAddress` API.
+///
+/// # Fields
+/// - `billing_type`: The billing method of the EIP.
+/// - `bandwidth`: The peak bandwidth of the EIP in Mbps.
+/// - `isp`: The line of the EIP, e.g. `BGP` (the default) or `ChinaMobile`.
+/// - `name`: The name of the EIP.
+/// - `description`: The description of the EIP.
+/// - `period`: The length of the subscription for `PrePaid`, in `period_unit`.
+/// - `period_unit`: The unit of `period`, `1` for months (the default) or `2` for years.
+/// - `bandwidth_package_id`: The shared bandwidth package to add the EIP to.
+/// - `project_name`: The project of the EIP.
+/// - `tags`: The tags of the EIP.
+/// - `client_token`: A token making the request idempotent.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AllocateEipAddressReq {
+    /// The billing method
+    #[serde(rename = "BillingType")]
+    pub billing_type: EipBillingType,
+
+    /// The peak bandwidth in Mbps
+    #[serde(rename = "Bandwidth", skip_serializing_if = "Option::is_none")]
+    pub bandwidth: Option<i32>,
+
+    /// The line of the EIP
+    #[serde(rename = "ISP", skip_serializing_if = "Option::is_none")]
+    pub isp: Option<String>,
+
+    /// The name of the EIP
+    #[serde(rename = "Name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// The description of the EIP
+    #[serde(rename = "Description", skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// The length of the subscription
+    #[serde(rename = "Period", skip_serializing_if = "Option::is_none")]
+    pub period: Option<i32>,
+
+    /// The unit of the subscription
+    #[serde(rename = "PeriodUnit", skip_serializing_if = "Option::is_none")]
+    pub period_unit: Option<i32>,
+
+    /// The shared bandwidth package
+    #[serde(rename = "BandwidthPackageId", skip_serializing_if = "Option::is_none")]
+    pub bandwidth_package_id: Option<String>,
+
+    /// The project of the EIP
+    #[serde(rename = "ProjectName", skip_serializing_if = "Option::is_none")]
+    pub project_name: Option<String>,
+
+    /// The tags of the EIP
+    #[serde(rename = "Tags")]
+    pub tags: Vec<api_tag_model::Tag>,
+
+    /// The idempotency token
+    #[serde(rename = "ClientToken", skip_serializing_if = "Option::is_none")]
+    pub client_token: Option<String>,
+}
+
+impl Default for AllocateEipAddressReq {
+    /// Returns a request for a pay-as-you-go EIP billed by bandwidth.
+    fn default() -> Self {
+        AllocateEipAddressReq {
+            billing_type: EipBillingType::PostPaidByBandwidth,
+            bandwidth: None,
+            isp: None,
+            name: None,
+            description: None,
+            period: None,
+            period_unit: None,
+            bandwidth_package_id: None,
+            project_name: None,
+            tags: Vec::new(),
+            client_token: None,
+        }
+    }
+}
+
+/// Response of the `AllocateEipAddress` API.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct AllocateEipAddressResp {
+    /// Metadata of the response
+    #[serde(rename = "ResponseMetadata")]
+    pub response_metadata: response::ApiResponseMetadata,
+
+    /// The result of the call
+    #[serde(rename = "Result")]
+    pub result: Option<AllocateEipAddressResult>,
+}
+
+/// Result of the `AllocateEipAddress` API.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct AllocateEipAddressResult {
+    /// The ID of the EIP allocated
+    #[serde(rename = "AllocationId")]
+    pub allocation_id: String,
+
+    /// The public IP address of the EIP
+    #[serde(rename = "EipAddress")]
+    pub eip_address: String,
+}
+
+/// Request of the `DescribeEipAddresses` API.
+///
+/// # Fields
+/// - `allocation_ids`: Lists these EIPs.
+/// - `eip_addresses`: Lists the EIPs with these public IP addresses.
+/// - `status`: Lists the EIPs in this status, e.g. `Available` or `Attached`.
+/// - `associated_instance_type`: Lists the EIPs associated with this type of resource, e.g. `EcsInstance`.
+/// - `associated_instance_id`: Lists the EIP associated with this resource.
+/// - `name`: Lists the EIPs with this name.
+/// - `project_name`: Lists the EIPs of this project.
+/// - `page_number`: The page to list, from 1.
+/// - `page_size`: The number of EIPs per page.
+/// - `max_results`: The number of EIPs per page, when paging with `next_token`.
+/// - `next_token`: The token of the page to list.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DescribeEipAddressesReq {
+    /// The IDs of the EIPs
+    #[serde(rename = "AllocationIds")]
+    pub allocation_ids: Vec<String>,
+
+    /// The public IP addresses of the EIPs
+    #[serde(rename = "EipAddresses")]
+    pub eip_addresses: Vec<String>,
+
+    /// The status of the EIPs
+    #[serde(rename = "Status", skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+
+    /// The type of the associated resources
+    #[serde(
+        rename = "AssociatedInstanceType",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub associated_instance_type: Option<String>,
+
+    /// The ID of the associated resource
+    #[serde(
+        rename = "AssociatedInstanceId",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub associated_instance_id: Option<String>,
+
+    /// The name of the EIPs
+    #[serde(rename = "Name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// The project of the EIPs
+    #[serde(rename = "ProjectName", skip_serializing_if = "Option::is_none")]
+    pub project_name: Option<String>,
+
+    /// The page number
+    #[serde(rename = "PageNumber", skip_serializing_if = "Option::is_none")]
+    pub page_number: Option<i64>,
+
+    /// The page size
+    #[serde(rename = "PageSize", skip_serializing_if = "Option::is_none")]
+    pub page_size: Option<i64>,
+
+    /// The page size when paging with tokens
+    #[serde(rename = "MaxResults", skip_serializing_if = "Option::is_none")]
+    pub max_results: Option<i64>,
+
+    /// The token of the page
+    #[serde(rename = "NextToken", skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+}
+
+/// Response of the `DescribeEipAddresses` API.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct DescribeEipAddressesResp {
+    /// Metadata of the response
+    #[serde(rename = "ResponseMetadata")]
+    pub response_metadata: response::ApiResponseMetadata,
+
+    /// The result of the call
+    #[serde(rename = "Result")]
+    pub result: Option<DescribeEipAddressesResult>,
+}
+
+/// Result of the `DescribeEipAddresses` API.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct DescribeEipAddressesResult {
+    /// The EIPs of the page
+    #[serde(rename = "EipAddresses")]
+    pub eip_addresses: Vec<EipAddress>,
+
+    /// The number of EIPs listed
+    #[serde(rename = "TotalCount")]
+    pub total_count: Option<i64>,
+
+    /// The page number
+    #[serde(rename = "PageNumber")]
+    pub page_number: Option<i64>,
+
+    /// The page size
+    #[serde(rename = "PageSize")]
+    pub page_size: Option<i64>,
+
+    /// The token of the next page, empty on the last page
+    #[serde(rename = "NextToken")]
+    pub next_token: Option<String>,
+}
+
+/// An EIP listed by `DescribeEipAddresses`.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct EipAddress {
+    /// The ID of the EIP
+    #[serde(rename = "AllocationId")]
+    pub allocation_id: String,
+
+    /// The public IP address of the EIP
+    #[serde(rename = "EipAddress")]
+    pub eip_address: String,
+
+    /// The status of the EIP, e.g. `Available` or `Attached`
+    #[serde(rename = "Status")]
+    pub status: String,
+
+    /// The type of the associated resource, e.g. `EcsInstance`, empty when not associated
+    #[serde(rename = "InstanceType")]
+    pub instance_type: String,
+
+    /// The ID of the associated resource, empty when not associated
+    #[serde(rename = "InstanceId")]
+    pub instance_id: String,
+
+    /// The private IP address the EIP is bound to
+    #[serde(rename = "PrivateIpAddress")]
+    pub private_ip_address: String,
+
+    /// The billing method of the EIP
+    #[serde(rename = "BillingType")]
+    pub billing_type: Option<EipBillingType>,
+
+    /// The peak bandwidth in Mbps
+    #[serde(rename = "Bandwidth")]
+    pub bandwidth: i32,
+
+    /// The line of the EIP
+    #[serde(rename = "ISP")]
+    pub isp: String,
+
+    /// The name of the EIP
+    #[serde(rename = "Name")]
+    pub name: String,
+
+    /// The description of the EIP
+    #[serde(rename = "Description")]
+    pub description: String,
+
+    /// The project of the EIP
+    #[serde(rename = "ProjectName")]
+    pub project_name: String,
+
+    /// The allocation time of the EIP
+    #[serde(rename = "AllocationTime")]
+    pub allocation_time: String,
+
+    /// The tags of the EIP
+    #[serde(rename = "Tags")]
+    pub tags: Vec<api_tag_model::Tag>,
+}
+
+impl EipAddress {
+    /// Returns the resource the EIP is associated with.
+    ///
+    /// # Returns
+    /// - `Some(EipTarget)`: The resource.
+    /// - `None`: If the EIP is not associated, or with a type of resource the SDK does not know.
+    pub fn target(&self) -> Option<EipTarget> {
+        if self.instance_id.is_empty() {
+            return None;
+        }
+        EipTarget::try_from(RawEipTarget {
+            instance_type: self.instance_type.clone(),
+            instance_id: self.instance_id.clone(),
+            private_ip_address: Some(self.private_ip_address.clone()),
+        })
+        .ok()
+    }
+}
+
+/// Request of the `AssociateEipAddress` and `DisassociateEipAddress` APIs.
+///
+/// # Fields
+/// - `allocation_id`: The ID of the EIP.
+/// - `target`: The resource to associate the EIP with, or to disassociate it from.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct EipAssociationReq {
+    /// The ID of the EIP
+    #[serde(rename = "AllocationId")]
+    pub allocation_id: String,
+
+    /// The resource
+    #[serde(flatten)]
+    pub target: EipTarget,
+}
+
+/// Request of the `ModifyEipAddressAttributes` API.
+///
+/// Only the attributes that are set are modified.
+///
+/// # Fields
+/// - `allocation_id`: The ID of the EIP.
+/// - `name`: The new name of the EIP.
+/// - `description`: The new description of the EIP.
+/// - `bandwidth`: The new peak bandwidth of the EIP in Mbps.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ModifyEipAddressAttributesReq {
+    /// The ID of the EIP
+    #[serde(rename = "AllocationId")]
+    pub allocation_id: String,
+
+    /// The name of the EIP
+    #[serde(rename = "Name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// The description of the EIP
+    #[serde(rename = "Description", skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// The peak bandwidth in Mbps
+    #[serde(rename = "Bandwidth", skip_serializing_if = "Option::is_none")]
+    pub bandwidth: Option<i32>,
+}
+
+/// Request of the `ReleaseEipAddress` API.
+///
+/// # Fields
+/// - `allocation_id`: The ID of the EIP, which must not be associated.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ReleaseEipAddressReq {
+    /// The ID of the EIP
+    #[serde(rename = "AllocationId")]
+    pub allocation_id: String,
+}
+
+/// Response of the EIP APIs returning no result (`AssociateEipAddress`,
+/// `DisassociateEipAddress`, `ModifyEipAddressAttributes` and `ReleaseEipAddress`).
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct EipOperationResp {
+    /// Metadata of the response
+    #[serde(rename = "ResponseMetadata")]
+    pub response_metadata: response::ApiResponseMetadata,
+}
+
+/// Implementation of the `Paginated` trait for the `DescribeEipAddressesReq` structure.
+/// EIPs are paged with `NextToken` when `MaxResults` or `NextToken` is set,
+/// and with `PageNumber` / `PageSize` otherwise.
+impl paginator::Paginated for DescribeEipAddressesReq {
+    type Response = DescribeEipAddressesResp;
+    type Item = EipAddress;
+
+    /// Returns the EIPs of a page.
+    fn items(response: &Self::Response) -> &[Self::Item] {
+        response
+            .result
+            .as_ref()
+            .map_or(&[], |result| result.eip_addresses.as_slice())
+    }
+
+    /// Requests the page following the `NextToken` or the page number of the response.
+    fn next_request(&self, response: &Self::Response, fetched: usize) -> Option<Self> {
+        let result = response.result.as_ref()?;
+
+        // Token-based pagination, chosen by the caller through `MaxResults` / `NextToken`.
+        if self.max_results.is_some() || self.next_token.is_some() {
+            return paginator::next_token_request(
+                self,
+                self.next_token.as_ref(),
+                result.next_token.as_ref(),
+                |request, token| request.next_token = Some(token),
+            );
+        }
+
+        // Page-number pagination.
+        let page_number = paginator::next_page_number(
+            self.page_number.unwrap_or_default(),
+            result.eip_addresses.len(),
+            fetched,
+            result.total_count,
+        )?;
+        Some(Self {
+            page_number: Some(page_number),
+            ..self.clone()
+        })
+    }
+
+    /// Counts the items of the pages before the requested page number.
+    fn items_before(&self) -> usize {
+        paginator::items_before_page(self.page_number.unwrap_or_default(), self.page_size)
+    }
+}
@@ -0,0 +1,703 @@
+/*
+ * @Author: Jerry.Yang
+ * @Date: 2025-03-24 09:36:42
+ * @LastEditors: Jerry.Yang
+ * @LastEditTime: 2025-03-29 20:50:00
+ * @Description: security group models
+ */
+use crate::service::vpc::api_tag_model;
+use crate::volcengine::paginator::paginator;
+use crate::volcengine::request::response;
+use serde::{Deserialize, Serialize};
+
+/// The protocol matched by a security group rule.
+///
+/// Protocols the SDK does not know yet are kept as `Other`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+#[serde(from = "String", into = "String")]
+pub enum RuleProtocol {
+    /// `tcp`
+    Tcp,
+    /// `udp`
+    Udp,
+    /// `icmp`
+    Icmp,
+    /// `icmpv6`
+    Icmpv6,
+    /// `all`, every protocol
+    #[default]
+    All,
+    /// Any other protocol, verbatim
+    Other(String),
+}
+
+impl RuleProtocol {
+    /// Returns the protocol as sent to the API.
+    ///
+    /// # Returns
+    /// - `&str`: The protocol, e.g. `tcp`.
+    pub fn as_str(&self) -> &str {
+        match self {
+            RuleProtocol::Tcp => "tcp",
+            RuleProtocol::Udp => "udp",
+            RuleProtocol::Icmp => "icmp",
+            RuleProtocol::Icmpv6 => "icmpv6",
+            RuleProtocol::All => "all",
+            RuleProtocol::Other(protocol) => protocol,
+        }
+    }
+}
+
+impl From<String> for RuleProtocol {
+    fn from(protocol: String) -> Self {
+        match protocol.as_str() {
+            "tcp" => RuleProtocol::Tcp,
+            "udp" => RuleProtocol::Udp,
+            "icmp" => RuleProtocol::Icmp,
+            "icmpv6" => RuleProtocol::Icmpv6,
+            "all" => RuleProtocol::All,
+            _ => RuleProtocol::Other(protocol),
+        }
+    }
+}
+
+impl From<RuleProtocol> for String {
+    fn from(protocol: RuleProtocol) -> Self {
+        protocol.as_str().to_string()
+    }
+}
+
+/// The ports matched by a security group rule.
+///
+/// Sent as `PortStart` / `PortEnd`, `-1/-1` standing for every port (the only range allowed
+/// for `icmp`, `icmpv6` and `all`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PortRange {
+    /// Every port
+    #[default]
+    All,
+    /// One port, e.g. `22`
+    Single(u16),
+    /// The ports from the first to the second, both included
+    Range(u16, u16),
+}
+
+impl PortRange {
+    /// Returns the `PortStart` / `PortEnd` of the range.
+    ///
+    /// # Returns
+    /// - `(i32, i32)`: The first and last port, `(-1, -1)` for every port.
+    pub fn bounds(&self) -> (i32, i32) {
+        match *self {
+            PortRange::All => (-1, -1),
+            PortRange::Single(port) => (port as i32, port as i32),
+            PortRange::Range(start, end) => (start as i32, end as i32),
+        }
+    }
+
+    /// Reads a range from its `PortStart` / `PortEnd`.
+    ///
+    /// # Arguments
+    /// - `start`: The first port, `-1` for every port.
+    /// - `end`: The last port, `-1` for every port.
+    ///
+    /// # Returns
+    /// - `Ok(PortRange)`: The range.
+    /// - `Err(String)`: If the ports are out of range or reversed.
+    pub fn from_bounds(start: i32, end: i32) -> Result<PortRange, String> {
+        if start == -1 && end == -1 {
+            return Ok(PortRange::All);
+        }
+        let port = |port: i32| u16::try_from(port).map_err(|_| format!("invalid port {}", port));
+        let (start, end) = (port(start)?, port(end)?);
+        match start.cmp(&end) {
+            std::cmp::Ordering::Equal => Ok(PortRange::Single(start)),
+            std::cmp::Ordering::Less => Ok(PortRange::Range(start, end)),
+            std::cmp::Ordering::Greater => Err(format!("invalid port range {}/{}", start, end)),
+        }
+    }
+}
+
+/// The peer of a security group rule: the source of an ingress rule, the destination of an
+/// egress rule.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RuleSource {
+    /// An IPv4 or IPv6 CIDR block, sent as `CidrIp`
+    Cidr(String),
+    /// A prefix list, sent as `PrefixListId`
+    PrefixList(String),
+    /// The instances of a security group, sent as `SourceGroupId`
+    SecurityGroup(String),
+}
+
+impl Default for RuleSource {
+    fn default() -> Self {
+        RuleSource::Cidr("0.0.0.0/0".to_string())
+    }
+}
+
+/// The action of a security group rule.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum RulePolicy {
+    /// `accept`
+    #[default]
+    Accept,
+    /// `drop`
+    Drop,
+}
+
+/// The direction of a security group rule.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum RuleDirection {
+    /// `ingress`
+    Ingress,
+    /// `egress`
+    Egress,
+    /// Any other direction, which the SDK does not know yet
+    #[serde(other)]
+    Other,
+}
+
+/// A security group rule.
+///
+/// The rule is flattened into the request of the `Authorize*` and `Revoke*` APIs and read back
+/// from the `Permissions` of `DescribeSecurityGroupAttributes` with `SecurityGroupPermission::rule`.
+///
+/// # Fields
+/// - `protocol`: The protocol matched, `all` by default.
+/// - `port_range`: The ports matched, every port by default.
+/// - `source`: The source (ingress) or destination (egress) matched, `0.0.0.0/0` by default.
+/// - `policy`: Whether matching traffic is accepted or dropped, `accept` by default.
+/// - `priority`: The priority of the rule, from 1 (highest) to 100, 1 by default.
+/// - `description`: The description of the rule.
+///
+/// # Example
+/// ```rust
+/// use volcengine_rust_sdk::service::vpc::api_security_group_model::{
+///     PortRange, RuleProtocol, RuleSource, SecurityGroupRule,
+/// };
+///
+/// let ssh = SecurityGroupRule {
+///     protocol: RuleProtocol::Tcp,
+///     port_range: PortRange::Single(22),
+///     source: RuleSource::Cidr("10.0.0.0/8".to_string()),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+#[serde(try_from = "RawSecurityGroupRule", into = "RawSecurityGroupRule")]
+pub struct SecurityGroupRule {
+    pub protocol: RuleProtocol,      // The protocol matched.
+    pub port_range: PortRange,       // The ports matched.
+    pub source: RuleSource,          // The source or destination matched.
+    pub policy: RulePolicy,          // The action on matching traffic.
+    pub priority: Option<i32>,       // The priority of the rule.
+    pub description: Option<String>, // The description of the rule.
+}
+
+/// The fields of a security group rule as sent by the API.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+struct RawSecurityGroupRule {
+    #[serde(rename = "Protocol")]
+    protocol: String,
+    #[serde(rename = "PortStart")]
+    port_start: i32,
+    #[serde(rename = "PortEnd")]
+    port_end: i32,
+    #[serde(rename = "CidrIp", skip_serializing_if = "Option::is_none")]
+    cidr_ip: Option<String>,
+    #[serde(rename = "PrefixListId", skip_serializing_if = "Option::is_none")]
+    prefix_list_id: Option<String>,
+    #[serde(rename = "SourceGroupId", skip_serializing_if = "Option::is_none")]
+    source_group_id: Option<String>,
+    #[serde(rename = "Policy")]
+    policy: Option<RulePolicy>,
+    #[serde(rename = "Priority", skip_serializing_if = "Option::is_none")]
+    priority: Option<i32>,
+    #[serde(rename = "Description", skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+}
+
+impl From<SecurityGroupRule> for RawSecurityGroupRule {
+    fn from(rule: SecurityGroupRule) -> Self {
+        let (port_start, port_end) = rule.port_range.bounds();
+        let mut raw = RawSecurityGroupRule {
+            protocol: rule.protocol.into(),
+            port_start,
+            port_end,
+            policy: Some(rule.policy),
+            priority: rule.priority,
+            description: rule.description,
+            ..Default::default()
+        };
+        match rule.source {
+            RuleSource::Cidr(cidr) => raw.cidr_ip = Some(cidr),
+            RuleSource::PrefixList(prefix_list_id) => raw.prefix_list_id = Some(prefix_list_id),
+            RuleSource::SecurityGroup(group_id) => raw.source_group_id = Some(group_id),
+        }
+        raw
+    }
+}
+
+impl TryFrom<RawSecurityGroupRule> for SecurityGroupRule {
+    type Error = String;
+
+    fn try_from(raw: RawSecurityGroupRule) -> Result<Self, Self::Error> {
+        // The API returns the unset peers as empty strings.
+        let set = |value: Option<String>| value.filter(|value| !value.is_empty());
+        let source = match (
+            set(raw.cidr_ip),
+            set(raw.prefix_list_id),
+            set(raw.source_group_id),
+        ) {
+            (Some(cidr), None, None) => RuleSource::Cidr(cidr),
+            (None, Some(prefix_list_id), None) => RuleSource::PrefixList(prefix_list_id),
+            (None, None, Some(group_id)) => RuleSource::SecurityGroup(group_id),
+            _ => return Err("a rule needs one of CidrIp, PrefixListId or SourceGroupId".into()),
+        };
+
+        Ok(SecurityGroupRule {
+            protocol: raw.protocol.into(),
+            port_range: PortRange::from_bounds(raw.port_start, raw.port_end)?,
+            source,
+            policy: raw.policy.unwrap_or_default(),
+            priority: raw.priority,
+            description: set(raw.description),
+        })
+    }
+}
+
+/// Request of the `CreateSecurityGroup` API.
+///
+/// # Fields
+/// - `vpc_id`: The ID of the VPC of the security group.
+/// - `security_group_name`: The name of the security group.
+/// - `description`: The description of the security group.
+/// - `project_name`: The project of the security group.
+/// - `tags`: The tags of the security group.
+/// - `client_token`: A token making the request idempotent.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct CreateSecurityGroupReq {
+    /// The ID of the VPC
+    #[serde(rename = "VpcId")]
+    pub vpc_id: String,
+
+    /// The name of the security group
+    #[serde(rename = "SecurityGroupName", skip_serializing_if = "Option::is_none")]
+    pub security_group_name: Option<String>,
+
+    /// The description of the security group
+    #[serde(rename = "Description", skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// The project of the security group
+    #[serde(rename = "ProjectName", skip_serializing_if = "Option::is_none")]
+    pub project_name: Option<String>,
+
+    /// The tags of the security group
+    #[serde(rename = "Tags")]
+    pub tags: Vec<api_tag_model::Tag>,
+
+    /// The idempotency token
+    #[serde(rename = "ClientToken", skip_serializing_if = "Option::is_none")]
+    pub client_token: Option<String>,
+}
+
+/// Response of the `CreateSecurityGroup` API.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct CreateSecurityGroupResp {
+    /// Metadata of the response
+    #[serde(rename = "ResponseMetadata")]
+    pub response_metadata: response::ApiResponseMetadata,
+
+    /// The result of the call
+    #[serde(rename = "Result")]
+    pub result: Option<CreateSecurityGroupResult>,
+}
+
+/// Result of the `CreateSecurityGroup` API.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct CreateSecurityGroupResult {
+    /// The ID of the security group created
+    #[serde(rename = "SecurityGroupId")]
+    pub security_group_id: String,
+}
+
+/// Request of the `DescribeSecurityGroups` API.
+///
+/// # Fields
+/// - `vpc_id`: Lists the security groups of this VPC.
+/// - `security_group_ids`: Lists these security groups.
+/// - `security_group_names`: Lists the security groups with these names.
+/// - `project_name`: Lists the security groups of this project.
+/// - `page_number`: The page to list, from 1.
+/// - `page_size`: The number of security groups per page.
+/// - `max_results`: The number of security groups per page, when paging with `next_token`.
+/// - `next_token`: The token of the page to list.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DescribeSecurityGroupsReq {
+    /// The ID of the VPC
+    #[serde(rename = "VpcId", skip_serializing_if = "Option::is_none")]
+    pub vpc_id: Option<String>,
+
+    /// The IDs of the security groups
+    #[serde(rename = "SecurityGroupIds")]
+    pub security_group_ids: Vec<String>,
+
+    /// The names of the security groups
+    #[serde(rename = "SecurityGroupNames")]
+    pub security_group_names: Vec<String>,
+
+    /// The project of the security groups
+    #[serde(rename = "ProjectName", skip_serializing_if = "Option::is_none")]
+    pub project_name: Option<String>,
+
+    /// The page number
+    #[serde(rename = "PageNumber", skip_serializing_if = "Option::is_none")]
+    pub page_number: Option<i64>,
+
+    /// The page size
+    #[serde(rename = "PageSize", skip_serializing_if = "Option::is_none")]
+    pub page_size: Option<i64>,
+
+    /// The page size when paging with tokens
+    #[serde(rename = "MaxResults", skip_serializing_if = "Option::is_none")]
+    pub max_results: Option<i64>,
+
+    /// The token of the page
+    #[serde(rename = "NextToken", skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+}
+
+/// Response of the `DescribeSecurityGroups` API.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct DescribeSecurityGroupsResp {
+    /// Metadata of the response
+    #[serde(rename = "ResponseMetadata")]
+    pub response_metadata: response::ApiResponseMetadata,
+
+    /// The result of the call
+    #[serde(rename = "Result")]
+    pub result: Option<DescribeSecurityGroupsResult>,
+}
+
+/// Result of the `DescribeSecurityGroups` API.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct DescribeSecurityGroupsResult {
+    /// The security groups of the page
+    #[serde(rename = "SecurityGroups")]
+    pub security_groups: Vec<SecurityGroup>,
+
+    /// The number of security groups listed
+    #[serde(rename = "TotalCount")]
+    pub total_count: Option<i64>,
+
+    /// The page number
+    #[serde(rename = "PageNumber")]
+    pub page_number: Option<i64>,
+
+    /// The page size
+    #[serde(rename = "PageSize")]
+    pub page_size: Option<i64>,
+
+    /// The token of the next page, empty on the last page
+    #[serde(rename = "NextToken")]
+    pub next_token: Option<String>,
+}
+
+/// A security group listed by `DescribeSecurityGroups`.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct SecurityGroup {
+    /// The ID of the security group
+    #[serde(rename = "SecurityGroupId")]
+    pub security_group_id: String,
+
+    /// The name of the security group
+    #[serde(rename = "SecurityGroupName")]
+    pub security_group_name: String,
+
+    /// The ID of the VPC
+    #[serde(rename = "VpcId")]
+    pub vpc_id: String,
+
+    /// The description of the security group
+    #[serde(rename = "Description")]
+    pub description: String,
+
+    /// The status of the security group, e.g. `Available`
+    #[serde(rename = "Status")]
+    pub status: String,
+
+    /// The type of the security group, e.g. `default` or `normal`
+    #[serde(rename = "Type")]
+    pub r#type: String,
+
+    /// The project of the security group
+    #[serde(rename = "ProjectName")]
+    pub project_name: String,
+
+    /// The creation time of the security group
+    #[serde(rename = "CreationTime")]
+    pub creation_time: String,
+
+    /// The tags of the security group
+    #[serde(rename = "Tags")]
+    pub tags: Vec<api_tag_model::Tag>,
+}
+
+/// Request of the `DescribeSecurityGroupAttributes` API.
+///
+/// # Fields
+/// - `security_group_id`: The ID of the security group.
+/// - `direction`: Lists only the rules of this direction.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DescribeSecurityGroupAttributesReq {
+    /// The ID of the security group
+    #[serde(rename = "SecurityGroupId")]
+    pub security_group_id: String,
+
+    /// The direction of the rules
+    #[serde(rename = "Direction", skip_serializing_if = "Option::is_none")]
+    pub direction: Option<RuleDirection>,
+}
+
+/// Response of the `DescribeSecurityGroupAttributes` API.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct DescribeSecurityGroupAttributesResp {
+    /// Metadata of the response
+    #[serde(rename = "ResponseMetadata")]
+    pub response_metadata: response::ApiResponseMetadata,
+
+    /// The result of the call
+    #[serde(rename = "Result")]
+    pub result: Option<DescribeSecurityGroupAttributesResult>,
+}
+
+/// Result of the `DescribeSecurityGroupAttributes` API.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct DescribeSecurityGroupAttributesResult {
+    /// The ID of the security group
+    #[serde(rename = "SecurityGroupId")]
+    pub security_group_id: String,
+
+    /// The name of the security group
+    #[serde(rename = "SecurityGroupName")]
+    pub security_group_name: String,
+
+    /// The ID of the VPC
+    #[serde(rename = "VpcId")]
+    pub vpc_id: String,
+
+    /// The description of the security group
+    #[serde(rename = "Description")]
+    pub description: String,
+
+    /// The status of the security group
+    #[serde(rename = "Status")]
+    pub status: String,
+
+    /// The rules of the security group
+    #[serde(rename = "Permissions")]
+    pub permissions: Vec<SecurityGroupPermission>,
+}
+
+/// A rule of a security group, as listed by `DescribeSecurityGroupAttributes`.
+///
+/// The fields are kept as sent by the API, so that a rule the SDK cannot model (e.g. an
+/// unknown policy or a peer it does not know) does not fail the whole response; `rule`
+/// reads them into a `SecurityGroupRule`.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct SecurityGroupPermission {
+    /// The direction of the rule
+    #[serde(rename = "Direction")]
+    pub direction: Option<RuleDirection>,
+
+    /// The protocol of the rule
+    #[serde(rename = "Protocol")]
+    pub protocol: String,
+
+    /// The first port of the rule, -1 for every port
+    #[serde(rename = "PortStart")]
+    pub port_start: i32,
+
+    /// The last port of the rule, -1 for every port
+    #[serde(rename = "PortEnd")]
+    pub port_end: i32,
+
+    /// The CIDR block of the peer, empty if the peer is not a CIDR block
+    #[serde(rename = "CidrIp")]
+    pub cidr_ip: String,
+
+    /// The prefix list of the peer, empty if the peer is not a prefix list
+    #[serde(rename = "PrefixListId")]
+    pub prefix_list_id: String,
+
+    /// The security group of the peer, empty if the peer is not a security group
+    #[serde(rename = "SourceGroupId")]
+    pub source_group_id: String,
+
+    /// The policy of the rule, `accept` or `drop`
+    #[serde(rename = "Policy")]
+    pub policy: String,
+
+    /// The priority of the rule
+    #[serde(rename = "Priority")]
+    pub priority: Option<i32>,
+
+    /// The description of the rule
+    #[serde(rename = "Description")]
+    pub description: String,
+
+    /// The creation time of the rule
+    #[serde(rename = "CreationTime")]
+    pub creation_time: String,
+
+    /// The last update time of the rule
+    #[serde(rename = "UpdateTime")]
+    pub update_time: String,
+}
+
+impl SecurityGroupPermission {
+    /// Reads the rule into the typed model.
+    ///
+    /// # Returns
+    /// - `Ok(SecurityGroupRule)`: The rule.
+    /// - `Err(String)`: If the ports, the policy or the peer of the rule cannot be modeled.
+    pub fn rule(&self) -> Result<SecurityGroupRule, String> {
+        let policy = match self.policy.as_str() {
+            "" | "accept" => RulePolicy::Accept,
+            "drop" => RulePolicy::Drop,
+            policy => return Err(format!("unknown policy {}", policy)),
+        };
+        SecurityGroupRule::try_from(RawSecurityGroupRule {
+            protocol: self.protocol.clone(),
+            port_start: self.port_start,
+            port_end: self.port_end,
+            cidr_ip: Some(self.cidr_ip.clone()),
+            prefix_list_id: Some(self.prefix_list_id.clone()),
+            source_group_id: Some(self.source_group_id.clone()),
+            policy: Some(policy),
+            priority: self.priority,
+            description: Some(self.description.clone()),
+        })
+    }
+}
+
+/// Request of the `AuthorizeSecurityGroupIngress` and `AuthorizeSecurityGroupEgress` APIs.
+///
+/// # Fields
+/// - `security_group_id`: The ID of the security group.
+/// - `rule`: The rule to add, its source being the destination of an egress rule.
+/// - `client_token`: A token making the request idempotent.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct AuthorizeSecurityGroupReq {
+    /// The ID of the security group
+    #[serde(rename = "SecurityGroupId")]
+    pub security_group_id: String,
+
+    /// The rule to add
+    #[serde(flatten)]
+    pub rule: SecurityGroupRule,
+
+    /// The idempotency token
+    #[serde(rename = "ClientToken", skip_serializing_if = "Option::is_none")]
+    pub client_token: Option<String>,
+}
+
+/// Request of the `RevokeSecurityGroupIngress` and `RevokeSecurityGroupEgress` APIs.
+///
+/// The rule is removed if the protocol, ports, source, policy and priority match.
+///
+/// # Fields
+/// - `security_group_id`: The ID of the security group.
+/// - `rule`: The rule to remove.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct RevokeSecurityGroupReq {
+    /// The ID of the security group
+    #[serde(rename = "SecurityGroupId")]
+    pub security_group_id: String,
+
+    /// The rule to remove
+    #[serde(flatten)]
+    pub rule: SecurityGroupRule,
+}
+
+/// Request of the `DeleteSecurityGroup` API.
+///
+/// # Fields
+/// - `security_group_id`: The ID of the security group, which no instance may use.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DeleteSecurityGroupReq {
+    /// The ID of the security group
+    #[serde(rename = "SecurityGroupId")]
+    pub security_group_id: String,
+}
+
+/// Response of the security group APIs returning no result (`Authorize*`, `Revoke*` and
+/// `DeleteSecurityGroup`).
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct SecurityGroupOperationResp {
+    /// Metadata of the response
+    #[serde(rename = "ResponseMetadata")]
+    pub response_metadata: response::ApiResponseMetadata,
+}
+
+/// Implementation of the `Paginated` trait for the `DescribeSecurityGroupsReq` structure.
+/// Security groups are paged with `NextToken` when `MaxResults` or `NextToken` is set,
+/// and with `PageNumber` / `PageSize` otherwise.
+impl paginator::Paginated for DescribeSecurityGroupsReq {
+    type Response = DescribeSecurityGroupsResp;
+    type Item = SecurityGroup;
+
+    /// Returns the security groups of a page.
+    fn items(response: &Self::Response) -> &[Self::Item] {
+        response
+            .result
+            .as_ref()
+            .map_or(&[], |result| result.security_groups.as_slice())
+    }
+
+    /// Requests the page following the `NextToken` or the page number of the response.
+    fn next_request(&self, response: &Self::Response, fetched: usize) -> Option<Self> {
+        let result = response.result.as_ref()?;
+
+        // Token-based pagination, chosen by the caller through `MaxResults` / `NextToken`.
+        if self.max_results.is_some() || self.next_token.is_some() {
+            return paginator::next_token_request(
+                self,
+                self.next_token.as_ref(),
+                result.next_token.as_ref(),
+                |request, token| request.next_token = Some(token),
+            );
+        }
+
+        // Page-number pagination.
+        let page_number = paginator::next_page_number(
+            self.page_number.unwrap_or_default(),
+            result.security_groups.len(),
+            fetched,
+            result.total_count,
+        )?;
+        Some(Self {
+            page_number: Some(page_number),
+            ..self.clone()
+        })
+    }
+
+    /// Counts the items of the pages before the requested page number.
+    fn items_before(&self) -> usize {
+        paginator::items_before_page(self.page_number.unwrap_or_default(), self.page_size)
+    }
+}
@@ -0,0 +1,23 @@
+/*
+ * @Author: Jerry.Yang
+ * @Date: 2025-03-29 14:08:26
+ * @LastEditors: Jerry.Yang
+ * @LastEditTime: 2025-03-29 14:08:26
+ * @Description: tag model shared by the VPC resources
+ */
+use serde::{Deserialize, Serialize};
+
+/// A tag of a VPC resource: a VPC, a subnet, a security group or an EIP.
+///
+/// Sent as `Tags.N.Key` / `Tags.N.Value` and read back from the `Tags` of the describe APIs.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+#[serde(default)]
+pub struct Tag {
+    /// The key of the tag
+    #[serde(rename = "Key")]
+    pub key: String,
+
+    /// The value of the tag
+    #[serde(rename = "Value")]
+    pub value: String,
+}
@@ -0,0 +1,270 @@
+/*
+ * @Author: Jerry.Yang
+ * @Date: 2025-03-25 10:12:05
+ * @LastEditors: Jerry.Yang
+ * @LastEditTime: 2025-03-29 14:08:26
+ * @Description: vpc and subnet write models
+ */
+use crate::service::vpc::api_tag_model;
+use crate::volcengine::request::response;
+use serde::{Deserialize, Serialize};
+
+/// Request of the `CreateVpc` API.
+///
+/// # Fields
+/// - `cidr_block`: The IPv4 CIDR block of the VPC, e.g. `172.16.0.0/16`.
+/// - `vpc_name`: The name of the VPC.
+/// - `description`: The description of the VPC.
+/// - `dns_servers`: The DNS servers of the VPC, at most 5.
+/// - `enable_ipv6`: Whether the VPC gets an IPv6 CIDR block.
+/// - `project_name`: The project of the VPC.
+/// - `tags`: The tags of the VPC.
+/// - `client_token`: A token making the request idempotent.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct CreateVpcReq {
+    /// The CIDR block of the VPC
+    #[serde(rename = "CidrBlock")]
+    pub cidr_block: String,
+
+    /// The name of the VPC
+    #[serde(rename = "VpcName", skip_serializing_if = "Option::is_none")]
+    pub vpc_name: Option<String>,
+
+    /// The description of the VPC
+    #[serde(rename = "Description", skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// The DNS servers of the VPC
+    #[serde(rename = "DnsServers")]
+    pub dns_servers: Vec<String>,
+
+    /// Whether the VPC gets an IPv6 CIDR block
+    #[serde(rename = "EnableIpv6", skip_serializing_if = "Option::is_none")]
+    pub enable_ipv6: Option<bool>,
+
+    /// The project of the VPC
+    #[serde(rename = "ProjectName", skip_serializing_if = "Option::is_none")]
+    pub project_name: Option<String>,
+
+    /// The tags of the VPC
+    #[serde(rename = "Tags")]
+    pub tags: Vec<api_tag_model::Tag>,
+
+    /// The idempotency token
+    #[serde(rename = "ClientToken", skip_serializing_if = "Option::is_none")]
+    pub client_token: Option<String>,
+}
+
+/// Response of the `CreateVpc` API.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct CreateVpcResp {
+    /// Metadata of the response
+    #[serde(rename = "ResponseMetadata")]
+    pub response_metadata: response::ApiResponseMetadata,
+
+    /// The result of the call
+    #[serde(rename = "Result")]
+    pub result: Option<CreateVpcResult>,
+}
+
+/// Result of the `CreateVpc` API.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct CreateVpcResult {
+    /// The ID of the VPC created
+    #[serde(rename = "VpcId")]
+    pub vpc_id: String,
+
+    /// The ID of the system route table of the VPC
+    #[serde(rename = "RouteTableId")]
+    pub route_table_id: String,
+}
+
+/// Request of the `ModifyVpcAttributes` API.
+///
+/// Only the attributes that are set are modified.
+///
+/// # Fields
+/// - `vpc_id`: The ID of the VPC.
+/// - `vpc_name`: The new name of the VPC.
+/// - `description`: The new description of the VPC.
+/// - `dns_servers`: The new DNS servers of the VPC, left unchanged when empty.
+/// - `enable_ipv6`: Whether the VPC gets an IPv6 CIDR block.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ModifyVpcAttributesReq {
+    /// The ID of the VPC
+    #[serde(rename = "VpcId")]
+    pub vpc_id: String,
+
+    /// The name of the VPC
+    #[serde(rename = "VpcName", skip_serializing_if = "Option::is_none")]
+    pub vpc_name: Option<String>,
+
+    /// The description of the VPC
+    #[serde(rename = "Description", skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// The DNS servers of the VPC
+    #[serde(rename = "DnsServers")]
+    pub dns_servers: Vec<String>,
+
+    /// Whether the VPC gets an IPv6 CIDR block
+    #[serde(rename = "EnableIpv6", skip_serializing_if = "Option::is_none")]
+    pub enable_ipv6: Option<bool>,
+}
+
+/// Request of the `DeleteVpc` API.
+///
+/// # Fields
+/// - `vpc_id`: The ID of the VPC, which must hold no subnet or other resource.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DeleteVpcReq {
+    /// The ID of the VPC
+    #[serde(rename = "VpcId")]
+    pub vpc_id: String,
+}
+
+/// Request of the `AssociateCidrBlock` API.
+///
+/// # Fields
+/// - `vpc_id`: The ID of the VPC.
+/// - `secondary_cidr_block`: The secondary IPv4 CIDR block to add to the VPC.
+/// - `ipv6_cidr_block`: The IPv6 CIDR block to add to the VPC.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct AssociateCidrBlockReq {
+    /// The ID of the VPC
+    #[serde(rename = "VpcId")]
+    pub vpc_id: String,
+
+    /// The secondary IPv4 CIDR block
+    #[serde(rename = "SecondaryCidrBlock", skip_serializing_if = "Option::is_none")]
+    pub secondary_cidr_block: Option<String>,
+
+    /// The IPv6 CIDR block
+    #[serde(rename = "Ipv6CidrBlock", skip_serializing_if = "Option::is_none")]
+    pub ipv6_cidr_block: Option<String>,
+}
+
+/// Request of the `CreateSubnet` API.
+///
+/// # Fields
+/// - `vpc_id`: The ID of the VPC of the subnet.
+/// - `zone_id`: The availability zone of the subnet, e.g. `cn-beijing-a`.
+/// - `cidr_block`: The IPv4 CIDR block of the subnet, within the one of the VPC.
+/// - `subnet_name`: The name of the subnet.
+/// - `description`: The description of the subnet.
+/// - `ipv6_cidr_block`: The last 8 bits of the IPv6 CIDR block of the subnet (0 to 255), for a
+///   VPC with IPv6 enabled.
+/// - `tags`: The tags of the subnet.
+/// - `client_token`: A token making the request idempotent.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct CreateSubnetReq {
+    /// The ID of the VPC
+    #[serde(rename = "VpcId")]
+    pub vpc_id: String,
+
+    /// The availability zone of the subnet
+    #[serde(rename = "ZoneId")]
+    pub zone_id: String,
+
+    /// The CIDR block of the subnet
+    #[serde(rename = "CidrBlock")]
+    pub cidr_block: String,
+
+    /// The name of the subnet
+    #[serde(rename = "SubnetName", skip_serializing_if = "Option::is_none")]
+    pub subnet_name: Option<String>,
+
+    /// The description of the subnet
+    #[serde(rename = "Description", skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// The IPv6 CIDR block of the subnet
+    #[serde(rename = "Ipv6CidrBlock", skip_serializing_if = "Option::is_none")]
+    pub ipv6_cidr_block: Option<i32>,
+
+    /// The tags of the subnet
+    #[serde(rename = "Tags")]
+    pub tags: Vec<api_tag_model::Tag>,
+
+    /// The idempotency token
+    #[serde(rename = "ClientToken", skip_serializing_if = "Option::is_none")]
+    pub client_token: Option<String>,
+}
+
+/// Response of the `CreateSubnet` API.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct CreateSubnetResp {
+    /// Metadata of the response
+    #[serde(rename = "ResponseMetadata")]
+    pub response_metadata: response::ApiResponseMetadata,
+
+    /// The result of the call
+    #[serde(rename = "Result")]
+    pub result: Option<CreateSubnetResult>,
+}
+
+/// Result of the `CreateSubnet` API.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct CreateSubnetResult {
+    /// The ID of the subnet created
+    #[serde(rename = "SubnetId")]
+    pub subnet_id: String,
+}
+
+/// Request of the `ModifySubnetAttributes` API.
+///
+/// Only the attributes that are set are modified.
+///
+/// # Fields
+/// - `subnet_id`: The ID of the subnet.
+/// - `subnet_name`: The new name of the subnet.
+/// - `description`: The new description of the subnet.
+/// - `enable_ipv6`: Whether the subnet gets an IPv6 CIDR block.
+/// - `ipv6_cidr_block`: The last 8 bits of the IPv6 CIDR block of the subnet (0 to 255).
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ModifySubnetAttributesReq {
+    /// The ID of the subnet
+    #[serde(rename = "SubnetId")]
+    pub subnet_id: String,
+
+    /// The name of the subnet
+    #[serde(rename = "SubnetName", skip_serializing_if = "Option::is_none")]
+    pub subnet_name: Option<String>,
+
+    /// The description of the subnet
+    #[serde(rename = "Description", skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Whether the subnet gets an IPv6 CIDR block
+    #[serde(rename = "EnableIpv6", skip_serializing_if = "Option::is_none")]
+    pub enable_ipv6: Option<bool>,
+
+    /// The IPv6 CIDR block of the subnet
+    #[serde(rename = "Ipv6CidrBlock", skip_serializing_if = "Option::is_none")]
+    pub ipv6_cidr_block: Option<i32>,
+}
+
+/// Request of the `DeleteSubnet` API.
+///
+/// # Fields
+/// - `subnet_id`: The ID of the subnet, which no instance may use.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DeleteSubnetReq {
+    /// The ID of the subnet
+    #[serde(rename = "SubnetId")]
+    pub subnet_id: String,
+}
+
+/// Response of the VPC and subnet APIs returning no result (`ModifyVpcAttributes`,
+/// `DeleteVpc`, `AssociateCidrBlock`, `ModifySubnetAttributes` and `DeleteSubnet`).
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct VpcOperationResp {
+    /// Metadata of the response
+    #[serde(rename = "ResponseMetadata")]
+    pub response_metadata: response::ApiResponseMetadata,
+}
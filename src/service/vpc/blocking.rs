@@ -0,0 +1,349 @@
+/*
+ * @Author: Jerry.Yang
+ * @Date: 2025-03-18 11:03:52
+ * @LastEditors: Jerry.Yang
+ * @LastEditTime: 2025-03-30 11:10:00
+ * @Description: Blocking facade for VPC (Virtual Private Cloud) operations
+ */
+use crate::service::vpc;
+use crate::service::vpc::api_eip_model;
+use crate::service::vpc::api_security_group_model;
+use crate::service::vpc::api_vpc_subnet_model;
+use crate::service::vpc::VpcService;
+use crate::volcengine::blocking::blocking;
+use crate::volcengine::error::error;
+use crate::volcengine::session::session;
+use volcengine_sdk_protobuf::protobuf::vpc_subnet;
+use volcengine_sdk_protobuf::protobuf::vpc_vpc;
+
+/// A synchronous facade over `vpc::Vpc`.
+///
+/// Exposes the operations of `VpcService` as blocking methods, driven by the runtime shared by
+/// the blocking facades. Meant for build scripts and command line tools; asynchronous code
+/// should use `vpc::Vpc` directly.
+///
+/// # Example
+/// ```rust,no_run
+/// # use volcengine_rust_sdk::volcengine::error::error;
+/// # use volcengine_rust_sdk::volcengine::session::session;
+/// use volcengine_rust_sdk::service::vpc;
+///
+/// # fn example(session: session::Session) -> Result<(), error::Error> {
+/// let vpc = vpc::blocking::Vpc::new_vpc(session)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Vpc {
+    vpc: vpc::Vpc,              // The asynchronous service.
+    runtime: blocking::Runtime, // The runtime driving its requests.
+}
+
+impl Vpc {
+    /// Creates a new blocking `Vpc` using the provided session.
+    ///
+    /// # Arguments
+    /// - `session`: A `Session` object containing authentication and configuration information.
+    ///
+    /// # Returns
+    /// - `Ok(Vpc)`: The blocking service.
+    /// - `Err(error::Error)`: If the service or the runtime could not be created.
+    pub fn new_vpc(session: session::Session) -> Result<Vpc, error::Error> {
+        Ok(Vpc {
+            vpc: vpc::Vpc::new_vpc(session)?,
+            runtime: blocking::Runtime::shared()?,
+        })
+    }
+
+    /// Describes VPCs.
+    ///
+    /// Blocks until `VpcService::new_describe_vpcs` completes.
+    pub fn new_describe_vpcs(
+        &self,
+        request: vpc_vpc::DescribeVpcsReq,
+    ) -> Result<vpc_vpc::DescribeVpcsResp, error::Error> {
+        self.runtime.block_on(self.vpc.new_describe_vpcs(request))
+    }
+
+    /// Lists VPCs page by page.
+    ///
+    /// The pages are fetched synchronously as the iterators of the paginator are advanced.
+    pub fn describe_vpcs_paginator(
+        &self,
+        request: vpc_vpc::DescribeVpcsReq,
+    ) -> blocking::Paginator<vpc_vpc::DescribeVpcsReq> {
+        self.runtime
+            .paginator(self.vpc.describe_vpcs_paginator(request))
+    }
+
+    /// Describes VPC subnets.
+    ///
+    /// Blocks until `VpcService::new_describe_subnets` completes.
+    pub fn new_describe_subnets(
+        &self,
+        request: vpc_subnet::DescribeSubnetsReq,
+    ) -> Result<vpc_subnet::DescribeSubnetsResp, error::Error> {
+        self.runtime
+            .block_on(self.vpc.new_describe_subnets(request))
+    }
+
+    /// Lists subnets page by page.
+    ///
+    /// The pages are fetched synchronously as the iterators of the paginator are advanced.
+    pub fn describe_subnets_paginator(
+        &self,
+        request: vpc_subnet::DescribeSubnetsReq,
+    ) -> blocking::Paginator<vpc_subnet::DescribeSubnetsReq> {
+        self.runtime
+            .paginator(self.vpc.describe_subnets_paginator(request))
+    }
+
+    /// Creates a security group in a VPC.
+    ///
+    /// Blocks until `VpcService::new_create_security_group` completes.
+    pub fn new_create_security_group(
+        &self,
+        request: api_security_group_model::CreateSecurityGroupReq,
+    ) -> Result<api_security_group_model::CreateSecurityGroupResp, error::Error> {
+        self.runtime
+            .block_on(self.vpc.new_create_security_group(request))
+    }
+
+    /// Describes security groups.
+    ///
+    /// Blocks until `VpcService::new_describe_security_groups` completes.
+    pub fn new_describe_security_groups(
+        &self,
+        request: api_security_group_model::DescribeSecurityGroupsReq,
+    ) -> Result<api_security_group_model::DescribeSecurityGroupsResp, error::Error> {
+        self.runtime
+            .block_on(self.vpc.new_describe_security_groups(request))
+    }
+
+    /// Describes a security group and its rules.
+    ///
+    /// Blocks until `VpcService::new_describe_security_group_attributes` completes.
+    pub fn new_describe_security_group_attributes(
+        &self,
+        request: api_security_group_model::DescribeSecurityGroupAttributesReq,
+    ) -> Result<api_security_group_model::DescribeSecurityGroupAttributesResp, error::Error> {
+        self.runtime
+            .block_on(self.vpc.new_describe_security_group_attributes(request))
+    }
+
+    /// Adds an ingress rule to a security group.
+    ///
+    /// Blocks until `VpcService::new_authorize_security_group_ingress` completes.
+    pub fn new_authorize_security_group_ingress(
+        &self,
+        request: api_security_group_model::AuthorizeSecurityGroupReq,
+    ) -> Result<api_security_group_model::SecurityGroupOperationResp, error::Error> {
+        self.runtime
+            .block_on(self.vpc.new_authorize_security_group_ingress(request))
+    }
+
+    /// Adds an egress rule to a security group.
+    ///
+    /// Blocks until `VpcService::new_authorize_security_group_egress` completes.
+    pub fn new_authorize_security_group_egress(
+        &self,
+        request: api_security_group_model::AuthorizeSecurityGroupReq,
+    ) -> Result<api_security_group_model::SecurityGroupOperationResp, error::Error> {
+        self.runtime
+            .block_on(self.vpc.new_authorize_security_group_egress(request))
+    }
+
+    /// Removes an ingress rule from a security group.
+    ///
+    /// Blocks until `VpcService::new_revoke_security_group_ingress` completes.
+    pub fn new_revoke_security_group_ingress(
+        &self,
+        request: api_security_group_model::RevokeSecurityGroupReq,
+    ) -> Result<api_security_group_model::SecurityGroupOperationResp, error::Error> {
+        self.runtime
+            .block_on(self.vpc.new_revoke_security_group_ingress(request))
+    }
+
+    /// Removes an egress rule from a security group.
+    ///
+    /// Blocks until `VpcService::new_revoke_security_group_egress` completes.
+    pub fn new_revoke_security_group_egress(
+        &self,
+        request: api_security_group_model::RevokeSecurityGroupReq,
+    ) -> Result<api_security_group_model::SecurityGroupOperationResp, error::Error> {
+        self.runtime
+            .block_on(self.vpc.new_revoke_security_group_egress(request))
+    }
+
+    /// Deletes a security group.
+    ///
+    /// Blocks until `VpcService::new_delete_security_group` completes.
+    pub fn new_delete_security_group(
+        &self,
+        request: api_security_group_model::DeleteSecurityGroupReq,
+    ) -> Result<api_security_group_model::SecurityGroupOperationResp, error::Error> {
+        self.runtime
+            .block_on(self.vpc.new_delete_security_group(request))
+    }
+
+    /// Lists security groups page by page.
+    ///
+    /// The pages are fetched synchronously as the iterators of the paginator are advanced.
+    pub fn describe_security_groups_paginator(
+        &self,
+        request: api_security_group_model::DescribeSecurityGroupsReq,
+    ) -> blocking::Paginator<api_security_group_model::DescribeSecurityGroupsReq> {
+        self.runtime
+            .paginator(self.vpc.describe_security_groups_paginator(request))
+    }
+
+    /// Creates a VPC.
+    ///
+    /// Blocks until `VpcService::new_create_vpc` completes.
+    pub fn new_create_vpc(
+        &self,
+        request: api_vpc_subnet_model::CreateVpcReq,
+    ) -> Result<api_vpc_subnet_model::CreateVpcResp, error::Error> {
+        self.runtime.block_on(self.vpc.new_create_vpc(request))
+    }
+
+    /// Modifies the name, description or DNS servers of a VPC.
+    ///
+    /// Blocks until `VpcService::new_modify_vpc_attributes` completes.
+    pub fn new_modify_vpc_attributes(
+        &self,
+        request: api_vpc_subnet_model::ModifyVpcAttributesReq,
+    ) -> Result<api_vpc_subnet_model::VpcOperationResp, error::Error> {
+        self.runtime
+            .block_on(self.vpc.new_modify_vpc_attributes(request))
+    }
+
+    /// Deletes a VPC.
+    ///
+    /// Blocks until `VpcService::new_delete_vpc` completes.
+    pub fn new_delete_vpc(
+        &self,
+        request: api_vpc_subnet_model::DeleteVpcReq,
+    ) -> Result<api_vpc_subnet_model::VpcOperationResp, error::Error> {
+        self.runtime.block_on(self.vpc.new_delete_vpc(request))
+    }
+
+    /// Adds a secondary CIDR block to a VPC.
+    ///
+    /// Blocks until `VpcService::new_associate_cidr_block` completes.
+    pub fn new_associate_cidr_block(
+        &self,
+        request: api_vpc_subnet_model::AssociateCidrBlockReq,
+    ) -> Result<api_vpc_subnet_model::VpcOperationResp, error::Error> {
+        self.runtime
+            .block_on(self.vpc.new_associate_cidr_block(request))
+    }
+
+    /// Creates a subnet in a VPC.
+    ///
+    /// Blocks until `VpcService::new_create_subnet` completes.
+    pub fn new_create_subnet(
+        &self,
+        request: api_vpc_subnet_model::CreateSubnetReq,
+    ) -> Result<api_vpc_subnet_model::CreateSubnetResp, error::Error> {
+        self.runtime.block_on(self.vpc.new_create_subnet(request))
+    }
+
+    /// Modifies the name or description of a subnet.
+    ///
+    /// Blocks until `VpcService::new_modify_subnet_attributes` completes.
+    pub fn new_modify_subnet_attributes(
+        &self,
+        request: api_vpc_subnet_model::ModifySubnetAttributesReq,
+    ) -> Result<api_vpc_subnet_model::VpcOperationResp, error::Error> {
+        self.runtime
+            .block_on(self.vpc.new_modify_subnet_attributes(request))
+    }
+
+    /// Deletes a subnet.
+    ///
+    /// Blocks until `VpcService::new_delete_subnet` completes.
+    pub fn new_delete_subnet(
+        &self,
+        request: api_vpc_subnet_model::DeleteSubnetReq,
+    ) -> Result<api_vpc_subnet_model::VpcOperationResp, error::Error> {
+        self.runtime.block_on(self.vpc.new_delete_subnet(request))
+    }
+
+    /// Allocates an elastic IP address.
+    ///
+    /// Blocks until `VpcService::new_allocate_eip_address` completes.
+    pub fn new_allocate_eip_address(
+        &self,
+        request: api_eip_model::AllocateEipAddressReq,
+    ) -> Result<api_eip_model::AllocateEipAddressResp, error::Error> {
+        self.runtime
+            .block_on(self.vpc.new_allocate_eip_address(request))
+    }
+
+    /// Describes elastic IP addresses.
+    ///
+    /// Blocks until `VpcService::new_describe_eip_addresses` completes.
+    pub fn new_describe_eip_addresses(
+        &self,
+        request: api_eip_model::DescribeEipAddressesReq,
+    ) -> Result<api_eip_model::DescribeEipAddressesResp, error::Error> {
+        self.runtime
+            .block_on(self.vpc.new_describe_eip_addresses(request))
+    }
+
+    /// Associates an elastic IP address with an ECS instance, a CLB instance, a NAT gateway or a network interface.
+    ///
+    /// Blocks until `VpcService::new_associate_eip_address` completes.
+    pub fn new_associate_eip_address(
+        &self,
+        request: api_eip_model::EipAssociationReq,
+    ) -> Result<api_eip_model::EipOperationResp, error::Error> {
+        self.runtime
+            .block_on(self.vpc.new_associate_eip_address(request))
+    }
+
+    /// Disassociates an elastic IP address from the resource it is associated with.
+    ///
+    /// Blocks until `VpcService::new_disassociate_eip_address` completes.
+    pub fn new_disassociate_eip_address(
+        &self,
+        request: api_eip_model::EipAssociationReq,
+    ) -> Result<api_eip_model::EipOperationResp, error::Error> {
+        self.runtime
+            .block_on(self.vpc.new_disassociate_eip_address(request))
+    }
+
+    /// Modifies the name, the description or the bandwidth of an elastic IP address.
+    ///
+    /// Blocks until `VpcService::new_modify_eip_address_attributes` completes.
+    pub fn new_modify_eip_address_attributes(
+        &self,
+        request: api_eip_model::ModifyEipAddressAttributesReq,
+    ) -> Result<api_eip_model::EipOperationResp, error::Error> {
+        self.runtime
+            .block_on(self.vpc.new_modify_eip_address_attributes(request))
+    }
+
+    /// Releases an elastic IP address.
+    ///
+    /// Blocks until `VpcService::new_release_eip_address` completes.
+    pub fn new_release_eip_address(
+        &self,
+        request: api_eip_model::ReleaseEipAddressReq,
+    ) -> Result<api_eip_model::EipOperationResp, error::Error> {
+        self.runtime
+            .block_on(self.vpc.new_release_eip_address(request))
+    }
+
+    /// Lists EIPs page by page.
+    ///
+    /// The pages are fetched synchronously as the iterators of the paginator are advanced.
+    pub fn describe_eip_addresses_paginator(
+        &self,
+        request: api_eip_model::DescribeEipAddressesReq,
+    ) -> blocking::Paginator<api_eip_model::DescribeEipAddressesReq> {
+        self.runtime
+            .paginator(self.vpc.describe_eip_addresses_paginator(request))
+    }
+}
@@ -2,22 +2,28 @@
  * @Author: Jerry.Yang
  * @Date: 2024-10-29 17:23:24
  * @LastEditors: Jerry.Yang
- * @LastEditTime: 2025-02-06 14:37:16
+ * @LastEditTime: 2025-03-29 14:08:26
  * @Description: Module for managing VPCs and subnets using the Volcengine SDK.
  */
 use crate::volcengine::client::client;
 use crate::volcengine::error::error;
+use crate::volcengine::paginator::paginator;
 use crate::volcengine::session::session;
 use std::future::Future;
 use volcengine_sdk_protobuf::protobuf::vpc_subnet;
 use volcengine_sdk_protobuf::protobuf::vpc_vpc;
 
 // Import modules for various VPC operations
-mod api_describe_subnets;
 mod api_describe_subnets_model;
-mod api_describe_vpcs;
 mod api_describe_vpcs_model;
+pub mod api_eip_model;
+pub mod api_security_group_model;
+pub mod api_tag_model;
+pub mod api_vpc_subnet_model;
 pub mod service_vpc;
+
+// Blocking facade exposing the service operations synchronously.
+pub mod blocking;
 mod tests;
 
 /// Defines the VpcService trait, providing methods for various VPC operations.
@@ -47,6 +53,20 @@ pub trait VpcService {
         request: vpc_vpc::DescribeVpcsReq,
     ) -> impl Future<Output = Result<vpc_vpc::DescribeVpcsResp, error::Error>>;
 
+    /// Lists VPCs page by page.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current service instance.
+    /// - `request`: The request of the first page; set `MaxResults` to page with `NextToken`, or `PageSize` to page by number.
+    ///
+    /// # Returns
+    /// - `paginator::Paginator<vpc_vpc::DescribeVpcsReq>`: A paginator turned into a stream over the pages with `pages()`
+    ///   or over the VPCs with `items()`.
+    fn describe_vpcs_paginator(
+        &self,
+        request: vpc_vpc::DescribeVpcsReq,
+    ) -> paginator::Paginator<vpc_vpc::DescribeVpcsReq>;
+
     /// Describes VPC subnets.
     ///
     /// # Arguments
@@ -60,6 +80,347 @@ pub trait VpcService {
         &self,
         request: vpc_subnet::DescribeSubnetsReq,
     ) -> impl Future<Output = Result<vpc_subnet::DescribeSubnetsResp, error::Error>>;
+
+    /// Lists subnets page by page.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current service instance.
+    /// - `request`: The request of the first page; set `MaxResults` to page with `NextToken`, or `PageSize` to page by number.
+    ///
+    /// # Returns
+    /// - `paginator::Paginator<vpc_subnet::DescribeSubnetsReq>`: A paginator turned into a stream over the pages with `pages()`
+    ///   or over the subnets with `items()`.
+    fn describe_subnets_paginator(
+        &self,
+        request: vpc_subnet::DescribeSubnetsReq,
+    ) -> paginator::Paginator<vpc_subnet::DescribeSubnetsReq>;
+
+    /// Creates a security group in a VPC.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current VPC service instance.
+    /// - `request`: The request structure containing the VPC and the name of the security group.
+    ///
+    /// # Returns
+    /// - `impl Future<Output = Result<api_security_group_model::CreateSecurityGroupResp, error::Error>>`: On success, returns a future that resolves to the response from the VPC service.
+    ///   On failure, returns an error indicating the cause of the failure.
+    fn new_create_security_group(
+        &self,
+        request: api_security_group_model::CreateSecurityGroupReq,
+    ) -> impl Future<Output = Result<api_security_group_model::CreateSecurityGroupResp, error::Error>>;
+
+    /// Describes security groups.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current VPC service instance.
+    /// - `request`: The request structure containing the parameters for describing security groups.
+    ///
+    /// # Returns
+    /// - `impl Future<Output = Result<api_security_group_model::DescribeSecurityGroupsResp, error::Error>>`: On success, returns a future that resolves to the response from the VPC service.
+    ///   On failure, returns an error indicating the cause of the failure.
+    fn new_describe_security_groups(
+        &self,
+        request: api_security_group_model::DescribeSecurityGroupsReq,
+    ) -> impl Future<Output = Result<api_security_group_model::DescribeSecurityGroupsResp, error::Error>>;
+
+    /// Describes a security group and its rules.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current VPC service instance.
+    /// - `request`: The request structure containing the ID of the security group.
+    ///
+    /// # Returns
+    /// - `impl Future<Output = Result<api_security_group_model::DescribeSecurityGroupAttributesResp, error::Error>>`: On success, returns a future that resolves to the response from the VPC service.
+    ///   On failure, returns an error indicating the cause of the failure.
+    fn new_describe_security_group_attributes(
+        &self,
+        request: api_security_group_model::DescribeSecurityGroupAttributesReq,
+    ) -> impl Future<
+        Output = Result<
+            api_security_group_model::DescribeSecurityGroupAttributesResp,
+            error::Error,
+        >,
+    >;
+
+    /// Adds an ingress rule to a security group.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current VPC service instance.
+    /// - `request`: The request structure containing the security group and the rule, whose source is matched against the incoming traffic.
+    ///
+    /// # Returns
+    /// - `impl Future<Output = Result<api_security_group_model::SecurityGroupOperationResp, error::Error>>`: On success, returns a future that resolves to the response from the VPC service.
+    ///   On failure, returns an error indicating the cause of the failure.
+    fn new_authorize_security_group_ingress(
+        &self,
+        request: api_security_group_model::AuthorizeSecurityGroupReq,
+    ) -> impl Future<Output = Result<api_security_group_model::SecurityGroupOperationResp, error::Error>>;
+
+    /// Adds an egress rule to a security group.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current VPC service instance.
+    /// - `request`: The request structure containing the security group and the rule, whose source is matched against the destination of the outgoing traffic.
+    ///
+    /// # Returns
+    /// - `impl Future<Output = Result<api_security_group_model::SecurityGroupOperationResp, error::Error>>`: On success, returns a future that resolves to the response from the VPC service.
+    ///   On failure, returns an error indicating the cause of the failure.
+    fn new_authorize_security_group_egress(
+        &self,
+        request: api_security_group_model::AuthorizeSecurityGroupReq,
+    ) -> impl Future<Output = Result<api_security_group_model::SecurityGroupOperationResp, error::Error>>;
+
+    /// Removes an ingress rule from a security group.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current VPC service instance.
+    /// - `request`: The request structure containing the security group and the rule to remove.
+    ///
+    /// # Returns
+    /// - `impl Future<Output = Result<api_security_group_model::SecurityGroupOperationResp, error::Error>>`: On success, returns a future that resolves to the response from the VPC service.
+    ///   On failure, returns an error indicating the cause of the failure.
+    fn new_revoke_security_group_ingress(
+        &self,
+        request: api_security_group_model::RevokeSecurityGroupReq,
+    ) -> impl Future<Output = Result<api_security_group_model::SecurityGroupOperationResp, error::Error>>;
+
+    /// Removes an egress rule from a security group.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current VPC service instance.
+    /// - `request`: The request structure containing the security group and the rule to remove.
+    ///
+    /// # Returns
+    /// - `impl Future<Output = Result<api_security_group_model::SecurityGroupOperationResp, error::Error>>`: On success, returns a future that resolves to the response from the VPC service.
+    ///   On failure, returns an error indicating the cause of the failure.
+    fn new_revoke_security_group_egress(
+        &self,
+        request: api_security_group_model::RevokeSecurityGroupReq,
+    ) -> impl Future<Output = Result<api_security_group_model::SecurityGroupOperationResp, error::Error>>;
+
+    /// Deletes a security group.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current VPC service instance.
+    /// - `request`: The request structure containing the ID of the security group.
+    ///
+    /// # Returns
+    /// - `impl Future<Output = Result<api_security_group_model::SecurityGroupOperationResp, error::Error>>`: On success, returns a future that resolves to the response from the VPC service.
+    ///   On failure, returns an error indicating the cause of the failure.
+    fn new_delete_security_group(
+        &self,
+        request: api_security_group_model::DeleteSecurityGroupReq,
+    ) -> impl Future<Output = Result<api_security_group_model::SecurityGroupOperationResp, error::Error>>;
+
+    /// Lists security groups page by page.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current service instance.
+    /// - `request`: The request of the first page; set `MaxResults` to page with `NextToken`, or `PageSize` to page by number.
+    ///
+    /// # Returns
+    /// - `paginator::Paginator<api_security_group_model::DescribeSecurityGroupsReq>`: A paginator turned into a stream over the pages with `pages()`
+    ///   or over the security groups with `items()`.
+    fn describe_security_groups_paginator(
+        &self,
+        request: api_security_group_model::DescribeSecurityGroupsReq,
+    ) -> paginator::Paginator<api_security_group_model::DescribeSecurityGroupsReq>;
+
+    /// Creates a VPC.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current VPC service instance.
+    /// - `request`: The request structure containing the CIDR block and the attributes of the VPC.
+    ///
+    /// # Returns
+    /// - `impl Future<Output = Result<api_vpc_subnet_model::CreateVpcResp, error::Error>>`: On success, returns a future that resolves to the response from the VPC service.
+    ///   On failure, returns an error indicating the cause of the failure.
+    fn new_create_vpc(
+        &self,
+        request: api_vpc_subnet_model::CreateVpcReq,
+    ) -> impl Future<Output = Result<api_vpc_subnet_model::CreateVpcResp, error::Error>>;
+
+    /// Modifies the name, description or DNS servers of a VPC.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current VPC service instance.
+    /// - `request`: The request structure containing the VPC and the attributes to modify.
+    ///
+    /// # Returns
+    /// - `impl Future<Output = Result<api_vpc_subnet_model::VpcOperationResp, error::Error>>`: On success, returns a future that resolves to the response from the VPC service.
+    ///   On failure, returns an error indicating the cause of the failure.
+    fn new_modify_vpc_attributes(
+        &self,
+        request: api_vpc_subnet_model::ModifyVpcAttributesReq,
+    ) -> impl Future<Output = Result<api_vpc_subnet_model::VpcOperationResp, error::Error>>;
+
+    /// Deletes a VPC.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current VPC service instance.
+    /// - `request`: The request structure containing the ID of the VPC.
+    ///
+    /// # Returns
+    /// - `impl Future<Output = Result<api_vpc_subnet_model::VpcOperationResp, error::Error>>`: On success, returns a future that resolves to the response from the VPC service.
+    ///   On failure, returns an error indicating the cause of the failure.
+    fn new_delete_vpc(
+        &self,
+        request: api_vpc_subnet_model::DeleteVpcReq,
+    ) -> impl Future<Output = Result<api_vpc_subnet_model::VpcOperationResp, error::Error>>;
+
+    /// Adds a secondary CIDR block to a VPC.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current VPC service instance.
+    /// - `request`: The request structure containing the VPC and the CIDR block to add.
+    ///
+    /// # Returns
+    /// - `impl Future<Output = Result<api_vpc_subnet_model::VpcOperationResp, error::Error>>`: On success, returns a future that resolves to the response from the VPC service.
+    ///   On failure, returns an error indicating the cause of the failure.
+    fn new_associate_cidr_block(
+        &self,
+        request: api_vpc_subnet_model::AssociateCidrBlockReq,
+    ) -> impl Future<Output = Result<api_vpc_subnet_model::VpcOperationResp, error::Error>>;
+
+    /// Creates a subnet in a VPC.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current VPC service instance.
+    /// - `request`: The request structure containing the VPC, the zone and the CIDR block of the subnet.
+    ///
+    /// # Returns
+    /// - `impl Future<Output = Result<api_vpc_subnet_model::CreateSubnetResp, error::Error>>`: On success, returns a future that resolves to the response from the VPC service.
+    ///   On failure, returns an error indicating the cause of the failure.
+    fn new_create_subnet(
+        &self,
+        request: api_vpc_subnet_model::CreateSubnetReq,
+    ) -> impl Future<Output = Result<api_vpc_subnet_model::CreateSubnetResp, error::Error>>;
+
+    /// Modifies the name or description of a subnet.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current VPC service instance.
+    /// - `request`: The request structure containing the subnet and the attributes to modify.
+    ///
+    /// # Returns
+    /// - `impl Future<Output = Result<api_vpc_subnet_model::VpcOperationResp, error::Error>>`: On success, returns a future that resolves to the response from the VPC service.
+    ///   On failure, returns an error indicating the cause of the failure.
+    fn new_modify_subnet_attributes(
+        &self,
+        request: api_vpc_subnet_model::ModifySubnetAttributesReq,
+    ) -> impl Future<Output = Result<api_vpc_subnet_model::VpcOperationResp, error::Error>>;
+
+    /// Deletes a subnet.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current VPC service instance.
+    /// - `request`: The request structure containing the ID of the subnet.
+    ///
+    /// # Returns
+    /// - `impl Future<Output = Result<api_vpc_subnet_model::VpcOperationResp, error::Error>>`: On success, returns a future that resolves to the response from the VPC service.
+    ///   On failure, returns an error indicating the cause of the failure.
+    fn new_delete_subnet(
+        &self,
+        request: api_vpc_subnet_model::DeleteSubnetReq,
+    ) -> impl Future<Output = Result<api_vpc_subnet_model::VpcOperationResp, error::Error>>;
+
+    /// Allocates an elastic IP address.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current VPC service instance.
+    /// - `request`: The request structure containing the billing method and the bandwidth of the EIP.
+    ///
+    /// # Returns
+    /// - `impl Future<Output = Result<api_eip_model::AllocateEipAddressResp, error::Error>>`: On success, returns a future that resolves to the response from the VPC service.
+    ///   On failure, returns an error indicating the cause of the failure.
+    fn new_allocate_eip_address(
+        &self,
+        request: api_eip_model::AllocateEipAddressReq,
+    ) -> impl Future<Output = Result<api_eip_model::AllocateEipAddressResp, error::Error>>;
+
+    /// Describes elastic IP addresses.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current VPC service instance.
+    /// - `request`: The request structure containing the parameters for describing EIPs.
+    ///
+    /// # Returns
+    /// - `impl Future<Output = Result<api_eip_model::DescribeEipAddressesResp, error::Error>>`: On success, returns a future that resolves to the response from the VPC service.
+    ///   On failure, returns an error indicating the cause of the failure.
+    fn new_describe_eip_addresses(
+        &self,
+        request: api_eip_model::DescribeEipAddressesReq,
+    ) -> impl Future<Output = Result<api_eip_model::DescribeEipAddressesResp, error::Error>>;
+
+    /// Associates an elastic IP address with an ECS instance, a CLB instance, a NAT gateway or a network interface.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current VPC service instance.
+    /// - `request`: The request structure containing the ID of the EIP and the resource to associate it with.
+    ///
+    /// # Returns
+    /// - `impl Future<Output = Result<api_eip_model::EipOperationResp, error::Error>>`: On success, returns a future that resolves to the response from the VPC service.
+    ///   On failure, returns an error indicating the cause of the failure.
+    fn new_associate_eip_address(
+        &self,
+        request: api_eip_model::EipAssociationReq,
+    ) -> impl Future<Output = Result<api_eip_model::EipOperationResp, error::Error>>;
+
+    /// Disassociates an elastic IP address from the resource it is associated with.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current VPC service instance.
+    /// - `request`: The request structure containing the ID of the EIP and the resource it is associated with.
+    ///
+    /// # Returns
+    /// - `impl Future<Output = Result<api_eip_model::EipOperationResp, error::Error>>`: On success, returns a future that resolves to the response from the VPC service.
+    ///   On failure, returns an error indicating the cause of the failure.
+    fn new_disassociate_eip_address(
+        &self,
+        request: api_eip_model::EipAssociationReq,
+    ) -> impl Future<Output = Result<api_eip_model::EipOperationResp, error::Error>>;
+
+    /// Modifies the name, the description or the bandwidth of an elastic IP address.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current VPC service instance.
+    /// - `request`: The request structure containing the ID of the EIP and the attributes to modify.
+    ///
+    /// # Returns
+    /// - `impl Future<Output = Result<api_eip_model::EipOperationResp, error::Error>>`: On success, returns a future that resolves to the response from the VPC service.
+    ///   On failure, returns an error indicating the cause of the failure.
+    fn new_modify_eip_address_attributes(
+        &self,
+        request: api_eip_model::ModifyEipAddressAttributesReq,
+    ) -> impl Future<Output = Result<api_eip_model::EipOperationResp, error::Error>>;
+
+    /// Releases an elastic IP address.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current VPC service instance.
+    /// - `request`: The request structure containing the ID of the EIP.
+    ///
+    /// # Returns
+    /// - `impl Future<Output = Result<api_eip_model::EipOperationResp, error::Error>>`: On success, returns a future that resolves to the response from the VPC service.
+    ///   On failure, returns an error indicating the cause of the failure.
+    fn new_release_eip_address(
+        &self,
+        request: api_eip_model::ReleaseEipAddressReq,
+    ) -> impl Future<Output = Result<api_eip_model::EipOperationResp, error::Error>>;
+
+    /// Lists EIPs page by page.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current service instance.
+    /// - `request`: The request of the first page; set `MaxResults` to page with `NextToken`, or `PageSize` to page by number.
+    ///
+    /// # Returns
+    /// - `paginator::Paginator<api_eip_model::DescribeEipAddressesReq>`: A paginator turned into a stream over the pages with `pages()`
+    ///   or over the EIPs with `items()`.
+    fn describe_eip_addresses_paginator(
+        &self,
+        request: api_eip_model::DescribeEipAddressesReq,
+    ) -> paginator::Paginator<api_eip_model::DescribeEipAddressesReq>;
 }
 
 /// Represents the VPC service, encapsulating the client information required to interact with the Volcengine VPC service.
@@ -2,19 +2,23 @@
  * @Author: Jerry.Yang
  * @Date: 2024-10-29 17:29:44
  * @LastEditors: Jerry.Yang
- * @LastEditTime: 2025-02-06 14:38:16
+ * @LastEditTime: 2025-03-28 10:14:26
  * @Description: Implementation of the VPC service, providing methods for managing VPCs and subnets.
  */
 use super::{Vpc, VpcService};
-use crate::service::vpc::api_describe_subnets;
-use crate::service::vpc::api_describe_vpcs;
+use crate::service::vpc::api_eip_model;
+use crate::service::vpc::api_security_group_model;
+use crate::service::vpc::api_vpc_subnet_model;
 use crate::volcengine::client::client;
 use crate::volcengine::client::client_info;
 use crate::volcengine::client::config as client_config;
 use crate::volcengine::common;
 use crate::volcengine::error::error;
-use crate::volcengine::request::handles;
+use crate::volcengine::paginator::paginator;
+use crate::volcengine::registry::registry;
 use crate::volcengine::session::session;
+use volcengine_sdk_protobuf::protobuf::vpc_subnet;
+use volcengine_sdk_protobuf::protobuf::vpc_vpc;
 
 /// Implementation of the VpcService trait for the Vpc struct.
 /// This implementation provides the necessary logic to interact with the Volcengine VPC service,
@@ -30,7 +34,7 @@ impl VpcService for Vpc {
     ///   On failure, returns an error indicating the cause of the failure.
     fn new_vpc(session: session::Session) -> Result<Self, error::Error> {
         // Create a new client configuration for the VPC service.
-        let client_config = session.new_client_config(client_config::ClientServiceName::Vpc);
+        let client_config = session.new_client_config(client_config::ClientServiceName::Vpc)?;
 
         // Build the client information with the required parameters.
         let client_info = client_info::ClientInfo::builder()
@@ -39,8 +43,8 @@ impl VpcService for Vpc {
             .with_signing_region(&client_config.signing_region)
             .build()?;
 
-        // Initialize the request handles.
-        let request_handles = handles::Handles {};
+        // Use the request handles registered on the session
+        let request_handles = client_config.handles.clone();
 
         // Build the client with the provided information.
         let client = client::Client::builder()
@@ -66,9 +70,26 @@ impl VpcService for Vpc {
         &self,
         request: volcengine_sdk_protobuf::protobuf::vpc_vpc::DescribeVpcsReq,
     ) -> Result<volcengine_sdk_protobuf::protobuf::vpc_vpc::DescribeVpcsResp, error::Error> {
-        api_describe_vpcs::ApiDescribeVpcsVpc
-            .new_describe_vpcs(self, request)
-            .await
+        registry::invoke(&self.client, &registry::VPC_DESCRIBE_VPCS, &request).await
+    }
+
+    /// Lists VPCs page by page.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current service instance.
+    /// - `request`: The request of the first page.
+    ///
+    /// # Returns
+    /// - `paginator::Paginator<vpc_vpc::DescribeVpcsReq>`: A paginator sending `new_describe_vpcs` once per page.
+    fn describe_vpcs_paginator(
+        &self,
+        request: vpc_vpc::DescribeVpcsReq,
+    ) -> paginator::Paginator<vpc_vpc::DescribeVpcsReq> {
+        let vpc = self.clone();
+        paginator::Paginator::new(request, move |request| {
+            let vpc = vpc.clone();
+            Box::pin(async move { vpc.new_describe_vpcs(request).await })
+        })
     }
 
     /// Describes VPC subnets.
@@ -85,8 +106,491 @@ impl VpcService for Vpc {
         request: volcengine_sdk_protobuf::protobuf::vpc_subnet::DescribeSubnetsReq,
     ) -> Result<volcengine_sdk_protobuf::protobuf::vpc_subnet::DescribeSubnetsResp, error::Error>
     {
-        api_describe_subnets::ApiDescribeSubnetsVpc
-            .new_describe_subnets(self, request)
-            .await
+        registry::invoke(&self.client, &registry::VPC_DESCRIBE_SUBNETS, &request).await
+    }
+
+    /// Lists subnets page by page.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current service instance.
+    /// - `request`: The request of the first page.
+    ///
+    /// # Returns
+    /// - `paginator::Paginator<vpc_subnet::DescribeSubnetsReq>`: A paginator sending `new_describe_subnets` once per page.
+    fn describe_subnets_paginator(
+        &self,
+        request: vpc_subnet::DescribeSubnetsReq,
+    ) -> paginator::Paginator<vpc_subnet::DescribeSubnetsReq> {
+        let vpc = self.clone();
+        paginator::Paginator::new(request, move |request| {
+            let vpc = vpc.clone();
+            Box::pin(async move { vpc.new_describe_subnets(request).await })
+        })
+    }
+
+    /// Creates a security group in a VPC.
+    ///
+    /// The operation is declared in the registry and sent with `registry::invoke`.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current VPC service instance.
+    /// - `request`: The request structure containing the VPC and the name of the security group.
+    ///
+    /// # Returns
+    /// - `Result<api_security_group_model::CreateSecurityGroupResp, error::Error>`: On success, returns the response from the VPC service.
+    ///   On failure, returns an error indicating the cause of the failure.
+    async fn new_create_security_group(
+        &self,
+        request: api_security_group_model::CreateSecurityGroupReq,
+    ) -> Result<api_security_group_model::CreateSecurityGroupResp, error::Error> {
+        registry::invoke(&self.client, &registry::VPC_CREATE_SECURITY_GROUP, &request).await
+    }
+
+    /// Describes security groups.
+    ///
+    /// The operation is declared in the registry and sent with `registry::invoke`.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current VPC service instance.
+    /// - `request`: The request structure containing the parameters for describing security groups.
+    ///
+    /// # Returns
+    /// - `Result<api_security_group_model::DescribeSecurityGroupsResp, error::Error>`: On success, returns the response from the VPC service.
+    ///   On failure, returns an error indicating the cause of the failure.
+    async fn new_describe_security_groups(
+        &self,
+        request: api_security_group_model::DescribeSecurityGroupsReq,
+    ) -> Result<api_security_group_model::DescribeSecurityGroupsResp, error::Error> {
+        registry::invoke(
+            &self.client,
+            &registry::VPC_DESCRIBE_SECURITY_GROUPS,
+            &request,
+        )
+        .await
+    }
+
+    /// Describes a security group and its rules.
+    ///
+    /// The operation is declared in the registry and sent with `registry::invoke`.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current VPC service instance.
+    /// - `request`: The request structure containing the ID of the security group.
+    ///
+    /// # Returns
+    /// - `Result<api_security_group_model::DescribeSecurityGroupAttributesResp, error::Error>`: On success, returns the response from the VPC service.
+    ///   On failure, returns an error indicating the cause of the failure.
+    async fn new_describe_security_group_attributes(
+        &self,
+        request: api_security_group_model::DescribeSecurityGroupAttributesReq,
+    ) -> Result<api_security_group_model::DescribeSecurityGroupAttributesResp, error::Error> {
+        registry::invoke(
+            &self.client,
+            &registry::VPC_DESCRIBE_SECURITY_GROUP_ATTRIBUTES,
+            &request,
+        )
+        .await
+    }
+
+    /// Adds an ingress rule to a security group.
+    ///
+    /// The operation is declared in the registry and sent with `registry::invoke`.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current VPC service instance.
+    /// - `request`: The request structure containing the security group and the rule, whose source is matched against the incoming traffic.
+    ///
+    /// # Returns
+    /// - `Result<api_security_group_model::SecurityGroupOperationResp, error::Error>`: On success, returns the response from the VPC service.
+    ///   On failure, returns an error indicating the cause of the failure.
+    async fn new_authorize_security_group_ingress(
+        &self,
+        request: api_security_group_model::AuthorizeSecurityGroupReq,
+    ) -> Result<api_security_group_model::SecurityGroupOperationResp, error::Error> {
+        registry::invoke(
+            &self.client,
+            &registry::VPC_AUTHORIZE_SECURITY_GROUP_INGRESS,
+            &request,
+        )
+        .await
+    }
+
+    /// Adds an egress rule to a security group.
+    ///
+    /// The operation is declared in the registry and sent with `registry::invoke`.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current VPC service instance.
+    /// - `request`: The request structure containing the security group and the rule, whose source is matched against the destination of the outgoing traffic.
+    ///
+    /// # Returns
+    /// - `Result<api_security_group_model::SecurityGroupOperationResp, error::Error>`: On success, returns the response from the VPC service.
+    ///   On failure, returns an error indicating the cause of the failure.
+    async fn new_authorize_security_group_egress(
+        &self,
+        request: api_security_group_model::AuthorizeSecurityGroupReq,
+    ) -> Result<api_security_group_model::SecurityGroupOperationResp, error::Error> {
+        registry::invoke(
+            &self.client,
+            &registry::VPC_AUTHORIZE_SECURITY_GROUP_EGRESS,
+            &request,
+        )
+        .await
+    }
+
+    /// Removes an ingress rule from a security group.
+    ///
+    /// The operation is declared in the registry and sent with `registry::invoke`.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current VPC service instance.
+    /// - `request`: The request structure containing the security group and the rule to remove.
+    ///
+    /// # Returns
+    /// - `Result<api_security_group_model::SecurityGroupOperationResp, error::Error>`: On success, returns the response from the VPC service.
+    ///   On failure, returns an error indicating the cause of the failure.
+    async fn new_revoke_security_group_ingress(
+        &self,
+        request: api_security_group_model::RevokeSecurityGroupReq,
+    ) -> Result<api_security_group_model::SecurityGroupOperationResp, error::Error> {
+        registry::invoke(
+            &self.client,
+            &registry::VPC_REVOKE_SECURITY_GROUP_INGRESS,
+            &request,
+        )
+        .await
+    }
+
+    /// Removes an egress rule from a security group.
+    ///
+    /// The operation is declared in the registry and sent with `registry::invoke`.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current VPC service instance.
+    /// - `request`: The request structure containing the security group and the rule to remove.
+    ///
+    /// # Returns
+    /// - `Result<api_security_group_model::SecurityGroupOperationResp, error::Error>`: On success, returns the response from the VPC service.
+    ///   On failure, returns an error indicating the cause of the failure.
+    async fn new_revoke_security_group_egress(
+        &self,
+        request: api_security_group_model::RevokeSecurityGroupReq,
+    ) -> Result<api_security_group_model::SecurityGroupOperationResp, error::Error> {
+        registry::invoke(
+            &self.client,
+            &registry::VPC_REVOKE_SECURITY_GROUP_EGRESS,
+            &request,
+        )
+        .await
+    }
+
+    /// Deletes a security group.
+    ///
+    /// The operation is declared in the registry and sent with `registry::invoke`.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current VPC service instance.
+    /// - `request`: The request structure containing the ID of the security group.
+    ///
+    /// # Returns
+    /// - `Result<api_security_group_model::SecurityGroupOperationResp, error::Error>`: On success, returns the response from the VPC service.
+    ///   On failure, returns an error indicating the cause of the failure.
+    async fn new_delete_security_group(
+        &self,
+        request: api_security_group_model::DeleteSecurityGroupReq,
+    ) -> Result<api_security_group_model::SecurityGroupOperationResp, error::Error> {
+        registry::invoke(&self.client, &registry::VPC_DELETE_SECURITY_GROUP, &request).await
+    }
+
+    /// Lists security groups page by page.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current service instance.
+    /// - `request`: The request of the first page.
+    ///
+    /// # Returns
+    /// - `paginator::Paginator<api_security_group_model::DescribeSecurityGroupsReq>`: A paginator sending `new_describe_security_groups` once per page.
+    fn describe_security_groups_paginator(
+        &self,
+        request: api_security_group_model::DescribeSecurityGroupsReq,
+    ) -> paginator::Paginator<api_security_group_model::DescribeSecurityGroupsReq> {
+        let vpc = self.clone();
+        paginator::Paginator::new(request, move |request| {
+            let vpc = vpc.clone();
+            Box::pin(async move { vpc.new_describe_security_groups(request).await })
+        })
+    }
+
+    /// Creates a VPC.
+    ///
+    /// The operation is declared in the registry and sent with `registry::invoke`.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current VPC service instance.
+    /// - `request`: The request structure containing the CIDR block and the attributes of the VPC.
+    ///
+    /// # Returns
+    /// - `Result<api_vpc_subnet_model::CreateVpcResp, error::Error>`: On success, returns the response from the VPC service.
+    ///   On failure, returns an error indicating the cause of the failure.
+    async fn new_create_vpc(
+        &self,
+        request: api_vpc_subnet_model::CreateVpcReq,
+    ) -> Result<api_vpc_subnet_model::CreateVpcResp, error::Error> {
+        registry::invoke(&self.client, &registry::VPC_CREATE_VPC, &request).await
+    }
+
+    /// Modifies the name, description or DNS servers of a VPC.
+    ///
+    /// The operation is declared in the registry and sent with `registry::invoke`.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current VPC service instance.
+    /// - `request`: The request structure containing the VPC and the attributes to modify.
+    ///
+    /// # Returns
+    /// - `Result<api_vpc_subnet_model::VpcOperationResp, error::Error>`: On success, returns the response from the VPC service.
+    ///   On failure, returns an error indicating the cause of the failure.
+    async fn new_modify_vpc_attributes(
+        &self,
+        request: api_vpc_subnet_model::ModifyVpcAttributesReq,
+    ) -> Result<api_vpc_subnet_model::VpcOperationResp, error::Error> {
+        registry::invoke(&self.client, &registry::VPC_MODIFY_VPC_ATTRIBUTES, &request).await
+    }
+
+    /// Deletes a VPC.
+    ///
+    /// The operation is declared in the registry and sent with `registry::invoke`.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current VPC service instance.
+    /// - `request`: The request structure containing the ID of the VPC.
+    ///
+    /// # Returns
+    /// - `Result<api_vpc_subnet_model::VpcOperationResp, error::Error>`: On success, returns the response from the VPC service.
+    ///   On failure, returns an error indicating the cause of the failure.
+    async fn new_delete_vpc(
+        &self,
+        request: api_vpc_subnet_model::DeleteVpcReq,
+    ) -> Result<api_vpc_subnet_model::VpcOperationResp, error::Error> {
+        registry::invoke(&self.client, &registry::VPC_DELETE_VPC, &request).await
+    }
+
+    /// Adds a secondary CIDR block to a VPC.
+    ///
+    /// The operation is declared in the registry and sent with `registry::invoke`.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current VPC service instance.
+    /// - `request`: The request structure containing the VPC and the CIDR block to add.
+    ///
+    /// # Returns
+    /// - `Result<api_vpc_subnet_model::VpcOperationResp, error::Error>`: On success, returns the response from the VPC service.
+    ///   On failure, returns an error indicating the cause of the failure.
+    async fn new_associate_cidr_block(
+        &self,
+        request: api_vpc_subnet_model::AssociateCidrBlockReq,
+    ) -> Result<api_vpc_subnet_model::VpcOperationResp, error::Error> {
+        registry::invoke(&self.client, &registry::VPC_ASSOCIATE_CIDR_BLOCK, &request).await
+    }
+
+    /// Creates a subnet in a VPC.
+    ///
+    /// The operation is declared in the registry and sent with `registry::invoke`.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current VPC service instance.
+    /// - `request`: The request structure containing the VPC, the zone and the CIDR block of the subnet.
+    ///
+    /// # Returns
+    /// - `Result<api_vpc_subnet_model::CreateSubnetResp, error::Error>`: On success, returns the response from the VPC service.
+    ///   On failure, returns an error indicating the cause of the failure.
+    async fn new_create_subnet(
+        &self,
+        request: api_vpc_subnet_model::CreateSubnetReq,
+    ) -> Result<api_vpc_subnet_model::CreateSubnetResp, error::Error> {
+        registry::invoke(&self.client, &registry::VPC_CREATE_SUBNET, &request).await
+    }
+
+    /// Modifies the name or description of a subnet.
+    ///
+    /// The operation is declared in the registry and sent with `registry::invoke`.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current VPC service instance.
+    /// - `request`: The request structure containing the subnet and the attributes to modify.
+    ///
+    /// # Returns
+    /// - `Result<api_vpc_subnet_model::VpcOperationResp, error::Error>`: On success, returns the response from the VPC service.
+    ///   On failure, returns an error indicating the cause of the failure.
+    async fn new_modify_subnet_attributes(
+        &self,
+        request: api_vpc_subnet_model::ModifySubnetAttributesReq,
+    ) -> Result<api_vpc_subnet_model::VpcOperationResp, error::Error> {
+        registry::invoke(
+            &self.client,
+            &registry::VPC_MODIFY_SUBNET_ATTRIBUTES,
+            &request,
+        )
+        .await
+    }
+
+    /// Deletes a subnet.
+    ///
+    /// The operation is declared in the registry and sent with `registry::invoke`.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current VPC service instance.
+    /// - `request`: The request structure containing the ID of the subnet.
+    ///
+    /// # Returns
+    /// - `Result<api_vpc_subnet_model::VpcOperationResp, error::Error>`: On success, returns the response from the VPC service.
+    ///   On failure, returns an error indicating the cause of the failure.
+    async fn new_delete_subnet(
+        &self,
+        request: api_vpc_subnet_model::DeleteSubnetReq,
+    ) -> Result<api_vpc_subnet_model::VpcOperationResp, error::Error> {
+        registry::invoke(&self.client, &registry::VPC_DELETE_SUBNET, &request).await
+    }
+
+    /// Allocates an elastic IP address.
+    ///
+    /// The operation is declared in the registry and sent with `registry::invoke`.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current VPC service instance.
+    /// - `request`: The request structure containing the billing method and the bandwidth of the EIP.
+    ///
+    /// # Returns
+    /// - `Result<api_eip_model::AllocateEipAddressResp, error::Error>`: On success, returns the response from the VPC service.
+    ///   On failure, returns an error indicating the cause of the failure.
+    async fn new_allocate_eip_address(
+        &self,
+        request: api_eip_model::AllocateEipAddressReq,
+    ) -> Result<api_eip_model::AllocateEipAddressResp, error::Error> {
+        registry::invoke(&self.client, &registry::VPC_ALLOCATE_EIP_ADDRESS, &request).await
+    }
+
+    /// Describes elastic IP addresses.
+    ///
+    /// The operation is declared in the registry and sent with `registry::invoke`.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current VPC service instance.
+    /// - `request`: The request structure containing the parameters for describing EIPs.
+    ///
+    /// # Returns
+    /// - `Result<api_eip_model::DescribeEipAddressesResp, error::Error>`: On success, returns the response from the VPC service.
+    ///   On failure, returns an error indicating the cause of the failure.
+    async fn new_describe_eip_addresses(
+        &self,
+        request: api_eip_model::DescribeEipAddressesReq,
+    ) -> Result<api_eip_model::DescribeEipAddressesResp, error::Error> {
+        registry::invoke(
+            &self.client,
+            &registry::VPC_DESCRIBE_EIP_ADDRESSES,
+            &request,
+        )
+        .await
+    }
+
+    /// Associates an elastic IP address with an ECS instance, a CLB instance, a NAT gateway or a network interface.
+    ///
+    /// The operation is declared in the registry and sent with `registry::invoke`.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current VPC service instance.
+    /// - `request`: The request structure containing the ID of the EIP and the resource to associate it with.
+    ///
+    /// # Returns
+    /// - `Result<api_eip_model::EipOperationResp, error::Error>`: On success, returns the response from the VPC service.
+    ///   On failure, returns an error indicating the cause of the failure.
+    async fn new_associate_eip_address(
+        &self,
+        request: api_eip_model::EipAssociationReq,
+    ) -> Result<api_eip_model::EipOperationResp, error::Error> {
+        registry::invoke(&self.client, &registry::VPC_ASSOCIATE_EIP_ADDRESS, &request).await
+    }
+
+    /// Disassociates an elastic IP address from the resource it is associated with.
+    ///
+    /// The operation is declared in the registry and sent with `registry::invoke`.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current VPC service instance.
+    /// - `request`: The request structure containing the ID of the EIP and the resource it is associated with.
+    ///
+    /// # Returns
+    /// - `Result<api_eip_model::EipOperationResp, error::Error>`: On success, returns the response from the VPC service.
+    ///   On failure, returns an error indicating the cause of the failure.
+    async fn new_disassociate_eip_address(
+        &self,
+        request: api_eip_model::EipAssociationReq,
+    ) -> Result<api_eip_model::EipOperationResp, error::Error> {
+        registry::invoke(
+            &self.client,
+            &registry::VPC_DISASSOCIATE_EIP_ADDRESS,
+            &request,
+        )
+        .await
+    }
+
+    /// Modifies the name, the description or the bandwidth of an elastic IP address.
+    ///
+    /// The operation is declared in the registry and sent with `registry::invoke`.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current VPC service instance.
+    /// - `request`: The request structure containing the ID of the EIP and the attributes to modify.
+    ///
+    /// # Returns
+    /// - `Result<api_eip_model::EipOperationResp, error::Error>`: On success, returns the response from the VPC service.
+    ///   On failure, returns an error indicating the cause of the failure.
+    async fn new_modify_eip_address_attributes(
+        &self,
+        request: api_eip_model::ModifyEipAddressAttributesReq,
+    ) -> Result<api_eip_model::EipOperationResp, error::Error> {
+        registry::invoke(
+            &self.client,
+            &registry::VPC_MODIFY_EIP_ADDRESS_ATTRIBUTES,
+            &request,
+        )
+        .await
+    }
+
+    /// Releases an elastic IP address.
+    ///
+    /// The operation is declared in the registry and sent with `registry::invoke`.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current VPC service instance.
+    /// - `request`: The request structure containing the ID of the EIP.
+    ///
+    /// # Returns
+    /// - `Result<api_eip_model::EipOperationResp, error::Error>`: On success, returns the response from the VPC service.
+    ///   On failure, returns an error indicating the cause of the failure.
+    async fn new_release_eip_address(
+        &self,
+        request: api_eip_model::ReleaseEipAddressReq,
+    ) -> Result<api_eip_model::EipOperationResp, error::Error> {
+        registry::invoke(&self.client, &registry::VPC_RELEASE_EIP_ADDRESS, &request).await
+    }
+
+    /// Lists EIPs page by page.
+    ///
+    /// # Arguments
+    /// - `&self`: Reference to the current service instance.
+    /// - `request`: The request of the first page.
+    ///
+    /// # Returns
+    /// - `paginator::Paginator<api_eip_model::DescribeEipAddressesReq>`: A paginator sending `new_describe_eip_addresses` once per page.
+    fn describe_eip_addresses_paginator(
+        &self,
+        request: api_eip_model::DescribeEipAddressesReq,
+    ) -> paginator::Paginator<api_eip_model::DescribeEipAddressesReq> {
+        let vpc = self.clone();
+        paginator::Paginator::new(request, move |request| {
+            let vpc = vpc.clone();
+            Box::pin(async move { vpc.new_describe_eip_addresses(request).await })
+        })
     }
 }
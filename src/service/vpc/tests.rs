@@ -2,67 +2,821 @@
  * @Author: Jerry.Yang
  * @Date: 2024-10-22 13:52:22
  * @LastEditors: Jerry.Yang
- * @LastEditTime: 2025-02-20 16:19:10
- * @Description: create_user test
+ * @LastEditTime: 2025-03-29 14:08:26
+ * @Description: vpc test
  */
 #[cfg(test)]
 mod tests {
-    use volcengine_sdk_protobuf::protobuf::vpc_subnet;
+    use futures::TryStreamExt;
+    use volcengine_sdk_protobuf::protobuf::{vpc_subnet, vpc_vpc};
 
     use crate::{
-        service::vpc::{self, VpcService},
-        volcengine::{config, credentials::credentials, session::session},
+        service::vpc::{
+            self, api_eip_model, api_security_group_model, api_tag_model, api_vpc_subnet_model,
+            VpcService,
+        },
+        volcengine::{error::error, mock::mock},
     };
 
     #[tokio::test]
     async fn test_get_user() {
-        let access_key_id = ""; // 这里填入实际的 Access Key ID
-        let secret_access_key = ""; // 这里填入实际的 Secret Access Key
-        let region_id = "cn-beijing"; // 这里填入实际的 Region ID
+        // start the mock server
+        let server = mock::MockServer::start().await;
+        server.mock(
+            "DescribeSubnets",
+            "2020-04-01",
+            mock::MockResponse::json(&vpc_subnet::DescribeSubnetsResp {
+                result: Some(vpc_subnet::DescribeSubnetsResultResp {
+                    subnets: vec![vpc_subnet::DescribeSubnetsResultSubnetResp {
+                        subnet_id: Some("subnet-1".to_string()),
+                        vpc_id: Some("vpc-1".to_string()),
+                        ..Default::default()
+                    }],
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+        );
 
-        let credentials = credentials::Credentials::new(access_key_id, secret_access_key);
+        // new vpc
+        let vpc = vpc::Vpc::new_vpc(server.session()).unwrap();
 
-        // new config
-        let config = config::Config::builder()
-            .with_region(&region_id)
-            .with_credentials(credentials)
-            .build();
+        // describe subnets
+        let mut request = vpc_subnet::DescribeSubnetsReq::default();
+        request.vpc_id = Some("vpc-1".to_string());
+        request.zone_id = Some("cn-beijing-a".to_string());
+        let result = vpc.new_describe_subnets(request).await.unwrap();
 
-        // print config
-        println!("config : {:?}", config);
+        let subnets = result.result.unwrap().subnets;
+        assert_eq!(subnets[0].subnet_id.as_deref(), Some("subnet-1"));
 
-        // reset config
-        let config = config.unwrap();
+        // the request is signed and carries the parameters
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].signature_valid);
+        assert_eq!(requests[0].action, "DescribeSubnets");
+        assert_eq!(requests[0].service, "vpc");
+        assert_eq!(requests[0].query["VpcId"], "vpc-1");
+        assert_eq!(requests[0].query["ZoneId"], "cn-beijing-a");
+    }
 
-        // new session
-        let session = session::Session::builder().with_config(config).build();
+    #[tokio::test]
+    async fn test_describe_vpcs() {
+        let server = mock::MockServer::start().await;
+        server.mock(
+            "DescribeVpcs",
+            "2020-04-01",
+            mock::MockResponse::json(&vpc_vpc::DescribeVpcsResp {
+                result: Some(vpc_vpc::DescribeVpcsResultResp {
+                    total_count: 1,
+                    vpcs: vec![vpc_vpc::DescribeVpcsResultVpcResp {
+                        vpc_id: "vpc-1".to_string(),
+                        ..Default::default()
+                    }],
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+        );
 
-        // print session
-        println!("session : {:?}", session);
+        let vpc = vpc::Vpc::new_vpc(server.session()).unwrap();
+        let request = vpc_vpc::DescribeVpcsReq {
+            vpc_ids: vec!["vpc-1".to_string()],
+            ..Default::default()
+        };
+        let result = vpc.new_describe_vpcs(request).await.unwrap();
 
-        // reset session
-        let session = session.unwrap();
+        assert_eq!(result.result.unwrap().vpcs[0].vpc_id, "vpc-1");
+        let requests = server.requests();
+        assert!(requests[0].signature_valid);
+        assert_eq!(requests[0].query["VpcIds.1"], "vpc-1");
+    }
 
-        // new vpc
-        let vpc = vpc::Vpc::new_vpc(session);
+    #[tokio::test]
+    async fn test_describe_vpcs_paginator() {
+        // two pages linked by NextToken
+        let server = mock::MockServer::start().await;
+        for (vpc_id, next_token) in [("vpc-1", "token-2"), ("vpc-2", "")] {
+            server.mock(
+                "DescribeVpcs",
+                "2020-04-01",
+                mock::MockResponse::json(&vpc_vpc::DescribeVpcsResp {
+                    result: Some(vpc_vpc::DescribeVpcsResultResp {
+                        next_token: Some(next_token.to_string()),
+                        vpcs: vec![vpc_vpc::DescribeVpcsResultVpcResp {
+                            vpc_id: vpc_id.to_string(),
+                            ..Default::default()
+                        }],
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                }),
+            );
+        }
 
-        // print vpc
-        println!("vpc : {:?}", vpc);
+        let vpc = vpc::Vpc::new_vpc(server.session()).unwrap();
+        let request = vpc_vpc::DescribeVpcsReq {
+            max_results: Some(1),
+            ..Default::default()
+        };
+        let vpcs: Vec<_> = vpc
+            .describe_vpcs_paginator(request)
+            .items()
+            .try_collect()
+            .await
+            .unwrap();
 
-        // reset vpc
-        let vpc = vpc.unwrap();
+        let vpc_ids: Vec<_> = vpcs.iter().map(|vpc| vpc.vpc_id.as_str()).collect();
+        assert_eq!(vpc_ids, ["vpc-1", "vpc-2"]);
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[1].query["NextToken"], "token-2");
+    }
 
-        // create_user
-        let mut request = vpc_subnet::DescribeSubnetsReq::default();
-        request.vpc_id = Some("".to_string());
-        request.zone_id = Some("".to_string());
+    #[tokio::test]
+    async fn test_describe_subnets_paginator() {
+        let server = mock::MockServer::start().await;
+        server.mock(
+            "DescribeSubnets",
+            "2020-04-01",
+            mock::MockResponse::json(&vpc_subnet::DescribeSubnetsResp {
+                result: Some(vpc_subnet::DescribeSubnetsResultResp {
+                    total_count: Some(1),
+                    subnets: vec![vpc_subnet::DescribeSubnetsResultSubnetResp {
+                        subnet_id: Some("subnet-1".to_string()),
+                        ..Default::default()
+                    }],
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+        );
+
+        let vpc = vpc::Vpc::new_vpc(server.session()).unwrap();
+        let subnets: Vec<_> = vpc
+            .describe_subnets_paginator(vpc_subnet::DescribeSubnetsReq::default())
+            .items()
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(subnets.len(), 1);
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_describe_vpcs_api_error() {
+        let server = mock::MockServer::start().await;
+        server.mock(
+            "DescribeVpcs",
+            "2020-04-01",
+            mock::MockResponse::error(400, "InvalidVpc.NotFound", "The vpc does not exist."),
+        );
+
+        let vpc = vpc::Vpc::new_vpc(server.session()).unwrap();
+        let result = vpc
+            .new_describe_vpcs(vpc_vpc::DescribeVpcsReq::default())
+            .await;
+
+        match result {
+            Err(error::Error::Api {
+                code, request_id, ..
+            }) => {
+                assert_eq!(code, "InvalidVpc.NotFound");
+                assert_eq!(request_id, "mock-request-1");
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    /// Starts a mock server answering `action` with `response`, and a `Vpc` calling it.
+    async fn mock_vpc(action: &str, response: mock::MockResponse) -> (mock::MockServer, vpc::Vpc) {
+        let server = mock::MockServer::start().await;
+        server.mock(action, "2020-04-01", response);
+        let vpc = vpc::Vpc::new_vpc(server.session()).unwrap();
+        (server, vpc)
+    }
+
+    #[tokio::test]
+    async fn test_create_security_group() {
+        let (server, vpc) = mock_vpc(
+            "CreateSecurityGroup",
+            mock::MockResponse::json(&serde_json::json!({
+                "Result": { "SecurityGroupId": "sg-1" },
+            })),
+        )
+        .await;
+
+        let request = api_security_group_model::CreateSecurityGroupReq {
+            vpc_id: "vpc-1".to_string(),
+            security_group_name: Some("web".to_string()),
+            tags: vec![api_tag_model::Tag {
+                key: "env".to_string(),
+                value: "prod".to_string(),
+            }],
+            ..Default::default()
+        };
+        let response = vpc.new_create_security_group(request).await.unwrap();
+        assert_eq!(response.result.unwrap().security_group_id, "sg-1");
+
+        let request = server.single_request("CreateSecurityGroup", "vpc");
+        assert_eq!(request.query["VpcId"], "vpc-1");
+        assert_eq!(request.query["SecurityGroupName"], "web");
+        assert_eq!(request.query["Tags.1.Key"], "env");
+        assert_eq!(request.query["Tags.1.Value"], "prod");
+    }
+
+    #[tokio::test]
+    async fn test_authorize_security_group_ingress() {
+        let (server, vpc) =
+            mock_vpc("AuthorizeSecurityGroupIngress", mock::MockResponse::empty()).await;
+
+        let request = api_security_group_model::AuthorizeSecurityGroupReq {
+            security_group_id: "sg-1".to_string(),
+            rule: api_security_group_model::SecurityGroupRule {
+                protocol: api_security_group_model::RuleProtocol::Tcp,
+                port_range: api_security_group_model::PortRange::Single(22),
+                source: api_security_group_model::RuleSource::Cidr("10.0.0.0/8".to_string()),
+                priority: Some(10),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        vpc.new_authorize_security_group_ingress(request)
+            .await
+            .unwrap();
+
+        // the rule is flattened into the query
+        let request = server.single_request("AuthorizeSecurityGroupIngress", "vpc");
+        assert_eq!(request.query["SecurityGroupId"], "sg-1");
+        assert_eq!(request.query["Protocol"], "tcp");
+        assert_eq!(request.query["PortStart"], "22");
+        assert_eq!(request.query["PortEnd"], "22");
+        assert_eq!(request.query["CidrIp"], "10.0.0.0/8");
+        assert_eq!(request.query["Policy"], "accept");
+        assert_eq!(request.query["Priority"], "10");
+        assert!(!request.query.contains_key("PrefixListId"));
+        assert!(!request.query.contains_key("SourceGroupId"));
+    }
+
+    #[tokio::test]
+    async fn test_authorize_security_group_egress() {
+        let (server, vpc) =
+            mock_vpc("AuthorizeSecurityGroupEgress", mock::MockResponse::empty()).await;
+
+        let request = api_security_group_model::AuthorizeSecurityGroupReq {
+            security_group_id: "sg-1".to_string(),
+            rule: api_security_group_model::SecurityGroupRule {
+                source: api_security_group_model::RuleSource::PrefixList("pl-1".to_string()),
+                policy: api_security_group_model::RulePolicy::Drop,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        vpc.new_authorize_security_group_egress(request)
+            .await
+            .unwrap();
+
+        // every port of every protocol
+        let request = server.single_request("AuthorizeSecurityGroupEgress", "vpc");
+        assert_eq!(request.query["Protocol"], "all");
+        assert_eq!(request.query["PortStart"], "-1");
+        assert_eq!(request.query["PortEnd"], "-1");
+        assert_eq!(request.query["PrefixListId"], "pl-1");
+        assert_eq!(request.query["Policy"], "drop");
+        assert!(!request.query.contains_key("CidrIp"));
+    }
+
+    #[tokio::test]
+    async fn test_revoke_security_group_ingress() {
+        let (server, vpc) =
+            mock_vpc("RevokeSecurityGroupIngress", mock::MockResponse::empty()).await;
+
+        let request = api_security_group_model::RevokeSecurityGroupReq {
+            security_group_id: "sg-1".to_string(),
+            rule: api_security_group_model::SecurityGroupRule {
+                protocol: api_security_group_model::RuleProtocol::Udp,
+                port_range: api_security_group_model::PortRange::Range(8000, 8080),
+                source: api_security_group_model::RuleSource::SecurityGroup("sg-2".to_string()),
+                ..Default::default()
+            },
+        };
+        vpc.new_revoke_security_group_ingress(request)
+            .await
+            .unwrap();
+
+        let request = server.single_request("RevokeSecurityGroupIngress", "vpc");
+        assert_eq!(request.query["PortStart"], "8000");
+        assert_eq!(request.query["PortEnd"], "8080");
+        assert_eq!(request.query["SourceGroupId"], "sg-2");
+    }
+
+    #[tokio::test]
+    async fn test_describe_security_group_attributes() {
+        let (server, vpc) = mock_vpc(
+            "DescribeSecurityGroupAttributes",
+            mock::MockResponse::json(&serde_json::json!({
+                "Result": {
+                    "SecurityGroupId": "sg-1",
+                    "Permissions": [
+                        {
+                            "Direction": "ingress",
+                            "Protocol": "tcp",
+                            "PortStart": 8000,
+                            "PortEnd": 8080,
+                            "CidrIp": "",
+                            "PrefixListId": "pl-1",
+                            "SourceGroupId": "",
+                            "Policy": "accept",
+                            "Priority": 1,
+                            "Description": "",
+                        },
+                        {
+                            "Direction": "egress",
+                            "Protocol": "gre",
+                            "PortStart": -1,
+                            "PortEnd": -1,
+                            "CidrIp": "0.0.0.0/0",
+                            "Policy": "drop",
+                            "Priority": 100,
+                        },
+                    ],
+                },
+            })),
+        )
+        .await;
+
+        let request = api_security_group_model::DescribeSecurityGroupAttributesReq {
+            security_group_id: "sg-1".to_string(),
+            ..Default::default()
+        };
+        let response = vpc
+            .new_describe_security_group_attributes(request)
+            .await
+            .unwrap();
+
+        // the rules are read back into the typed model
+        let permissions = response.result.unwrap().permissions;
+        assert_eq!(
+            permissions[0].direction,
+            Some(api_security_group_model::RuleDirection::Ingress)
+        );
+        assert_eq!(
+            permissions[0].rule().unwrap(),
+            api_security_group_model::SecurityGroupRule {
+                protocol: api_security_group_model::RuleProtocol::Tcp,
+                port_range: api_security_group_model::PortRange::Range(8000, 8080),
+                source: api_security_group_model::RuleSource::PrefixList("pl-1".to_string()),
+                policy: api_security_group_model::RulePolicy::Accept,
+                priority: Some(1),
+                description: None,
+            }
+        );
+        let rule = permissions[1].rule().unwrap();
+        assert_eq!(
+            rule.protocol,
+            api_security_group_model::RuleProtocol::Other("gre".to_string())
+        );
+        assert_eq!(rule.port_range, api_security_group_model::PortRange::All);
+        assert_eq!(rule.policy, api_security_group_model::RulePolicy::Drop);
+
+        let request = server.single_request("DescribeSecurityGroupAttributes", "vpc");
+        assert_eq!(request.query["SecurityGroupId"], "sg-1");
+    }
+
+    #[tokio::test]
+    async fn test_describe_security_group_attributes_lenient() {
+        let (_server, vpc) = mock_vpc(
+            "DescribeSecurityGroupAttributes",
+            mock::MockResponse::json(&serde_json::json!({
+                "Result": {
+                    "SecurityGroupId": "sg-1",
+                    "Permissions": [
+                        {
+                            "Direction": "ingress",
+                            "Protocol": "tcp",
+                            "PortStart": 22,
+                            "PortEnd": 22,
+                            "CidrIp": "10.0.0.0/8",
+                            "Policy": "accept",
+                        },
+                        {
+                            "Direction": "ingress",
+                            "Protocol": "tcp",
+                            "PortStart": 443,
+                            "PortEnd": 80,
+                            "CidrIp": "",
+                            "PrefixListId": "",
+                            "SourceGroupId": "",
+                            "Policy": "reject",
+                        },
+                        {
+                            "Direction": "sideways",
+                            "Protocol": "all",
+                            "PortStart": -1,
+                            "PortEnd": -1,
+                            "CidrIp": "0.0.0.0/0",
+                            "SourceGroupId": "sg-2",
+                        },
+                    ],
+                },
+            })),
+        )
+        .await;
+
+        let request = api_security_group_model::DescribeSecurityGroupAttributesReq {
+            security_group_id: "sg-1".to_string(),
+            ..Default::default()
+        };
+        let response = vpc
+            .new_describe_security_group_attributes(request)
+            .await
+            .unwrap();
+
+        // rules which cannot be modeled do not fail the response, only their `rule()`
+        let permissions = response.result.unwrap().permissions;
+        assert_eq!(permissions.len(), 3);
+        assert_eq!(
+            permissions[0].rule().unwrap().port_range,
+            api_security_group_model::PortRange::Single(22)
+        );
+        assert_eq!(permissions[1].port_start, 443);
+        assert_eq!(permissions[1].policy, "reject");
+        assert!(permissions[1].rule().is_err());
+        assert_eq!(
+            permissions[2].direction,
+            Some(api_security_group_model::RuleDirection::Other)
+        );
+        assert!(permissions[2].rule().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_describe_security_groups_paginator() {
+        // two pages numbered 1 and 2
+        let server = mock::MockServer::start().await;
+        for security_group_id in ["sg-1", "sg-2"] {
+            server.mock(
+                "DescribeSecurityGroups",
+                "2020-04-01",
+                mock::MockResponse::json(&serde_json::json!({
+                    "Result": {
+                        "TotalCount": 2,
+                        "SecurityGroups": [{ "SecurityGroupId": security_group_id }],
+                    },
+                })),
+            );
+        }
+
+        let vpc = vpc::Vpc::new_vpc(server.session()).unwrap();
+        let request = api_security_group_model::DescribeSecurityGroupsReq {
+            vpc_id: Some("vpc-1".to_string()),
+            page_size: Some(1),
+            ..Default::default()
+        };
+        let security_groups: Vec<_> = vpc
+            .describe_security_groups_paginator(request)
+            .items()
+            .try_collect()
+            .await
+            .unwrap();
+
+        let security_group_ids: Vec<_> = security_groups
+            .iter()
+            .map(|security_group| security_group.security_group_id.as_str())
+            .collect();
+        assert_eq!(security_group_ids, ["sg-1", "sg-2"]);
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[1].query["PageNumber"], "2");
+        assert_eq!(requests[1].query["VpcId"], "vpc-1");
+    }
+
+    #[tokio::test]
+    async fn test_delete_security_group() {
+        let (server, vpc) = mock_vpc(
+            "DeleteSecurityGroup",
+            mock::MockResponse::error(400, "DependencyViolation", "in use"),
+        )
+        .await;
+
+        let request = api_security_group_model::DeleteSecurityGroupReq {
+            security_group_id: "sg-1".to_string(),
+        };
+        let result = vpc.new_delete_security_group(request).await;
+
+        assert!(matches!(
+            result,
+            Err(error::Error::Api { code, .. }) if code == "DependencyViolation"
+        ));
+        server.single_request("DeleteSecurityGroup", "vpc");
+    }
+
+    #[tokio::test]
+    async fn test_create_vpc() {
+        let (server, vpc) = mock_vpc(
+            "CreateVpc",
+            mock::MockResponse::json(&serde_json::json!({
+                "Result": { "VpcId": "vpc-1", "RouteTableId": "vtb-1" },
+            })),
+        )
+        .await;
+
+        let request = api_vpc_subnet_model::CreateVpcReq {
+            cidr_block: "172.16.0.0/16".to_string(),
+            vpc_name: Some("tenant-1".to_string()),
+            dns_servers: vec!["10.0.0.2".to_string(), "10.0.0.3".to_string()],
+            tags: vec![api_tag_model::Tag {
+                key: "tenant".to_string(),
+                value: "1".to_string(),
+            }],
+            ..Default::default()
+        };
+        let response = vpc.new_create_vpc(request).await.unwrap();
+        assert_eq!(response.result.unwrap().vpc_id, "vpc-1");
+
+        let request = server.single_request("CreateVpc", "vpc");
+        assert_eq!(request.query["CidrBlock"], "172.16.0.0/16");
+        assert_eq!(request.query["VpcName"], "tenant-1");
+        assert_eq!(request.query["DnsServers.1"], "10.0.0.2");
+        assert_eq!(request.query["DnsServers.2"], "10.0.0.3");
+        assert_eq!(request.query["Tags.1.Key"], "tenant");
+    }
+
+    #[tokio::test]
+    async fn test_modify_vpc_attributes() {
+        let (server, vpc) = mock_vpc("ModifyVpcAttributes", mock::MockResponse::empty()).await;
+
+        let request = api_vpc_subnet_model::ModifyVpcAttributesReq {
+            vpc_id: "vpc-1".to_string(),
+            description: Some("sandbox".to_string()),
+            ..Default::default()
+        };
+        vpc.new_modify_vpc_attributes(request).await.unwrap();
+
+        // only the attributes set are sent
+        let request = server.single_request("ModifyVpcAttributes", "vpc");
+        assert_eq!(request.query["Description"], "sandbox");
+        assert!(!request.query.contains_key("VpcName"));
+        assert!(!request.query.contains_key("DnsServers.1"));
+    }
+
+    #[tokio::test]
+    async fn test_associate_cidr_block() {
+        let (server, vpc) = mock_vpc("AssociateCidrBlock", mock::MockResponse::empty()).await;
+
+        let request = api_vpc_subnet_model::AssociateCidrBlockReq {
+            vpc_id: "vpc-1".to_string(),
+            secondary_cidr_block: Some("192.168.0.0/16".to_string()),
+            ..Default::default()
+        };
+        vpc.new_associate_cidr_block(request).await.unwrap();
+
+        let request = server.single_request("AssociateCidrBlock", "vpc");
+        assert_eq!(request.query["SecondaryCidrBlock"], "192.168.0.0/16");
+    }
+
+    #[tokio::test]
+    async fn test_create_subnet() {
+        let (server, vpc) = mock_vpc(
+            "CreateSubnet",
+            mock::MockResponse::json(&serde_json::json!({
+                "Result": { "SubnetId": "subnet-1" },
+            })),
+        )
+        .await;
+
+        let request = api_vpc_subnet_model::CreateSubnetReq {
+            vpc_id: "vpc-1".to_string(),
+            zone_id: "cn-beijing-a".to_string(),
+            cidr_block: "172.16.1.0/24".to_string(),
+            ipv6_cidr_block: Some(1),
+            ..Default::default()
+        };
+        let response = vpc.new_create_subnet(request).await.unwrap();
+        assert_eq!(response.result.unwrap().subnet_id, "subnet-1");
+
+        let request = server.single_request("CreateSubnet", "vpc");
+        assert_eq!(request.query["ZoneId"], "cn-beijing-a");
+        assert_eq!(request.query["CidrBlock"], "172.16.1.0/24");
+        assert_eq!(request.query["Ipv6CidrBlock"], "1");
+    }
+
+    #[tokio::test]
+    async fn test_delete_vpc() {
+        let (server, vpc) = mock_vpc(
+            "DeleteVpc",
+            mock::MockResponse::error(400, "DependencyViolation.Subnet", "has subnets"),
+        )
+        .await;
+
+        let request = api_vpc_subnet_model::DeleteVpcReq {
+            vpc_id: "vpc-1".to_string(),
+        };
+        let result = vpc.new_delete_vpc(request).await;
+
+        assert!(matches!(
+            result,
+            Err(error::Error::Api { code, .. }) if code == "DependencyViolation.Subnet"
+        ));
+        server.single_request("DeleteVpc", "vpc");
+    }
+
+    #[tokio::test]
+    async fn test_delete_subnet() {
+        let (server, vpc) = mock_vpc("DeleteSubnet", mock::MockResponse::empty()).await;
+
+        let request = api_vpc_subnet_model::DeleteSubnetReq {
+            subnet_id: "subnet-1".to_string(),
+        };
+        vpc.new_delete_subnet(request).await.unwrap();
+
+        let request = server.single_request("DeleteSubnet", "vpc");
+        assert_eq!(request.query["SubnetId"], "subnet-1");
+    }
+
+    #[tokio::test]
+    async fn test_allocate_eip_address() {
+        let (server, vpc) = mock_vpc(
+            "AllocateEipAddress",
+            mock::MockResponse::json(&serde_json::json!({
+                "Result": { "AllocationId": "eip-1", "EipAddress": "203.0.113.10" },
+            })),
+        )
+        .await;
+
+        let request = api_eip_model::AllocateEipAddressReq {
+            bandwidth: Some(5),
+            isp: Some("BGP".to_string()),
+            ..Default::default()
+        };
+        let response = vpc.new_allocate_eip_address(request).await.unwrap();
+        let result = response.result.unwrap();
+        assert_eq!(result.allocation_id, "eip-1");
+        assert_eq!(result.eip_address, "203.0.113.10");
+
+        // pay-as-you-go by bandwidth unless told otherwise
+        let request = server.single_request("AllocateEipAddress", "vpc");
+        assert_eq!(request.query["BillingType"], "2");
+        assert_eq!(request.query["Bandwidth"], "5");
+        assert_eq!(request.query["ISP"], "BGP");
+    }
+
+    #[tokio::test]
+    async fn test_associate_eip_address() {
+        let targets = [
+            (
+                api_eip_model::EipTarget::EcsInstance("i-1".to_string()),
+                "EcsInstance",
+            ),
+            (
+                api_eip_model::EipTarget::ClbInstance("clb-1".to_string()),
+                "ClbInstance",
+            ),
+            (
+                api_eip_model::EipTarget::NatGateway("ngw-1".to_string()),
+                "Nat",
+            ),
+        ];
+        for (target, instance_type) in targets {
+            let (server, vpc) = mock_vpc("AssociateEipAddress", mock::MockResponse::empty()).await;
+
+            let instance_id = target.instance_id().to_string();
+            let request = api_eip_model::EipAssociationReq {
+                allocation_id: "eip-1".to_string(),
+                target,
+            };
+            vpc.new_associate_eip_address(request).await.unwrap();
+
+            let request = server.single_request("AssociateEipAddress", "vpc");
+            assert_eq!(request.query["AllocationId"], "eip-1");
+            assert_eq!(request.query["InstanceType"], instance_type);
+            assert_eq!(request.query["InstanceId"], instance_id);
+            assert!(!request.query.contains_key("PrivateIpAddress"));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_associate_eip_address_with_network_interface() {
+        let (server, vpc) = mock_vpc("AssociateEipAddress", mock::MockResponse::empty()).await;
+
+        let request = api_eip_model::EipAssociationReq {
+            allocation_id: "eip-1".to_string(),
+            target: api_eip_model::EipTarget::NetworkInterface {
+                network_interface_id: "eni-1".to_string(),
+                private_ip_address: Some("172.16.1.5".to_string()),
+            },
+        };
+        vpc.new_associate_eip_address(request).await.unwrap();
+
+        let request = server.single_request("AssociateEipAddress", "vpc");
+        assert_eq!(request.query["InstanceType"], "NetworkInterface");
+        assert_eq!(request.query["InstanceId"], "eni-1");
+        assert_eq!(request.query["PrivateIpAddress"], "172.16.1.5");
+    }
+
+    #[tokio::test]
+    async fn test_disassociate_eip_address() {
+        let (server, vpc) = mock_vpc("DisassociateEipAddress", mock::MockResponse::empty()).await;
+
+        let request = api_eip_model::EipAssociationReq {
+            allocation_id: "eip-1".to_string(),
+            target: api_eip_model::EipTarget::EcsInstance("i-1".to_string()),
+        };
+        vpc.new_disassociate_eip_address(request).await.unwrap();
+
+        let request = server.single_request("DisassociateEipAddress", "vpc");
+        assert_eq!(request.query["AllocationId"], "eip-1");
+        assert_eq!(request.query["InstanceType"], "EcsInstance");
+        assert_eq!(request.query["InstanceId"], "i-1");
+    }
+
+    #[tokio::test]
+    async fn test_describe_eip_addresses_paginator() {
+        // two pages chained by NextToken
+        let server = mock::MockServer::start().await;
+        for (eip, next_token) in [
+            (
+                serde_json::json!({
+                    "AllocationId": "eip-1",
+                    "InstanceType": "NetworkInterface",
+                    "InstanceId": "eni-1",
+                    "PrivateIpAddress": "172.16.1.5",
+                    "BillingType": 3,
+                }),
+                "token-2",
+            ),
+            (serde_json::json!({ "AllocationId": "eip-2" }), ""),
+        ] {
+            server.mock(
+                "DescribeEipAddresses",
+                "2020-04-01",
+                mock::MockResponse::json(&serde_json::json!({
+                    "Result": { "NextToken": next_token, "EipAddresses": [eip] },
+                })),
+            );
+        }
 
-        // 运行异步代码的 helper 函数
-        let result = vpc.new_describe_subnets(request).await;
+        let vpc = vpc::Vpc::new_vpc(server.session()).unwrap();
+        let request = api_eip_model::DescribeEipAddressesReq {
+            max_results: Some(1),
+            ..Default::default()
+        };
+        let eips: Vec<_> = vpc
+            .describe_eip_addresses_paginator(request)
+            .items()
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(eips.len(), 2);
+        assert_eq!(
+            eips[0].target(),
+            Some(api_eip_model::EipTarget::NetworkInterface {
+                network_interface_id: "eni-1".to_string(),
+                private_ip_address: Some("172.16.1.5".to_string()),
+            })
+        );
+        assert_eq!(
+            eips[0].billing_type,
+            Some(api_eip_model::EipBillingType::PostPaidByTraffic)
+        );
+        assert_eq!(eips[1].target(), None);
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[1].query["NextToken"], "token-2");
+    }
+
+    #[tokio::test]
+    async fn test_release_eip_address() {
+        let (server, vpc) = mock_vpc(
+            "ReleaseEipAddress",
+            mock::MockResponse::error(400, "InvalidEip.InUse", "the eip is associated"),
+        )
+        .await;
+
+        let request = api_eip_model::ReleaseEipAddressReq {
+            allocation_id: "eip-1".to_string(),
+        };
+        let result = vpc.new_release_eip_address(request).await;
+
+        assert!(matches!(
+            result,
+            Err(error::Error::Api { code, .. }) if code == "InvalidEip.InUse"
+        ));
+        server.single_request("ReleaseEipAddress", "vpc");
+    }
 
-        println!("result : {:?}", result);
+    #[test]
+    fn test_port_range_from_bounds() {
+        use api_security_group_model::PortRange;
 
-        // 这里可以添加断言来检查结果
-        assert!(result.is_ok());
+        assert_eq!(PortRange::from_bounds(-1, -1), Ok(PortRange::All));
+        assert_eq!(PortRange::from_bounds(443, 443), Ok(PortRange::Single(443)));
+        assert_eq!(
+            PortRange::from_bounds(1, 65535),
+            Ok(PortRange::Range(1, 65535))
+        );
+        assert!(PortRange::from_bounds(8080, 8000).is_err());
+        assert!(PortRange::from_bounds(0, 70000).is_err());
     }
 }
@@ -0,0 +1,153 @@
+/*
+ * @Author: Jerry.Yang
+ * @Date: 2025-03-18 10:11:05
+ * @LastEditors: Jerry.Yang
+ * @LastEditTime: 2025-03-30 11:10:00
+ * @Description: Runtime shared by the blocking service facades
+ */
+use crate::volcengine::error::error;
+use crate::volcengine::paginator::paginator;
+use futures::stream::{BoxStream, StreamExt};
+use std::fmt;
+use std::future::Future;
+use std::sync::OnceLock;
+
+/// The runtime driving the requests of the blocking facades.
+static SHARED_RUNTIME: OnceLock<tokio::runtime::Runtime> = OnceLock::new();
+
+/// A handle on the runtime of the blocking facades, such as `ecs::blocking::Ecs`.
+///
+/// Every facade shares one multi-threaded runtime, created on first use. Its worker keeps the
+/// pooled connections of the sessions alive between two calls, while the futures of the
+/// requests themselves run on the calling thread.
+///
+/// The blocking facades must not be called from asynchronous code: blocking on a future
+/// inside a tokio runtime panics. Use the asynchronous services there instead.
+#[derive(Clone, Copy)]
+pub struct Runtime {
+    runtime: &'static tokio::runtime::Runtime, // The shared runtime.
+}
+
+impl fmt::Debug for Runtime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Runtime").finish_non_exhaustive()
+    }
+}
+
+impl Runtime {
+    /// Returns the shared runtime, creating it on first use.
+    ///
+    /// # Returns
+    /// - `Ok(Runtime)`: The handle on the runtime.
+    /// - `Err(error::Error::ErrBlockingBuildRuntime)`: If the runtime could not be created.
+    pub fn shared() -> Result<Runtime, error::Error> {
+        if let Some(runtime) = SHARED_RUNTIME.get() {
+            return Ok(Runtime { runtime });
+        }
+
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(1)
+            .thread_name("volcengine-blocking")
+            .enable_all()
+            .build()
+            .map_err(error::Error::ErrBlockingBuildRuntime)?;
+
+        // Another thread may have won the race, in which case this runtime is dropped.
+        Ok(Runtime {
+            runtime: SHARED_RUNTIME.get_or_init(|| runtime),
+        })
+    }
+
+    /// Runs a future to completion on the calling thread.
+    ///
+    /// # Arguments
+    /// - `future`: The future, e.g. a call of an asynchronous service.
+    ///
+    /// # Returns
+    /// - `F::Output`: The output of the future.
+    ///
+    /// # Panics
+    /// If called from within an asynchronous runtime.
+    pub fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    /// Wraps a paginator to walk through its pages synchronously.
+    ///
+    /// # Arguments
+    /// - `paginator`: The paginator returned by an asynchronous service.
+    ///
+    /// # Returns
+    /// - `Paginator<R>`: The blocking paginator.
+    pub fn paginator<R: paginator::Paginated>(
+        &self,
+        paginator: paginator::Paginator<R>,
+    ) -> Paginator<R> {
+        Paginator {
+            runtime: *self,
+            paginator,
+        }
+    }
+}
+
+/// Walks through the pages of a `Describe*` / `List*` operation synchronously.
+///
+/// # Example
+/// ```rust,no_run
+/// # use volcengine_rust_sdk::volcengine::error::error;
+/// # use volcengine_rust_sdk::volcengine::session::session;
+/// use volcengine_rust_sdk::service::ecs;
+/// use volcengine_sdk_protobuf::protobuf::ecs_instance;
+///
+/// # fn example(session: session::Session) -> Result<(), error::Error> {
+/// let ecs = ecs::blocking::Ecs::new_ecs(session)?;
+/// let request = ecs_instance::DescribeInstancesReq::default();
+/// for instance in ecs.describe_instances_paginator(request).items() {
+///     println!("{:?}", instance?.instance_id);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct Paginator<R: paginator::Paginated> {
+    runtime: Runtime,                   // Runtime fetching the pages.
+    paginator: paginator::Paginator<R>, // The asynchronous paginator.
+}
+
+impl<R: paginator::Paginated> Paginator<R> {
+    /// Returns an iterator over the responses, one per page.
+    ///
+    /// # Returns
+    /// - `Iter<Result<R::Response, error::Error>>`: The pages in order.
+    pub fn pages(self) -> Iter<Result<R::Response, error::Error>> {
+        Iter {
+            runtime: self.runtime,
+            stream: self.paginator.pages().boxed(),
+        }
+    }
+
+    /// Returns an iterator over the items of every page.
+    ///
+    /// # Returns
+    /// - `Iter<Result<R::Item, error::Error>>`: The items in order.
+    pub fn items(self) -> Iter<Result<R::Item, error::Error>> {
+        Iter {
+            runtime: self.runtime,
+            stream: self.paginator.items().boxed(),
+        }
+    }
+}
+
+/// An iterator fetching the pages of a paginator as it is advanced.
+pub struct Iter<T> {
+    runtime: Runtime,              // Runtime polling the stream.
+    stream: BoxStream<'static, T>, // The asynchronous stream.
+}
+
+impl<T> Iterator for Iter<T> {
+    type Item = T;
+
+    /// Blocks until the next page or item is available.
+    fn next(&mut self) -> Option<T> {
+        self.runtime.block_on(self.stream.next())
+    }
+}
@@ -0,0 +1,11 @@
+/*
+ * @Author: Jerry.Yang
+ * @Date: 2025-03-18 10:11:05
+ * @LastEditors: Jerry.Yang
+ * @LastEditTime: 2025-03-18 10:11:05
+ * @Description: mod
+ */
+pub mod blocking;
+
+// Test module for the blocking runtime.
+mod tests;
@@ -0,0 +1,100 @@
+/*
+ * @Author: Jerry.Yang
+ * @Date: 2025-03-18 14:26:40
+ * @LastEditors: Jerry.Yang
+ * @LastEditTime: 2025-03-18 14:26:40
+ * @Description: blocking test
+ */
+#[cfg(test)]
+mod tests {
+    use futures::FutureExt;
+
+    use crate::volcengine::blocking::blocking;
+    use crate::volcengine::error::error;
+    use crate::volcengine::paginator::paginator::{self, Paginated};
+
+    /// A request listing the numbers below `end`, `page_size` at a time.
+    #[derive(Debug, Clone)]
+    struct NumbersReq {
+        start: u32,
+        end: u32,
+        page_size: u32,
+    }
+
+    impl Paginated for NumbersReq {
+        type Response = Vec<u32>;
+        type Item = u32;
+
+        fn items(response: &Vec<u32>) -> &[u32] {
+            response
+        }
+
+        fn next_request(&self, response: &Vec<u32>, _fetched: usize) -> Option<Self> {
+            let start = self.start + response.len() as u32;
+            (start < self.end).then(|| NumbersReq {
+                start,
+                ..self.clone()
+            })
+        }
+    }
+
+    fn numbers_paginator(
+        end: u32,
+        page_size: u32,
+        fail_at: Option<u32>,
+    ) -> paginator::Paginator<NumbersReq> {
+        let request = NumbersReq {
+            start: 0,
+            end,
+            page_size,
+        };
+        paginator::Paginator::new(request, move |request: NumbersReq| {
+            async move {
+                // Fetching a page really awaits, as a request would.
+                tokio::task::yield_now().await;
+                if fail_at == Some(request.start) {
+                    return Err(error::Error::ErrRequestBuildFailed);
+                }
+                Ok((request.start..(request.start + request.page_size).min(request.end)).collect())
+            }
+            .boxed()
+        })
+    }
+
+    #[test]
+    fn test_block_on() {
+        let runtime = blocking::Runtime::shared().unwrap();
+        let value = runtime.block_on(async {
+            tokio::time::sleep(std::time::Duration::from_millis(1)).await;
+            42
+        });
+        assert_eq!(value, 42);
+    }
+
+    #[test]
+    fn test_paginator() {
+        let runtime = blocking::Runtime::shared().unwrap();
+
+        let pages: Vec<Vec<u32>> = runtime
+            .paginator(numbers_paginator(5, 2, None))
+            .pages()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(pages, vec![vec![0, 1], vec![2, 3], vec![4]]);
+
+        let items: Vec<u32> = runtime
+            .paginator(numbers_paginator(5, 2, None))
+            .items()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(items, vec![0, 1, 2, 3, 4]);
+
+        // The iterator ends after the first error.
+        let results: Vec<_> = runtime
+            .paginator(numbers_paginator(5, 2, Some(2)))
+            .items()
+            .collect();
+        assert_eq!(results.len(), 3);
+        assert!(results[2].is_err());
+    }
+}
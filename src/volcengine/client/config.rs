@@ -2,11 +2,12 @@
  * @Author: Jerry.Yang
  * @Date: 2024-10-18 10:33:04
  * @LastEditors: Jerry.Yang
- * @LastEditTime: 2025-02-05 14:16:56
+ * @LastEditTime: 2025-03-30 11:10:00
  * @Description: Client configuration for Volcengine API
  */
 use crate::volcengine::config;
 use crate::volcengine::request::handles;
+use crate::volcengine::transport::transport;
 
 /// Struct representing the client configuration for accessing Volcengine services.
 ///
@@ -24,6 +25,7 @@ use crate::volcengine::request::handles;
 /// - `signing_region`: The region to be used for signing API requests.
 /// - `signing_name`: The name of the service to be used for signing the requests.
 /// - `signing_name_derived`: A flag indicating whether the signing name is derived from metadata or explicitly modeled.
+/// - `transport`: The pooled HTTP transport shared with the session that created this configuration.
 #[derive(Debug, Clone)]
 pub struct Config {
     /// The configuration details for the client, including authentication and settings.
@@ -44,12 +46,15 @@ pub struct Config {
     /// A flag indicating whether the signing name was derived from metadata
     /// but was not explicitly modeled in the service configuration.
     pub signing_name_derived: bool,
+
+    /// The HTTP transport used to send requests, shared with the originating session.
+    pub transport: transport::Transport,
 }
 
 /// Enum representing the available service names for the Volcengine API client.
 ///
 /// This enum is used to define the different services that are accessible through
-/// the Volcengine API, including services such as IAM, ECS, VPC, RDS, Redis, and CLB.
+/// the Volcengine API, including services such as IAM, ECS, VPC, RDS, Redis, CLB and NAT gateway.
 /// Each variant represents a distinct service that can be used by the API client
 /// to make requests to the corresponding Volcengine API endpoint.
 ///
@@ -60,14 +65,20 @@ pub struct Config {
 /// - `Rds`: The Relational Database Service (RDS) for MySQL-based databases.
 /// - `Redis`: The Redis service.
 /// - `Clb`: The Cloud Load Balancer (CLB) service.
-#[derive(Debug, Clone)]
+/// - `Sts`: The Security Token Service (STS).
+/// - `NatGateway`: The NAT gateway service.
+/// - `Custom`: Any other service, named as in its signing scope, e.g. `billing`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum ClientServiceName {
-    Iam,   // Identity and Access Management (IAM) service
-    Ecs,   // Elastic Compute Service (ECS) service
-    Vpc,   // Virtual Private Cloud (VPC) service
-    Rds,   // Relational Database Service (RDS) - specifically MySQL
-    Redis, // Redis service
-    Clb,   // CLB service
+    Iam,            // Identity and Access Management (IAM) service
+    Ecs,            // Elastic Compute Service (ECS) service
+    Vpc,            // Virtual Private Cloud (VPC) service
+    Rds,            // Relational Database Service (RDS) - specifically MySQL
+    Redis,          // Redis service
+    Clb,            // CLB service
+    Sts,            // Security Token Service (STS)
+    NatGateway,     // NAT gateway service
+    Custom(String), // Any other service, by its signing name
 }
 
 /**
@@ -92,17 +103,56 @@ impl ClientServiceName {
     ///
     /// # Example
     /// ```rust
+    /// # use volcengine_rust_sdk::volcengine::client::config::ClientServiceName;
     /// let service_name = ClientServiceName::Iam.as_str();
     /// assert_eq!(service_name, "iam");
     /// ```
     pub fn as_str(&self) -> &str {
         match self {
-            ClientServiceName::Iam => "iam",       // IAM service
-            ClientServiceName::Ecs => "ecs",       // ECS service
-            ClientServiceName::Vpc => "vpc",       // VPC service
-            ClientServiceName::Rds => "rds_mysql", // MySQL-based RDS service
-            ClientServiceName::Redis => "redis",   // Redis service
-            ClientServiceName::Clb => "clb",       // CLB service
+            ClientServiceName::Iam => "iam",               // IAM service
+            ClientServiceName::Ecs => "ecs",               // ECS service
+            ClientServiceName::Vpc => "vpc",               // VPC service
+            ClientServiceName::Rds => "rds_mysql",         // MySQL-based RDS service
+            ClientServiceName::Redis => "redis",           // Redis service
+            ClientServiceName::Clb => "clb",               // CLB service
+            ClientServiceName::Sts => "sts",               // STS service
+            ClientServiceName::NatGateway => "natgateway", // NAT gateway service
+            ClientServiceName::Custom(name) => name,       // Other services
+        }
+    }
+
+    /// Looks up a service by the name used in its signing scope.
+    ///
+    /// The names of the services modeled by the SDK map to their variant, so that endpoint
+    /// overrides and resolution apply to them; other names become `Custom`.
+    ///
+    /// # Arguments
+    /// - `name`: The service name, e.g. `ecs` or `rds_mysql`.
+    ///
+    /// # Returns
+    /// Returns the matching `ClientServiceName`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use volcengine_rust_sdk::volcengine::client::config::ClientServiceName;
+    ///
+    /// assert_eq!(ClientServiceName::from_name("ecs"), ClientServiceName::Ecs);
+    /// assert_eq!(
+    ///     ClientServiceName::from_name("billing"),
+    ///     ClientServiceName::Custom("billing".to_string())
+    /// );
+    /// ```
+    pub fn from_name(name: &str) -> ClientServiceName {
+        match name {
+            "iam" => ClientServiceName::Iam,
+            "ecs" => ClientServiceName::Ecs,
+            "vpc" => ClientServiceName::Vpc,
+            "rds_mysql" => ClientServiceName::Rds,
+            "redis" => ClientServiceName::Redis,
+            "clb" => ClientServiceName::Clb,
+            "sts" => ClientServiceName::Sts,
+            "natgateway" => ClientServiceName::NatGateway,
+            _ => ClientServiceName::Custom(name.to_string()),
         }
     }
 }
@@ -0,0 +1,145 @@
+/*
+ * @Author: Jerry.Yang
+ * @Date: 2025-03-17 10:06:33
+ * @LastEditors: Jerry.Yang
+ * @LastEditTime: 2025-03-17 17:34:52
+ * @Description: Signing clock and clock-skew correction
+ */
+use chrono::{DateTime, Utc};
+use std::fmt;
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::time::Duration;
+
+/// Error codes returned when a request is rejected for its `X-Date`.
+///
+/// `SignatureDoesNotMatch` is also returned for wrong keys, so a skew is only corrected when
+/// the `Date` of the server is further than `CLOCK_SKEW_THRESHOLD` from the local clock.
+pub const CLOCK_SKEW_ERROR_CODES: [&str; 3] = [
+    "RequestExpired",
+    "InvalidTimestamp",
+    "SignatureDoesNotMatch",
+];
+
+/// The smallest difference with the server time treated as a skew of the local clock.
+pub const CLOCK_SKEW_THRESHOLD: Duration = Duration::from_secs(60);
+
+/// The source of the signing time.
+///
+/// `Config` uses `SystemClock` unless another clock is set with `with_clock`, e.g. a
+/// `FixedClock` to reproduce signatures in tests.
+pub trait Clock: fmt::Debug + Send + Sync {
+    /// Returns the current time.
+    ///
+    /// # Returns
+    /// - `DateTime<Utc>`: The current time.
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// The clock of the host.
+#[derive(Debug, Default, Clone)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    /// Returns `Utc::now()`.
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// A clock stopped at a given time.
+#[derive(Debug, Clone)]
+pub struct FixedClock {
+    now: DateTime<Utc>, // The time always returned.
+}
+
+impl FixedClock {
+    /// Creates a clock always returning `now`.
+    ///
+    /// # Arguments
+    /// - `now`: The time to return.
+    ///
+    /// # Returns
+    /// - `FixedClock`: The clock.
+    pub fn new(now: DateTime<Utc>) -> Self {
+        FixedClock { now }
+    }
+}
+
+impl Clock for FixedClock {
+    /// Returns the fixed time.
+    fn now(&self) -> DateTime<Utc> {
+        self.now
+    }
+}
+
+/// The offset between the server time and the local clock.
+///
+/// One offset is shared by every service built from the same `Config`. It starts at zero and
+/// is updated when the server rejects a request whose `X-Date` is too far from its own time.
+#[derive(Debug, Default)]
+pub struct ClockSkew {
+    offset_millis: AtomicI64, // Server time minus local time, in milliseconds.
+}
+
+impl ClockSkew {
+    /// Returns the stored offset.
+    ///
+    /// # Returns
+    /// - `chrono::Duration`: The server time minus the local time.
+    pub fn offset(&self) -> chrono::Duration {
+        chrono::Duration::milliseconds(self.offset_millis.load(Ordering::Relaxed))
+    }
+
+    /// Stores a new offset.
+    ///
+    /// # Arguments
+    /// - `offset`: The server time minus the local time.
+    pub fn set_offset(&self, offset: chrono::Duration) {
+        self.offset_millis
+            .store(offset.num_milliseconds(), Ordering::Relaxed);
+    }
+
+    /// Corrects a local time with the stored offset.
+    ///
+    /// # Arguments
+    /// - `now`: A time read from the local clock.
+    ///
+    /// # Returns
+    /// - `DateTime<Utc>`: The estimated server time.
+    pub fn adjust(&self, now: DateTime<Utc>) -> DateTime<Utc> {
+        now + self.offset()
+    }
+}
+
+/// Measures the skew of the local clock from the `Date` header of a response.
+///
+/// # Arguments
+/// - `server_date`: The `Date` header, e.g. `Mon, 17 Mar 2025 08:00:00 GMT`.
+/// - `local_now`: The local time the response was received at.
+///
+/// # Returns
+/// - `Some(chrono::Duration)`: The server time minus the local time, if the header could be
+///   parsed and the difference reaches `CLOCK_SKEW_THRESHOLD`.
+/// - `None`: Otherwise.
+pub fn skew_from_server_date(
+    server_date: &str,
+    local_now: DateTime<Utc>,
+) -> Option<chrono::Duration> {
+    let server_now = DateTime::parse_from_rfc2822(server_date.trim()).ok()?;
+    let offset = server_now.with_timezone(&Utc) - local_now;
+    if offset.num_seconds().unsigned_abs() < CLOCK_SKEW_THRESHOLD.as_secs() {
+        return None;
+    }
+    Some(offset)
+}
+
+/// Tells whether an error code reports a request signed at the wrong time.
+///
+/// # Arguments
+/// - `code`: The code from `ResponseMetadata.Error.Code`.
+///
+/// # Returns
+/// - `bool`: `true` for the codes of `CLOCK_SKEW_ERROR_CODES`.
+pub fn is_clock_skew_error_code(code: &str) -> bool {
+    CLOCK_SKEW_ERROR_CODES.contains(&code)
+}
@@ -0,0 +1,11 @@
+/*
+ * @Author: Jerry.Yang
+ * @Date: 2025-03-17 10:06:33
+ * @LastEditors: Jerry.Yang
+ * @LastEditTime: 2025-03-17 10:06:33
+ * @Description: mod
+ */
+pub mod clock;
+
+// Test module for the clock.
+mod tests;
@@ -0,0 +1,158 @@
+/*
+ * @Author: Jerry.Yang
+ * @Date: 2025-03-17 15:12:08
+ * @LastEditors: Jerry.Yang
+ * @LastEditTime: 2025-03-29 20:20:00
+ * @Description: clock test
+ */
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use volcengine_sdk_protobuf::protobuf::rds_instance;
+
+    use crate::service::rds::{self, RdsService};
+    use crate::volcengine::clock::clock::{self, Clock};
+    use crate::volcengine::error::error;
+    use crate::volcengine::mock::mock;
+    use crate::volcengine::session::session;
+    use std::sync::Arc;
+
+    /// Returns an `Rds` calling the mock server with a clock fixed at 2025-03-17 08:00:00,
+    /// and the skew shared with its config.
+    fn rds_with_fixed_clock(server: &mock::MockServer) -> (rds::Rds, Arc<clock::ClockSkew>) {
+        let config = server
+            .config_builder()
+            .with_clock(clock::FixedClock::new(
+                Utc.with_ymd_and_hms(2025, 3, 17, 8, 0, 0).unwrap(),
+            ))
+            .build()
+            .unwrap();
+        let clock_skew = config.clock_skew.clone();
+        let session = session::Session::builder()
+            .with_config(config)
+            .build()
+            .unwrap();
+        (rds::Rds::new_rds(session).unwrap(), clock_skew)
+    }
+
+    /// Sends a `DescribeDBInstanceDetail` request.
+    async fn describe_db_instance_detail(
+        rds: &rds::Rds,
+    ) -> Result<rds_instance::DescribeDbInstanceDetailResp, error::Error> {
+        rds.new_describe_db_instance_detail(rds_instance::DescribeDbInstanceDetailReq {
+            instance_id: Some("mysql-1".to_string()),
+        })
+        .await
+    }
+
+    #[test]
+    fn test_fixed_clock() {
+        let now = Utc.with_ymd_and_hms(2025, 3, 17, 8, 0, 0).unwrap();
+        let fixed_clock = clock::FixedClock::new(now);
+        assert_eq!(fixed_clock.now(), now);
+        assert_eq!(fixed_clock.now(), now);
+    }
+
+    #[test]
+    fn test_clock_skew() {
+        let local_now = Utc.with_ymd_and_hms(2025, 3, 17, 8, 0, 0).unwrap();
+
+        // The server is ten minutes ahead.
+        let offset =
+            clock::skew_from_server_date("Mon, 17 Mar 2025 08:10:00 GMT", local_now).unwrap();
+        assert_eq!(offset, chrono::Duration::minutes(10));
+
+        let clock_skew = clock::ClockSkew::default();
+        assert_eq!(clock_skew.adjust(local_now), local_now);
+        clock_skew.set_offset(offset);
+        assert_eq!(
+            clock_skew.adjust(local_now),
+            Utc.with_ymd_and_hms(2025, 3, 17, 8, 10, 0).unwrap()
+        );
+
+        // Small differences and unreadable dates are not a skew.
+        assert!(clock::skew_from_server_date("Mon, 17 Mar 2025 08:00:30 GMT", local_now).is_none());
+        assert!(clock::skew_from_server_date("yesterday", local_now).is_none());
+    }
+
+    #[test]
+    fn test_is_clock_skew_error_code() {
+        assert!(clock::is_clock_skew_error_code("RequestExpired"));
+        assert!(clock::is_clock_skew_error_code("SignatureDoesNotMatch"));
+        assert!(!clock::is_clock_skew_error_code("Throttling"));
+    }
+
+    #[tokio::test]
+    async fn test_clock_skew_corrected() {
+        for code in clock::CLOCK_SKEW_ERROR_CODES {
+            // The server is ten minutes ahead and rejects the first signature.
+            let server = mock::MockServer::start().await;
+            server.mock(
+                "DescribeDBInstanceDetail",
+                "2022-01-01",
+                mock::MockResponse::error(403, code, "the request time is out of range")
+                    .with_header("Date", "Mon, 17 Mar 2025 08:10:00 GMT"),
+            );
+            server.mock(
+                "DescribeDBInstanceDetail",
+                "2022-01-01",
+                mock::MockResponse::json(&rds_instance::DescribeDbInstanceDetailResp::default()),
+            );
+
+            // Retries are disabled, the corrected attempt is sent anyway.
+            let (rds, clock_skew) = rds_with_fixed_clock(&server);
+            describe_db_instance_detail(&rds).await.unwrap();
+
+            assert_eq!(clock_skew.offset(), chrono::Duration::minutes(10), "{code}");
+            let requests = server.requests();
+            assert_eq!(requests.len(), 2, "{code}");
+            assert_eq!(requests[0].headers["X-Date"], "20250317T080000Z");
+            assert_eq!(requests[1].headers["X-Date"], "20250317T081000Z");
+
+            // Later requests are signed with the corrected time from the start.
+            describe_db_instance_detail(&rds).await.unwrap();
+            assert_eq!(server.requests()[2].headers["X-Date"], "20250317T081000Z");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_clock_skew_corrected_once() {
+        // The server keeps rejecting the signature.
+        let server = mock::MockServer::start().await;
+        server.mock(
+            "DescribeDBInstanceDetail",
+            "2022-01-01",
+            mock::MockResponse::error(403, "RequestExpired", "the request time is out of range")
+                .with_header("Date", "Mon, 17 Mar 2025 08:10:00 GMT"),
+        );
+
+        let (rds, clock_skew) = rds_with_fixed_clock(&server);
+        let result = describe_db_instance_detail(&rds).await;
+
+        assert!(matches!(
+            result,
+            Err(error::Error::Api { code, .. }) if code == "RequestExpired"
+        ));
+        assert_eq!(clock_skew.offset(), chrono::Duration::minutes(10));
+        assert_eq!(server.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_clock_skew_not_corrected_without_skew() {
+        // The signature is rejected while the clocks agree.
+        let server = mock::MockServer::start().await;
+        server.mock(
+            "DescribeDBInstanceDetail",
+            "2022-01-01",
+            mock::MockResponse::error(403, "SignatureDoesNotMatch", "the signature is invalid")
+                .with_header("Date", "Mon, 17 Mar 2025 08:00:10 GMT"),
+        );
+
+        let (rds, clock_skew) = rds_with_fixed_clock(&server);
+        let result = describe_db_instance_detail(&rds).await;
+
+        assert!(matches!(result, Err(error::Error::Api { .. })));
+        assert_eq!(clock_skew.offset(), chrono::Duration::zero());
+        server.single_request("DescribeDBInstanceDetail", "rds_mysql");
+    }
+}
@@ -2,44 +2,86 @@
  * @Author: Jerry.Yang
  * @Date: 2024-10-16 17:41:31
  * @LastEditors: Jerry.Yang
- * @LastEditTime: 2025-02-05 14:08:39
+ * @LastEditTime: 2025-03-30 11:30:00
  * @Description: Utility configuration for Volcengine.
  */
 
+use crate::volcengine::client::config as client_config; // Importing the service names
+use crate::volcengine::clock::clock; // Importing the signing clock
+use crate::volcengine::credentials::chain_provider; // Importing the default credentials chain
 use crate::volcengine::credentials::credentials; // Importing the Credentials struct
+use crate::volcengine::credentials::provider; // Importing the CredentialsProvider trait
+use crate::volcengine::endpoint::endpoint; // Importing the URL scheme
+use crate::volcengine::endpoint::endpoint_resolver; // Importing the endpoint resolver
 use crate::volcengine::error::error; // Importing the custom Error type
+use crate::volcengine::retry::retry; // Importing the retry policy
+use crate::volcengine::transport::transport; // Importing the HTTP transport settings
+use std::collections::HashMap;
+use std::sync::Arc;
 
 /// Represents the configuration settings required for connecting to Volcengine services.
 ///
 /// The `Config` struct holds essential parameters such as the service region, endpoint,
-/// SSL settings, and authentication credentials. It is designed to be used with a builder
+/// URL scheme, and authentication credentials. It is designed to be used with a builder
 /// pattern (`ConfigBuilder`) to allow flexible and structured configuration.
 ///
 /// # Fields
 /// - `region` (`String`): Specifies the geographical region for the service.
 /// - `endpoint` (`String`): The base URL of the API service. If manually set, this overrides
-///   the default endpoint.
-/// - `disable_ssl` (`bool`): Determines whether SSL/TLS is disabled.
-///   - `true`: SSL is disabled (not recommended for production).
-///   - `false`: SSL is enabled for secure communication.
-/// - `credentials` (`credentials::Credentials`): Stores authentication credentials,
-///   including access keys and secret keys, required for making API requests.
+///   the default endpoint of every service.
+/// - `service_endpoints` (`HashMap<ClientServiceName, String>`): Endpoints overriding the
+///   default endpoint of a single service.
+/// - `endpoint_resolver` (`Arc<dyn endpoint_resolver::EndpointResolver>`): Resolves the endpoint
+///   of the services without override from the service and the region.
+/// - `scheme` (`endpoint::Scheme`): The scheme added to endpoints given without one.
+///   - `Scheme::Https` (default): Secure communication over TLS.
+///   - `Scheme::Http`: Plain text, for local proxies and test servers only.
+/// - `credentials_provider` (`Arc<dyn provider::CredentialsProvider>`): The source of the
+///   authentication credentials. It is asked for credentials lazily, before each request is signed.
+/// - `transport_config` (`transport::TransportConfig`): HTTP client settings such as timeouts,
+///   proxy, extra root certificates and user agent.
+/// - `retry_policy` (`retry::RetryPolicy`): When and how often throttled or failed requests are retried.
+/// - `clock` (`Arc<dyn clock::Clock>`): The source of the signing time.
+/// - `clock_skew` (`Arc<clock::ClockSkew>`): The offset of the server time, learnt from responses
+///   rejecting a request for its `X-Date` and shared by every service of the configuration.
 ///
 /// # Example
 /// ```rust
+/// # use std::collections::HashMap;
+/// # use std::sync::Arc;
+/// # use volcengine_rust_sdk::volcengine::clock::clock;
+/// # use volcengine_rust_sdk::volcengine::credentials::{credentials, provider};
+/// # use volcengine_rust_sdk::volcengine::endpoint::{endpoint, endpoint_resolver};
+/// # use volcengine_rust_sdk::volcengine::retry::retry;
+/// # use volcengine_rust_sdk::volcengine::transport::transport;
+/// use volcengine_rust_sdk::volcengine::config::Config;
+///
+/// # let credentials = credentials::Credentials::new("access_key", "secret_key");
 /// let config = Config {
 ///     region: "us-west-2".to_string(),
 ///     endpoint: "https://api.volcengine.com".to_string(),
-///     disable_ssl: false,
-///     credentials: credentials::Credentials::new("access_key", "secret_key"),
+///     service_endpoints: HashMap::new(),
+///     endpoint_resolver: Arc::new(endpoint_resolver::DefaultEndpointResolver::default()),
+///     scheme: endpoint::Scheme::Https,
+///     credentials_provider: Arc::new(provider::StaticProvider::new(credentials)),
+///     transport_config: transport::TransportConfig::default(),
+///     retry_policy: retry::RetryPolicy::default(),
+///     clock: Arc::new(clock::SystemClock),
+///     clock_skew: Arc::new(clock::ClockSkew::default()),
 /// };
 /// ```
 #[derive(Debug, Clone)]
 pub struct Config {
-    pub region: String,    // The geographical region where the service is deployed.
-    pub endpoint: String,  // The API endpoint for the service.
-    pub disable_ssl: bool, // Indicates whether SSL/TLS is disabled.
-    pub credentials: credentials::Credentials, // Authentication credentials for accessing the service.
+    pub region: String,   // The geographical region where the service is deployed.
+    pub endpoint: String, // The API endpoint for the service.
+    pub service_endpoints: HashMap<client_config::ClientServiceName, String>, // Per-service endpoint overrides.
+    pub endpoint_resolver: Arc<dyn endpoint_resolver::EndpointResolver>, // Resolver of the default endpoints.
+    pub scheme: endpoint::Scheme, // The scheme added to endpoints given without one.
+    pub credentials_provider: Arc<dyn provider::CredentialsProvider>, // Source of the authentication credentials.
+    pub transport_config: transport::TransportConfig, // HTTP client settings shared by all services.
+    pub retry_policy: retry::RetryPolicy,             // Retry policy applied to every request.
+    pub clock: Arc<dyn clock::Clock>,                 // Source of the signing time.
+    pub clock_skew: Arc<clock::ClockSkew>,            // Offset of the server time.
 }
 
 /// Implementation of the `Config` struct, providing utility methods  
@@ -53,7 +95,7 @@ pub struct Config {
 ///   allowing users to set configuration properties before building a `Config` object.  
 ///  
 /// The `Config` struct encapsulates essential configuration parameters such as  
-/// service region, endpoint URL, SSL settings, and authentication credentials.  
+/// service region, endpoint URL, URL scheme, and authentication credentials.  
 /// This implementation ensures that configuration objects are created in  
 /// a structured and validated manner.  
 impl Config {
@@ -64,6 +106,7 @@ impl Config {
     ///
     /// # Example
     /// ```rust
+    /// # use volcengine_rust_sdk::volcengine::config::Config;
     /// let builder = Config::builder();
     /// ```
     pub fn builder() -> ConfigBuilder {
@@ -79,13 +122,27 @@ impl Config {
 /// # Fields
 /// - `region` (`Option<String>`): Optional region value.
 /// - `endpoint` (`Option<String>`): Optional endpoint value.
-/// - `disable_ssl` (`Option<bool>`): Optional SSL flag.
-/// - `credentials` (`Option<credentials::Credentials>`): Optional authentication credentials.
+/// - `service_endpoints` (`HashMap<ClientServiceName, String>`): Per-service endpoint overrides.
+/// - `endpoint_resolver` (`Option<Arc<dyn endpoint_resolver::EndpointResolver>>`): Optional endpoint resolver.
+/// - `endpoint_variant` (`Option<endpoint_resolver::EndpointVariant>`): Optional variant of the built-in resolver.
+/// - `scheme` (`Option<endpoint::Scheme>`): Optional URL scheme.
+/// - `credentials` (`Option<credentials::Credentials>`): Optional static authentication credentials.
+/// - `credentials_provider` (`Option<Arc<dyn provider::CredentialsProvider>>`): Optional credentials provider.
+/// - `transport_config` (`Option<transport::TransportConfig>`): Optional HTTP client settings.
+/// - `retry_policy` (`Option<retry::RetryPolicy>`): Optional retry policy.
+/// - `clock` (`Option<Arc<dyn clock::Clock>>`): Optional signing clock.
 pub struct ConfigBuilder {
-    region: Option<String>,                        // Optional region value.
-    endpoint: Option<String>,                      // Optional endpoint value.
-    disable_ssl: Option<bool>,                     // Optional SSL flag.
-    credentials: Option<credentials::Credentials>, // Optional credentials.
+    region: Option<String>,   // Optional region value.
+    endpoint: Option<String>, // Optional endpoint value.
+    service_endpoints: HashMap<client_config::ClientServiceName, String>, // Per-service endpoint overrides.
+    endpoint_resolver: Option<Arc<dyn endpoint_resolver::EndpointResolver>>, // Optional endpoint resolver.
+    endpoint_variant: Option<endpoint_resolver::EndpointVariant>, // Optional variant of the built-in resolver.
+    scheme: Option<endpoint::Scheme>,                             // Optional URL scheme.
+    credentials: Option<credentials::Credentials>,                // Optional credentials.
+    credentials_provider: Option<Arc<dyn provider::CredentialsProvider>>, // Optional credentials provider.
+    transport_config: Option<transport::TransportConfig>, // Optional HTTP client settings.
+    retry_policy: Option<retry::RetryPolicy>,             // Optional retry policy.
+    clock: Option<Arc<dyn clock::Clock>>,                 // Optional signing clock.
 }
 
 /// Provides a default implementation for `ConfigBuilder`,  
@@ -98,18 +155,32 @@ pub struct ConfigBuilder {
 /// ## Default Values  
 /// - `region`: `None` (must be provided if required for the configuration)  
 /// - `endpoint`: `None` (must be provided if required for the configuration)  
-/// - `disable_ssl`: `None` (defaults to `false` if not explicitly set)  
-/// - `credentials`: `None` (mandatory; `build()` will return an error if not provided)  
+/// - `service_endpoints`: empty (no service overrides its endpoint)  
+/// - `endpoint_resolver`: `None` (defaults to `DefaultEndpointResolver`)  
+/// - `endpoint_variant`: `None` (defaults to `EndpointVariant::Global`)  
+/// - `scheme`: `None` (defaults to `Scheme::Https` if not explicitly set)  
+/// - `credentials`: `None` (optional; used first by the default credentials chain)  
+/// - `credentials_provider`: `None` (defaults to the default credentials chain)  
+/// - `transport_config`: `None` (defaults to `TransportConfig::default()`)  
+/// - `retry_policy`: `None` (defaults to `RetryPolicy::default()`)  
+/// - `clock`: `None` (defaults to `SystemClock`)  
 ///  
 /// Using the builder pattern with a default constructor allows flexibility  
 /// while enforcing validation rules in the `build()` method.  
 impl Default for ConfigBuilder {
     fn default() -> Self {
         Self {
-            region: None,      // Default region is None.
-            endpoint: None,    // Default endpoint is None.
-            disable_ssl: None, // Default SSL flag is None.
-            credentials: None, // Default credentials are None.
+            region: None,                      // Default region is None.
+            endpoint: None,                    // Default endpoint is None.
+            service_endpoints: HashMap::new(), // No per-service endpoint by default.
+            endpoint_resolver: None,           // Default endpoint resolver is None.
+            endpoint_variant: None,            // Default endpoint variant is None.
+            scheme: None,                      // Default URL scheme is None.
+            credentials: None,                 // Default credentials are None.
+            credentials_provider: None,        // Default credentials provider is None.
+            transport_config: None,            // Default HTTP client settings are None.
+            retry_policy: None,                // Default retry policy is None.
+            clock: None,                       // Default signing clock is None.
         }
     }
 }
@@ -122,17 +193,20 @@ impl Default for ConfigBuilder {
 /// that required fields are validated before the final `Config` instance is created.  
 ///  
 /// ## Example Usage  
-/// ```rust  
-/// use crate::volcengine::config::Config;  
-///  
-/// let config = Config::builder()  
-///     .with_region("us-east-1")  
-///     .with_endpoint("https://example.com")  
-///     .with_disable_ssl(true)  
-///     .with_credentials(credentials)  
-///     .build()  
-///     .expect("Failed to build config");  
-/// ```  
+/// ```rust
+/// use volcengine_rust_sdk::volcengine::config::Config;
+/// use volcengine_rust_sdk::volcengine::credentials::credentials;
+/// use volcengine_rust_sdk::volcengine::endpoint::endpoint;
+///
+/// # let credentials = credentials::Credentials::new("access_key", "secret_key");
+/// let config = Config::builder()
+///     .with_region("us-east-1")
+///     .with_endpoint("https://example.com")
+///     .with_scheme(endpoint::Scheme::Https)
+///     .with_credentials(credentials)
+///     .build()
+///     .expect("Failed to build config");
+/// ```
 ///  
 /// Each setter method in `ConfigBuilder` accepts an argument, modifies the corresponding  
 /// field, and returns `self` to allow method chaining. The `build` method performs final  
@@ -162,19 +236,94 @@ impl ConfigBuilder {
         self
     }
 
-    /// Enables or disables SSL in the configuration.
+    /// Overrides the endpoint of a single service.
+    ///
+    /// The other services keep the endpoint given by `with_endpoint` or the resolver.
+    ///
+    /// # Arguments
+    /// - `service_name` (`ClientServiceName`): The service, e.g. `ClientServiceName::Ecs`.
+    /// - `endpoint` (`&str`): The endpoint of that service, e.g. `ecs.cn-beijing.ivolces.com`.
+    ///
+    /// # Returns
+    /// - `Self`: The updated `ConfigBuilder` instance with the service endpoint.
+    pub fn with_service_endpoint(
+        mut self,
+        service_name: client_config::ClientServiceName,
+        endpoint: &str,
+    ) -> Self {
+        self.service_endpoints
+            .insert(service_name, endpoint.to_string()); // Replace any previous override.
+        self
+    }
+
+    /// Sets the resolver of the default endpoints.
+    ///
+    /// # Arguments
+    /// - `endpoint_resolver` (`impl endpoint_resolver::EndpointResolver`): The resolver.
+    ///
+    /// # Returns
+    /// - `Self`: The updated `ConfigBuilder` instance with the specified resolver.
+    pub fn with_endpoint_resolver(
+        mut self,
+        endpoint_resolver: impl endpoint_resolver::EndpointResolver + 'static,
+    ) -> Self {
+        self.endpoint_resolver = Some(Arc::new(endpoint_resolver)); // Store the resolver.
+        self
+    }
+
+    /// Selects the family of hosts of the built-in resolver.
+    ///
+    /// Ignored when a resolver is set with `with_endpoint_resolver`.
+    ///
+    /// # Arguments
+    /// - `endpoint_variant` (`endpoint_resolver::EndpointVariant`): `Global` (default),
+    ///   `Regional`, `DualStack` or `VpcInternal`.
+    ///
+    /// # Returns
+    /// - `Self`: The updated `ConfigBuilder` instance with the specified variant.
+    pub fn with_endpoint_variant(
+        mut self,
+        endpoint_variant: endpoint_resolver::EndpointVariant,
+    ) -> Self {
+        self.endpoint_variant = Some(endpoint_variant); // Store the variant.
+        self
+    }
+
+    /// Sets the scheme added to endpoints given without one.
+    ///
+    /// An endpoint set with `with_endpoint` that already starts with `http://` or
+    /// `https://` keeps its own scheme.
     ///
     /// # Arguments
-    /// - `disable_ssl` (`bool`): Set to `true` to disable SSL, or `false` to enable it.
+    /// - `scheme` (`endpoint::Scheme`): `Scheme::Https` (default) or `Scheme::Http`.
     ///
     /// # Returns
-    /// - `Self`: The updated `ConfigBuilder` instance with the specified SSL setting.
-    pub fn with_disable_ssl(mut self, disable_ssl: bool) -> Self {
-        self.disable_ssl = Some(disable_ssl); // Store the SSL flag as an `Option<bool>`.
+    /// - `Self`: The updated `ConfigBuilder` instance with the specified scheme.
+    pub fn with_scheme(mut self, scheme: endpoint::Scheme) -> Self {
+        self.scheme = Some(scheme); // Store the scheme as an `Option<Scheme>`.
         self
     }
 
-    /// Sets the credentials for the configuration.
+    /// Enables or disables SSL in the configuration.
+    ///
+    /// # Arguments
+    /// - `disable_ssl` (`bool`): `true` selects `Scheme::Http`, `false` selects `Scheme::Https`.
+    ///
+    /// # Returns
+    /// - `Self`: The updated `ConfigBuilder` instance with the specified scheme.
+    #[deprecated(note = "use `with_scheme` instead")]
+    pub fn with_disable_ssl(self, disable_ssl: bool) -> Self {
+        match disable_ssl {
+            true => self.with_scheme(endpoint::Scheme::Http),
+            false => self.with_scheme(endpoint::Scheme::Https),
+        }
+    }
+
+    /// Sets the static credentials for the configuration.
+    ///
+    /// The keys are the first link of the default credentials chain: when set, they are used
+    /// for every request and the environment variables and the shared profile file are never
+    /// consulted. They are ignored when `with_credentials_provider` sets a provider.
     ///
     /// # Arguments
     /// - `credentials` (`credentials::Credentials`): Authentication credentials.
@@ -186,21 +335,85 @@ impl ConfigBuilder {
         self
     }
 
+    /// Sets the credentials provider for the configuration, replacing the default chain.
+    ///
+    /// # Arguments
+    /// - `credentials_provider` (`impl provider::CredentialsProvider`): The source of the credentials.
+    ///
+    /// # Returns
+    /// - `Self`: The updated `ConfigBuilder` instance with the specified credentials provider.
+    pub fn with_credentials_provider(
+        mut self,
+        credentials_provider: impl provider::CredentialsProvider + 'static,
+    ) -> Self {
+        self.credentials_provider = Some(Arc::new(credentials_provider)); // Store the provider.
+        self
+    }
+
+    /// Sets the HTTP transport settings for the configuration.
+    ///
+    /// The settings are used once, when the `Session` is built, to create the pooled
+    /// HTTP client shared by every service of that session.
+    ///
+    /// # Arguments
+    /// - `transport_config` (`transport::TransportConfig`): Timeouts, proxy, root CAs and user agent.
+    ///
+    /// # Returns
+    /// - `Self`: The updated `ConfigBuilder` instance with the specified transport settings.
+    pub fn with_transport_config(mut self, transport_config: transport::TransportConfig) -> Self {
+        self.transport_config = Some(transport_config); // Store the transport settings.
+        self
+    }
+
+    /// Sets the retry policy for the configuration.
+    ///
+    /// The policy controls how throttled (HTTP 429, `Throttling`, `RequestLimitExceeded`, ...)
+    /// and failed (connection errors, HTTP 5xx) requests are retried.
+    /// Use `retry::RetryPolicy::disabled()` to send every request exactly once.
+    ///
+    /// # Arguments
+    /// - `retry_policy` (`retry::RetryPolicy`): The retry policy.
+    ///
+    /// # Returns
+    /// - `Self`: The updated `ConfigBuilder` instance with the specified retry policy.
+    pub fn with_retry_policy(mut self, retry_policy: retry::RetryPolicy) -> Self {
+        self.retry_policy = Some(retry_policy); // Store the retry policy.
+        self
+    }
+
+    /// Sets the source of the signing time.
+    ///
+    /// The time of the clock is corrected by the skew learnt from the server, if any.
+    ///
+    /// # Arguments
+    /// - `clock` (`impl clock::Clock`): The clock, e.g. `clock::FixedClock` in tests.
+    ///
+    /// # Returns
+    /// - `Self`: The updated `ConfigBuilder` instance with the specified clock.
+    pub fn with_clock(mut self, clock: impl clock::Clock + 'static) -> Self {
+        self.clock = Some(Arc::new(clock)); // Store the clock.
+        self
+    }
+
     /// Builds the final `Config` object.
     ///
-    /// This method ensures that all required fields (such as credentials) are provided before
-    /// constructing the `Config` instance. If any required field is missing, an error is returned.
+    /// Credentials are not resolved here: when no provider is set, the default chain
+    /// (environment variables, shared profile file, then the static credentials) is
+    /// consulted lazily, before each request is signed.
     ///
     /// # Returns
     /// - `Ok(Config)`: The successfully built `Config` instance.
-    /// - `Err(error::Error)`: Returns an error if required fields (e.g., credentials) are missing.
+    /// - `Err(error::Error)`: Reserved for invalid settings.
     ///
     /// # Example
     /// ```rust
+    /// # use volcengine_rust_sdk::volcengine::config::Config;
+    /// # use volcengine_rust_sdk::volcengine::credentials::credentials;
+    /// # use volcengine_rust_sdk::volcengine::endpoint::endpoint;
     /// let config = Config::builder()
     ///     .with_region("us-west-2")
     ///     .with_endpoint("https://api.volcengine.com")
-    ///     .with_disable_ssl(false)
+    ///     .with_scheme(endpoint::Scheme::Https)
     ///     .with_credentials(credentials::Credentials::new("access_key", "secret_key"))
     ///     .build();
     ///
@@ -210,17 +423,32 @@ impl ConfigBuilder {
     /// }
     /// ```
     pub fn build(self) -> Result<Config, error::Error> {
-        // Ensure that credentials are provided; return an error if missing.
-        if self.credentials.is_none() {
-            return Err(error::Error::ErrUtilConfigBuildConfigNoCredentials);
-        }
+        // Use the configured provider, falling back to the default chain.
+        let credentials_provider = self.credentials_provider.unwrap_or_else(|| {
+            Arc::new(chain_provider::ChainProvider::default_chain(
+                self.credentials,
+            ))
+        });
+
+        // Use the configured resolver, falling back to the built-in table.
+        let endpoint_resolver = self.endpoint_resolver.unwrap_or_else(|| {
+            Arc::new(endpoint_resolver::DefaultEndpointResolver::new(
+                self.endpoint_variant.unwrap_or_default(),
+            ))
+        });
 
         // Construct and return a `Config` instance, using default values where necessary.
         Ok(Config {
             region: self.region.unwrap_or_default(), // Defaults to an empty string if region is not set.
             endpoint: self.endpoint.unwrap_or_default(), // Defaults to an empty string if endpoint is not set.
-            disable_ssl: self.disable_ssl.unwrap_or_default(), // Defaults to `false` if not set.
-            credentials: self.credentials.unwrap(), // Credentials are required and safely unwrapped.
+            service_endpoints: self.service_endpoints,   // Per-service endpoint overrides.
+            endpoint_resolver, // Resolves the endpoints of the other services.
+            scheme: self.scheme.unwrap_or_default(), // Defaults to `Scheme::Https` if not set.
+            credentials_provider, // Resolved lazily before each request is signed.
+            transport_config: self.transport_config.unwrap_or_default(), // Defaults to reqwest defaults.
+            retry_policy: self.retry_policy.unwrap_or_default(), // Defaults to 3 attempts with full jitter.
+            clock: self.clock.unwrap_or_else(|| Arc::new(clock::SystemClock)), // Defaults to the host clock.
+            clock_skew: Arc::new(clock::ClockSkew::default()), // No skew until the server reports one.
         })
     }
 }
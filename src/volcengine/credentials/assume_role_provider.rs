@@ -0,0 +1,292 @@
+/*
+ * @Author: Jerry.Yang
+ * @Date: 2025-03-10 11:20:37
+ * @LastEditors: Jerry.Yang
+ * @LastEditTime: 2025-03-30 11:10:00
+ * @Description: assume role credentials provider
+ */
+use crate::service::sts;
+use crate::service::sts::api_assume_role_model;
+use crate::service::sts::StsService;
+use crate::volcengine::clock::clock;
+use crate::volcengine::credentials::credentials;
+use crate::volcengine::credentials::provider;
+use crate::volcengine::error::error;
+use crate::volcengine::session::session;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Mutex;
+
+/// The default validity requested for the temporary credentials.
+pub const DEFAULT_DURATION_SECONDS: i32 = 3600;
+
+/// The default margin before expiry at which the credentials are renewed.
+pub const DEFAULT_REFRESH_WINDOW: Duration = Duration::from_secs(300);
+
+/// Temporary credentials together with their expiry.
+#[derive(Debug, Clone)]
+struct CachedCredentials {
+    credentials: credentials::Credentials, // The temporary credentials.
+    expires_at: DateTime<Utc>,             // When the credentials expire.
+}
+
+/// A provider assuming a role through STS and caching the temporary credentials.
+///
+/// The first call assumes the role; later calls return the cached credentials until they
+/// are within `refresh_window` of their expiry, at which point the role is assumed again.
+/// Concurrent callers wait for a single refresh instead of each calling STS.
+///
+/// The STS calls are signed with the credentials of the `Session` given to the builder,
+/// typically the keys of a sub-user allowed to assume the role.
+///
+/// # Example
+/// ```rust,no_run
+/// # use volcengine_rust_sdk::volcengine::error::error;
+/// use volcengine_rust_sdk::volcengine::config::Config;
+/// use volcengine_rust_sdk::volcengine::credentials::assume_role_provider::AssumeRoleCredentialsProvider;
+/// use volcengine_rust_sdk::volcengine::session::session::Session;
+///
+/// # fn example(base_config: Config) -> Result<(), error::Error> {
+/// let base_session = Session::builder().with_config(base_config).build()?;
+/// let provider = AssumeRoleCredentialsProvider::builder()
+///     .with_session(base_session)
+///     .with_role_trn("trn:iam::2100000000:role/ops")
+///     .with_role_session_name("batch-job")
+///     .build()?;
+/// let config = Config::builder()
+///     .with_region("cn-beijing")
+///     .with_credentials_provider(provider)
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct AssumeRoleCredentialsProvider {
+    sts: sts::Sts,                                // STS client used to assume the role.
+    role_trn: String,                             // TRN of the role to assume.
+    role_session_name: String,                    // Name of the role session.
+    duration_seconds: i32,                        // Requested validity in seconds.
+    policy: Option<String>,                       // Optional inline session policy.
+    refresh_window: Duration,                     // Renew this long before expiry.
+    clock: Arc<dyn clock::Clock>,                 // Clock compared with the expiry.
+    cache: Arc<Mutex<Option<CachedCredentials>>>, // Cached temporary credentials.
+}
+
+impl AssumeRoleCredentialsProvider {
+    /// Creates a new `AssumeRoleCredentialsProviderBuilder`.
+    ///
+    /// # Returns
+    /// - `AssumeRoleCredentialsProviderBuilder`: A builder used to configure the provider.
+    pub fn builder() -> AssumeRoleCredentialsProviderBuilder {
+        AssumeRoleCredentialsProviderBuilder {
+            session: None,
+            role_trn: None,
+            role_session_name: None,
+            duration_seconds: None,
+            policy: None,
+            refresh_window: None,
+            clock: None,
+        }
+    }
+
+    /// Returns the cached credentials, assuming the role again when they are about to expire.
+    ///
+    /// # Returns
+    /// - `Ok(Credentials)`: Valid temporary credentials.
+    /// - `Err(error::Error)`: If the role cannot be assumed.
+    async fn load(&self) -> Result<credentials::Credentials, error::Error> {
+        // Hold the lock while refreshing so that concurrent callers share one STS call.
+        let mut cache = self.cache.lock().await;
+
+        // Serve the cached credentials while they are far enough from expiry.
+        let refresh_window = chrono::Duration::from_std(self.refresh_window)
+            .unwrap_or_else(|_| chrono::Duration::zero());
+        if let Some(cached) = cache.as_ref() {
+            if self.clock.now() + refresh_window < cached.expires_at {
+                return Ok(cached.credentials.clone());
+            }
+        }
+
+        // Assume the role to obtain new credentials.
+        let cached = self.assume_role().await?;
+        let credentials = cached.credentials.clone();
+        *cache = Some(cached);
+        Ok(credentials)
+    }
+
+    /// Calls `AssumeRole` and converts the result into cached credentials.
+    ///
+    /// # Returns
+    /// - `Ok(CachedCredentials)`: The temporary credentials and their expiry.
+    /// - `Err(error::Error)`: If the call fails or returns no credentials.
+    async fn assume_role(&self) -> Result<CachedCredentials, error::Error> {
+        let request = api_assume_role_model::AssumeRoleReq {
+            role_trn: self.role_trn.clone(),
+            role_session_name: self.role_session_name.clone(),
+            duration_seconds: Some(self.duration_seconds),
+            policy: self.policy.clone(),
+        };
+        let requested_at = self.clock.now();
+        let response = self.sts.new_assume_role(request).await?;
+
+        // An answer without credentials cannot be used to sign requests.
+        let result = response.result.ok_or_else(|| {
+            error::Error::ErrCredentialsNotFound(format!(
+                "AssumeRole {} returned no credentials",
+                self.role_trn
+            ))
+        })?;
+        let assumed = result.credentials;
+
+        // Use the expiry reported by STS, or the requested duration when it cannot be parsed.
+        let expires_at = DateTime::parse_from_rfc3339(&assumed.expired_time)
+            .map(|expired_time| expired_time.with_timezone(&Utc))
+            .unwrap_or_else(|_| {
+                requested_at + chrono::Duration::seconds(self.duration_seconds.into())
+            });
+
+        Ok(CachedCredentials {
+            credentials: credentials::Credentials::new(
+                &assumed.access_key_id,
+                &assumed.secret_access_key,
+            )
+            .with_session_token(&assumed.session_token),
+            expires_at,
+        })
+    }
+}
+
+impl provider::CredentialsProvider for AssumeRoleCredentialsProvider {
+    /// Returns temporary credentials of the role, renewing them before they expire.
+    fn provide_credentials(&self) -> provider::ProvideCredentialsFuture<'_> {
+        Box::pin(self.load())
+    }
+}
+
+/// A builder for constructing an `AssumeRoleCredentialsProvider`.
+pub struct AssumeRoleCredentialsProviderBuilder {
+    session: Option<session::Session>,    // Session used to call STS.
+    role_trn: Option<String>,             // TRN of the role to assume.
+    role_session_name: Option<String>,    // Name of the role session.
+    duration_seconds: Option<i32>,        // Requested validity in seconds.
+    policy: Option<String>,               // Optional inline session policy.
+    refresh_window: Option<Duration>,     // Renew this long before expiry.
+    clock: Option<Arc<dyn clock::Clock>>, // Clock compared with the expiry.
+}
+
+impl AssumeRoleCredentialsProviderBuilder {
+    /// Sets the session used to call STS.
+    ///
+    /// # Arguments
+    /// - `session` (`session::Session`): A session holding the credentials allowed to assume the role.
+    ///
+    /// # Returns
+    /// - `Self`: The updated builder.
+    pub fn with_session(mut self, session: session::Session) -> Self {
+        self.session = Some(session);
+        self
+    }
+
+    /// Sets the role to assume.
+    ///
+    /// # Arguments
+    /// - `role_trn` (`&str`): The TRN of the role, e.g. `trn:iam::2100000000:role/ops`.
+    ///
+    /// # Returns
+    /// - `Self`: The updated builder.
+    pub fn with_role_trn(mut self, role_trn: &str) -> Self {
+        self.role_trn = Some(role_trn.to_string());
+        self
+    }
+
+    /// Sets the name of the role session.
+    ///
+    /// # Arguments
+    /// - `role_session_name` (`&str`): A name identifying the session in audit logs.
+    ///
+    /// # Returns
+    /// - `Self`: The updated builder.
+    pub fn with_role_session_name(mut self, role_session_name: &str) -> Self {
+        self.role_session_name = Some(role_session_name.to_string());
+        self
+    }
+
+    /// Sets the requested validity of the temporary credentials.
+    ///
+    /// # Arguments
+    /// - `duration_seconds` (`i32`): The validity in seconds, 3600 by default.
+    ///
+    /// # Returns
+    /// - `Self`: The updated builder.
+    pub fn with_duration_seconds(mut self, duration_seconds: i32) -> Self {
+        self.duration_seconds = Some(duration_seconds);
+        self
+    }
+
+    /// Restricts the permissions of the session with an inline policy.
+    ///
+    /// # Arguments
+    /// - `policy` (`&str`): The policy document.
+    ///
+    /// # Returns
+    /// - `Self`: The updated builder.
+    pub fn with_policy(mut self, policy: &str) -> Self {
+        self.policy = Some(policy.to_string());
+        self
+    }
+
+    /// Sets how long before expiry the credentials are renewed.
+    ///
+    /// # Arguments
+    /// - `refresh_window` (`Duration`): The renewal margin, 5 minutes by default.
+    ///
+    /// # Returns
+    /// - `Self`: The updated builder.
+    pub fn with_refresh_window(mut self, refresh_window: Duration) -> Self {
+        self.refresh_window = Some(refresh_window);
+        self
+    }
+
+    /// Sets the clock telling when the credentials are due for renewal.
+    ///
+    /// # Arguments
+    /// - `clock` (`impl clock::Clock`): The clock, `clock::SystemClock` by default, e.g.
+    ///   `clock::FixedClock` in tests.
+    ///
+    /// # Returns
+    /// - `Self`: The updated builder.
+    pub fn with_clock(mut self, clock: impl clock::Clock + 'static) -> Self {
+        self.clock = Some(Arc::new(clock));
+        self
+    }
+
+    /// Builds the provider.
+    ///
+    /// # Returns
+    /// - `Ok(AssumeRoleCredentialsProvider)`: The provider.
+    /// - `Err(error::Error)`: If the session, role TRN or session name is missing, or the STS
+    ///   client cannot be created.
+    pub fn build(self) -> Result<AssumeRoleCredentialsProvider, error::Error> {
+        let session = self
+            .session
+            .ok_or_else(|| error::Error::ErrCredentialsBuildProviderNo("session".to_string()))?;
+        let role_trn = self
+            .role_trn
+            .ok_or_else(|| error::Error::ErrCredentialsBuildProviderNo("role_trn".to_string()))?;
+        let role_session_name = self.role_session_name.ok_or_else(|| {
+            error::Error::ErrCredentialsBuildProviderNo("role_session_name".to_string())
+        })?;
+
+        Ok(AssumeRoleCredentialsProvider {
+            sts: sts::Sts::new_sts(session)?,
+            role_trn,
+            role_session_name,
+            duration_seconds: self.duration_seconds.unwrap_or(DEFAULT_DURATION_SECONDS),
+            policy: self.policy,
+            refresh_window: self.refresh_window.unwrap_or(DEFAULT_REFRESH_WINDOW),
+            clock: self.clock.unwrap_or_else(|| Arc::new(clock::SystemClock)),
+            cache: Arc::new(Mutex::new(None)),
+        })
+    }
+}
@@ -0,0 +1,113 @@
+/*
+ * @Author: Jerry.Yang
+ * @Date: 2025-03-07 10:36:52
+ * @LastEditors: Jerry.Yang
+ * @LastEditTime: 2025-03-30 11:30:00
+ * @Description: chain of credentials providers
+ */
+use crate::volcengine::credentials::credentials;
+use crate::volcengine::credentials::env_provider;
+use crate::volcengine::credentials::profile_provider;
+use crate::volcengine::credentials::provider;
+use crate::volcengine::error::error;
+use std::sync::Arc;
+
+/// A provider trying several providers in order and returning the first credentials found.
+///
+/// The default chain, used by `Config` when no provider is set, is:
+///
+/// 1. `StaticProvider`: the keys passed to `ConfigBuilder::with_credentials`, if any;
+/// 2. `EnvProvider`: `VOLCENGINE_ACCESS_KEY`, `VOLCENGINE_SECRET_KEY`, `VOLCENGINE_SESSION_TOKEN`;
+/// 3. `ProfileProvider`: the shared profile file `~/.volcengine/config`.
+///
+/// Keys set explicitly in code therefore always win over the environment and the profile file.
+///
+/// # Example
+/// ```rust,no_run
+/// # use volcengine_rust_sdk::volcengine::error::error;
+/// use volcengine_rust_sdk::volcengine::config::Config;
+/// use volcengine_rust_sdk::volcengine::credentials::chain_provider::ChainProvider;
+/// use volcengine_rust_sdk::volcengine::credentials::env_provider::EnvProvider;
+/// use volcengine_rust_sdk::volcengine::credentials::profile_provider::ProfileProvider;
+///
+/// # fn example() -> Result<(), error::Error> {
+/// let chain = ChainProvider::new()
+///     .with_provider(EnvProvider::new())
+///     .with_provider(ProfileProvider::new().with_profile("staging"));
+/// let config = Config::builder().with_credentials_provider(chain).build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ChainProvider {
+    providers: Vec<Arc<dyn provider::CredentialsProvider>>, // Providers in lookup order.
+}
+
+impl ChainProvider {
+    /// Creates an empty chain.
+    ///
+    /// # Returns
+    /// - `ChainProvider`: The chain.
+    pub fn new() -> Self {
+        ChainProvider::default()
+    }
+
+    /// Creates the default chain: the optional static keys, environment, then profile file.
+    ///
+    /// # Arguments
+    /// - `static_credentials`: Keys used before the environment and the profile file are
+    ///   looked at.
+    ///
+    /// # Returns
+    /// - `ChainProvider`: The default chain.
+    pub fn default_chain(static_credentials: Option<credentials::Credentials>) -> Self {
+        let chain = match static_credentials {
+            Some(credentials) => {
+                ChainProvider::new().with_provider(provider::StaticProvider::new(credentials))
+            }
+            None => ChainProvider::new(),
+        };
+
+        chain
+            .with_provider(env_provider::EnvProvider::new())
+            .with_provider(profile_provider::ProfileProvider::new())
+    }
+
+    /// Appends a provider to the end of the chain.
+    ///
+    /// # Arguments
+    /// - `provider`: The provider to append.
+    ///
+    /// # Returns
+    /// - `Self`: The updated chain.
+    pub fn with_provider(mut self, provider: impl provider::CredentialsProvider + 'static) -> Self {
+        self.providers.push(Arc::new(provider));
+        self
+    }
+
+    /// Tries every provider in order.
+    ///
+    /// # Returns
+    /// - `Ok(Credentials)`: The credentials of the first provider that has some.
+    /// - `Err(error::Error)`: If no provider has credentials, listing every failure.
+    async fn load(&self) -> Result<credentials::Credentials, error::Error> {
+        let mut reasons: Vec<String> = Vec::new();
+
+        for provider in &self.providers {
+            match provider.provide_credentials().await {
+                Ok(credentials) => return Ok(credentials),
+                Err(error::Error::ErrCredentialsNotFound(reason)) => reasons.push(reason),
+                Err(err) => reasons.push(err.to_string()),
+            }
+        }
+
+        Err(error::Error::ErrCredentialsNotFound(reasons.join("; ")))
+    }
+}
+
+impl provider::CredentialsProvider for ChainProvider {
+    /// Returns the credentials of the first provider that has some.
+    fn provide_credentials(&self) -> provider::ProvideCredentialsFuture<'_> {
+        Box::pin(self.load())
+    }
+}
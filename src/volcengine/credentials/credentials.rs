@@ -2,7 +2,7 @@
  * @Author: Jerry.Yang
  * @Date: 2024-10-17 10:34:24
  * @LastEditors: Jerry.Yang
- * @LastEditTime: 2025-02-05 14:15:55
+ * @LastEditTime: 2025-03-30 11:10:00
  * @Description: credentials
  */
 /// Represents the authentication credentials required to access Volcengine services.
@@ -16,6 +16,8 @@
 ///   This key acts as a unique identifier for authentication.
 /// - `secret_access_key` (`String`): The secret access key associated with the access key ID.
 ///   This key must be kept secure, as it is used to sign API requests.
+/// - `session_token` (`Option<String>`): The session token issued together with temporary
+///   (STS) credentials. `None` for long-lived keys.
 ///
 /// # Security Considerations
 /// - The `secret_access_key` should **never** be exposed in logs, environment variables,  
@@ -26,9 +28,12 @@
 ///
 /// # Example Usage
 /// ```rust
+/// use volcengine_rust_sdk::volcengine::credentials::credentials::Credentials;
+///
 /// let creds = Credentials {
 ///     access_key_id: "your-access-key-id".to_string(),
 ///     secret_access_key: "your-secret-access-key".to_string(),
+///     session_token: None,
 /// };
 ///
 /// println!("Access Key ID: {}", creds.access_key_id);
@@ -43,6 +48,9 @@ pub struct Credentials {
 
     /// The secret access key for authentication (must be kept secure).
     pub secret_access_key: String,
+
+    /// The session token of temporary credentials, if any.
+    pub session_token: Option<String>,
 }
 
 /// Represents a set of credentials used for authenticating API requests.
@@ -58,6 +66,7 @@ pub struct Credentials {
 ///
 /// # Example
 /// ```rust
+/// # use volcengine_rust_sdk::volcengine::credentials::credentials::Credentials;
 /// let creds = Credentials::new("your-access-key-id", "your-secret-access-key");
 /// println!("Access Key ID: {}", creds.access_key_id);
 /// println!("Secret Access Key: {}", creds.secret_access_key);
@@ -82,6 +91,7 @@ impl Credentials {
     ///
     /// # Example
     /// ```rust
+    /// # use volcengine_rust_sdk::volcengine::credentials::credentials::Credentials;
     /// let creds = Credentials::new("your-access-key-id", "your-secret-access-key");
     /// println!("Access Key ID: {}", creds.access_key_id);
     /// println!("Secret Access Key: {}", creds.secret_access_key);
@@ -90,6 +100,29 @@ impl Credentials {
         Credentials {
             access_key_id: access_key_id.to_string(),
             secret_access_key: secret_access_key.to_string(),
+            session_token: None,
         }
     }
+
+    /// Attaches a session token to the credentials.
+    ///
+    /// Temporary credentials issued by STS are only valid together with their session token.
+    ///
+    /// # Parameters
+    /// - `session_token` (`&str`): The session token issued with the temporary credentials.
+    ///
+    /// # Returns
+    /// Returns the `Credentials` instance (`Self`) carrying the session token.
+    ///
+    /// # Example
+    /// ```rust
+    /// use volcengine_rust_sdk::volcengine::credentials::credentials::Credentials;
+    ///
+    /// let creds = Credentials::new("your-access-key-id", "your-secret-access-key")
+    ///     .with_session_token("your-session-token");
+    /// ```
+    pub fn with_session_token(mut self, session_token: &str) -> Self {
+        self.session_token = Some(session_token.to_string());
+        self
+    }
 }
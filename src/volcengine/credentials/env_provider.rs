@@ -0,0 +1,97 @@
+/*
+ * @Author: Jerry.Yang
+ * @Date: 2025-03-07 09:58:44
+ * @LastEditors: Jerry.Yang
+ * @LastEditTime: 2025-03-29 17:48:00
+ * @Description: environment variables credentials provider
+ */
+use crate::volcengine::credentials::credentials;
+use crate::volcengine::credentials::provider;
+use crate::volcengine::error::error;
+use std::env;
+
+/// The environment variable holding the access key id.
+pub const ENV_ACCESS_KEY: &str = "VOLCENGINE_ACCESS_KEY";
+
+/// The environment variable holding the secret access key.
+pub const ENV_SECRET_KEY: &str = "VOLCENGINE_SECRET_KEY";
+
+/// The environment variable holding the optional session token.
+pub const ENV_SESSION_TOKEN: &str = "VOLCENGINE_SESSION_TOKEN";
+
+/// A provider reading credentials from environment variables.
+///
+/// - `VOLCENGINE_ACCESS_KEY`: The access key id (required).
+/// - `VOLCENGINE_SECRET_KEY`: The secret access key (required).
+/// - `VOLCENGINE_SESSION_TOKEN`: The session token of temporary credentials (optional).
+///
+/// The variables are read on every call, so rotated keys are picked up without a restart.
+#[derive(Debug, Clone, Default)]
+pub struct EnvProvider;
+
+impl EnvProvider {
+    /// Creates a new environment variables provider.
+    ///
+    /// # Returns
+    /// - `EnvProvider`: The provider.
+    pub fn new() -> Self {
+        EnvProvider
+    }
+
+    /// Reads the credentials from the environment.
+    ///
+    /// # Returns
+    /// - `Ok(Credentials)`: If both keys are set and not empty.
+    /// - `Err(error::Error)`: If a key is missing.
+    fn load(&self) -> Result<credentials::Credentials, error::Error> {
+        load_from(|name| env::var(name).ok())
+    }
+}
+
+impl provider::CredentialsProvider for EnvProvider {
+    /// Returns the credentials found in the environment.
+    fn provide_credentials(&self) -> provider::ProvideCredentialsFuture<'_> {
+        Box::pin(async move { self.load() })
+    }
+}
+
+/// Reads the credentials from the variables returned by `lookup`.
+///
+/// # Arguments
+/// - `lookup`: Returns the value of a variable, `None` when unset.
+///
+/// # Returns
+/// - `Ok(Credentials)`: If both keys are set and not empty.
+/// - `Err(error::Error)`: If a key is missing.
+pub(crate) fn load_from(
+    lookup: impl Fn(&str) -> Option<String>,
+) -> Result<credentials::Credentials, error::Error> {
+    let access_key_id = read_env(&lookup, ENV_ACCESS_KEY)?;
+    let secret_access_key = read_env(&lookup, ENV_SECRET_KEY)?;
+    let credentials = credentials::Credentials::new(&access_key_id, &secret_access_key);
+
+    // Attach the session token of temporary credentials.
+    match read_env(&lookup, ENV_SESSION_TOKEN) {
+        Ok(session_token) => Ok(credentials.with_session_token(&session_token)),
+        Err(_) => Ok(credentials),
+    }
+}
+
+/// Reads a non-empty environment variable.
+///
+/// # Arguments
+/// - `lookup`: Returns the value of a variable, `None` when unset.
+/// - `name`: The name of the variable.
+///
+/// # Returns
+/// - `Ok(String)`: The trimmed value.
+/// - `Err(error::Error)`: If the variable is unset or empty.
+fn read_env(lookup: &impl Fn(&str) -> Option<String>, name: &str) -> Result<String, error::Error> {
+    match lookup(name) {
+        Some(value) if !value.trim().is_empty() => Ok(value.trim().to_string()),
+        _ => Err(error::Error::ErrCredentialsNotFound(format!(
+            "environment variable {} not set",
+            name
+        ))),
+    }
+}
@@ -2,7 +2,15 @@
  * @Author: Jerry.Yang
  * @Date: 2024-10-17 10:34:32
  * @LastEditors: Jerry.Yang
- * @LastEditTime: 2024-10-17 14:03:25
+ * @LastEditTime: 2025-03-29 17:48:00
  * @Description: mod
  */
+pub mod assume_role_provider;
+pub mod chain_provider;
 pub mod credentials;
+pub mod env_provider;
+pub mod profile_provider;
+pub mod provider;
+
+// Test module for the credentials providers.
+mod tests;
@@ -0,0 +1,226 @@
+/*
+ * @Author: Jerry.Yang
+ * @Date: 2025-03-07 10:12:09
+ * @LastEditors: Jerry.Yang
+ * @LastEditTime: 2025-03-30 11:30:00
+ * @Description: shared profile file credentials provider
+ */
+use crate::volcengine::credentials::credentials;
+use crate::volcengine::credentials::provider;
+use crate::volcengine::error::error;
+use std::collections::HashMap;
+use std::env;
+use std::path::PathBuf;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+/// The environment variable selecting the profile, `default` when unset.
+pub const ENV_PROFILE: &str = "VOLCENGINE_PROFILE";
+
+/// The environment variable overriding the location of the profile file.
+pub const ENV_CONFIG_FILE: &str = "VOLCENGINE_CONFIG_FILE";
+
+/// The profile used when none is selected.
+pub const DEFAULT_PROFILE: &str = "default";
+
+/// A provider reading credentials from the shared profile file `~/.volcengine/config`.
+///
+/// The file uses the INI format, one section per profile:
+///
+/// ```ini
+/// [default]
+/// access_key_id = AKLT...
+/// secret_access_key = ...
+///
+/// [staging]
+/// access_key_id = AKLT...
+/// secret_access_key = ...
+/// session_token = ...
+/// ```
+///
+/// The profile is taken from `VOLCENGINE_PROFILE` and the file location from
+/// `VOLCENGINE_CONFIG_FILE`, unless set explicitly on the provider.
+///
+/// The file is read on the first request and the credentials found are kept for the lifetime
+/// of the provider and its clones: later requests reuse them without touching the file. A
+/// read finding no credentials is not kept, so a file or profile created later is picked up
+/// by the next request. Build a new `Config` to pick up an edited file.
+#[derive(Debug, Clone, Default)]
+pub struct ProfileProvider {
+    profile: Option<String>,                             // Explicit profile name.
+    path: Option<PathBuf>,                               // Explicit profile file location.
+    cache: Arc<Mutex<Option<credentials::Credentials>>>, // Credentials of the first successful read.
+}
+
+impl ProfileProvider {
+    /// Creates a provider using the profile and file selected by the environment.
+    ///
+    /// # Returns
+    /// - `ProfileProvider`: The provider.
+    pub fn new() -> Self {
+        ProfileProvider::default()
+    }
+
+    /// Selects the profile to read.
+    ///
+    /// # Arguments
+    /// - `profile` (`&str`): The name of the section in the profile file.
+    ///
+    /// # Returns
+    /// - `Self`: The updated provider.
+    pub fn with_profile(mut self, profile: &str) -> Self {
+        self.profile = Some(profile.to_string());
+        self.cache = Arc::default();
+        self
+    }
+
+    /// Sets the location of the profile file.
+    ///
+    /// # Arguments
+    /// - `path` (`impl Into<PathBuf>`): The path of the profile file.
+    ///
+    /// # Returns
+    /// - `Self`: The updated provider.
+    pub fn with_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.path = Some(path.into());
+        self.cache = Arc::default();
+        self
+    }
+
+    /// Resolves the profile name: explicit, then `VOLCENGINE_PROFILE`, then `default`.
+    fn profile_name(&self) -> String {
+        self.profile
+            .clone()
+            .or_else(|| env::var(ENV_PROFILE).ok().filter(|v| !v.is_empty()))
+            .unwrap_or_else(|| DEFAULT_PROFILE.to_string())
+    }
+
+    /// Resolves the file location: explicit, then `VOLCENGINE_CONFIG_FILE`, then the home directory.
+    fn profile_path(&self) -> Option<PathBuf> {
+        if let Some(path) = &self.path {
+            return Some(path.clone());
+        }
+        if let Some(path) = env::var_os(ENV_CONFIG_FILE).filter(|v| !v.is_empty()) {
+            return Some(PathBuf::from(path));
+        }
+        env::var_os("HOME")
+            .or_else(|| env::var_os("USERPROFILE"))
+            .map(|home| PathBuf::from(home).join(".volcengine").join("config"))
+    }
+
+    /// Returns the credentials of the selected profile, reading the file until it provides some.
+    ///
+    /// The lock is held while the file is read, so concurrent first requests read it once.
+    /// Only credentials are cached: a failed read is retried by the next request.
+    ///
+    /// # Returns
+    /// - `Ok(Credentials)`: If the profile exists and holds both keys.
+    /// - `Err(error::Error::ErrCredentialsNotFound)`: If the file, the profile or a key is missing.
+    async fn cached(&self) -> Result<credentials::Credentials, error::Error> {
+        let mut cache = self.cache.lock().await;
+        if let Some(credentials) = &*cache {
+            return Ok(credentials.clone());
+        }
+
+        let credentials = self.load().await?;
+        *cache = Some(credentials.clone());
+        Ok(credentials)
+    }
+
+    /// Reads the credentials of the selected profile.
+    ///
+    /// # Returns
+    /// - `Ok(Credentials)`: If the profile exists and holds both keys.
+    /// - `Err(error::Error)`: If the file, the profile or a key is missing.
+    async fn load(&self) -> Result<credentials::Credentials, error::Error> {
+        let path = self.profile_path().ok_or_else(|| {
+            error::Error::ErrCredentialsNotFound("home directory not found".to_string())
+        })?;
+        let profile_name = self.profile_name();
+
+        // Read the profile file.
+        let content = tokio::fs::read_to_string(&path).await.map_err(|e| {
+            error::Error::ErrCredentialsNotFound(format!("profile file {} : {}", path.display(), e))
+        })?;
+
+        // Pick the selected profile.
+        let mut profiles = parse_profiles(&content);
+        let profile = profiles.remove(&profile_name).ok_or_else(|| {
+            error::Error::ErrCredentialsNotFound(format!(
+                "profile {} not found in {}",
+                profile_name,
+                path.display()
+            ))
+        })?;
+
+        // Read the keys of the profile.
+        let read_key = |key: &str| {
+            profile.get(key).cloned().ok_or_else(|| {
+                error::Error::ErrCredentialsNotFound(format!(
+                    "profile {} has no {}",
+                    profile_name, key
+                ))
+            })
+        };
+        let credentials = credentials::Credentials::new(
+            &read_key("access_key_id")?,
+            &read_key("secret_access_key")?,
+        );
+
+        // Attach the session token of temporary credentials.
+        match profile.get("session_token") {
+            Some(session_token) => Ok(credentials.with_session_token(session_token)),
+            None => Ok(credentials),
+        }
+    }
+}
+
+impl provider::CredentialsProvider for ProfileProvider {
+    /// Returns the credentials of the selected profile.
+    fn provide_credentials(&self) -> provider::ProvideCredentialsFuture<'_> {
+        Box::pin(self.cached())
+    }
+}
+
+/// Parses an INI document into `profile -> key -> value` maps.
+///
+/// Blank lines and lines starting with `#` or `;` are ignored, keys outside of a
+/// section are dropped and empty values are skipped.
+///
+/// # Arguments
+/// - `content`: The content of the profile file.
+///
+/// # Returns
+/// - `HashMap<String, HashMap<String, String>>`: The parsed profiles.
+pub(crate) fn parse_profiles(content: &str) -> HashMap<String, HashMap<String, String>> {
+    let mut profiles: HashMap<String, HashMap<String, String>> = HashMap::new();
+    let mut current: Option<String> = None;
+
+    for line in content.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+            continue;
+        }
+
+        // A section header opens a new profile.
+        if line.starts_with('[') && line.ends_with(']') {
+            let name = line[1..line.len() - 1].trim().to_string();
+            profiles.entry(name.clone()).or_default();
+            current = Some(name);
+            continue;
+        }
+
+        // A `key = value` pair belongs to the current profile.
+        if let (Some(name), Some((key, value))) = (&current, line.split_once('=')) {
+            let value = value.trim();
+            if !value.is_empty() {
+                profiles
+                    .entry(name.clone())
+                    .or_default()
+                    .insert(key.trim().to_string(), value.to_string());
+            }
+        }
+    }
+
+    profiles
+}
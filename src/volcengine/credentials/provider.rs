@@ -0,0 +1,80 @@
+/*
+ * @Author: Jerry.Yang
+ * @Date: 2025-03-07 09:41:18
+ * @LastEditors: Jerry.Yang
+ * @LastEditTime: 2025-03-30 11:10:00
+ * @Description: credentials provider
+ */
+use crate::volcengine::credentials::credentials;
+use crate::volcengine::error::error;
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+
+/// The future returned by `CredentialsProvider::provide_credentials`.
+pub type ProvideCredentialsFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<credentials::Credentials, error::Error>> + Send + 'a>>;
+
+/// A source of credentials used to sign requests.
+///
+/// Providers are resolved lazily: `provide_credentials` is called before every request is
+/// signed, so a provider may read its source again, refresh expiring credentials or serve
+/// them from a cache. Providers are stored behind an `Arc` in the `Config` and shared by
+/// every client of a session, hence the `Send + Sync` bound.
+///
+/// # Example
+/// ```rust,no_run
+/// # use volcengine_rust_sdk::volcengine::error::error;
+/// use volcengine_rust_sdk::volcengine::config::Config;
+/// use volcengine_rust_sdk::volcengine::credentials::credentials::Credentials;
+/// use volcengine_rust_sdk::volcengine::credentials::provider::{CredentialsProvider, ProvideCredentialsFuture};
+///
+/// #[derive(Debug)]
+/// struct VaultProvider;
+///
+/// impl CredentialsProvider for VaultProvider {
+///     fn provide_credentials(&self) -> ProvideCredentialsFuture<'_> {
+///         Box::pin(async { Ok(Credentials::new("access-key-id", "secret-access-key")) })
+///     }
+/// }
+///
+/// # fn example() -> Result<(), error::Error> {
+/// let config = Config::builder().with_credentials_provider(VaultProvider).build()?;
+/// # Ok(())
+/// # }
+/// ```
+pub trait CredentialsProvider: fmt::Debug + Send + Sync {
+    /// Returns the credentials to sign the next request with.
+    ///
+    /// # Returns
+    /// A future resolving to the credentials, or an error if none are available.
+    fn provide_credentials(&self) -> ProvideCredentialsFuture<'_>;
+}
+
+/// A provider which always returns the same, fixed credentials.
+///
+/// Wraps the keys passed to `ConfigBuilder::with_credentials`.
+#[derive(Debug, Clone)]
+pub struct StaticProvider {
+    credentials: credentials::Credentials, // The fixed credentials.
+}
+
+impl StaticProvider {
+    /// Creates a provider returning the given credentials.
+    ///
+    /// # Arguments
+    /// - `credentials`: The credentials to return.
+    ///
+    /// # Returns
+    /// - `StaticProvider`: The provider.
+    pub fn new(credentials: credentials::Credentials) -> Self {
+        StaticProvider { credentials }
+    }
+}
+
+impl CredentialsProvider for StaticProvider {
+    /// Returns a copy of the fixed credentials.
+    fn provide_credentials(&self) -> ProvideCredentialsFuture<'_> {
+        Box::pin(async move { Ok(self.credentials.clone()) })
+    }
+}
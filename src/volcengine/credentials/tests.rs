@@ -0,0 +1,435 @@
+/*
+ * @Author: Jerry.Yang
+ * @Date: 2025-03-29 17:32:14
+ * @LastEditors: Jerry.Yang
+ * @LastEditTime: 2025-03-30 11:30:00
+ * @Description: credentials providers test
+ */
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, TimeZone, Utc};
+    use std::collections::HashMap;
+    use std::path::PathBuf;
+    use std::time::Duration;
+
+    use crate::volcengine::clock::clock;
+    use crate::volcengine::credentials::provider::{self, CredentialsProvider};
+    use crate::volcengine::credentials::{
+        assume_role_provider, chain_provider, credentials, env_provider, profile_provider,
+    };
+    use crate::volcengine::error::error;
+    use crate::volcengine::mock::mock;
+
+    /// Returns a profile file path unique to the test.
+    fn profile_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!(
+            "volcengine-profile-{}-{}",
+            std::process::id(),
+            name
+        ))
+    }
+
+    /// Returns the reason of a `ErrCredentialsNotFound` error.
+    fn not_found_reason(result: Result<credentials::Credentials, error::Error>) -> String {
+        match result {
+            Err(error::Error::ErrCredentialsNotFound(reason)) => reason,
+            other => panic!("expected ErrCredentialsNotFound, got {:?}", other),
+        }
+    }
+
+    /// Returns the time the `FixedClock` of the `AssumeRole` tests is stopped at.
+    fn assume_role_now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 3, 29, 8, 0, 0).unwrap()
+    }
+
+    /// Queues an `AssumeRole` answer with `access_key_id` expiring at `expired_time`.
+    fn mock_assume_role(server: &mock::MockServer, access_key_id: &str, expired_time: &str) {
+        server.mock(
+            "AssumeRole",
+            "2018-01-01",
+            mock::MockResponse::json(&serde_json::json!({
+                "Result": {
+                    "Credentials": {
+                        "AccessKeyId": access_key_id,
+                        "SecretAccessKey": "secret",
+                        "SessionToken": "token",
+                        "ExpiredTime": expired_time,
+                    },
+                },
+            })),
+        );
+    }
+
+    /// Returns an `AssumeRole` provider calling the mock server, at `assume_role_now`.
+    fn assume_role_provider_builder(
+        server: &mock::MockServer,
+    ) -> assume_role_provider::AssumeRoleCredentialsProviderBuilder {
+        assume_role_provider::AssumeRoleCredentialsProvider::builder()
+            .with_session(server.session())
+            .with_role_trn("trn:iam::2100000000:role/ops")
+            .with_role_session_name("batch-job")
+            .with_clock(clock::FixedClock::new(assume_role_now()))
+    }
+
+    /// Returns `assume_role_now` moved by `minutes`, in RFC 3339.
+    fn minutes_from_now(minutes: i64) -> String {
+        (assume_role_now() + chrono::Duration::minutes(minutes)).to_rfc3339()
+    }
+
+    #[tokio::test]
+    async fn test_assume_role_provider_cache() {
+        let server = mock::MockServer::start().await;
+        mock_assume_role(&server, "AKTPfirst", &minutes_from_now(60));
+        mock_assume_role(&server, "AKTPsecond", &minutes_from_now(60));
+
+        let provider = assume_role_provider_builder(&server).build().unwrap();
+        let credentials = provider.provide_credentials().await.unwrap();
+        assert_eq!(credentials.access_key_id, "AKTPfirst");
+        assert_eq!(credentials.session_token.as_deref(), Some("token"));
+
+        // the credentials are served from the cache, shared by the clones
+        let clone = provider.clone();
+        for provider in [&provider, &clone] {
+            let credentials = provider.provide_credentials().await.unwrap();
+            assert_eq!(credentials.access_key_id, "AKTPfirst");
+        }
+
+        let request = server.single_request("AssumeRole", "sts");
+        assert_eq!(request.query["RoleTrn"], "trn:iam::2100000000:role/ops");
+        assert_eq!(request.query["RoleSessionName"], "batch-job");
+        assert_eq!(request.query["DurationSeconds"], "3600");
+    }
+
+    #[tokio::test]
+    async fn test_assume_role_provider_refresh_window() {
+        // expiring in 4 minutes, within the default window of 5 minutes
+        let server = mock::MockServer::start().await;
+        mock_assume_role(&server, "AKTPfirst", &minutes_from_now(4));
+        mock_assume_role(&server, "AKTPsecond", &minutes_from_now(60));
+
+        let provider = assume_role_provider_builder(&server).build().unwrap();
+        let access_key_ids = [
+            provider.provide_credentials().await.unwrap().access_key_id,
+            provider.provide_credentials().await.unwrap().access_key_id,
+            provider.provide_credentials().await.unwrap().access_key_id,
+        ];
+        assert_eq!(access_key_ids, ["AKTPfirst", "AKTPsecond", "AKTPsecond"]);
+        assert_eq!(server.requests().len(), 2);
+
+        // outside of a window of 1 minute
+        let server = mock::MockServer::start().await;
+        mock_assume_role(&server, "AKTPfirst", &minutes_from_now(4));
+        mock_assume_role(&server, "AKTPsecond", &minutes_from_now(60));
+
+        let provider = assume_role_provider_builder(&server)
+            .with_refresh_window(Duration::from_secs(60))
+            .build()
+            .unwrap();
+        for _ in 0..2 {
+            let credentials = provider.provide_credentials().await.unwrap();
+            assert_eq!(credentials.access_key_id, "AKTPfirst");
+        }
+        assert_eq!(server.requests().len(), 1);
+
+        // without a readable expiry, the requested duration counts
+        let server = mock::MockServer::start().await;
+        mock_assume_role(&server, "AKTPfirst", "");
+        mock_assume_role(&server, "AKTPsecond", "");
+
+        let provider = assume_role_provider_builder(&server)
+            .with_duration_seconds(200)
+            .build()
+            .unwrap();
+        provider.provide_credentials().await.unwrap();
+        let credentials = provider.provide_credentials().await.unwrap();
+        assert_eq!(credentials.access_key_id, "AKTPsecond");
+        assert_eq!(server.requests()[0].query["DurationSeconds"], "200");
+    }
+
+    #[tokio::test]
+    async fn test_assume_role_provider_concurrent_refresh() {
+        // concurrent first callers wait for one call rather than each assuming the role
+        let server = mock::MockServer::start().await;
+        mock_assume_role(&server, "AKTPfirst", &minutes_from_now(60));
+        mock_assume_role(&server, "AKTPsecond", &minutes_from_now(60));
+
+        let provider = assume_role_provider_builder(&server).build().unwrap();
+        let results =
+            futures::future::join_all((0..8).map(|_| provider.provide_credentials())).await;
+        for result in results {
+            assert_eq!(result.unwrap().access_key_id, "AKTPfirst");
+        }
+        assert_eq!(server.requests().len(), 1);
+
+        // so do concurrent callers of credentials about to expire, for a single refresh
+        let server = mock::MockServer::start().await;
+        mock_assume_role(&server, "AKTPfirst", &minutes_from_now(1));
+        mock_assume_role(&server, "AKTPsecond", &minutes_from_now(60));
+        mock_assume_role(&server, "AKTPthird", &minutes_from_now(60));
+
+        let provider = assume_role_provider_builder(&server).build().unwrap();
+        provider.provide_credentials().await.unwrap();
+        let results =
+            futures::future::join_all((0..8).map(|_| provider.provide_credentials())).await;
+        for result in results {
+            assert_eq!(result.unwrap().access_key_id, "AKTPsecond");
+        }
+        assert_eq!(server.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_assume_role_provider_error_not_cached() {
+        let server = mock::MockServer::start().await;
+        server.mock(
+            "AssumeRole",
+            "2018-01-01",
+            mock::MockResponse::error(403, "AccessDenied", "not allowed to assume the role"),
+        );
+        mock_assume_role(&server, "AKTPfirst", &minutes_from_now(60));
+
+        // a failed call is not cached, the next caller assumes the role again
+        let provider = assume_role_provider_builder(&server).build().unwrap();
+        assert!(matches!(
+            provider.provide_credentials().await,
+            Err(error::Error::Api { code, .. }) if code == "AccessDenied"
+        ));
+        let credentials = provider.provide_credentials().await.unwrap();
+        assert_eq!(credentials.access_key_id, "AKTPfirst");
+        assert_eq!(server.requests().len(), 2);
+    }
+
+    #[test]
+    fn test_env_provider() {
+        let lookup = |variables: &'static [(&'static str, &'static str)]| {
+            let variables: HashMap<_, _> = variables.iter().copied().collect();
+            move |name: &str| variables.get(name).map(|value| value.to_string())
+        };
+
+        // the keys are trimmed and the session token is optional
+        let credentials = env_provider::load_from(lookup(&[
+            (env_provider::ENV_ACCESS_KEY, " AKLTenv "),
+            (env_provider::ENV_SECRET_KEY, "secret"),
+        ]))
+        .unwrap();
+        assert_eq!(credentials.access_key_id, "AKLTenv");
+        assert_eq!(credentials.secret_access_key, "secret");
+        assert_eq!(credentials.session_token, None);
+
+        let credentials = env_provider::load_from(lookup(&[
+            (env_provider::ENV_ACCESS_KEY, "AKLTenv"),
+            (env_provider::ENV_SECRET_KEY, "secret"),
+            (env_provider::ENV_SESSION_TOKEN, "token"),
+        ]))
+        .unwrap();
+        assert_eq!(credentials.session_token.as_deref(), Some("token"));
+
+        // a missing or blank key is not found
+        let reason = not_found_reason(env_provider::load_from(lookup(&[(
+            env_provider::ENV_ACCESS_KEY,
+            "AKLTenv",
+        )])));
+        assert!(reason.contains(env_provider::ENV_SECRET_KEY));
+        let reason = not_found_reason(env_provider::load_from(lookup(&[
+            (env_provider::ENV_ACCESS_KEY, "  "),
+            (env_provider::ENV_SECRET_KEY, "secret"),
+        ])));
+        assert!(reason.contains(env_provider::ENV_ACCESS_KEY));
+    }
+
+    #[test]
+    fn test_parse_profiles() {
+        let profiles = profile_provider::parse_profiles(
+            "
+            orphan = dropped
+            # a comment
+            ; another comment
+            [default]
+            access_key_id = AKLTdefault
+            secret_access_key = a=b
+
+            [ staging ]
+            access_key_id=AKLTstaging
+            session_token =
+            ",
+        );
+
+        // keys outside of a section are dropped, values keep their `=`
+        assert_eq!(profiles.len(), 2);
+        assert_eq!(profiles["default"]["access_key_id"], "AKLTdefault");
+        assert_eq!(profiles["default"]["secret_access_key"], "a=b");
+        assert!(!profiles["default"].contains_key("orphan"));
+        // section names are trimmed and empty values skipped
+        assert_eq!(profiles["staging"]["access_key_id"], "AKLTstaging");
+        assert!(!profiles["staging"].contains_key("session_token"));
+    }
+
+    #[tokio::test]
+    async fn test_profile_provider() {
+        let path = profile_path("profile_provider");
+        std::fs::write(
+            &path,
+            "[default]\naccess_key_id = AKLTdefault\nsecret_access_key = secret\n\
+             [staging]\naccess_key_id = AKLTstaging\nsecret_access_key = secret\nsession_token = token\n\
+             [broken]\naccess_key_id = AKLTbroken\n",
+        )
+        .unwrap();
+
+        let provider = profile_provider::ProfileProvider::new()
+            .with_path(&path)
+            .with_profile("staging");
+        let credentials = provider.provide_credentials().await.unwrap();
+        assert_eq!(credentials.access_key_id, "AKLTstaging");
+        assert_eq!(credentials.session_token.as_deref(), Some("token"));
+
+        // a missing profile, or a missing key, is not found
+        let provider = profile_provider::ProfileProvider::new()
+            .with_path(&path)
+            .with_profile("production");
+        let reason = not_found_reason(provider.provide_credentials().await);
+        assert!(reason.contains("profile production not found"));
+        let provider = profile_provider::ProfileProvider::new()
+            .with_path(&path)
+            .with_profile("broken");
+        let reason = not_found_reason(provider.provide_credentials().await);
+        assert!(reason.contains("profile broken has no secret_access_key"));
+
+        // so is a missing file
+        let provider = profile_provider::ProfileProvider::new()
+            .with_path(profile_path("missing"))
+            .with_profile("default");
+        let reason = not_found_reason(provider.provide_credentials().await);
+        assert!(reason.contains("profile file"));
+
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[tokio::test]
+    async fn test_profile_provider_reads_file_once() {
+        let path = profile_path("reads_file_once");
+        std::fs::write(
+            &path,
+            "[default]\naccess_key_id = AKLTfirst\nsecret_access_key = secret\n",
+        )
+        .unwrap();
+
+        let provider = profile_provider::ProfileProvider::new()
+            .with_path(&path)
+            .with_profile("default");
+        let clone = provider.clone();
+        assert_eq!(
+            provider.provide_credentials().await.unwrap().access_key_id,
+            "AKLTfirst"
+        );
+
+        // the provider and its clones keep the first read
+        std::fs::write(
+            &path,
+            "[default]\naccess_key_id = AKLTsecond\nsecret_access_key = secret\n",
+        )
+        .unwrap();
+        assert_eq!(
+            provider.provide_credentials().await.unwrap().access_key_id,
+            "AKLTfirst"
+        );
+        assert_eq!(
+            clone.provide_credentials().await.unwrap().access_key_id,
+            "AKLTfirst"
+        );
+
+        // a new provider reads the file again
+        let provider = profile_provider::ProfileProvider::new()
+            .with_path(&path)
+            .with_profile("default");
+        assert_eq!(
+            provider.provide_credentials().await.unwrap().access_key_id,
+            "AKLTsecond"
+        );
+
+        // a failed read is not kept, the file created later is read by the next request
+        std::fs::remove_file(&path).unwrap();
+        let provider = profile_provider::ProfileProvider::new()
+            .with_path(&path)
+            .with_profile("default");
+        not_found_reason(provider.provide_credentials().await);
+        std::fs::write(
+            &path,
+            "[default]\naccess_key_id = AKLTthird\nsecret_access_key = secret\n",
+        )
+        .unwrap();
+        assert_eq!(
+            provider.provide_credentials().await.unwrap().access_key_id,
+            "AKLTthird"
+        );
+
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[tokio::test]
+    async fn test_chain_provider_precedence() {
+        let path = profile_path("chain_precedence");
+        std::fs::write(
+            &path,
+            "[default]\naccess_key_id = AKLTprofile\nsecret_access_key = secret\n",
+        )
+        .unwrap();
+        let profile = || {
+            profile_provider::ProfileProvider::new()
+                .with_path(&path)
+                .with_profile("default")
+        };
+        let missing_profile = || {
+            profile_provider::ProfileProvider::new()
+                .with_path(profile_path("chain_missing"))
+                .with_profile("default")
+        };
+        let static_provider = |access_key_id: &str| {
+            provider::StaticProvider::new(credentials::Credentials::new(access_key_id, "secret"))
+        };
+
+        // the first provider with credentials wins
+        let chain = chain_provider::ChainProvider::new()
+            .with_provider(profile())
+            .with_provider(static_provider("AKLTstatic"));
+        assert_eq!(
+            chain.provide_credentials().await.unwrap().access_key_id,
+            "AKLTprofile"
+        );
+
+        // providers without credentials are skipped
+        let chain = chain_provider::ChainProvider::new()
+            .with_provider(missing_profile())
+            .with_provider(static_provider("AKLTfirst"))
+            .with_provider(static_provider("AKLTsecond"));
+        assert_eq!(
+            chain.provide_credentials().await.unwrap().access_key_id,
+            "AKLTfirst"
+        );
+
+        // without any, every reason is reported
+        let chain = chain_provider::ChainProvider::new()
+            .with_provider(missing_profile())
+            .with_provider(missing_profile().with_profile("staging"));
+        let reason = not_found_reason(chain.provide_credentials().await);
+        assert_eq!(reason.matches("profile file").count(), 2);
+        let reason = not_found_reason(
+            chain_provider::ChainProvider::new()
+                .provide_credentials()
+                .await,
+        );
+        assert!(reason.is_empty());
+
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[tokio::test]
+    async fn test_default_chain_explicit_credentials_first() {
+        // the explicit keys are used whatever the environment and profile file hold
+        let chain = chain_provider::ChainProvider::default_chain(Some(
+            credentials::Credentials::new("AKLTexplicit", "secret"),
+        ));
+        assert_eq!(
+            chain.provide_credentials().await.unwrap().access_key_id,
+            "AKLTexplicit"
+        );
+    }
+}
@@ -2,9 +2,11 @@
  * @Author: Jerry.Yang
  * @Date: 2024-10-18 11:31:37
  * @LastEditors: Jerry.Yang
- * @LastEditTime: 2025-02-05 14:12:17
+ * @LastEditTime: 2025-03-27 11:06:42
  * @Description: Endpoint and utilities for URL scheme handling
  */
+use crate::volcengine::error::error;
+
 /// Enum representing different service endpoints in the Volcengine API.  
 ///  
 /// This enum defines various service-specific endpoints that are used to  
@@ -17,12 +19,15 @@
 /// the selected service.
 #[derive(Debug, Clone)]
 pub enum Endpoint {
-    IamEndpoint,   // IAM (Identity and Access Management) endpoint
-    EcsEndpoint,   // ECS (Elastic Compute Service) endpoint
-    VpcEndpoint,   // VPC (Virtual Private Cloud) endpoint
-    RdsEndpoint,   // RDS (Relational Database Service) endpoint
-    RedisEndpoint, // Redis service endpoint
-    ClbEndpoint,   // Clb service endpoint
+    IamEndpoint,        // IAM (Identity and Access Management) endpoint
+    EcsEndpoint,        // ECS (Elastic Compute Service) endpoint
+    VpcEndpoint,        // VPC (Virtual Private Cloud) endpoint
+    RdsEndpoint,        // RDS (Relational Database Service) endpoint
+    RedisEndpoint,      // Redis service endpoint
+    ClbEndpoint,        // Clb service endpoint
+    StsEndpoint,        // STS (Security Token Service) endpoint
+    NatGatewayEndpoint, // NAT gateway service endpoint
+    OpenEndpoint,       // Shared endpoint of the services without a host of their own
 }
 
 /// Represents the resolved endpoint details for a specific service in Volcengine.
@@ -147,29 +152,131 @@ impl Endpoint {
             // Cloud Load Balancer (CLB) service endpoint
             // Uses the same endpoint as ECS and VPC
             Endpoint::ClbEndpoint => "open.volcengineapi.com",
+
+            // Security Token Service (STS) endpoint
+            Endpoint::StsEndpoint => "sts.volcengineapi.com",
+
+            // NAT gateway service endpoint
+            // Uses the same endpoint as ECS and VPC
+            Endpoint::NatGatewayEndpoint => "open.volcengineapi.com",
+
+            // Shared endpoint of the other services
+            Endpoint::OpenEndpoint => "open.volcengineapi.com",
+        }
+    }
+}
+
+/// The URL scheme used to reach the service endpoints.
+///
+/// HTTPS is the default; plain HTTP is meant for local proxies and test servers only,
+/// since the request headers, including the signature, are then sent in clear text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Scheme {
+    #[default]
+    Https, // TLS-protected connections (default)
+    Http, // Plain-text connections
+}
+
+impl Scheme {
+    /// Returns the scheme as written in URLs.
+    ///
+    /// # Returns
+    /// - `&str`: `"https"` or `"http"`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Scheme::Https => "https",
+            Scheme::Http => "http",
+        }
+    }
+
+    /// Returns the well-known port of the scheme.
+    ///
+    /// # Returns
+    /// - `u16`: `443` for HTTPS, `80` for HTTP.
+    pub fn default_port(&self) -> u16 {
+        match self {
+            Scheme::Https => 443,
+            Scheme::Http => 80,
+        }
+    }
+
+    /// Parses the scheme of a URL.
+    ///
+    /// # Arguments
+    /// - `scheme`: The scheme, e.g. `"https"`, case-insensitive.
+    ///
+    /// # Returns
+    /// - `Some(Scheme)`: For `http` and `https`.
+    /// - `None`: For any other scheme.
+    pub fn parse(scheme: &str) -> Option<Scheme> {
+        match scheme.to_ascii_lowercase().as_str() {
+            "https" => Some(Scheme::Https),
+            "http" => Some(Scheme::Http),
+            _ => None,
         }
     }
 }
 
-/// Adds the appropriate scheme (HTTP or HTTPS) to the endpoint URL.
+/// Adds the scheme to an endpoint given without one.
 ///
 /// # Parameters
-/// - `endpoint`: The endpoint URL to which the scheme should be added.
-/// - `disable_ssl`: A boolean flag indicating whether to disable SSL (use HTTP instead of HTTPS).
+/// - `endpoint`: The endpoint, e.g. `open.volcengineapi.com` or `https://open.volcengineapi.com`.
+/// - `scheme`: The scheme to add when the endpoint has none.
 ///
 /// # Returns
-/// A `String` representing the full endpoint URL with the scheme added.
-pub fn add_scheme(endpoint: &str, disable_ssl: bool) -> String {
-    // If the endpoint already starts with "http://" or "https://", return it as is.
-    if endpoint.starts_with("http://") || endpoint.starts_with("https://") {
+/// A `String` with the endpoint unchanged if it already has a scheme, prefixed with
+/// `scheme://` otherwise.
+pub fn add_scheme(endpoint: &str, scheme: Scheme) -> String {
+    // An endpoint with an explicit scheme is kept as is.
+    if endpoint.contains("://") {
         return endpoint.to_string();
     }
 
-    // If SSL is disabled, force HTTPS as the scheme.
-    if disable_ssl {
-        return format!("https://{}", endpoint);
+    format!("{}://{}", scheme.as_str(), endpoint)
+}
+
+/// Validates an endpoint and reduces it to its origin, `scheme://host[:port]`.
+///
+/// # Parameters
+/// - `endpoint`: The endpoint, with or without a scheme.
+/// - `scheme`: The scheme used when the endpoint has none.
+///
+/// # Returns
+/// - `Ok(String)`: The origin of the endpoint; the port is omitted when it is the default one.
+/// - `Err(error::Error::ErrEndpointInvalid)`: If the endpoint is not a valid `http`/`https` URL
+///   with a host and without a path, query or fragment.
+/// - `Err(error::Error::ErrEndpointSchemeConflict)`: If an HTTP endpoint uses port 443 or an
+///   HTTPS endpoint uses port 80.
+pub fn normalize_endpoint(endpoint: &str, scheme: Scheme) -> Result<String, error::Error> {
+    let endpoint_url = add_scheme(endpoint.trim(), scheme);
+    let invalid =
+        |reason: &str| error::Error::ErrEndpointInvalid(format!("{} : {}", endpoint_url, reason));
+
+    // Parse the endpoint.
+    let url = url::Url::parse(&endpoint_url).map_err(|e| invalid(&e.to_string()))?;
+    let scheme =
+        Scheme::parse(url.scheme()).ok_or_else(|| invalid("scheme must be http or https"))?;
+    if url.host_str().unwrap_or_default().is_empty() {
+        return Err(invalid("missing host"));
+    }
+    if url.path() != "/" || url.query().is_some() || url.fragment().is_some() {
+        return Err(invalid("path, query and fragment are not supported"));
+    }
+
+    // Reject the well-known port of the other scheme, e.g. `http://host:443`.
+    if let Some(port) = url.port() {
+        let other = match scheme {
+            Scheme::Https => Scheme::Http,
+            Scheme::Http => Scheme::Https,
+        };
+        if port == other.default_port() {
+            return Err(error::Error::ErrEndpointSchemeConflict {
+                endpoint: endpoint_url,
+                scheme: scheme.as_str().to_string(),
+                port,
+            });
+        }
     }
 
-    // Default case, add HTTP scheme.
-    format!("http://{}", endpoint)
+    Ok(url.origin().ascii_serialization())
 }
@@ -0,0 +1,172 @@
+/*
+ * @Author: Jerry.Yang
+ * @Date: 2025-03-13 10:21:44
+ * @LastEditors: Jerry.Yang
+ * @LastEditTime: 2025-03-30 11:10:00
+ * @Description: Region-aware endpoint resolution
+ */
+use crate::volcengine::client::config as client_config;
+use crate::volcengine::error::error;
+use crate::volcengine::util::url;
+use std::fmt;
+
+/// The domain of the public endpoints.
+pub const STANDARD_ENDPOINT_SUFFIX: &str = "volcengineapi.com";
+
+/// The domain of the dual-stack (IPv4 and IPv6) endpoints.
+pub const DUAL_STACK_ENDPOINT_SUFFIX: &str = "volcengine-api.com";
+
+/// The domain of the endpoints reachable from inside a Volcengine VPC.
+pub const VPC_INTERNAL_ENDPOINT_SUFFIX: &str = "ivolces.com";
+
+/// The family of hosts the built-in resolver picks from.
+///
+/// | Variant       | ECS in `cn-beijing`                 | IAM (global service)     |
+/// |---------------|-------------------------------------|--------------------------|
+/// | `Global`      | `open.volcengineapi.com`            | `iam.volcengineapi.com`  |
+/// | `Regional`    | `ecs.cn-beijing.volcengineapi.com`  | `iam.volcengineapi.com`  |
+/// | `DualStack`   | `ecs.cn-beijing.volcengine-api.com` | `iam.volcengine-api.com` |
+/// | `VpcInternal` | `ecs.cn-beijing.ivolces.com`        | `iam.ivolces.com`        |
+///
+/// Global services (IAM, STS) have no regional host, and every variant falls back to the
+/// global host of the domain when no region is configured.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EndpointVariant {
+    #[default]
+    Global, // One public host per service, regardless of the region (default)
+    Regional,    // Public regional hosts, `{service}.{region}.volcengineapi.com`
+    DualStack,   // Regional hosts reachable over IPv4 and IPv6
+    VpcInternal, // Regional hosts reachable from inside a Volcengine VPC only
+}
+
+/// Resolves the host serving a service in a region.
+///
+/// The resolver configured on `Config` is consulted by `Session::new_client_config` for every
+/// service that has no endpoint override. The returned endpoint may omit the scheme, in which
+/// case `Config.scheme` is added.
+///
+/// # Example
+/// ```rust,no_run
+/// use volcengine_rust_sdk::volcengine::client::config::ClientServiceName;
+/// use volcengine_rust_sdk::volcengine::config::Config;
+/// use volcengine_rust_sdk::volcengine::endpoint::endpoint_resolver::EndpointResolver;
+/// use volcengine_rust_sdk::volcengine::error::error::Error;
+///
+/// #[derive(Debug)]
+/// struct GatewayResolver;
+///
+/// impl EndpointResolver for GatewayResolver {
+///     fn resolve_endpoint(
+///         &self,
+///         service_name: &ClientServiceName,
+///         region: &str,
+///     ) -> Result<String, Error> {
+///         Ok(format!("{}.{}.gateway.internal", service_name.as_str(), region))
+///     }
+/// }
+///
+/// # fn example() -> Result<(), Error> {
+/// let config = Config::builder().with_endpoint_resolver(GatewayResolver).build()?;
+/// # Ok(())
+/// # }
+/// ```
+pub trait EndpointResolver: fmt::Debug + Send + Sync {
+    /// Returns the endpoint of `service_name` in `region`.
+    ///
+    /// # Arguments
+    /// - `service_name`: The service being called.
+    /// - `region`: The region of the `Config`, possibly empty.
+    ///
+    /// # Returns
+    /// - `Ok(String)`: The endpoint, e.g. `ecs.cn-beijing.volcengineapi.com`.
+    /// - `Err(error::Error)`: If the service cannot be reached in that region.
+    fn resolve_endpoint(
+        &self,
+        service_name: &client_config::ClientServiceName,
+        region: &str,
+    ) -> Result<String, error::Error>;
+}
+
+/// The built-in resolver, building hosts from the service, the region and a variant.
+#[derive(Debug, Clone, Default)]
+pub struct DefaultEndpointResolver {
+    variant: EndpointVariant, // The family of hosts to resolve to.
+}
+
+impl DefaultEndpointResolver {
+    /// Creates a resolver for the given variant.
+    ///
+    /// # Arguments
+    /// - `variant`: The family of hosts to resolve to.
+    ///
+    /// # Returns
+    /// - `DefaultEndpointResolver`: The resolver.
+    pub fn new(variant: EndpointVariant) -> Self {
+        DefaultEndpointResolver { variant }
+    }
+}
+
+impl EndpointResolver for DefaultEndpointResolver {
+    /// Builds the host of the service from the table of the variant.
+    fn resolve_endpoint(
+        &self,
+        service_name: &client_config::ClientServiceName,
+        region: &str,
+    ) -> Result<String, error::Error> {
+        let suffix = match self.variant {
+            // The historical table, shared hosts included.
+            EndpointVariant::Global => {
+                return Ok(url::Url {
+                    service_name: service_name.clone(),
+                    region: region.to_string(),
+                }
+                .get_endpoint())
+            }
+            EndpointVariant::Regional => STANDARD_ENDPOINT_SUFFIX,
+            EndpointVariant::DualStack => DUAL_STACK_ENDPOINT_SUFFIX,
+            EndpointVariant::VpcInternal => VPC_INTERNAL_ENDPOINT_SUFFIX,
+        };
+
+        let host_prefix = service_host_prefix(service_name);
+        let region = region.trim();
+        if is_global_service(service_name) || region.is_empty() {
+            return Ok(format!("{}.{}", host_prefix, suffix));
+        }
+        Ok(format!("{}.{}.{}", host_prefix, region, suffix))
+    }
+}
+
+/// Returns the first label of the hosts of a service.
+///
+/// # Arguments
+/// - `service_name`: The service.
+///
+/// # Returns
+/// - `&str`: The label, e.g. `ecs`; the signing name for other services.
+fn service_host_prefix(service_name: &client_config::ClientServiceName) -> &str {
+    match service_name {
+        client_config::ClientServiceName::Iam => "iam",
+        client_config::ClientServiceName::Ecs => "ecs",
+        client_config::ClientServiceName::Vpc => "vpc",
+        client_config::ClientServiceName::Rds => "rds",
+        client_config::ClientServiceName::Redis => "redis",
+        client_config::ClientServiceName::Clb => "clb",
+        client_config::ClientServiceName::Sts => "sts",
+        client_config::ClientServiceName::NatGateway => "natgateway",
+        client_config::ClientServiceName::Custom(name) => name,
+    }
+}
+
+/// Tells whether a service is served from a single, global host.
+///
+/// # Arguments
+/// - `service_name`: The service.
+///
+/// # Returns
+/// - `bool`: `true` for IAM and STS.
+fn is_global_service(service_name: &client_config::ClientServiceName) -> bool {
+    matches!(
+        service_name,
+        client_config::ClientServiceName::Iam | client_config::ClientServiceName::Sts
+    )
+}
@@ -2,7 +2,11 @@
  * @Author: Jerry.Yang
  * @Date: 2024-10-18 11:31:22
  * @LastEditors: Jerry.Yang
- * @LastEditTime: 2024-10-18 11:31:43
+ * @LastEditTime: 2025-03-29 19:20:00
  * @Description:
  */
 pub mod endpoint;
+pub mod endpoint_resolver;
+
+// Test module for the endpoints.
+mod tests;
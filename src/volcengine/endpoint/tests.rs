@@ -0,0 +1,360 @@
+/*
+ * @Author: Jerry.Yang
+ * @Date: 2025-03-29 19:06:51
+ * @LastEditors: Jerry.Yang
+ * @LastEditTime: 2025-03-29 19:41:00
+ * @Description: endpoint test
+ */
+#[cfg(test)]
+mod tests {
+    use crate::service::ecs;
+    use crate::service::ecs::EcsService;
+    use crate::volcengine::client::config as client_config;
+    use crate::volcengine::config;
+    use crate::volcengine::credentials::credentials;
+    use crate::volcengine::endpoint::endpoint;
+    use crate::volcengine::endpoint::endpoint_resolver::{self, EndpointResolver, EndpointVariant};
+    use crate::volcengine::error::error;
+    use crate::volcengine::session::session;
+
+    /// Returns a config builder with a region and static credentials.
+    fn config_builder() -> config::ConfigBuilder {
+        config::Config::builder()
+            .with_region("cn-beijing")
+            .with_credentials(credentials::Credentials::new("AKLTtest", "secret"))
+    }
+
+    /// Returns the endpoint of the ECS client built from `config`.
+    fn ecs_endpoint(config: config::Config) -> Result<String, error::Error> {
+        let session = session::Session::builder()
+            .with_config(config)
+            .build()
+            .unwrap();
+        session
+            .new_client_config(client_config::ClientServiceName::Ecs)
+            .map(|client_config| client_config.endpoint)
+    }
+
+    /// A resolver answering `{service}.{region}.example.com` for every service.
+    #[derive(Debug)]
+    struct ExampleResolver;
+
+    impl EndpointResolver for ExampleResolver {
+        fn resolve_endpoint(
+            &self,
+            service_name: &client_config::ClientServiceName,
+            region: &str,
+        ) -> Result<String, error::Error> {
+            Ok(format!("{}.{}.example.com", service_name.as_str(), region))
+        }
+    }
+
+    /// Returns the endpoint of the client of `service_name` built from `config`.
+    fn client_endpoint(
+        config: &config::Config,
+        service_name: client_config::ClientServiceName,
+    ) -> Result<String, error::Error> {
+        let session = session::Session::builder()
+            .with_config(config.clone())
+            .build()
+            .unwrap();
+        session
+            .new_client_config(service_name)
+            .map(|client_config| client_config.endpoint)
+    }
+
+    #[test]
+    fn test_add_scheme() {
+        // the scheme asked for is the one added
+        assert_eq!(
+            endpoint::add_scheme("open.volcengineapi.com", endpoint::Scheme::Https),
+            "https://open.volcengineapi.com"
+        );
+        assert_eq!(
+            endpoint::add_scheme("127.0.0.1:8080", endpoint::Scheme::Http),
+            "http://127.0.0.1:8080"
+        );
+        // an explicit scheme is kept
+        assert_eq!(
+            endpoint::add_scheme("http://127.0.0.1:8080", endpoint::Scheme::Https),
+            "http://127.0.0.1:8080"
+        );
+        assert_eq!(endpoint::Scheme::default(), endpoint::Scheme::Https);
+    }
+
+    #[test]
+    fn test_normalize_endpoint() {
+        let cases = [
+            (
+                "open.volcengineapi.com",
+                endpoint::Scheme::Https,
+                "https://open.volcengineapi.com",
+            ),
+            (
+                "open.volcengineapi.com",
+                endpoint::Scheme::Http,
+                "http://open.volcengineapi.com",
+            ),
+            (
+                " https://open.volcengineapi.com/ ",
+                endpoint::Scheme::Http,
+                "https://open.volcengineapi.com",
+            ),
+            (
+                "HTTP://Example.com",
+                endpoint::Scheme::Https,
+                "http://example.com",
+            ),
+            // the default port is dropped, another one kept
+            (
+                "https://open.volcengineapi.com:443",
+                endpoint::Scheme::Https,
+                "https://open.volcengineapi.com",
+            ),
+            ("127.0.0.1:80", endpoint::Scheme::Http, "http://127.0.0.1"),
+            (
+                "127.0.0.1:8080",
+                endpoint::Scheme::Http,
+                "http://127.0.0.1:8080",
+            ),
+            (
+                "localhost:8443",
+                endpoint::Scheme::Https,
+                "https://localhost:8443",
+            ),
+        ];
+        for (input, scheme, expected) in cases {
+            assert_eq!(
+                endpoint::normalize_endpoint(input, scheme).unwrap(),
+                expected,
+                "{}",
+                input
+            );
+        }
+
+        for input in [
+            "https://open.volcengineapi.com/v1",
+            "https://open.volcengineapi.com?Action=DescribeInstances",
+            "https://open.volcengineapi.com#top",
+            "ftp://open.volcengineapi.com",
+            "https://",
+            "",
+        ] {
+            assert!(
+                matches!(
+                    endpoint::normalize_endpoint(input, endpoint::Scheme::Https),
+                    Err(error::Error::ErrEndpointInvalid(_))
+                ),
+                "{}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_normalize_endpoint_scheme_conflict() {
+        // the well-known port of the other scheme, explicit or added
+        let cases = [
+            (
+                "http://open.volcengineapi.com:443",
+                endpoint::Scheme::Https,
+                "http",
+                443,
+            ),
+            (
+                "open.volcengineapi.com:443",
+                endpoint::Scheme::Http,
+                "http",
+                443,
+            ),
+            ("https://127.0.0.1:80", endpoint::Scheme::Http, "https", 80),
+            ("127.0.0.1:80", endpoint::Scheme::Https, "https", 80),
+        ];
+        for (input, scheme, expected_scheme, expected_port) in cases {
+            match endpoint::normalize_endpoint(input, scheme) {
+                Err(error::Error::ErrEndpointSchemeConflict {
+                    endpoint,
+                    scheme,
+                    port,
+                }) => {
+                    assert!(endpoint.ends_with(input), "{}", endpoint);
+                    assert_eq!(scheme, expected_scheme);
+                    assert_eq!(port, expected_port);
+                }
+                other => panic!("{}: expected a scheme conflict, got {:?}", input, other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_client_endpoint_scheme() {
+        // HTTPS unless HTTP is asked for
+        let config = config_builder()
+            .with_endpoint("open.volcengineapi.com")
+            .build()
+            .unwrap();
+        assert_eq!(
+            ecs_endpoint(config).unwrap(),
+            "https://open.volcengineapi.com"
+        );
+
+        let config = config_builder()
+            .with_endpoint("127.0.0.1:8080")
+            .with_scheme(endpoint::Scheme::Http)
+            .build()
+            .unwrap();
+        assert_eq!(ecs_endpoint(config).unwrap(), "http://127.0.0.1:8080");
+
+        // resolved endpoints use the scheme too
+        let config = config_builder().build().unwrap();
+        assert!(ecs_endpoint(config).unwrap().starts_with("https://"));
+        let config = config_builder()
+            .with_scheme(endpoint::Scheme::Http)
+            .build()
+            .unwrap();
+        assert!(ecs_endpoint(config).unwrap().starts_with("http://"));
+    }
+
+    #[test]
+    fn test_client_endpoint_scheme_conflict() {
+        // the conflict is reported when the service client is created
+        let config = config_builder()
+            .with_endpoint("http://127.0.0.1:443")
+            .build()
+            .unwrap();
+        let session = session::Session::builder()
+            .with_config(config)
+            .build()
+            .unwrap();
+        assert!(matches!(
+            ecs::Ecs::new_ecs(session),
+            Err(error::Error::ErrEndpointSchemeConflict { port: 443, .. })
+        ));
+    }
+
+    #[test]
+    fn test_endpoint_variant_hosts() {
+        use client_config::ClientServiceName::{Custom, Ecs, Iam, NatGateway, Rds, Sts};
+
+        let resolve = |variant: EndpointVariant,
+                       service_name: client_config::ClientServiceName,
+                       region: &str| {
+            endpoint_resolver::DefaultEndpointResolver::new(variant)
+                .resolve_endpoint(&service_name, region)
+                .unwrap()
+        };
+
+        // one public host per service, whatever the region
+        assert_eq!(
+            resolve(EndpointVariant::Global, Ecs, "cn-beijing"),
+            "open.volcengineapi.com"
+        );
+        assert_eq!(
+            resolve(EndpointVariant::Global, Rds, "cn-shanghai"),
+            "rds.volcengineapi.com"
+        );
+        assert_eq!(
+            resolve(EndpointVariant::Global, Iam, "cn-beijing"),
+            "iam.volcengineapi.com"
+        );
+        assert_eq!(EndpointVariant::default(), EndpointVariant::Global);
+
+        // regional hosts, the IAM and STS ones staying global
+        let cases = [
+            (EndpointVariant::Regional, "volcengineapi.com"),
+            (EndpointVariant::DualStack, "volcengine-api.com"),
+            (EndpointVariant::VpcInternal, "ivolces.com"),
+        ];
+        for (variant, suffix) in cases {
+            assert_eq!(
+                resolve(variant, Ecs, "cn-beijing"),
+                format!("ecs.cn-beijing.{}", suffix)
+            );
+            assert_eq!(
+                resolve(variant, NatGateway, " ap-southeast-1 "),
+                format!("natgateway.ap-southeast-1.{}", suffix)
+            );
+            assert_eq!(
+                resolve(variant, Custom("billing".to_string()), "cn-beijing"),
+                format!("billing.cn-beijing.{}", suffix)
+            );
+            assert_eq!(
+                resolve(variant, Iam, "cn-beijing"),
+                format!("iam.{}", suffix)
+            );
+            assert_eq!(
+                resolve(variant, Sts, "cn-beijing"),
+                format!("sts.{}", suffix)
+            );
+            // without a region, the host of the service
+            assert_eq!(resolve(variant, Ecs, ""), format!("ecs.{}", suffix));
+        }
+    }
+
+    #[test]
+    fn test_service_endpoint_precedence() {
+        use client_config::ClientServiceName::{Ecs, Vpc};
+
+        // the variant picks the hosts of the built-in resolver
+        let config = config_builder()
+            .with_endpoint_variant(EndpointVariant::VpcInternal)
+            .build()
+            .unwrap();
+        assert_eq!(
+            client_endpoint(&config, Ecs).unwrap(),
+            "https://ecs.cn-beijing.ivolces.com"
+        );
+
+        // a resolver replaces the built-in one
+        let config = config_builder()
+            .with_endpoint_resolver(ExampleResolver)
+            .build()
+            .unwrap();
+        assert_eq!(
+            client_endpoint(&config, Vpc).unwrap(),
+            "https://vpc.cn-beijing.example.com"
+        );
+
+        // a service endpoint beats the resolver, for that service only
+        let config = config_builder()
+            .with_endpoint_resolver(ExampleResolver)
+            .with_service_endpoint(Ecs, "http://127.0.0.1:8080")
+            .build()
+            .unwrap();
+        assert_eq!(
+            client_endpoint(&config, Ecs).unwrap(),
+            "http://127.0.0.1:8080"
+        );
+        assert_eq!(
+            client_endpoint(&config, Vpc).unwrap(),
+            "https://vpc.cn-beijing.example.com"
+        );
+
+        // the global endpoint beats the resolver, a service endpoint beats both
+        let config = config_builder()
+            .with_endpoint_variant(EndpointVariant::Regional)
+            .with_endpoint("proxy.example.com")
+            .with_service_endpoint(Ecs, "ecs.proxy.example.com")
+            .build()
+            .unwrap();
+        assert_eq!(
+            client_endpoint(&config, Ecs).unwrap(),
+            "https://ecs.proxy.example.com"
+        );
+        assert_eq!(
+            client_endpoint(&config, Vpc).unwrap(),
+            "https://proxy.example.com"
+        );
+
+        // a service endpoint is validated like the others, failing its service only
+        let config = config_builder()
+            .with_service_endpoint(Ecs, "http://127.0.0.1:443")
+            .build()
+            .unwrap();
+        assert!(matches!(
+            client_endpoint(&config, Ecs),
+            Err(error::Error::ErrEndpointSchemeConflict { .. })
+        ));
+        assert!(client_endpoint(&config, Vpc).is_ok());
+    }
+}
@@ -6,7 +6,7 @@ use url::ParseError;
  * @Author: Jerry.Yang
  * @Date: 2024-10-21 14:20:07
  * @LastEditors: Jerry.Yang
- * @LastEditTime: 2024-11-18 16:50:08
+ * @LastEditTime: 2025-03-30 09:40:00
  * @Description: error
  */
 #[derive(Error, Debug)]
@@ -15,6 +15,22 @@ pub enum Error {
     #[error("util config Err : build Config no credentials")]
     ErrUtilConfigBuildConfigNoCredentials,
 
+    // credentials
+    #[error("credentials Err : no credentials found : {0}")]
+    ErrCredentialsNotFound(String),
+    #[error("credentials Err : build provider no {0}")]
+    ErrCredentialsBuildProviderNo(String),
+
+    // endpoint
+    #[error("endpoint Err : invalid endpoint {0}")]
+    ErrEndpointInvalid(String),
+    #[error("endpoint Err : scheme {scheme} conflicts with port {port} in {endpoint}")]
+    ErrEndpointSchemeConflict {
+        endpoint: String, // The endpoint as configured
+        scheme: String,   // Scheme of the endpoint
+        port: u16,        // Port of the endpoint
+    },
+
     // util session
     #[error("util session Err : build session no config")]
     ErrUtilSessionBuildSessionNoConfig,
@@ -25,6 +41,10 @@ pub enum Error {
     #[error("util request Err : build operation no {0}")]
     ErrUtilRequestBuildOperationNo(String),
 
+    // util transport
+    #[error("util transport Err : build http client failed : {0}")]
+    ErrUtilTransportBuildClient(reqwest::Error),
+
     // util client
     #[error("util client Err : build clientInfo no {0}")]
     ErrUtilClientBuildClientInfoNo(String),
@@ -44,6 +64,12 @@ pub enum Error {
     ErrRequestBuildFailed,
     #[error("request Err : header is Err : {0}")]
     ErrRequestHeaderIsErr(#[from] header::ToStrError),
+    #[error("request Err : serialize query failed : {0}")]
+    ErrSerializeQuery(String),
+    #[error("request Err : parameters must be a JSON object, got {0}")]
+    ErrRequestInvalidParams(String),
+    #[error("request Err : a GET request cannot carry a body, got {0} bytes")]
+    ErrRequestGetBody(usize),
     #[error("request Err : status is {0}")]
     ErrResponseStatus(reqwest::StatusCode),
     #[error("Failed to parse response: {0}")]
@@ -57,4 +83,54 @@ pub enum Error {
     ErrRequestSignGetHostNone,
     #[error("request sign Err : get header-{0} not found")]
     ErrRequestSignGetHeaderNone(String),
+    #[error("request sign Err : presign expires must be at least one second, got {0:?}")]
+    ErrRequestSignInvalidExpires(std::time::Duration),
+    #[error("request sign Err : a presigned URL cannot carry a body, got {0} bytes")]
+    ErrRequestPresignBody(usize),
+    // signer
+    #[error("signer Err : build signer no {0}")]
+    ErrSignerBuildSignerNo(String),
+    #[error("signer Err : invalid header {0}")]
+    ErrSignerInvalidHeader(String),
+    #[error("signer Err : invalid authorization : {0}")]
+    ErrSignerInvalidAuthorization(String),
+    // cassette
+    #[error("cassette Err : {0}")]
+    ErrCassetteIo(std::io::Error),
+    #[error("cassette Err : invalid cassette file : {0}")]
+    ErrCassetteFormat(serde_json::Error),
+    #[error("cassette Err : invalid recorded {0}")]
+    ErrCassetteInvalid(String),
+    #[error("cassette Err : no recorded interaction left for {action} {version}")]
+    ErrCassetteNoInteraction {
+        action: String,  // Action of the request
+        version: String, // Version of the request
+    },
+    // blocking
+    #[error("blocking Err : build runtime failed : {0}")]
+    ErrBlockingBuildRuntime(std::io::Error),
+    // waiter
+    #[error("waiter Err : {waiter} reached failure state {state}")]
+    ErrWaiterFailure {
+        waiter: String, // Name of the waiter, e.g. `InstanceRunning`
+        state: String,  // Failure state observed
+    },
+    #[error("waiter Err : {waiter} timed out after {elapsed:?} in state {state}")]
+    ErrWaiterTimeout {
+        waiter: String,               // Name of the waiter, e.g. `InstanceRunning`
+        state: String,                // Last state observed
+        elapsed: std::time::Duration, // Time spent waiting
+    },
+
+    // api
+    #[error("api Err : {service} {action} failed with {http_status} : {code} {message} (request_id : {request_id})")]
+    Api {
+        code: String,                     // Error code from `ResponseMetadata.Error.Code`
+        code_n: Option<i64>,              // Numeric error code from `ResponseMetadata.Error.CodeN`
+        message: String,                  // Error message from `ResponseMetadata.Error.Message`
+        request_id: String,               // Request id from `ResponseMetadata.RequestId`
+        http_status: reqwest::StatusCode, // HTTP status of the response
+        action: String,                   // Action from `ResponseMetadata.Action`
+        service: String,                  // Service from `ResponseMetadata.Service`
+    },
 }
@@ -0,0 +1,506 @@
+/*
+ * @Author: Jerry.Yang
+ * @Date: 2025-03-19 09:42:17
+ * @LastEditors: Jerry.Yang
+ * @LastEditTime: 2025-03-30 11:10:00
+ * @Description: Offline mock of the Volcengine OpenAPI for tests
+ */
+use crate::volcengine::config;
+use crate::volcengine::credentials::credentials;
+use crate::volcengine::retry::retry;
+use crate::volcengine::session::session;
+use crate::volcengine::signer::signer;
+use bytes::Bytes;
+use http::header::{HeaderName, HeaderValue, AUTHORIZATION};
+use http::{HeaderMap, Method, StatusCode};
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use tokio::sync::oneshot;
+
+/// The access key id of the credentials accepted by the mock server.
+pub const MOCK_ACCESS_KEY_ID: &str = "AKLTmockaccesskeyid";
+
+/// The secret access key of the credentials accepted by the mock server.
+pub const MOCK_SECRET_ACCESS_KEY: &str = "mocksecretaccesskey";
+
+/// The region of the sessions built by the mock server.
+pub const MOCK_REGION: &str = "cn-beijing";
+
+/// A canned response served by `MockServer`.
+///
+/// The body is a JSON document. When it is an object, the `RequestId`, `Action`, `Version`,
+/// `Service` and `Region` of its `ResponseMetadata` are filled in from the request unless set
+/// already, so canned responses only carry what a test asserts on.
+#[derive(Debug, Clone)]
+pub struct MockResponse {
+    status: StatusCode,      // Status of the response.
+    headers: HeaderMap,      // Extra headers of the response.
+    body: serde_json::Value, // JSON body of the response.
+}
+
+impl MockResponse {
+    /// Creates a successful response from a serializable body.
+    ///
+    /// # Arguments
+    /// - `body`: The body, usually a typed `*Resp` of the service.
+    ///
+    /// # Returns
+    /// - `MockResponse`: A `200 OK` response.
+    ///
+    /// # Panics
+    /// If `body` cannot be serialized to JSON.
+    pub fn json<T: Serialize>(body: &T) -> MockResponse {
+        MockResponse {
+            status: StatusCode::OK,
+            headers: HeaderMap::new(),
+            body: serde_json::to_value(body).expect("mock response body is not serializable"),
+        }
+    }
+
+    /// Creates a successful response carrying no `Result`.
+    ///
+    /// # Returns
+    /// - `MockResponse`: A `200 OK` response with only `ResponseMetadata`.
+    pub fn empty() -> MockResponse {
+        MockResponse::json(&serde_json::json!({}))
+    }
+
+    /// Creates an error response carrying `ResponseMetadata.Error`.
+    ///
+    /// # Arguments
+    /// - `status`: The HTTP status, e.g. `400`.
+    /// - `code`: The error code, e.g. `InvalidInstance.NotFound`.
+    /// - `message`: The error message.
+    ///
+    /// # Returns
+    /// - `MockResponse`: The error response.
+    ///
+    /// # Panics
+    /// If `status` is not a valid HTTP status.
+    pub fn error(status: u16, code: &str, message: &str) -> MockResponse {
+        MockResponse {
+            status: StatusCode::from_u16(status).expect("invalid mock response status"),
+            headers: HeaderMap::new(),
+            body: serde_json::json!({
+                "ResponseMetadata": {
+                    "Error": {
+                        "Code": code,
+                        "Message": message,
+                    },
+                },
+            }),
+        }
+    }
+
+    /// Sets the HTTP status of the response.
+    ///
+    /// # Arguments
+    /// - `status`: The HTTP status.
+    ///
+    /// # Returns
+    /// - `Self`: The updated response.
+    ///
+    /// # Panics
+    /// If `status` is not a valid HTTP status.
+    pub fn with_status(mut self, status: u16) -> Self {
+        self.status = StatusCode::from_u16(status).expect("invalid mock response status");
+        self
+    }
+
+    /// Adds a header to the response, e.g. a `Date` far from the local clock.
+    ///
+    /// # Arguments
+    /// - `name`: The header name.
+    /// - `value`: The header value.
+    ///
+    /// # Returns
+    /// - `Self`: The updated response.
+    ///
+    /// # Panics
+    /// If `name` or `value` is not a valid header.
+    pub fn with_header(mut self, name: &str, value: &str) -> Self {
+        self.headers.append(
+            HeaderName::from_bytes(name.as_bytes()).expect("invalid mock header name"),
+            HeaderValue::from_str(value).expect("invalid mock header value"),
+        );
+        self
+    }
+}
+
+/// A request received by `MockServer`.
+#[derive(Debug, Clone)]
+pub struct ReceivedRequest {
+    pub method: Method,                  // HTTP method of the request.
+    pub path: String,                    // Path of the request.
+    pub query: BTreeMap<String, String>, // Decoded query parameters.
+    pub headers: HeaderMap,              // Headers of the request.
+    pub body: Bytes,                     // Body of the request.
+    pub action: String,                  // `Action` query parameter.
+    pub version: String,                 // `Version` query parameter.
+    pub region: String,                  // Region of the credential scope.
+    pub service: String,                 // Service of the credential scope.
+    pub signature_valid: bool,           // Whether the V4 signature verified.
+}
+
+impl ReceivedRequest {
+    /// Decodes the body of a request sent as a form.
+    ///
+    /// # Returns
+    /// - `BTreeMap<String, String>`: The form parameters, empty if the body is not a form.
+    pub fn form(&self) -> BTreeMap<String, String> {
+        url::form_urlencoded::parse(&self.body)
+            .into_owned()
+            .collect()
+    }
+}
+
+/// The responses and requests shared with the server task.
+#[derive(Debug, Default)]
+struct MockState {
+    responses: HashMap<(String, String), VecDeque<MockResponse>>, // Canned responses per `Action`/`Version`.
+    requests: Vec<ReceivedRequest>,                               // Requests received so far.
+}
+
+/// A local HTTP stand-in for the Volcengine OpenAPI.
+///
+/// Tests register canned responses per `Action`/`Version` with `mock` and build services from
+/// `session`, which points at the server with the mock credentials. Every request is checked
+/// against its V4 signature with `signer::Signer` and recorded; requests which fail the check
+/// are answered with `SignatureDoesNotMatch`, and unknown actions with
+/// `InvalidActionOrVersion`. The server stops when it is dropped.
+///
+/// # Example
+/// ```rust,no_run
+/// # use volcengine_rust_sdk::volcengine::error::error;
+/// use volcengine_rust_sdk::service::vpc::{self, VpcService};
+/// use volcengine_rust_sdk::volcengine::mock::mock;
+/// use volcengine_sdk_protobuf::protobuf::vpc_vpc;
+///
+/// # async fn example() -> Result<(), error::Error> {
+/// let server = mock::MockServer::start().await;
+/// server.mock(
+///     "DescribeVpcs",
+///     "2020-04-01",
+///     mock::MockResponse::json(&vpc_vpc::DescribeVpcsResp::default()),
+/// );
+///
+/// let vpc = vpc::Vpc::new_vpc(server.session())?;
+/// vpc.new_describe_vpcs(vpc_vpc::DescribeVpcsReq::default()).await?;
+/// assert!(server.requests()[0].signature_valid);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct MockServer {
+    address: SocketAddr,                   // Local address of the server.
+    state: Arc<Mutex<MockState>>,          // Responses and requests.
+    shutdown: Option<oneshot::Sender<()>>, // Stops the server when dropped.
+}
+
+impl MockServer {
+    /// Starts a mock server on a free local port.
+    ///
+    /// The server runs on the current tokio runtime until it is dropped.
+    ///
+    /// # Returns
+    /// - `MockServer`: The running server.
+    ///
+    /// # Panics
+    /// If no local port can be bound.
+    pub async fn start() -> MockServer {
+        let state = Arc::new(Mutex::new(MockState::default()));
+
+        let service_state = state.clone();
+        let make_service = hyper::service::make_service_fn(move |_| {
+            let state = service_state.clone();
+            async move {
+                Ok::<_, Infallible>(hyper::service::service_fn(move |request| {
+                    handle(state.clone(), request)
+                }))
+            }
+        });
+
+        let server = hyper::Server::try_bind(&SocketAddr::from(([127, 0, 0, 1], 0)))
+            .expect("failed to bind the mock server")
+            .serve(make_service);
+        let address = server.local_addr();
+
+        let (shutdown, shutdown_receiver) = oneshot::channel::<()>();
+        tokio::spawn(server.with_graceful_shutdown(async {
+            shutdown_receiver.await.ok();
+        }));
+
+        MockServer {
+            address,
+            state,
+            shutdown: Some(shutdown),
+        }
+    }
+
+    /// Returns the endpoint of the server, e.g. `http://127.0.0.1:49152`.
+    ///
+    /// # Returns
+    /// - `String`: The endpoint.
+    pub fn endpoint(&self) -> String {
+        format!("http://{}", self.address)
+    }
+
+    /// Returns the credentials accepted by the server.
+    ///
+    /// # Returns
+    /// - `credentials::Credentials`: `MOCK_ACCESS_KEY_ID` and `MOCK_SECRET_ACCESS_KEY`.
+    pub fn credentials(&self) -> credentials::Credentials {
+        credentials::Credentials::new(MOCK_ACCESS_KEY_ID, MOCK_SECRET_ACCESS_KEY)
+    }
+
+    /// Returns a config builder pointing at the server.
+    ///
+    /// The region is `MOCK_REGION`, the credentials are the mock ones and retries are
+    /// disabled so that every call maps to one request. Tests can override any of them.
+    ///
+    /// # Returns
+    /// - `config::ConfigBuilder`: The builder.
+    pub fn config_builder(&self) -> config::ConfigBuilder {
+        config::Config::builder()
+            .with_region(MOCK_REGION)
+            .with_endpoint(&self.endpoint())
+            .with_credentials(self.credentials())
+            .with_retry_policy(retry::RetryPolicy::disabled())
+    }
+
+    /// Returns a session pointing at the server.
+    ///
+    /// # Returns
+    /// - `session::Session`: A session built from `config_builder`.
+    ///
+    /// # Panics
+    /// If the session cannot be built.
+    pub fn session(&self) -> session::Session {
+        let config = self
+            .config_builder()
+            .build()
+            .expect("failed to build the mock config");
+        session::Session::builder()
+            .with_config(config)
+            .build()
+            .expect("failed to build the mock session")
+    }
+
+    /// Registers a canned response for an `Action`/`Version`.
+    ///
+    /// Responses registered for the same action are served in order; the last one is then
+    /// served for every further request, e.g. the final state polled by a waiter.
+    ///
+    /// # Arguments
+    /// - `action`: The action, e.g. `DescribeInstances`.
+    /// - `version`: The API version, e.g. `2020-04-01`.
+    /// - `response`: The response.
+    pub fn mock(&self, action: &str, version: &str, response: MockResponse) {
+        self.lock()
+            .responses
+            .entry((action.to_string(), version.to_string()))
+            .or_default()
+            .push_back(response);
+    }
+
+    /// Returns the requests received so far, in order.
+    ///
+    /// # Returns
+    /// - `Vec<ReceivedRequest>`: The requests.
+    pub fn requests(&self) -> Vec<ReceivedRequest> {
+        self.lock().requests.clone()
+    }
+
+    /// Returns the only request received, checking that it is a signed `action` request of
+    /// `service`.
+    ///
+    /// # Arguments
+    /// - `action`: The expected action, e.g. `DescribeInstances`.
+    /// - `service`: The expected service of the credential scope, e.g. `ecs`.
+    ///
+    /// # Returns
+    /// - `ReceivedRequest`: The request.
+    ///
+    /// # Panics
+    /// If the server received no request or several, or the request is of another action or
+    /// service, or its signature does not verify.
+    pub fn single_request(&self, action: &str, service: &str) -> ReceivedRequest {
+        let mut requests = self.requests();
+        assert_eq!(
+            requests.len(),
+            1,
+            "expected one request, got {:?}",
+            requests
+        );
+        let request = requests.remove(0);
+        assert_eq!(request.action, action);
+        assert_eq!(request.service, service);
+        assert!(request.signature_valid, "the signature does not verify");
+        request
+    }
+
+    /// Locks the shared state, ignoring a poisoned lock left by a failed test.
+    fn lock(&self) -> std::sync::MutexGuard<'_, MockState> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Drop for MockServer {
+    /// Stops the server.
+    fn drop(&mut self) {
+        if let Some(shutdown) = self.shutdown.take() {
+            shutdown.send(()).ok();
+        }
+    }
+}
+
+/// Records a request and answers it with its canned response.
+///
+/// # Arguments
+/// - `state`: The state shared with the `MockServer`.
+/// - `request`: The incoming request.
+///
+/// # Returns
+/// - `Ok(hyper::Response)`: The response; the server never fails a request.
+async fn handle(
+    state: Arc<Mutex<MockState>>,
+    request: hyper::Request<hyper::Body>,
+) -> Result<hyper::Response<hyper::Body>, Infallible> {
+    let (parts, body) = request.into_parts();
+    let body = hyper::body::to_bytes(body).await.unwrap_or_default();
+    let request = http::Request::from_parts(parts, body);
+
+    let query: BTreeMap<String, String> =
+        url::form_urlencoded::parse(request.uri().query().unwrap_or_default().as_bytes())
+            .into_owned()
+            .collect();
+    let action = query.get("Action").cloned().unwrap_or_default();
+    let version = query.get("Version").cloned().unwrap_or_default();
+    let (region, service) = credential_scope(request.headers()).unwrap_or_default();
+    let signature_valid = verify_signature(&request, &region, &service);
+
+    let mut state = state
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    state.requests.push(ReceivedRequest {
+        method: request.method().clone(),
+        path: request.uri().path().to_string(),
+        query,
+        headers: request.headers().clone(),
+        body: request.body().clone(),
+        action: action.clone(),
+        version: version.clone(),
+        region: region.clone(),
+        service: service.clone(),
+        signature_valid,
+    });
+    let request_id = format!("mock-request-{}", state.requests.len());
+
+    // Pick the response: a signature error, the next canned response or an unknown action.
+    let response = if !signature_valid {
+        MockResponse::error(
+            401,
+            "SignatureDoesNotMatch",
+            "The request signature does not match the mock credentials.",
+        )
+    } else {
+        match state.responses.get_mut(&(action.clone(), version.clone())) {
+            Some(responses) if responses.len() > 1 => responses.pop_front().unwrap(),
+            Some(responses) => responses.front().cloned().unwrap(),
+            None => MockResponse::error(
+                404,
+                "InvalidActionOrVersion",
+                &format!("No mock response registered for {} {}.", action, version),
+            ),
+        }
+    };
+    drop(state);
+
+    // Fill the response metadata in from the request.
+    let mut body = response.body;
+    if let Some(object) = body.as_object_mut() {
+        let response_metadata = object
+            .entry("ResponseMetadata")
+            .or_insert(serde_json::Value::Null);
+        if !response_metadata.is_object() {
+            *response_metadata = serde_json::json!({});
+        }
+        if let Some(response_metadata) = response_metadata.as_object_mut() {
+            for (key, value) in [
+                ("RequestId", request_id),
+                ("Action", action),
+                ("Version", version),
+                ("Service", service),
+                ("Region", region),
+            ] {
+                let field = response_metadata
+                    .entry(key)
+                    .or_insert(serde_json::Value::Null);
+                if field.as_str().is_none_or(str::is_empty) {
+                    *field = serde_json::Value::String(value);
+                }
+            }
+        }
+    }
+
+    let mut http_response = hyper::Response::new(hyper::Body::from(body.to_string()));
+    *http_response.status_mut() = response.status;
+    http_response.headers_mut().insert(
+        http::header::CONTENT_TYPE,
+        HeaderValue::from_static("application/json"),
+    );
+    http_response.headers_mut().extend(response.headers);
+    Ok(http_response)
+}
+
+/// Reads the region and the service from the credential scope of the `Authorization` header.
+///
+/// # Arguments
+/// - `headers`: The request headers.
+///
+/// # Returns
+/// - `Some((region, service))`: If the header carries a credential scope.
+/// - `None`: If the header is missing or malformed.
+fn credential_scope(headers: &HeaderMap) -> Option<(String, String)> {
+    let authorization = headers.get(AUTHORIZATION)?.to_str().ok()?;
+    let credential = authorization
+        .split(',')
+        .find_map(|part| part.trim().split_once("Credential="))?
+        .1;
+    // `access_key_id/date/region/service/request`
+    let scope: Vec<&str> = credential.split('/').collect();
+    match scope.as_slice() {
+        [_, _, region, service, "request"] => Some((region.to_string(), service.to_string())),
+        _ => None,
+    }
+}
+
+/// Verifies the V4 signature of a request against the mock credentials.
+///
+/// # Arguments
+/// - `request`: The request.
+/// - `region`: The region of the credential scope.
+/// - `service`: The service of the credential scope.
+///
+/// # Returns
+/// - `bool`: Whether the request is signed by the mock credentials for the mock region.
+fn verify_signature(request: &http::Request<Bytes>, region: &str, service: &str) -> bool {
+    if region != MOCK_REGION {
+        return false;
+    }
+    signer::Signer::builder()
+        .with_credentials(credentials::Credentials::new(
+            MOCK_ACCESS_KEY_ID,
+            MOCK_SECRET_ACCESS_KEY,
+        ))
+        .with_region(region)
+        .with_service(service)
+        .build()
+        .and_then(|signer| signer.verify(request))
+        .unwrap_or(false)
+}
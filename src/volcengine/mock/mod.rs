@@ -0,0 +1,11 @@
+/*
+ * @Author: Jerry.Yang
+ * @Date: 2025-03-19 09:42:17
+ * @LastEditors: Jerry.Yang
+ * @LastEditTime: 2025-03-19 09:42:17
+ * @Description: mod
+ */
+pub mod mock;
+
+// Test module for the mock server.
+mod tests;
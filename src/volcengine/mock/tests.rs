@@ -0,0 +1,162 @@
+/*
+ * @Author: Jerry.Yang
+ * @Date: 2025-03-19 14:08:51
+ * @LastEditors: Jerry.Yang
+ * @LastEditTime: 2025-03-19 16:12:27
+ * @Description: mock test
+ */
+#[cfg(test)]
+mod tests {
+    use volcengine_sdk_protobuf::protobuf::vpc_vpc;
+
+    use crate::service::vpc::{self, VpcService};
+    use crate::volcengine::credentials::credentials;
+    use crate::volcengine::error::error;
+    use crate::volcengine::mock::mock;
+    use crate::volcengine::session::session;
+
+    /// Returns a `DescribeVpcs` response listing one VPC.
+    fn describe_vpcs_response(vpc_id: &str) -> mock::MockResponse {
+        mock::MockResponse::json(&vpc_vpc::DescribeVpcsResp {
+            result: Some(vpc_vpc::DescribeVpcsResultResp {
+                vpcs: vec![vpc_vpc::DescribeVpcsResultVpcResp {
+                    vpc_id: vpc_id.to_string(),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }),
+            ..Default::default()
+        })
+    }
+
+    /// Returns the code of an API error.
+    fn api_error_code<T: std::fmt::Debug>(result: Result<T, error::Error>) -> String {
+        match result {
+            Err(error::Error::Api { code, .. }) => code,
+            other => panic!("expected an API error, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_responses_served_in_order() {
+        let server = mock::MockServer::start().await;
+        server.mock(
+            "DescribeVpcs",
+            "2020-04-01",
+            describe_vpcs_response("vpc-1"),
+        );
+        server.mock(
+            "DescribeVpcs",
+            "2020-04-01",
+            describe_vpcs_response("vpc-2"),
+        );
+
+        let vpc = vpc::Vpc::new_vpc(server.session()).unwrap();
+        let mut vpc_ids = Vec::new();
+        for _ in 0..3 {
+            let response = vpc
+                .new_describe_vpcs(vpc_vpc::DescribeVpcsReq::default())
+                .await
+                .unwrap();
+            vpc_ids.push(response.result.unwrap().vpcs[0].vpc_id.clone());
+        }
+
+        // The last response is served again once the others are used up.
+        assert_eq!(vpc_ids, ["vpc-1", "vpc-2", "vpc-2"]);
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 3);
+        assert!(requests.iter().all(|request| request.signature_valid));
+        assert_eq!(requests[0].method, http::Method::GET);
+        assert_eq!(requests[0].version, "2020-04-01");
+        assert_eq!(requests[0].region, mock::MOCK_REGION);
+    }
+
+    #[tokio::test]
+    async fn test_response_metadata_filled_in() {
+        let server = mock::MockServer::start().await;
+        server.mock(
+            "DescribeVpcs",
+            "2020-04-01",
+            describe_vpcs_response("vpc-1"),
+        );
+
+        let vpc = vpc::Vpc::new_vpc(server.session()).unwrap();
+        let response = vpc
+            .new_describe_vpcs(vpc_vpc::DescribeVpcsReq::default())
+            .await
+            .unwrap();
+
+        let response_metadata = response.response_metadata.unwrap();
+        assert_eq!(response_metadata.request_id, "mock-request-1");
+        assert_eq!(response_metadata.action, "DescribeVpcs");
+        assert_eq!(response_metadata.service, "vpc");
+    }
+
+    #[tokio::test]
+    async fn test_unknown_action() {
+        let server = mock::MockServer::start().await;
+
+        let vpc = vpc::Vpc::new_vpc(server.session()).unwrap();
+        let result = vpc
+            .new_describe_vpcs(vpc_vpc::DescribeVpcsReq::default())
+            .await;
+
+        assert_eq!(api_error_code(result), "InvalidActionOrVersion");
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_wrong_credentials() {
+        let server = mock::MockServer::start().await;
+        server.mock(
+            "DescribeVpcs",
+            "2020-04-01",
+            describe_vpcs_response("vpc-1"),
+        );
+
+        // Sign with another secret key.
+        let config = server
+            .config_builder()
+            .with_credentials(credentials::Credentials::new(
+                mock::MOCK_ACCESS_KEY_ID,
+                "wrongsecretaccesskey",
+            ))
+            .build()
+            .unwrap();
+        let session = session::Session::builder()
+            .with_config(config)
+            .build()
+            .unwrap();
+
+        let vpc = vpc::Vpc::new_vpc(session).unwrap();
+        let result = vpc
+            .new_describe_vpcs(vpc_vpc::DescribeVpcsReq::default())
+            .await;
+
+        assert_eq!(api_error_code(result), "SignatureDoesNotMatch");
+        assert!(!server.requests()[0].signature_valid);
+    }
+
+    #[tokio::test]
+    async fn test_unsigned_request() {
+        let server = mock::MockServer::start().await;
+        server.mock(
+            "DescribeVpcs",
+            "2020-04-01",
+            describe_vpcs_response("vpc-1"),
+        );
+
+        let response = reqwest::get(format!(
+            "{}/?Action=DescribeVpcs&Version=2020-04-01",
+            server.endpoint()
+        ))
+        .await
+        .unwrap();
+
+        assert_eq!(response.status(), reqwest::StatusCode::UNAUTHORIZED);
+        let requests = server.requests();
+        assert_eq!(requests[0].action, "DescribeVpcs");
+        assert!(!requests[0].signature_valid);
+    }
+}
@@ -5,12 +5,23 @@
  * @LastEditTime: 2024-10-22 18:19:09
  * @Description: mod
  */
+pub mod blocking;
 pub mod client;
+pub mod clock;
 pub mod common;
 pub mod config;
 pub mod credentials;
-mod endpoint;
+pub mod endpoint;
 pub mod error;
+#[cfg(any(test, feature = "test-util"))]
+pub mod mock;
+pub mod paginator;
+pub mod registry;
 pub mod request;
+pub mod retry;
 pub mod session;
+pub mod signer;
+pub mod transport;
+pub mod universal;
 mod util;
+pub mod waiter;
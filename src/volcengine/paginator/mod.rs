@@ -0,0 +1,11 @@
+/*
+ * @Author: Jerry.Yang
+ * @Date: 2025-03-11 10:05:26
+ * @LastEditors: Jerry.Yang
+ * @LastEditTime: 2025-03-29 20:50:00
+ * @Description: mod
+ */
+pub mod paginator;
+
+// Test module for the paginator.
+mod tests;
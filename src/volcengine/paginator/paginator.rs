@@ -0,0 +1,242 @@
+/*
+ * @Author: Jerry.Yang
+ * @Date: 2025-03-11 10:05:26
+ * @LastEditors: Jerry.Yang
+ * @LastEditTime: 2025-03-30 11:10:00
+ * @Description: Pagination streams for Describe* / List* operations
+ */
+use crate::volcengine::error::error;
+use futures::future::BoxFuture;
+use futures::stream::{self, Stream, StreamExt};
+use std::sync::Arc;
+
+/// A request of an operation returning its results one page at a time.
+///
+/// Implemented next to the request model of every paginated operation. The implementation
+/// tells the paginator which items a page holds and how to ask for the following page,
+/// whether the operation pages with `NextToken` / `MaxResults`, `PageNumber` / `PageSize`
+/// or `Offset` / `Limit`. The helpers `next_token_request`, `next_page_number` and
+/// `next_offset` cover the three styles.
+pub trait Paginated: Clone + Send + Sync + 'static {
+    /// The response returned for one page.
+    type Response: Send + 'static;
+
+    /// The item listed by the operation, e.g. an instance or a VPC.
+    type Item: Clone + Send + 'static;
+
+    /// Returns the items of a page.
+    ///
+    /// # Arguments
+    /// - `response`: The response of the page.
+    ///
+    /// # Returns
+    /// - `&[Self::Item]`: The items, empty when the response has no result.
+    fn items(response: &Self::Response) -> &[Self::Item];
+
+    /// Builds the request for the page following `response`.
+    ///
+    /// # Arguments
+    /// - `response`: The response of the page just fetched with `self`.
+    /// - `fetched`: The number of items fetched so far, this page included.
+    ///
+    /// # Returns
+    /// - `Some(Self)`: The request for the next page.
+    /// - `None`: If `response` was the last page.
+    fn next_request(&self, response: &Self::Response, fetched: usize) -> Option<Self>;
+
+    /// Returns the number of items listed before the page requested by `self`.
+    ///
+    /// The paginator counts the fetched items from this value, so that a request starting
+    /// past the first page stops at the reported total. Operations paging with
+    /// `PageNumber` / `PageSize` implement it with `items_before_page`.
+    ///
+    /// # Returns
+    /// - `usize`: The number of items before the first page, `0` by default.
+    fn items_before(&self) -> usize {
+        0
+    }
+}
+
+/// The function fetching one page.
+type FetchPage<R> = Arc<
+    dyn Fn(R) -> BoxFuture<'static, Result<<R as Paginated>::Response, error::Error>> + Send + Sync,
+>;
+
+/// Walks through the pages of a `Describe*` / `List*` operation.
+///
+/// A paginator is returned by the `*_paginator` methods of the services and turned into a
+/// `futures::Stream` either over whole pages with `pages` or over the listed items with
+/// `items`. Pages are fetched lazily, one request per page as the stream is polled; the
+/// stream ends after the last page or after the first error, which is yielded as an item.
+///
+/// # Example
+/// ```rust,no_run
+/// # use volcengine_rust_sdk::volcengine::error::error;
+/// use futures::TryStreamExt;
+/// use volcengine_rust_sdk::service::ecs::{self, EcsService};
+/// use volcengine_sdk_protobuf::protobuf::ecs_instance;
+///
+/// # async fn example(ecs: ecs::Ecs) -> Result<(), error::Error> {
+/// let request = ecs_instance::DescribeInstancesReq {
+///     max_results: Some(50),
+///     ..Default::default()
+/// };
+/// let instances: Vec<_> = ecs
+///     .describe_instances_paginator(request)
+///     .items()
+///     .try_collect()
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct Paginator<R: Paginated> {
+    request: R,          // Request of the first page.
+    fetch: FetchPage<R>, // Function fetching one page.
+}
+
+impl<R: Paginated> Paginator<R> {
+    /// Creates a paginator starting with `request`.
+    ///
+    /// # Arguments
+    /// - `request`: The request of the first page.
+    /// - `fetch`: A function sending a request and returning its response.
+    ///
+    /// # Returns
+    /// - `Paginator<R>`: The paginator.
+    pub fn new<F>(request: R, fetch: F) -> Self
+    where
+        F: Fn(R) -> BoxFuture<'static, Result<R::Response, error::Error>> + Send + Sync + 'static,
+    {
+        Paginator {
+            request,
+            fetch: Arc::new(fetch),
+        }
+    }
+
+    /// Returns a stream over the responses, one per page.
+    ///
+    /// # Returns
+    /// - `impl Stream<Item = Result<R::Response, error::Error>>`: The pages in order.
+    pub fn pages(self) -> impl Stream<Item = Result<R::Response, error::Error>> + Send {
+        let fetch = self.fetch;
+        let fetched = self.request.items_before();
+
+        // The state is the request of the next page and the number of items fetched so far,
+        // counted from the start of the list.
+        stream::unfold(Some((self.request, fetched)), move |state| {
+            let fetch = fetch.clone();
+            async move {
+                let (request, fetched) = state?;
+                match fetch(request.clone()).await {
+                    Ok(response) => {
+                        let fetched = fetched + R::items(&response).len();
+                        let next = request
+                            .next_request(&response, fetched)
+                            .map(|request| (request, fetched));
+                        Some((Ok(response), next))
+                    }
+                    // Stop after an error; the caller decides whether to start over.
+                    Err(err) => Some((Err(err), None)),
+                }
+            }
+        })
+    }
+
+    /// Returns a stream over the items of every page.
+    ///
+    /// # Returns
+    /// - `impl Stream<Item = Result<R::Item, error::Error>>`: The items in order.
+    pub fn items(self) -> impl Stream<Item = Result<R::Item, error::Error>> + Send {
+        self.pages().flat_map(|page| {
+            let items = match page {
+                Ok(response) => R::items(&response).iter().cloned().map(Ok).collect(),
+                Err(err) => vec![Err(err)],
+            };
+            stream::iter(items)
+        })
+    }
+}
+
+/// Builds the next request of an operation paging with `NextToken`.
+///
+/// # Arguments
+/// - `request`: The request of the current page.
+/// - `current_token`: The token sent with the current page.
+/// - `next_token`: The `NextToken` of the current response.
+/// - `set_token`: Stores the token in the next request.
+///
+/// # Returns
+/// - `Some(R)`: The next request.
+/// - `None`: If the response has no token, or repeats the token just sent.
+pub fn next_token_request<R: Clone>(
+    request: &R,
+    current_token: Option<&String>,
+    next_token: Option<&String>,
+    set_token: impl FnOnce(&mut R, String),
+) -> Option<R> {
+    let next_token = next_token.filter(|token| !token.is_empty())?;
+    if current_token == Some(next_token) {
+        return None;
+    }
+
+    let mut request = request.clone();
+    set_token(&mut request, next_token.clone());
+    Some(request)
+}
+
+/// Returns the next page number of an operation paging with `PageNumber` / `PageSize`.
+///
+/// # Arguments
+/// - `page_number`: The page number of the current page, `0` when it was not set.
+/// - `page_items`: The number of items of the current page.
+/// - `fetched`: The number of items fetched so far.
+/// - `total`: The total number of items reported by the response, if any.
+///
+/// # Returns
+/// - `Some(i64)`: The next page number.
+/// - `None`: If the current page is empty or every item has been fetched.
+pub fn next_page_number(
+    page_number: i64,
+    page_items: usize,
+    fetched: usize,
+    total: Option<i64>,
+) -> Option<i64> {
+    if page_items == 0 || total.is_some_and(|total| fetched as i64 >= total) {
+        return None;
+    }
+
+    // Page numbers start at 1.
+    Some(page_number.max(1) + 1)
+}
+
+/// Returns the number of items before a page of an operation paging with `PageNumber` / `PageSize`.
+///
+/// # Arguments
+/// - `page_number`: The page number of the request, `0` when it was not set.
+/// - `page_size`: The page size of the request, if set.
+///
+/// # Returns
+/// - `usize`: The items of the pages before `page_number`, `0` when the page size is unknown.
+pub fn items_before_page(page_number: i64, page_size: Option<i64>) -> usize {
+    let page_size = page_size.unwrap_or_default().max(0);
+    ((page_number.max(1) - 1) * page_size) as usize
+}
+
+/// Returns the next offset of an operation paging with `Offset` / `Limit`.
+///
+/// # Arguments
+/// - `offset`: The offset of the current page.
+/// - `page_items`: The number of items of the current page.
+/// - `total`: The total number of items reported by the response.
+///
+/// # Returns
+/// - `Some(i64)`: The next offset.
+/// - `None`: If the current page is empty or reaches the end of the list.
+pub fn next_offset(offset: i64, page_items: usize, total: i64) -> Option<i64> {
+    let next_offset = offset + page_items as i64;
+    if page_items == 0 || next_offset >= total {
+        return None;
+    }
+
+    Some(next_offset)
+}
@@ -0,0 +1,167 @@
+/*
+ * @Author: Jerry.Yang
+ * @Date: 2025-03-29 20:40:12
+ * @LastEditors: Jerry.Yang
+ * @LastEditTime: 2025-03-29 20:50:00
+ * @Description: paginator test
+ */
+#[cfg(test)]
+mod tests {
+    use futures::future::FutureExt;
+    use futures::TryStreamExt;
+    use std::sync::{Arc, Mutex};
+    use volcengine_sdk_protobuf::protobuf::vpc_vpc;
+
+    use crate::volcengine::paginator::paginator::{self, Paginated};
+
+    /// A request paging with `PageNumber` / `PageSize` over a list of `TOTAL` items.
+    #[derive(Debug, Clone, Default)]
+    struct ListReq {
+        page_number: Option<i64>,
+        page_size: Option<i64>,
+    }
+
+    /// The number of items listed by `ListReq`.
+    const TOTAL: i64 = 5;
+
+    impl Paginated for ListReq {
+        type Response = Vec<i64>;
+        type Item = i64;
+
+        fn items(response: &Self::Response) -> &[Self::Item] {
+            response
+        }
+
+        fn next_request(&self, response: &Self::Response, fetched: usize) -> Option<Self> {
+            let page_number = paginator::next_page_number(
+                self.page_number.unwrap_or_default(),
+                response.len(),
+                fetched,
+                Some(TOTAL),
+            )?;
+            Some(Self {
+                page_number: Some(page_number),
+                ..self.clone()
+            })
+        }
+
+        fn items_before(&self) -> usize {
+            paginator::items_before_page(self.page_number.unwrap_or_default(), self.page_size)
+        }
+    }
+
+    /// Returns a paginator over `ListReq` starting with `request`, and the page numbers it requests.
+    fn list_paginator(request: ListReq) -> (paginator::Paginator<ListReq>, Arc<Mutex<Vec<i64>>>) {
+        let requested = Arc::new(Mutex::new(Vec::new()));
+        let paginator = paginator::Paginator::new(request, {
+            let requested = requested.clone();
+            move |request: ListReq| {
+                let page_number = request.page_number.unwrap_or(1);
+                let page_size = request.page_size.unwrap_or(2);
+                requested.lock().unwrap().push(page_number);
+
+                // The items are numbered from 1 to `TOTAL`.
+                let start = (page_number - 1) * page_size + 1;
+                let page: Vec<i64> = (start..start + page_size)
+                    .filter(|item| *item <= TOTAL)
+                    .collect();
+                async move { Ok(page) }.boxed()
+            }
+        });
+        (paginator, requested)
+    }
+
+    #[test]
+    fn test_next_token_request() {
+        let token = |token: &str| Some(token.to_string());
+        let set_token =
+            |request: &mut ListReq, token: String| request.page_number = Some(token.len() as i64);
+
+        // A new token is stored in the next request.
+        let next = paginator::next_token_request(
+            &ListReq::default(),
+            None,
+            token("abc").as_ref(),
+            set_token,
+        );
+        assert_eq!(next.unwrap().page_number, Some(3));
+
+        // A missing, empty or repeated token ends the pagination.
+        let request = ListReq::default();
+        assert!(paginator::next_token_request(&request, None, None, set_token).is_none());
+        assert!(
+            paginator::next_token_request(&request, None, token("").as_ref(), set_token).is_none()
+        );
+        assert!(paginator::next_token_request(
+            &request,
+            token("abc").as_ref(),
+            token("abc").as_ref(),
+            set_token
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn test_next_page_number() {
+        // An unset page number is the first page.
+        assert_eq!(paginator::next_page_number(0, 2, 2, Some(5)), Some(2));
+        assert_eq!(paginator::next_page_number(2, 2, 4, Some(5)), Some(3));
+
+        // Without a total the pages go on until one is empty.
+        assert_eq!(paginator::next_page_number(3, 2, 6, None), Some(4));
+        assert_eq!(paginator::next_page_number(4, 0, 6, None), None);
+
+        // The last page reaches the total.
+        assert_eq!(paginator::next_page_number(3, 1, 5, Some(5)), None);
+        assert_eq!(paginator::next_page_number(1, 0, 0, Some(5)), None);
+    }
+
+    #[test]
+    fn test_next_offset() {
+        assert_eq!(paginator::next_offset(0, 2, 5), Some(2));
+        assert_eq!(paginator::next_offset(2, 2, 5), Some(4));
+        assert_eq!(paginator::next_offset(4, 1, 5), None);
+        assert_eq!(paginator::next_offset(0, 0, 5), None);
+    }
+
+    #[test]
+    fn test_items_before_page() {
+        assert_eq!(paginator::items_before_page(0, Some(10)), 0);
+        assert_eq!(paginator::items_before_page(1, Some(10)), 0);
+        assert_eq!(paginator::items_before_page(3, Some(10)), 20);
+
+        // Without a page size the items before the page are unknown.
+        assert_eq!(paginator::items_before_page(3, None), 0);
+
+        // The requests of the services count from their page number.
+        let request = vpc_vpc::DescribeVpcsReq {
+            page_number: Some(3),
+            page_size: Some(10),
+            ..Default::default()
+        };
+        assert_eq!(request.items_before(), 20);
+    }
+
+    #[tokio::test]
+    async fn test_paginator_from_first_page() {
+        let (paginator, requested) = list_paginator(ListReq::default());
+        let items: Vec<_> = paginator.items().try_collect().await.unwrap();
+
+        assert_eq!(items, [1, 2, 3, 4, 5]);
+        assert_eq!(*requested.lock().unwrap(), [1, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn test_paginator_from_later_page() {
+        // Starting at page 2 the total is reached with page 3, no empty page is requested.
+        let request = ListReq {
+            page_number: Some(2),
+            page_size: Some(2),
+        };
+        let (paginator, requested) = list_paginator(request);
+        let items: Vec<_> = paginator.items().try_collect().await.unwrap();
+
+        assert_eq!(items, [3, 4, 5]);
+        assert_eq!(*requested.lock().unwrap(), [2, 3]);
+    }
+}
@@ -0,0 +1,11 @@
+/*
+ * @Author: Jerry.Yang
+ * @Date: 2025-03-22 09:48:30
+ * @LastEditors: Jerry.Yang
+ * @LastEditTime: 2025-03-22 09:48:30
+ * @Description: mod
+ */
+pub mod registry;
+
+// Test module for the operation registry.
+mod tests;
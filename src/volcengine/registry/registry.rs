@@ -0,0 +1,369 @@
+/*
+ * @Author: Jerry.Yang
+ * @Date: 2025-03-22 09:48:30
+ * @LastEditors: Jerry.Yang
+ * @LastEditTime: 2025-03-30 11:10:00
+ * @Description: Declarative registry of the API operations
+ */
+use crate::volcengine::client::client;
+use crate::volcengine::client::client_info;
+use crate::volcengine::client::config as client_config;
+use crate::volcengine::common;
+use crate::volcengine::error::error;
+use crate::volcengine::request::operation;
+use crate::volcengine::request::operation_config::operation_http_method;
+use crate::volcengine::request::operation_config::operation_http_path;
+use crate::volcengine::request::operation_config::operation_name;
+use crate::volcengine::request::query;
+use crate::volcengine::request::request;
+use crate::volcengine::request::request::RequestVolcengine;
+use crate::volcengine::request::response;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+
+/// How the parameters of an operation are sent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContentType {
+    Query, // Flattened into the query string (`InstanceIds.1=i-1`), with no body.
+    Json,  // Serialized as the JSON body, with only `Action` and `Version` in the query.
+    Form,  // Flattened like `Query`, but sent as a form body, keeping secrets out of the URL.
+}
+
+/// The description of an API operation.
+///
+/// Every operation of the SDK is declared once in the table of this module, and the service
+/// methods call it with `invoke` from its request and response types alone.
+///
+/// # Fields
+/// - `service`: The service owning the operation.
+/// - `action`: The `Action` of the operation, e.g. `DescribeInstances`.
+/// - `http_method`: The HTTP method of the operation.
+/// - `http_path`: The HTTP path of the operation.
+/// - `version`: The API version of the operation.
+/// - `content_type`: How the parameters are sent.
+#[derive(Debug, Clone)]
+pub struct OperationSpec {
+    pub service: client_config::ClientServiceName, // Service owning the operation.
+    pub action: &'static str,                      // The `Action` of the operation.
+    pub http_method: operation_http_method::OperationHttpMethod, // HTTP method.
+    pub http_path: operation_http_path::OperationHttpPath, // HTTP path.
+    pub version: &'static str,                     // API version.
+    pub content_type: ContentType,                 // How the parameters are sent.
+}
+
+impl OperationSpec {
+    /// Builds the `Operation` sent for this operation.
+    ///
+    /// # Returns
+    /// - `operation::Operation`: The operation name, HTTP method and HTTP path.
+    pub fn operation(&self) -> operation::Operation {
+        operation::Operation {
+            name: operation_name::OperationName::Action(self.action),
+            http_method: self.http_method.clone(),
+            http_path: self.http_path.clone(),
+        }
+    }
+}
+
+/// Declares the operations of the registry.
+///
+/// Each line `SPEC => Service, Action, METHOD, version, ContentType;` defines the
+/// `OperationSpec` constant `SPEC` for the `Action` of the service, served from the default
+/// path, and adds it to `OPERATIONS`. Adding an API takes one line; doc comments written
+/// above the line are added to the documentation of the constant.
+macro_rules! operation_specs {
+    ($($(#[$meta:meta])* $spec:ident => $service:ident, $action:ident, $method:ident, $version:expr, $content_type:ident;)*) => {
+        $(
+            #[doc = concat!("The `", stringify!($action), "` operation.")]
+            $(#[$meta])*
+            pub const $spec: OperationSpec = OperationSpec {
+                service: client_config::ClientServiceName::$service,
+                action: stringify!($action),
+                http_method: operation_http_method::OperationHttpMethod::$method,
+                http_path: operation_http_path::OperationHttpPath::Default,
+                version: $version,
+                content_type: ContentType::$content_type,
+            };
+        )*
+
+        /// Every operation of the registry.
+        pub static OPERATIONS: &[OperationSpec] = &[$($spec),*];
+    };
+}
+
+#[rustfmt::skip]
+operation_specs! {
+    // ECS
+    ECS_DESCRIBE_IMAGES => Ecs, DescribeImages, GET, common::COMMON_VERSION_2020_04_01, Query;
+    ECS_DESCRIBE_INSTANCES => Ecs, DescribeInstances, GET, common::COMMON_VERSION_2020_04_01, Query;
+    ECS_DESCRIBE_REGIONS => Ecs, DescribeRegions, GET, common::COMMON_VERSION_2020_04_01, Query;
+    ECS_DESCRIBE_ZONES => Ecs, DescribeZones, GET, common::COMMON_VERSION_2020_04_01, Query;
+    ECS_MODIFY_INSTANCE_SPEC => Ecs, ModifyInstanceSpec, GET, common::COMMON_VERSION_2020_04_01, Query;
+    ECS_RUN_INSTANCES => Ecs, RunInstances, GET, common::COMMON_VERSION_2020_04_01, Query;
+    ECS_STOP_INSTANCE => Ecs, StopInstance, GET, common::COMMON_VERSION_2020_04_01, Query;
+    ECS_STOP_INSTANCES => Ecs, StopInstances, GET, common::COMMON_VERSION_2020_04_01, Query;
+    ///
+    /// Starts a stopped instance.
+    ECS_START_INSTANCE => Ecs, StartInstance, GET, common::COMMON_VERSION_2020_04_01, Query;
+    ///
+    /// Starts stopped instances, reporting the result of each one.
+    ECS_START_INSTANCES => Ecs, StartInstances, GET, common::COMMON_VERSION_2020_04_01, Query;
+    ///
+    /// Reboots a running instance.
+    ECS_REBOOT_INSTANCE => Ecs, RebootInstance, GET, common::COMMON_VERSION_2020_04_01, Query;
+    ///
+    /// Reboots running instances, reporting the result of each one.
+    ECS_REBOOT_INSTANCES => Ecs, RebootInstances, GET, common::COMMON_VERSION_2020_04_01, Query;
+    ///
+    /// Releases an instance.
+    ECS_DELETE_INSTANCE => Ecs, DeleteInstance, GET, common::COMMON_VERSION_2020_04_01, Query;
+    ///
+    /// Releases instances, reporting the result of each one.
+    ECS_DELETE_INSTANCES => Ecs, DeleteInstances, GET, common::COMMON_VERSION_2020_04_01, Query;
+    ///
+    /// Modifies the name, description, password or user data of an instance. Sent as a form
+    /// body, so that the password is not part of the URL.
+    ECS_MODIFY_INSTANCE_ATTRIBUTE => Ecs, ModifyInstanceAttribute, POST, common::COMMON_VERSION_2020_04_01, Form;
+    ///
+    /// Renews a `PrePaid` instance, placing an order.
+    ECS_RENEW_INSTANCE => Ecs, RenewInstance, GET, common::COMMON_VERSION_2020_04_01, Query;
+    ///
+    /// Switches the billing method of instances, placing an order.
+    ECS_MODIFY_INSTANCE_CHARGE_TYPE => Ecs, ModifyInstanceChargeType, GET, common::COMMON_VERSION_2020_04_01, Query;
+    ///
+    /// Replaces the system volume of a stopped instance from an image. Sent as a form body, so
+    /// that the password is not part of the URL.
+    ECS_REPLACE_SYSTEM_VOLUME => Ecs, ReplaceSystemVolume, POST, common::COMMON_VERSION_2020_04_01, Form;
+
+    // VPC
+    VPC_DESCRIBE_SUBNETS => Vpc, DescribeSubnets, GET, common::COMMON_VERSION_2020_04_01, Query;
+    VPC_DESCRIBE_VPCS => Vpc, DescribeVpcs, GET, common::COMMON_VERSION_2020_04_01, Query;
+    VPC_CREATE_SECURITY_GROUP => Vpc, CreateSecurityGroup, GET, common::COMMON_VERSION_2020_04_01, Query;
+    VPC_DESCRIBE_SECURITY_GROUPS => Vpc, DescribeSecurityGroups, GET, common::COMMON_VERSION_2020_04_01, Query;
+    VPC_DESCRIBE_SECURITY_GROUP_ATTRIBUTES => Vpc, DescribeSecurityGroupAttributes, GET, common::COMMON_VERSION_2020_04_01, Query;
+    VPC_AUTHORIZE_SECURITY_GROUP_INGRESS => Vpc, AuthorizeSecurityGroupIngress, GET, common::COMMON_VERSION_2020_04_01, Query;
+    VPC_AUTHORIZE_SECURITY_GROUP_EGRESS => Vpc, AuthorizeSecurityGroupEgress, GET, common::COMMON_VERSION_2020_04_01, Query;
+    VPC_REVOKE_SECURITY_GROUP_INGRESS => Vpc, RevokeSecurityGroupIngress, GET, common::COMMON_VERSION_2020_04_01, Query;
+    VPC_REVOKE_SECURITY_GROUP_EGRESS => Vpc, RevokeSecurityGroupEgress, GET, common::COMMON_VERSION_2020_04_01, Query;
+    VPC_DELETE_SECURITY_GROUP => Vpc, DeleteSecurityGroup, GET, common::COMMON_VERSION_2020_04_01, Query;
+    VPC_CREATE_VPC => Vpc, CreateVpc, GET, common::COMMON_VERSION_2020_04_01, Query;
+    VPC_MODIFY_VPC_ATTRIBUTES => Vpc, ModifyVpcAttributes, GET, common::COMMON_VERSION_2020_04_01, Query;
+    VPC_DELETE_VPC => Vpc, DeleteVpc, GET, common::COMMON_VERSION_2020_04_01, Query;
+    VPC_ASSOCIATE_CIDR_BLOCK => Vpc, AssociateCidrBlock, GET, common::COMMON_VERSION_2020_04_01, Query;
+    VPC_CREATE_SUBNET => Vpc, CreateSubnet, GET, common::COMMON_VERSION_2020_04_01, Query;
+    VPC_MODIFY_SUBNET_ATTRIBUTES => Vpc, ModifySubnetAttributes, GET, common::COMMON_VERSION_2020_04_01, Query;
+    VPC_DELETE_SUBNET => Vpc, DeleteSubnet, GET, common::COMMON_VERSION_2020_04_01, Query;
+    VPC_ALLOCATE_EIP_ADDRESS => Vpc, AllocateEipAddress, GET, common::COMMON_VERSION_2020_04_01, Query;
+    VPC_DESCRIBE_EIP_ADDRESSES => Vpc, DescribeEipAddresses, GET, common::COMMON_VERSION_2020_04_01, Query;
+    VPC_ASSOCIATE_EIP_ADDRESS => Vpc, AssociateEipAddress, GET, common::COMMON_VERSION_2020_04_01, Query;
+    VPC_DISASSOCIATE_EIP_ADDRESS => Vpc, DisassociateEipAddress, GET, common::COMMON_VERSION_2020_04_01, Query;
+    VPC_MODIFY_EIP_ADDRESS_ATTRIBUTES => Vpc, ModifyEipAddressAttributes, GET, common::COMMON_VERSION_2020_04_01, Query;
+    VPC_RELEASE_EIP_ADDRESS => Vpc, ReleaseEipAddress, GET, common::COMMON_VERSION_2020_04_01, Query;
+
+    // IAM
+    IAM_ATTACH_USER_POLICY => Iam, AttachUserPolicy, GET, common::COMMON_VERSION, Query;
+    IAM_CREATE_LOGIN_PROFILE => Iam, CreateLoginProfile, GET, common::COMMON_VERSION, Query;
+    IAM_CREATE_POLICY => Iam, CreatePolicy, GET, common::COMMON_VERSION, Query;
+    IAM_CREATE_PROJECT => Iam, CreateProject, GET, common::COMMON_VERSION, Query;
+    IAM_CREATE_USER => Iam, CreateUser, GET, common::COMMON_VERSION, Query;
+    IAM_DELETE_LOGIN_PROFILE => Iam, DeleteLoginProfile, GET, common::COMMON_VERSION, Query;
+    IAM_DELETE_POLICY => Iam, DeletePolicy, GET, common::COMMON_VERSION, Query;
+    IAM_DELETE_USER => Iam, DeleteUser, GET, common::COMMON_VERSION, Query;
+    IAM_DETACH_USER_POLICY => Iam, DetachUserPolicy, GET, common::COMMON_VERSION, Query;
+    IAM_GET_LOGIN_PROFILE => Iam, GetLoginProfile, GET, common::COMMON_VERSION, Query;
+    IAM_GET_POLICY => Iam, GetPolicy, GET, common::COMMON_VERSION, Query;
+    IAM_GET_PROJECT => Iam, GetProject, GET, common::COMMON_VERSION, Query;
+    IAM_GET_SECURITY_CONFIG => Iam, GetSecurityConfig, GET, common::COMMON_VERSION, Query;
+    IAM_GET_USER => Iam, GetUser, GET, common::COMMON_VERSION, Query;
+    IAM_LIST_ATTACHED_USER_POLICIES => Iam, ListAttachedUserPolicies, GET, common::COMMON_VERSION, Query;
+    IAM_LIST_POLICIES => Iam, ListPolicies, GET, common::COMMON_VERSION, Query;
+    IAM_SET_SECURITY_CONFIG => Iam, SetSecurityConfig, GET, common::COMMON_VERSION, Query;
+    IAM_UPDATE_LOGIN_PROFILE => Iam, UpdateLoginProfile, GET, common::COMMON_VERSION, Query;
+    IAM_UPDATE_POLICY => Iam, UpdatePolicy, GET, common::COMMON_VERSION, Query;
+    IAM_UPDATE_USER => Iam, UpdateUser, GET, common::COMMON_VERSION, Query;
+
+    // RDS
+    RDS_CREATE_DB_ACCOUNT => Rds, CreateDBAccount, POST, common::COMMON_VERSION_2022_01_01, Json;
+    RDS_CREATE_DATABASE => Rds, CreateDatabase, POST, common::COMMON_VERSION_2022_01_01, Json;
+    RDS_CREATE_DB_ENDPOINT => Rds, CreateDBEndpoint, POST, common::COMMON_VERSION_2022_01_01, Json;
+    RDS_CREATE_DB_INSTANCE => Rds, CreateDBInstance, POST, common::COMMON_VERSION_2022_01_01, Json;
+    RDS_DESCRIBE_DB_ACCOUNTS => Rds, DescribeDBAccounts, GET, common::COMMON_VERSION_2022_01_01, Query;
+    RDS_DESCRIBE_DATABASES => Rds, DescribeDatabases, POST, common::COMMON_VERSION_2022_01_01, Json;
+    RDS_DESCRIBE_DB_INSTANCE_DETAIL => Rds, DescribeDBInstanceDetail, GET, common::COMMON_VERSION_2022_01_01, Query;
+    RDS_DESCRIBE_DB_INSTANCES => Rds, DescribeDBInstances, POST, common::COMMON_VERSION_2022_01_01, Json;
+    RDS_MODIFY_ALLOW_LIST => Rds, ModifyAllowList, POST, common::COMMON_VERSION_2022_01_01, Query;
+    RDS_MODIFY_DB_ENDPOINT => Rds, ModifyDBEndpoint, POST, common::COMMON_VERSION_2022_01_01, Json;
+    RDS_MODIFY_DB_INSTANCE_SPEC => Rds, ModifyDBInstanceSpec, POST, common::COMMON_VERSION_2022_01_01, Json;
+
+    // Redis
+    REDIS_CREATE_DB_INSTANCE => Redis, CreateDBInstance, GET, common::COMMON_VERSION_2020_12_07, Query;
+    REDIS_DECREASE_DB_INSTANCE_NODE_NUMBER => Redis, DecreaseDBInstanceNodeNumber, POST, common::COMMON_VERSION_2020_12_07, Json;
+    REDIS_DESCRIBE_DB_INSTANCE_DETAIL => Redis, DescribeDBInstanceDetail, GET, common::COMMON_VERSION_2020_12_07, Query;
+    REDIS_DESCRIBE_DB_INSTANCES => Redis, DescribeDBInstances, POST, common::COMMON_VERSION_2020_12_07, Json;
+    REDIS_ENABLE_SHARDED_CLUSTER => Redis, EnableShardedCluster, POST, common::COMMON_VERSION_2020_12_07, Json;
+    REDIS_INCREASE_DB_INSTANCE_NODE_NUMBER => Redis, IncreaseDBInstanceNodeNumber, POST, common::COMMON_VERSION_2020_12_07, Json;
+    REDIS_MODIFY_ALLOW_LIST => Redis, ModifyAllowList, POST, common::COMMON_VERSION_2020_12_07, Query;
+    REDIS_MODIFY_DB_INSTANCE_SHARD_CAPACITY => Redis, ModifyDBInstanceShardCapacity, POST, common::COMMON_VERSION_2020_12_07, Json;
+    REDIS_MODIFY_DB_INSTANCE_SHARD_NUMBER => Redis, ModifyDBInstanceShardNumber, POST, common::COMMON_VERSION_2020_12_07, Json;
+
+    // CLB
+    CLB_DESCRIBE_LOAD_BALANCERS => Clb, DescribeLoadBalancers, GET, common::COMMON_VERSION, Query;
+
+    // STS
+    STS_ASSUME_ROLE => Sts, AssumeRole, GET, common::COMMON_VERSION, Query;
+
+    // NAT gateway
+    NAT_GATEWAY_CREATE_NAT_GATEWAY => NatGateway, CreateNatGateway, GET, common::COMMON_VERSION_2020_04_01, Query;
+    NAT_GATEWAY_DESCRIBE_NAT_GATEWAYS => NatGateway, DescribeNatGateways, GET, common::COMMON_VERSION_2020_04_01, Query;
+    NAT_GATEWAY_DELETE_NAT_GATEWAY => NatGateway, DeleteNatGateway, GET, common::COMMON_VERSION_2020_04_01, Query;
+    NAT_GATEWAY_CREATE_SNAT_ENTRY => NatGateway, CreateSnatEntry, GET, common::COMMON_VERSION_2020_04_01, Query;
+    NAT_GATEWAY_DESCRIBE_SNAT_ENTRIES => NatGateway, DescribeSnatEntries, GET, common::COMMON_VERSION_2020_04_01, Query;
+    NAT_GATEWAY_DELETE_SNAT_ENTRY => NatGateway, DeleteSnatEntry, GET, common::COMMON_VERSION_2020_04_01, Query;
+    NAT_GATEWAY_CREATE_DNAT_ENTRY => NatGateway, CreateDnatEntry, GET, common::COMMON_VERSION_2020_04_01, Query;
+    NAT_GATEWAY_DELETE_DNAT_ENTRY => NatGateway, DeleteDnatEntry, GET, common::COMMON_VERSION_2020_04_01, Query;}
+
+/// Looks up an operation of the registry.
+///
+/// # Arguments
+/// - `service`: The service, as named in its signing scope, e.g. `ecs`.
+/// - `action`: The action, e.g. `DescribeInstances`.
+///
+/// # Returns
+/// - `Option<&'static OperationSpec>`: The operation, if the registry has it.
+pub fn find(service: &str, action: &str) -> Option<&'static OperationSpec> {
+    OPERATIONS
+        .iter()
+        .find(|spec| spec.service.as_str() == service && spec.action == action)
+}
+
+/// Calls an operation of the registry.
+///
+/// The request is laid out per the `content_type` of the operation and signed with the
+/// `version` of the operation; the response is parsed by `response::parse_response`, a
+/// `ResponseMetadata.Error` being returned as `error::Error::Api`. Adding an API therefore
+/// only takes a line in the table and a method calling `invoke`.
+///
+/// # Arguments
+/// - `client`: The client of the service owning the operation.
+/// - `spec`: The operation.
+/// - `request`: The request, serialized with its field names, e.g. `InstanceIds`.
+///
+/// # Returns
+/// - `Ok(Resp)`: The parsed response.
+/// - `Err(error::Error::Api)`: If the API returned an error.
+/// - `Err(error::Error)`: If the request cannot be laid out or sent, or the response parsed.
+///
+/// # Example
+/// ```rust,no_run
+/// # use volcengine_rust_sdk::volcengine::error::error;
+/// use volcengine_rust_sdk::volcengine::client::client;
+/// use volcengine_rust_sdk::volcengine::registry::registry;
+/// use volcengine_sdk_protobuf::protobuf::ecs_instance;
+///
+/// # async fn example(client: &client::Client) -> Result<(), error::Error> {
+/// let request = ecs_instance::DescribeInstancesReq::default();
+/// let response: ecs_instance::DescribeInstancesResp =
+///     registry::invoke(client, &registry::ECS_DESCRIBE_INSTANCES, &request).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn invoke<Req, Resp>(
+    client: &client::Client,
+    spec: &OperationSpec,
+    request: &Req,
+) -> Result<Resp, error::Error>
+where
+    Req: Serialize,
+    Resp: DeserializeOwned,
+{
+    // Sign with the version of the operation.
+    let client_info = client_info::ClientInfo {
+        api_version: spec.version.to_string(),
+        ..client.client_info.clone()
+    };
+
+    // Lay the parameters out for the operation.
+    let operation_params = OperationParams::new(spec.content_type, request)?;
+
+    // Send the request and read the response.
+    let response = request::Request::builder()
+        .with_client_info(&client_info)
+        .with_config(&client.config)
+        .with_handles(&client.handles)
+        .with_operation(&spec.operation())
+        .build()?
+        .send(operation_params)
+        .await?;
+    response::parse_response(response).await
+}
+
+/// The parameters of a request, laid out for its content type.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct OperationParams {
+    query: HashMap<String, String>, // Query parameters, for `ContentType::Query`.
+    body: Vec<u8>,                  // JSON or form body, for `ContentType::Json` and `Form`.
+    content_type: Option<&'static str>, // `Content-Type` header of the body, if not the default.
+}
+
+impl OperationParams {
+    /// Lays the parameters of a request out.
+    ///
+    /// # Arguments
+    /// - `content_type`: How the parameters are sent.
+    /// - `request`: The request.
+    ///
+    /// # Returns
+    /// - `Ok(OperationParams)`: The parameters.
+    /// - `Err(error::Error::ErrSerializeQuery)`: If the request cannot be flattened into a query.
+    /// - `Err(error::Error::ErrParseJson)`: If the request cannot be serialized to JSON.
+    pub(crate) fn new<T: Serialize + ?Sized>(
+        content_type: ContentType,
+        request: &T,
+    ) -> Result<OperationParams, error::Error> {
+        match content_type {
+            ContentType::Query => Ok(OperationParams {
+                query: query::to_query_pairs(request)?.into_iter().collect(),
+                body: Vec::new(),
+                content_type: None,
+            }),
+            ContentType::Json => Ok(OperationParams {
+                query: HashMap::new(),
+                body: serde_json::to_vec(request).map_err(error::Error::ErrParseJson)?,
+                content_type: None,
+            }),
+            ContentType::Form => {
+                // sorted by key, so that the same request always yields the same body
+                let body = query::to_query_pairs(request)?
+                    .into_iter()
+                    .collect::<BTreeMap<String, String>>()
+                    .iter()
+                    .map(|(k, v)| format!("{}={}", urlencoding::encode(k), urlencoding::encode(v)))
+                    .collect::<Vec<String>>()
+                    .join("&");
+                Ok(OperationParams {
+                    query: HashMap::new(),
+                    body: body.into_bytes(),
+                    content_type: Some("application/x-www-form-urlencoded"),
+                })
+            }
+        }
+    }
+}
+
+impl request::ApiRequest for OperationParams {
+    /// Returns the query parameters.
+    fn to_hashmap(&self) -> HashMap<String, String> {
+        self.query.clone()
+    }
+
+    /// Returns the JSON or form body.
+    fn to_body(&self) -> Vec<u8> {
+        self.body.clone()
+    }
+
+    /// Returns the `Content-Type` of the form body.
+    fn content_type(&self) -> Option<&'static str> {
+        self.content_type
+    }
+}
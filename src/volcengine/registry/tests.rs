@@ -0,0 +1,204 @@
+/*
+ * @Author: Jerry.Yang
+ * @Date: 2025-03-22 14:20:51
+ * @LastEditors: Jerry.Yang
+ * @LastEditTime: 2025-03-30 12:10:00
+ * @Description: registry test
+ */
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use volcengine_sdk_protobuf::protobuf::{ecs_instance, rds_database};
+
+    use crate::volcengine::client::{client, client_info, config as client_config};
+    use crate::volcengine::common;
+    use crate::volcengine::error::error;
+    use crate::volcengine::mock::mock;
+    use crate::volcengine::registry::registry;
+    use crate::volcengine::request::operation_config::operation_http_method::OperationHttpMethod;
+    use crate::volcengine::request::operation_config::operation_name::OperationName;
+
+    /// Returns a client of `service_name` sending its requests to the mock server.
+    fn mock_client(
+        server: &mock::MockServer,
+        service_name: client_config::ClientServiceName,
+        api_version: &str,
+    ) -> client::Client {
+        let client_config = server
+            .session()
+            .new_client_config(service_name.clone())
+            .unwrap();
+        let client_info = client_info::ClientInfo::builder()
+            .with_service_name(service_name)
+            .with_api_version(api_version)
+            .with_signing_region(&client_config.signing_region)
+            .build()
+            .unwrap();
+        client::Client::builder()
+            .with_client_info(&client_info)
+            .with_config(&client_config)
+            .with_handles(&client_config.handles)
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_invoke_query() {
+        let server = mock::MockServer::start().await;
+        server.mock(
+            "DescribeInstances",
+            "2020-04-01",
+            mock::MockResponse::json(&ecs_instance::DescribeInstancesResp {
+                result: Some(ecs_instance::DescribeInstancesResultResp {
+                    next_token: Some("token-2".to_string()),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+        );
+
+        let client = mock_client(
+            &server,
+            client_config::ClientServiceName::Ecs,
+            common::COMMON_VERSION_2020_04_01,
+        );
+        let request = ecs_instance::DescribeInstancesReq {
+            instance_ids: vec!["i-1".to_string(), "i-2".to_string()],
+            ..Default::default()
+        };
+        let response: ecs_instance::DescribeInstancesResp =
+            registry::invoke(&client, &registry::ECS_DESCRIBE_INSTANCES, &request)
+                .await
+                .unwrap();
+        assert_eq!(
+            response.result.unwrap().next_token.as_deref(),
+            Some("token-2")
+        );
+
+        // the parameters are flattened into the signed query
+        let requests = server.requests();
+        assert!(requests[0].signature_valid);
+        assert_eq!(requests[0].method, http::Method::GET);
+        assert_eq!(requests[0].query["InstanceIds.1"], "i-1");
+        assert_eq!(requests[0].query["InstanceIds.2"], "i-2");
+        assert!(requests[0].body.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_invoke_json() {
+        let server = mock::MockServer::start().await;
+        server.mock("CreateDatabase", "2022-01-01", mock::MockResponse::empty());
+
+        // the version of the operation wins over the version of the client
+        let client = mock_client(
+            &server,
+            client_config::ClientServiceName::Rds,
+            common::COMMON_VERSION,
+        );
+        let request = rds_database::CreateDatabaseReq {
+            instance_id: Some("mysql-1".to_string()),
+            db_name: Some("orders".to_string()),
+            ..Default::default()
+        };
+        let _: serde_json::Value =
+            registry::invoke(&client, &registry::RDS_CREATE_DATABASE, &request)
+                .await
+                .unwrap();
+
+        // the parameters are sent as the signed JSON body
+        let requests = server.requests();
+        assert!(requests[0].signature_valid);
+        assert_eq!(requests[0].method, http::Method::POST);
+        assert_eq!(requests[0].version, "2022-01-01");
+        assert!(!requests[0].query.contains_key("InstanceId"));
+        let body: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+        assert_eq!(body["InstanceId"], "mysql-1");
+        assert_eq!(body["DbName"], "orders");
+    }
+
+    #[tokio::test]
+    async fn test_invoke_api_error() {
+        let server = mock::MockServer::start().await;
+        server.mock(
+            "DescribeInstances",
+            "2020-04-01",
+            mock::MockResponse::error(400, "InvalidParameter", "bad request"),
+        );
+
+        let client = mock_client(
+            &server,
+            client_config::ClientServiceName::Ecs,
+            common::COMMON_VERSION_2020_04_01,
+        );
+        let result: Result<ecs_instance::DescribeInstancesResp, error::Error> = registry::invoke(
+            &client,
+            &registry::ECS_DESCRIBE_INSTANCES,
+            &ecs_instance::DescribeInstancesReq::default(),
+        )
+        .await;
+
+        assert!(matches!(
+            result,
+            Err(error::Error::Api { code, .. }) if code == "InvalidParameter"
+        ));
+    }
+
+    #[test]
+    fn test_find() {
+        let spec = registry::find("rds_mysql", "ModifyAllowList").unwrap();
+        assert_eq!(spec.version, common::COMMON_VERSION_2022_01_01);
+        assert!(matches!(spec.http_method, OperationHttpMethod::POST));
+        assert_eq!(spec.content_type, registry::ContentType::Query);
+
+        // the same action of another service is another operation
+        let spec = registry::find("redis", "ModifyAllowList").unwrap();
+        assert_eq!(spec.version, common::COMMON_VERSION_2020_12_07);
+
+        assert!(registry::find("ecs", "DescribeNothing").is_none());
+        assert!(registry::find("billing", "DescribeInstances").is_none());
+    }
+
+    #[test]
+    fn test_ecs_lifecycle_operations() {
+        // the lifecycle operations are registry lines, named by their action
+        for action in [
+            "StartInstance",
+            "StartInstances",
+            "RebootInstance",
+            "RebootInstances",
+            "DeleteInstance",
+            "DeleteInstances",
+            "ModifyInstanceAttribute",
+            "RenewInstance",
+            "ModifyInstanceChargeType",
+            "ReplaceSystemVolume",
+        ] {
+            let spec = registry::find("ecs", action).unwrap();
+            assert_eq!(spec.version, common::COMMON_VERSION_2020_04_01);
+            assert!(matches!(
+                spec.operation().name,
+                OperationName::Action(name) if name == action
+            ));
+        }
+    }
+
+    #[test]
+    fn test_operations_are_unique() {
+        let mut seen = HashSet::new();
+        for spec in registry::OPERATIONS {
+            assert!(
+                seen.insert((spec.service.as_str(), spec.action)),
+                "{} is declared twice",
+                spec.action
+            );
+
+            // the operation sent is the one declared
+            let operation = spec.operation();
+            assert_eq!(operation.name.to_string(), spec.action);
+            assert_eq!(
+                format!("{:?}", operation.http_method),
+                format!("{:?}", spec.http_method)
+            );
+        }
+    }
+}
@@ -2,13 +2,179 @@
  * @Author: Jerry.Yang
  * @Date: 2024-10-18 10:34:17
  * @LastEditors: Jerry.Yang
- * @LastEditTime: 2025-02-05 11:37:55
+ * @LastEditTime: 2025-03-30 11:10:00
  * @Description: handles
  */
+use crate::volcengine::error::error;
+use crate::volcengine::request::request;
+use std::fmt;
+use std::sync::Arc;
+
+/// A hook invoked right before a request is signed and sent.
+///
+/// The hook receives the SDK request (operation, client info, configuration) and the
+/// fully built `reqwest::Request`, which it may modify: add headers, rewrite the URL, etc.
+/// The request is signed after all before-send hooks have run, so changes to the URL and
+/// added `X-*` headers are covered by the signature. Returning an error aborts the call.
+pub type BeforeSendHandler =
+    Arc<dyn Fn(&request::Request, &mut reqwest::Request) -> Result<(), error::Error> + Send + Sync>;
+
+/// A hook invoked after a response has been received, before it is unmarshalled.
+///
+/// The hook receives the SDK request and the raw `reqwest::Response` (status and headers).
+/// Returning an error aborts the call.
+pub type AfterReceiveHandler =
+    Arc<dyn Fn(&request::Request, &reqwest::Response) -> Result<(), error::Error> + Send + Sync>;
+
+/// An ordered list of named handlers for a single request phase.
+///
+/// Handlers run in insertion order. Names allow a handler to be removed or replaced
+/// later, for example when a session-wide logger should be disabled for one service.
+#[derive(Clone)]
+pub struct HandlerList<H> {
+    handlers: Vec<(String, H)>, // Named handlers in execution order
+}
+
+/// Provides an empty handler list.
+impl<H> Default for HandlerList<H> {
+    fn default() -> Self {
+        HandlerList {
+            handlers: Vec::new(),
+        }
+    }
+}
+
+/// Prints the handler names only, the handlers themselves are closures.
+impl<H> fmt::Debug for HandlerList<H> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.handlers.iter().map(|(name, _)| name))
+            .finish()
+    }
+}
+
+impl<H> HandlerList<H> {
+    /// Appends a handler to the end of the list.
+    ///
+    /// # Arguments
+    /// - `name`: The name used to identify the handler.
+    /// - `handler`: The handler to append.
+    pub fn push_back(&mut self, name: &str, handler: H) {
+        self.handlers.push((name.to_string(), handler));
+    }
+
+    /// Inserts a handler at the front of the list so it runs first.
+    ///
+    /// # Arguments
+    /// - `name`: The name used to identify the handler.
+    /// - `handler`: The handler to insert.
+    pub fn push_front(&mut self, name: &str, handler: H) {
+        self.handlers.insert(0, (name.to_string(), handler));
+    }
+
+    /// Removes every handler registered under the given name.
+    ///
+    /// # Arguments
+    /// - `name`: The name of the handler to remove.
+    pub fn remove(&mut self, name: &str) {
+        self.handlers
+            .retain(|(handler_name, _)| handler_name != name);
+    }
+
+    /// Returns the number of registered handlers.
+    pub fn len(&self) -> usize {
+        self.handlers.len()
+    }
+
+    /// Returns `true` if no handler is registered.
+    pub fn is_empty(&self) -> bool {
+        self.handlers.is_empty()
+    }
+
+    /// Iterates over the handlers in execution order.
+    pub fn iter(&self) -> impl Iterator<Item = &H> {
+        self.handlers.iter().map(|(_, handler)| handler)
+    }
+}
+
 /// Represents the handles for managing request operations.
 ///
-/// This struct may contain various handles or resources required to manage the lifecycle and execution
-/// of the request operation. It serves as a container for those handles, allowing them to be passed
-/// along with the request for processing.
-#[derive(Debug, Clone)]
-pub struct Handles {}
+/// `Handles` is the request pipeline shared by a `Session` and every client created from it.
+/// Each request goes through the following phases:
+///
+/// 1. build: the `reqwest::Request` is built from the operation and API request;
+/// 2. before-send: every handler in `before_send` may inspect or modify the request;
+/// 3. sign: the `Authorization` header is computed over the final request;
+/// 4. send: the request is sent through the session transport;
+/// 5. after-receive: every handler in `after_receive` may inspect the response;
+/// 6. unmarshal: the response body is converted into the typed API response.
+///
+/// # Example
+/// ```rust,no_run
+/// # use volcengine_rust_sdk::volcengine::error::error;
+/// use reqwest::header::HeaderValue;
+/// use std::sync::Arc;
+/// use volcengine_rust_sdk::volcengine::config::Config;
+/// use volcengine_rust_sdk::volcengine::request::handles::Handles;
+/// use volcengine_rust_sdk::volcengine::session::session::Session;
+///
+/// # fn example(config: Config) -> Result<(), error::Error> {
+/// let mut handles = Handles::default();
+/// handles.before_send.push_back("trace-id", Arc::new(|_, reqwest_request| {
+///     reqwest_request
+///         .headers_mut()
+///         .insert("X-Trace-Id", HeaderValue::from_static("my-trace-id"));
+///     Ok(())
+/// }));
+/// let session = Session::builder().with_config(config).with_handles(handles).build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Handles {
+    /// Handlers run before the request is signed and sent.
+    pub before_send: HandlerList<BeforeSendHandler>,
+
+    /// Handlers run after the response has been received.
+    pub after_receive: HandlerList<AfterReceiveHandler>,
+}
+
+impl Handles {
+    /// Runs the before-send handlers in order, stopping at the first error.
+    ///
+    /// # Arguments
+    /// - `request`: The SDK request being sent.
+    /// - `reqwest_request`: The HTTP request, which handlers may modify.
+    ///
+    /// # Returns
+    /// - `Ok(())` if every handler succeeded, or the first handler error.
+    pub fn run_before_send(
+        &self,
+        request: &request::Request,
+        reqwest_request: &mut reqwest::Request,
+    ) -> Result<(), error::Error> {
+        for handler in self.before_send.iter() {
+            handler(request, reqwest_request)?;
+        }
+        Ok(())
+    }
+
+    /// Runs the after-receive handlers in order, stopping at the first error.
+    ///
+    /// # Arguments
+    /// - `request`: The SDK request that produced the response.
+    /// - `response`: The HTTP response received from the server.
+    ///
+    /// # Returns
+    /// - `Ok(())` if every handler succeeded, or the first handler error.
+    pub fn run_after_receive(
+        &self,
+        request: &request::Request,
+        response: &reqwest::Response,
+    ) -> Result<(), error::Error> {
+        for handler in self.after_receive.iter() {
+            handler(request, response)?;
+        }
+        Ok(())
+    }
+}
@@ -8,7 +8,11 @@
 pub mod handles;
 pub mod operation;
 pub mod operation_config;
+pub mod query;
 pub mod request;
 pub mod response;
 pub mod send;
-mod sign;
+pub(crate) mod sign;
+
+// Test module for request signing.
+mod tests;
@@ -2,15 +2,9 @@
  * @Author: Jerry.Yang
  * @Date: 2024-10-30 10:50:03
  * @LastEditors: Jerry.Yang
- * @LastEditTime: 2024-11-13 10:46:24
+ * @LastEditTime: 2025-03-28 11:02:47
  * @Description: mod
  */
 pub mod operation_http_method;
 pub mod operation_http_path;
 pub mod operation_name;
-pub mod operation_name_clb;
-pub mod operation_name_ecs;
-pub mod operation_name_iam;
-pub mod operation_name_rds;
-pub mod operation_name_redis;
-pub mod operation_name_vpc;
@@ -2,7 +2,7 @@
  * @Author: Jerry.Yang
  * @Date: 2024-10-30 10:50:35
  * @LastEditors: Jerry.Yang
- * @LastEditTime: 2025-02-06 16:35:29
+ * @LastEditTime: 2025-03-30 09:40:00
  * @Description: operation http method
  */
 
@@ -12,7 +12,8 @@
 /// The `Debug` derive allows for easy debugging by providing a default implementation
 /// of the `fmt::Debug` trait, which enables printing the enum variants in a readable format.
 /// The `Clone` derive allows for creating copies of the enum values when needed.
-#[derive(Debug, Clone)]
+/// The `PartialEq` derive allows comparing a method with the one an operation is registered with.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum OperationHttpMethod {
     /// Represents the HTTP GET method.
     /// The GET method is used to request data from a specified resource.
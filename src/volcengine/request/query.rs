@@ -0,0 +1,603 @@
+/*
+ * @Author: Jerry.Yang
+ * @Date: 2025-03-14 10:08:12
+ * @LastEditors: Jerry.Yang
+ * @LastEditTime: 2025-03-28 13:36:09
+ * @Description: serde serializer flattening requests into Volcengine query parameters
+ */
+use crate::volcengine::error::error;
+use serde::ser::{self, Impossible, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::Display;
+
+/// Flattens a request into Volcengine query parameters.
+///
+/// Nested values are addressed with dotted keys, lists being numbered from 1:
+///
+/// | Field                                        | Parameters                                   |
+/// |----------------------------------------------|----------------------------------------------|
+/// | `InstanceName: Some("web")`                  | `InstanceName=web`                           |
+/// | `InstanceIds: ["i-1", "i-2"]`                | `InstanceIds.1=i-1`, `InstanceIds.2=i-2`     |
+/// | `TagFilters: [{Key: "env", Values: ["a"]}]`  | `TagFilters.1.Key=env`, `TagFilters.1.Values.1=a` |
+/// | `Tags: {"env": "prod"}` (map)                | `Tags.env=prod`                              |
+///
+/// `None`, unit values and empty strings are left out, strings are kept verbatim (quotes
+/// included; escaping is left to the URL encoder) and booleans are written `true`/`false`.
+/// The parameters are returned sorted by key.
+///
+/// # Arguments
+/// - `value`: The request, a struct or a map.
+///
+/// # Returns
+/// - `Ok(BTreeMap<String, String>)`: The parameters, sorted by key.
+/// - `Err(error::Error::ErrSerializeQuery)`: If the value is not a struct or a map, holds
+///   bytes, or uses map keys which are not strings, numbers or booleans.
+pub fn to_query_pairs<T: Serialize + ?Sized>(
+    value: &T,
+) -> Result<BTreeMap<String, String>, error::Error> {
+    let mut pairs = BTreeMap::new();
+    value.serialize(QuerySerializer {
+        pairs: &mut pairs,
+        prefix: String::new(),
+    })?;
+    Ok(pairs)
+}
+
+/// Lets serde report serialization failures as `error::Error`.
+impl ser::Error for error::Error {
+    fn custom<T: Display>(msg: T) -> Self {
+        error::Error::ErrSerializeQuery(msg.to_string())
+    }
+}
+
+/// Appends `key` to `prefix`.
+///
+/// # Arguments
+/// - `prefix`: The key of the enclosing value, empty at the top level.
+/// - `key`: The field name, map key or list index.
+///
+/// # Returns
+/// - `String`: `prefix.key`, or `key` at the top level.
+fn join_key(prefix: &str, key: &str) -> String {
+    if prefix.is_empty() {
+        return key.to_string();
+    }
+    format!("{}.{}", prefix, key)
+}
+
+/// Serializes a value into the parameters under `prefix`.
+struct QuerySerializer<'a> {
+    pairs: &'a mut BTreeMap<String, String>, // Parameters collected so far.
+    prefix: String,                          // Key of the value being serialized.
+}
+
+impl QuerySerializer<'_> {
+    /// Stores a scalar under the current key.
+    fn insert(self, value: String) -> Result<(), error::Error> {
+        if self.prefix.is_empty() {
+            return Err(error::Error::ErrSerializeQuery(
+                "the request must be a struct or a map".to_string(),
+            ));
+        }
+        self.pairs.insert(self.prefix, value);
+        Ok(())
+    }
+}
+
+impl<'a> ser::Serializer for QuerySerializer<'a> {
+    type Ok = ();
+    type Error = error::Error;
+    type SerializeSeq = SeqSerializer<'a>;
+    type SerializeTuple = SeqSerializer<'a>;
+    type SerializeTupleStruct = SeqSerializer<'a>;
+    type SerializeTupleVariant = SeqSerializer<'a>;
+    type SerializeMap = MapSerializer<'a>;
+    type SerializeStruct = StructSerializer<'a>;
+    type SerializeStructVariant = StructSerializer<'a>;
+
+    fn serialize_bool(self, v: bool) -> Result<(), error::Error> {
+        self.insert(v.to_string())
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<(), error::Error> {
+        self.insert(v.to_string())
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<(), error::Error> {
+        self.insert(v.to_string())
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<(), error::Error> {
+        self.insert(v.to_string())
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<(), error::Error> {
+        self.insert(v.to_string())
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<(), error::Error> {
+        self.insert(v.to_string())
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<(), error::Error> {
+        self.insert(v.to_string())
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<(), error::Error> {
+        self.insert(v.to_string())
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<(), error::Error> {
+        self.insert(v.to_string())
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<(), error::Error> {
+        self.insert(v.to_string())
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<(), error::Error> {
+        self.insert(v.to_string())
+    }
+
+    fn serialize_char(self, v: char) -> Result<(), error::Error> {
+        self.insert(v.to_string())
+    }
+
+    fn serialize_str(self, v: &str) -> Result<(), error::Error> {
+        // Empty strings are the default of the non-optional protobuf fields.
+        if v.is_empty() {
+            return Ok(());
+        }
+        self.insert(v.to_string())
+    }
+
+    fn serialize_bytes(self, _v: &[u8]) -> Result<(), error::Error> {
+        Err(error::Error::ErrSerializeQuery(format!(
+            "{} : bytes are not supported",
+            self.prefix
+        )))
+    }
+
+    fn serialize_none(self) -> Result<(), error::Error> {
+        Ok(())
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<(), error::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<(), error::Error> {
+        Ok(())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<(), error::Error> {
+        Ok(())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<(), error::Error> {
+        self.insert(variant.to_string())
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<(), error::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<(), error::Error> {
+        value.serialize(QuerySerializer {
+            prefix: join_key(&self.prefix, variant),
+            pairs: self.pairs,
+        })
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<SeqSerializer<'a>, error::Error> {
+        // The elements of a list are numbered under the key of the list.
+        if self.prefix.is_empty() {
+            return Err(error::Error::ErrSerializeQuery(
+                "the request must be a struct or a map".to_string(),
+            ));
+        }
+        Ok(SeqSerializer {
+            pairs: self.pairs,
+            prefix: self.prefix,
+            index: 0,
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<SeqSerializer<'a>, error::Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<SeqSerializer<'a>, error::Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<SeqSerializer<'a>, error::Error> {
+        Ok(SeqSerializer {
+            prefix: join_key(&self.prefix, variant),
+            pairs: self.pairs,
+            index: 0,
+        })
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<MapSerializer<'a>, error::Error> {
+        Ok(MapSerializer {
+            pairs: self.pairs,
+            prefix: self.prefix,
+            key: None,
+        })
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<StructSerializer<'a>, error::Error> {
+        Ok(StructSerializer {
+            pairs: self.pairs,
+            prefix: self.prefix,
+        })
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<StructSerializer<'a>, error::Error> {
+        Ok(StructSerializer {
+            prefix: join_key(&self.prefix, variant),
+            pairs: self.pairs,
+        })
+    }
+}
+
+/// Serializes the elements of a list as `prefix.1`, `prefix.2`...
+struct SeqSerializer<'a> {
+    pairs: &'a mut BTreeMap<String, String>, // Parameters collected so far.
+    prefix: String,                          // Key of the list.
+    index: usize,                            // Number of elements serialized so far.
+}
+
+impl SeqSerializer<'_> {
+    /// Serializes the next element.
+    fn element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), error::Error> {
+        // Indexes start at 1 and skipped elements keep their position.
+        self.index += 1;
+        value.serialize(QuerySerializer {
+            prefix: join_key(&self.prefix, &self.index.to_string()),
+            pairs: self.pairs,
+        })
+    }
+}
+
+impl ser::SerializeSeq for SeqSerializer<'_> {
+    type Ok = ();
+    type Error = error::Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), error::Error> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<(), error::Error> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeTuple for SeqSerializer<'_> {
+    type Ok = ();
+    type Error = error::Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), error::Error> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<(), error::Error> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeTupleStruct for SeqSerializer<'_> {
+    type Ok = ();
+    type Error = error::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), error::Error> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<(), error::Error> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeTupleVariant for SeqSerializer<'_> {
+    type Ok = ();
+    type Error = error::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), error::Error> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<(), error::Error> {
+        Ok(())
+    }
+}
+
+/// Serializes the entries of a map as `prefix.key`.
+struct MapSerializer<'a> {
+    pairs: &'a mut BTreeMap<String, String>, // Parameters collected so far.
+    prefix: String,                          // Key of the map.
+    key: Option<String>,                     // Key of the entry being serialized.
+}
+
+impl ser::SerializeMap for MapSerializer<'_> {
+    type Ok = ();
+    type Error = error::Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), error::Error> {
+        self.key = Some(key.serialize(MapKeySerializer)?);
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), error::Error> {
+        let key = self.key.take().ok_or_else(|| {
+            error::Error::ErrSerializeQuery("map value without a key".to_string())
+        })?;
+        value.serialize(QuerySerializer {
+            prefix: join_key(&self.prefix, &key),
+            pairs: self.pairs,
+        })
+    }
+
+    fn end(self) -> Result<(), error::Error> {
+        Ok(())
+    }
+}
+
+/// Serializes the fields of a struct as `prefix.Field`.
+struct StructSerializer<'a> {
+    pairs: &'a mut BTreeMap<String, String>, // Parameters collected so far.
+    prefix: String,                          // Key of the struct.
+}
+
+impl StructSerializer<'_> {
+    /// Serializes one field.
+    fn field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), error::Error> {
+        value.serialize(QuerySerializer {
+            prefix: join_key(&self.prefix, key),
+            pairs: self.pairs,
+        })
+    }
+}
+
+impl ser::SerializeStruct for StructSerializer<'_> {
+    type Ok = ();
+    type Error = error::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), error::Error> {
+        self.field(key, value)
+    }
+
+    fn end(self) -> Result<(), error::Error> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeStructVariant for StructSerializer<'_> {
+    type Ok = ();
+    type Error = error::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), error::Error> {
+        self.field(key, value)
+    }
+
+    fn end(self) -> Result<(), error::Error> {
+        Ok(())
+    }
+}
+
+/// Serializes a map key into a string.
+struct MapKeySerializer;
+
+impl MapKeySerializer {
+    /// Rejects keys which cannot be written in a parameter name.
+    fn unsupported(kind: &str) -> error::Error {
+        error::Error::ErrSerializeQuery(format!("map keys cannot be {}", kind))
+    }
+}
+
+impl ser::Serializer for MapKeySerializer {
+    type Ok = String;
+    type Error = error::Error;
+    type SerializeSeq = Impossible<String, error::Error>;
+    type SerializeTuple = Impossible<String, error::Error>;
+    type SerializeTupleStruct = Impossible<String, error::Error>;
+    type SerializeTupleVariant = Impossible<String, error::Error>;
+    type SerializeMap = Impossible<String, error::Error>;
+    type SerializeStruct = Impossible<String, error::Error>;
+    type SerializeStructVariant = Impossible<String, error::Error>;
+
+    fn serialize_bool(self, v: bool) -> Result<String, error::Error> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<String, error::Error> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<String, error::Error> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<String, error::Error> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<String, error::Error> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<String, error::Error> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<String, error::Error> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<String, error::Error> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<String, error::Error> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_f32(self, _v: f32) -> Result<String, error::Error> {
+        Err(Self::unsupported("floats"))
+    }
+
+    fn serialize_f64(self, _v: f64) -> Result<String, error::Error> {
+        Err(Self::unsupported("floats"))
+    }
+
+    fn serialize_char(self, v: char) -> Result<String, error::Error> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_str(self, v: &str) -> Result<String, error::Error> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_bytes(self, _v: &[u8]) -> Result<String, error::Error> {
+        Err(Self::unsupported("bytes"))
+    }
+
+    fn serialize_none(self) -> Result<String, error::Error> {
+        Err(Self::unsupported("none"))
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<String, error::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<String, error::Error> {
+        Err(Self::unsupported("unit"))
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<String, error::Error> {
+        Err(Self::unsupported("unit structs"))
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<String, error::Error> {
+        Ok(variant.to_string())
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<String, error::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<String, error::Error> {
+        Err(Self::unsupported("enum variants with data"))
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, error::Error> {
+        Err(Self::unsupported("lists"))
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, error::Error> {
+        Err(Self::unsupported("tuples"))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct, error::Error> {
+        Err(Self::unsupported("tuple structs"))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, error::Error> {
+        Err(Self::unsupported("enum variants with data"))
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, error::Error> {
+        Err(Self::unsupported("maps"))
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStruct, error::Error> {
+        Err(Self::unsupported("structs"))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, error::Error> {
+        Err(Self::unsupported("enum variants with data"))
+    }
+}
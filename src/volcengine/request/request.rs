@@ -2,7 +2,7 @@
  * @Author: Jerry.Yang
  * @Date: 2024-10-17 16:35:22
  * @LastEditors: Jerry.Yang
- * @LastEditTime: 2025-02-05 11:36:18
+ * @LastEditTime: 2025-03-29 16:05:00
  * @Description: request
  */
 use crate::volcengine::client::client_info;
@@ -15,6 +15,7 @@ use crate::volcengine::request::send;
 use crate::volcengine::request::send::SendRequest;
 use std::collections::HashMap;
 use std::future::Future;
+use std::time::Duration;
 
 /// Trait representing an API request.
 ///
@@ -57,36 +58,26 @@ pub trait ApiRequest: Send {
     /// let byte_body = request.to_body();
     /// ```
     fn to_body(&self) -> Vec<u8>;
+
+    /// Returns the `Content-Type` header of the body, if it must be set.
+    ///
+    /// Bodies are sent without the header by default; form bodies set it so that the
+    /// parameters are read from the body rather than from the query string.
+    ///
+    /// # Returns
+    /// - `Option<&'static str>`: The content type, e.g. `application/x-www-form-urlencoded`.
+    fn content_type(&self) -> Option<&'static str> {
+        None
+    }
 }
 
 /// Trait for handling requests to the Volcengine API.
 ///
 /// This trait defines the core methods required for interacting with the Volcengine API. It includes
-/// a method to format requests into a `HashMap` and another to send the API request and handle the response.
-/// Implementing this trait allows types to convert requests into the appropriate format and handle communication
-/// with the Volcengine service.
+/// a method to send the API request and handle the response, and another to presign it. Requests are
+/// laid out by `registry::OperationParams`, which flattens query parameters with `query::to_query_pairs`
+/// and reports the requests that cannot be flattened as errors.
 pub trait RequestVolcengine {
-    /// Formats the provided request into a `HashMap<String, String>`.
-    ///
-    /// This method takes a serializable request object and converts it into a `HashMap` where each field
-    /// in the request is represented as a key-value pair. It filters out any `null` or empty values to ensure
-    /// only valid fields are included in the resulting map. This can be useful for serializing data that
-    /// will be sent to the Volcengine API, making it easier to construct HTTP request bodies or query parameters.
-    ///
-    /// # Parameters
-    /// - `request`: A reference to a serializable object (must implement `serde::Serialize`).
-    ///
-    /// # Returns
-    /// - `HashMap<String, String>`: A `HashMap` where the keys are field names and the values are their corresponding
-    /// values from the request. Null or empty values are excluded.
-    ///
-    /// # Example
-    /// ```rust
-    /// let request = MyRequest { ... };
-    /// let formatted_map = request.format_request_to_hashmap(&request);
-    /// ```
-    fn format_request_to_hashmap<T: serde::Serialize>(request: &T) -> HashMap<String, String>;
-
     /// Sends an API request to the Volcengine API and returns the response.
     ///
     /// This method is responsible for sending the request to the Volcengine API and returning the response,
@@ -109,6 +100,27 @@ pub trait RequestVolcengine {
         &self,
         request: T,
     ) -> impl Future<Output = Result<reqwest::Response, error::Error>>;
+
+    /// Presigns an API request into a URL usable without credentials.
+    ///
+    /// The URL carries the parameters of the request together with `X-Algorithm`, `X-Credential`,
+    /// `X-Date`, `X-Expires`, `X-SignedHeaders` and `X-Signature`, so that it can be handed to a
+    /// browser or a job holding no access key. It must be requested with the HTTP method of the
+    /// operation, without a body, before it expires. Only requests sending their parameters in
+    /// the query can be presigned; `UniversalClient::presign` is the public entry point.
+    ///
+    /// # Parameters
+    /// - `request`: An instance that implements the `ApiRequest` trait.
+    /// - `expires`: How long the URL stays valid, at least one second.
+    ///
+    /// # Returns
+    /// - `Result<String, error::Error>`: The presigned URL, or an error if the request carries a
+    ///   body (`ErrRequestPresignBody`), could not be built or the credentials could not be resolved.
+    fn presign<T: request::ApiRequest>(
+        &self,
+        request: T,
+        expires: Duration,
+    ) -> impl Future<Output = Result<String, error::Error>>;
 }
 
 /// Represents a request to the Volcengine API.
@@ -222,131 +234,6 @@ impl Request {
 /// let result = request.send(api_request);
 /// ```
 impl RequestVolcengine for Request {
-    /// Formats the request into a `HashMap<String, String>`.
-    ///
-    /// # Parameters
-    /// - `request`: A reference to a serializable object.
-    ///
-    /// # Returns
-    /// Returns a `HashMap<String, String>` representation of the request, filtering out null or empty values.
-    fn format_request_to_hashmap<T: serde::Serialize>(request: &T) -> HashMap<String, String> {
-        // Serialize the request into a JSON value.
-        let value = serde_json::to_value(request).unwrap();
-        // Convert the JSON value into a map of key-value pairs.
-        let map = value.as_object().unwrap();
-
-        // Convert each entry from `Value` to `String`, filtering out empty or null values.
-        let mut request_hashmap: HashMap<String, String> = HashMap::new();
-        for (k, v) in map.iter() {
-            if v.is_null() || v == "" || v == "null" {
-                // Skip null, empty, or "null" values.
-                continue;
-            }
-
-            if v.is_array() {
-                // If the value is an array, convert each element to `key.index=value`.
-                if let Some(arr) = v.as_array() {
-                    if !arr.is_empty() {
-                        // Map each array element to "key.index=value" format
-                        for (i, x) in arr.iter().enumerate() {
-                            // judge x object
-                            if x.is_object() {
-                                if let Some(mapx) = x.as_object() {
-                                    for (mapx_key, mapx_value) in mapx.iter() {
-                                        // judge mapx_value
-                                        if mapx_value.is_null()
-                                            || mapx_value == ""
-                                            || mapx_value == "null"
-                                        {
-                                            // Skip null, empty, or "null" values.
-                                            continue;
-                                        }
-
-                                        // judge mapx_value is_array
-                                        if mapx_value.is_array() {
-                                            if let Some(mapx_value_arr) = mapx_value.as_array() {
-                                                if !mapx_value_arr.is_empty() {
-                                                    // for mapx_value_arr
-                                                    for (
-                                                        mapx_value_arr_key,
-                                                        mapx_value_arr_value,
-                                                    ) in mapx_value_arr.iter().enumerate()
-                                                    {
-                                                        // judge mapx_value
-                                                        if mapx_value_arr_value.is_null()
-                                                            || mapx_value_arr_value == ""
-                                                            || mapx_value_arr_value == "null"
-                                                        {
-                                                            // Skip null, empty, or "null" values.
-                                                            continue;
-                                                        }
-                                                        // insert request_hashmap
-                                                        request_hashmap.insert(
-                                                            format!(
-                                                                "{}.{}.{}.{}",
-                                                                k.clone(),
-                                                                i + 1,
-                                                                mapx_key,
-                                                                mapx_value_arr_key + 1
-                                                            ),
-                                                            mapx_value_arr_value
-                                                                .to_string()
-                                                                .replace("\"", ""),
-                                                        );
-                                                    }
-                                                }
-                                            }
-                                            continue;
-                                        }
-
-                                        // insert request_hashmap
-                                        request_hashmap.insert(
-                                            format!("{}.{}.{}", k.clone(), i + 1, mapx_key),
-                                            mapx_value.to_string().replace("\"", ""),
-                                        );
-                                    }
-                                }
-                                continue;
-                            }
-
-                            if !x.is_null() {
-                                request_hashmap.insert(
-                                    format!("{}.{}", k, i + 1),
-                                    x.to_string().replace("\"", ""),
-                                );
-                            }
-                        }
-                    }
-                }
-            } else {
-                // judge k
-                // if k == PolicyDocument
-                if k == "PolicyDocument" {
-                    // policy_document
-                    let mut policy_document = v.to_string().replace("\\", "");
-                    policy_document = policy_document[1..policy_document.len() - 1].to_string();
-                    request_hashmap.insert(k.clone(), policy_document);
-                    continue;
-                }
-
-                // judge k
-                // if k == NewPolicyDocument
-                if k == "NewPolicyDocument" {
-                    // policy_document
-                    let mut policy_document = v.to_string().replace("\\", "");
-                    policy_document = policy_document[1..policy_document.len() - 1].to_string();
-                    request_hashmap.insert(k.clone(), policy_document);
-                    continue;
-                }
-
-                // Otherwise, directly convert the value to a string and remove quotes
-                request_hashmap.insert(k.clone(), v.to_string().replace("\"", ""));
-            }
-        }
-
-        request_hashmap
-    }
-
     /// Sends the request to the Volcengine API.
     ///
     /// # Parameters
@@ -362,6 +249,24 @@ impl RequestVolcengine for Request {
     ) -> Result<reqwest::Response, error::Error> {
         send::Send::set_request(self).send(&request).await
     }
+
+    /// Presigns the request into a URL.
+    ///
+    /// # Parameters
+    /// - `request`: An instance implementing the `ApiRequest` trait.
+    /// - `expires`: How long the URL stays valid.
+    ///
+    /// # Returns
+    /// Returns a `Result` containing the presigned URL or an error.
+    async fn presign<T: request::ApiRequest>(
+        &self,
+        request: T,
+        expires: Duration,
+    ) -> Result<String, error::Error> {
+        send::Send::set_request(self)
+            .presign(&request, expires)
+            .await
+    }
 }
 
 /// Builder for constructing a `Request` instance.
@@ -2,34 +2,18 @@
  * @Author: Jerry.Yang
  * @Date: 2024-10-21 18:19:47
  * @LastEditors: Jerry.Yang
- * @LastEditTime: 2024-11-08 10:49:53
+ * @LastEditTime: 2025-03-28 10:14:26
  * @Description: response module for handling API responses
  */
 use crate::volcengine::error::error;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
-use std::future::Future;
-
-/// Trait to handle API responses
-///
-/// This trait defines the method to convert an HTTP response into a struct.
-pub trait ApiResponse {
-    /// Converts the given `reqwest::Response` into a struct implementing `ApiResponse`
-    ///
-    /// # Arguments
-    /// - `http_response`: The HTTP response received from an API request
-    ///
-    /// # Returns
-    /// Returns a `Future` that resolves to a `Result` indicating success or failure.
-    fn to_struct(
-        &mut self,
-        http_response: reqwest::Response,
-    ) -> impl Future<Output = Result<(), error::Error>>;
-}
 
 /// Metadata structure for API responses
 ///
 /// This struct holds essential metadata details from the API response.
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
 pub struct ApiResponseMetadata {
     /// Unique request ID assigned to the request
     #[serde(rename = "RequestId")]
@@ -60,6 +44,7 @@ pub struct ApiResponseMetadata {
 ///
 /// This struct captures error details such as error code and message.
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
 pub struct ApiResponseMetadataErrData {
     /// Numeric error code (if available)
     #[serde(rename = "CodeN")]
@@ -73,3 +58,62 @@ pub struct ApiResponseMetadataErrData {
     #[serde(rename = "Message")]
     pub message: String,
 }
+
+/// The part of every API response shared by all services.
+///
+/// Used to read `ResponseMetadata` before the body is parsed into the typed response.
+#[derive(Deserialize, Debug, Default)]
+#[serde(default)]
+struct ApiResponseEnvelope {
+    /// Metadata of the response
+    #[serde(rename = "ResponseMetadata")]
+    response_metadata: Option<ApiResponseMetadata>,
+}
+
+/// Parses an HTTP response into a typed API response.
+///
+/// The body is first checked for `ResponseMetadata.Error`. When it is present the call
+/// failed and `error::Error::Api` is returned, whatever the HTTP status. A failure status
+/// without error metadata (e.g. a gateway error page) is reported as `ErrResponseStatus`.
+///
+/// # Arguments
+/// - `http_response`: The HTTP response received from an API request
+///
+/// # Returns
+/// - `Ok(T)`: The parsed response.
+/// - `Err(error::Error::Api)`: If the response metadata carries an error.
+/// - `Err(error::Error)`: If the status is a failure, or the body cannot be read or parsed.
+pub async fn parse_response<T: DeserializeOwned>(
+    http_response: reqwest::Response,
+) -> Result<T, error::Error> {
+    // Read the status and the raw body.
+    let http_status = http_response.status();
+    let body = http_response
+        .bytes()
+        .await
+        .map_err(error::Error::ErrParseResponse)?;
+
+    // Surface the API error carried by the response metadata.
+    let envelope: ApiResponseEnvelope = serde_json::from_slice(&body).unwrap_or_default();
+    if let Some(response_metadata) = envelope.response_metadata {
+        if let Some(response_metadata_error) = response_metadata.error {
+            return Err(error::Error::Api {
+                code: response_metadata_error.code,
+                code_n: response_metadata_error.code_no,
+                message: response_metadata_error.message,
+                request_id: response_metadata.request_id,
+                http_status,
+                action: response_metadata.action,
+                service: response_metadata.service,
+            });
+        }
+    }
+
+    // Reject failure statuses which carry no error metadata.
+    if !http_status.is_success() {
+        return Err(error::Error::ErrResponseStatus(http_status));
+    }
+
+    // Parse the body into the typed response.
+    serde_json::from_slice(&body).map_err(error::Error::ErrParseJson)
+}
@@ -1,12 +1,14 @@
+use crate::volcengine::clock::clock;
+use crate::volcengine::credentials::credentials;
 use crate::volcengine::error::error;
 use crate::volcengine::request::operation_config;
 use crate::volcengine::request::request;
 use crate::volcengine::request::sign;
 use crate::volcengine::request::sign::SignRequest;
-use chrono::Utc;
-use reqwest::{Client, RequestBuilder};
-use std::collections::HashMap;
+use reqwest::RequestBuilder;
+use std::collections::{BTreeMap, HashMap};
 use std::future::Future;
+use std::time::Duration;
 
 /// Trait for handling the creation, signing, and sending of API requests.
 /// Provides methods for building request headers, signing them, and sending requests.
@@ -59,26 +61,25 @@ pub trait SendRequest {
         request: &T,
     ) -> Result<RequestBuilder, error::Error>;
 
-    /// Builds the request headers and adds the authorization header.
+    /// Signs the request by adding the authorization header.
     ///
-    /// This method extends `build_request_headers` by adding an authorization header to
-    /// the request. The authorization header is typically generated using an HMAC or similar
-    /// signing method to ensure secure communication.
+    /// This method runs after the before-send handlers, so the authorization header is
+    /// computed over the final request. The authorization header is typically generated
+    /// using an HMAC or similar signing method to ensure secure communication.
     ///
     /// # Arguments
     /// - `now_date`: The current UTC date in a specific format, used for signing the authorization.
-    /// - `request`: A reference to a type implementing `ApiRequest`, which provides data for the request.
-    /// - `request_builder`: The request builder to which the authorization header will be added.
+    /// - `reqwest_request`: The built HTTP request to which the authorization header will be added.
+    /// - `credentials`: The credentials resolved from the configured provider.
     ///
     /// # Returns
-    /// Returns a `Result` containing the modified `RequestBuilder` with the authorization header
-    /// or an error.
-    fn build_request_headers_authorization<T: request::ApiRequest>(
+    /// Returns a `Result` containing the signed `reqwest::Request` or an error.
+    fn build_request_headers_authorization(
         &self,
         now_date: &str,
-        request: &T,
-        request_builder: RequestBuilder,
-    ) -> Result<RequestBuilder, error::Error>;
+        reqwest_request: reqwest::Request,
+        credentials: &credentials::Credentials,
+    ) -> Result<reqwest::Request, error::Error>;
 
     /// Builds the request object itself.
     ///
@@ -160,7 +161,15 @@ impl SendRequest for Send {
         }
     }
 
-    /// Sends the HTTP request.
+    /// Sends the HTTP request, retrying it according to the configured retry policy.
+    ///
+    /// Every attempt rebuilds the request, runs the before-send handlers and signs it again
+    /// with a fresh `X-Date`, so a retried request is never rejected as expired. The
+    /// after-receive handlers run for every response received, retried ones included.
+    ///
+    /// A request rejected for its `X-Date` while the `Date` of the server is far from the
+    /// local clock is sent once more, immediately, with the time corrected by the measured
+    /// skew; that extra attempt is not counted by the retry policy.
     ///
     /// # Arguments
     /// - `request`: The request object to be sent.
@@ -171,28 +180,45 @@ impl SendRequest for Send {
         &self,
         request: &T,
     ) -> Result<reqwest::Response, error::Error> {
-        // Get the current date and time in UTC.
-        let now_date_string = self.get_x_date();
-        let now_date = now_date_string.as_str();
-
-        // Build the request.
-        let request_builder = self.build_request(request)?;
-
-        // Build the request headers.
-        let request_builder = self.build_request_headers(&now_date, request_builder, request)?;
-
-        // Add the authorization header.
-        let request_builder =
-            self.build_request_headers_authorization(&now_date, request, request_builder)?;
-
-        // Send the request and handle the response.
-        let response = request_builder
-            .send()
-            .await
-            .map_err(|e| error::Error::ErrRequest(e))?;
+        let retry_policy = &self.request.config.config.retry_policy;
+        let mut attempt = 1;
+        let mut clock_skew_corrected = false;
+
+        loop {
+            // Send one attempt and find out whether it may be retried.
+            let (result, retryable) = match self.send_attempt(request).await {
+                Ok(response) => {
+                    let (response, retryable, error_code) = self.inspect_response(response).await?;
+
+                    // Run the after-receive handlers.
+                    self.request
+                        .handles
+                        .run_after_receive(&self.request, &response)?;
+
+                    // Sign again with the server time if the local clock is off.
+                    if !clock_skew_corrected
+                        && self.correct_clock_skew(&response, error_code.as_deref())
+                    {
+                        clock_skew_corrected = true;
+                        continue;
+                    }
+                    (Ok(response), retryable)
+                }
+                Err(err) => {
+                    let retryable = retry_policy.is_retryable_error(&err);
+                    (Err(err), retryable)
+                }
+            };
+
+            // Return the outcome once it is final or the attempts are exhausted.
+            if !retryable || !retry_policy.has_attempts_left(attempt) {
+                return result;
+            }
 
-        // Return the response.
-        Ok(response)
+            // Back off before the next attempt.
+            tokio::time::sleep(retry_policy.delay(attempt)).await;
+            attempt += 1;
+        }
     }
 
     /// Builds the request headers.
@@ -248,26 +274,28 @@ impl SendRequest for Send {
     ///
     /// # Arguments
     /// - `now_date`: The current date and time as a string.
-    /// - `request`: The request object.
-    /// - `request_builder`: The request builder.
+    /// - `reqwest_request`: The built HTTP request.
+    /// - `credentials`: The credentials used to sign the request.
     ///
     /// # Returns
-    /// Returns the request builder with the authorization header.
-    fn build_request_headers_authorization<T: request::ApiRequest>(
+    /// Returns the HTTP request with the authorization header.
+    fn build_request_headers_authorization(
         &self,
         now_date: &str,
-        request: &T,
-        request_builder: RequestBuilder,
-    ) -> Result<RequestBuilder, error::Error> {
-        // Clone the request builder.
-        let request_builder_clone = request_builder
-            .try_clone()
-            .ok_or_else(|| error::Error::ErrRequestBuilderIsNone)?;
+        mut reqwest_request: reqwest::Request,
+        credentials: &credentials::Credentials,
+    ) -> Result<reqwest::Request, error::Error> {
+        // Temporary credentials are only valid together with their session token,
+        // which is sent in the `X-Security-Token` header and covered by the signature.
+        if let Some(session_token) = &credentials.session_token {
+            let session_token = reqwest::header::HeaderValue::from_str(session_token)
+                .map_err(|_| error::Error::ErrRequestBuildFailed)?;
+            reqwest_request
+                .headers_mut()
+                .insert("X-Security-Token", session_token);
+        }
 
-        // Build the request and get the signing headers.
-        let reqwest_request = request_builder_clone
-            .build()
-            .map_err(|e| error::Error::ErrRequest(e))?;
+        // Get the signing headers.
         let request_sign = sign::Sign::default();
         let sign_headers = request_sign.get_sign_header_keys(&reqwest_request);
 
@@ -282,13 +310,13 @@ impl SendRequest for Send {
 
         // Generate the signature.
         let signature =
-            request_sign.build_signature(now_date, &self.request, request, &request_builder)?;
+            request_sign.build_signature(now_date, &self.request, &reqwest_request, credentials)?;
 
         // Build the `Authorization` header.
         let short_date = &now_date[..8];
         let authorization = format!(
             r#"HMAC-SHA256 Credential={AccessKey}/{ShortDate}/{Region}/{Service}/request, SignedHeaders={SignedHeaders}, Signature={Signature}"#,
-            AccessKey = self.request.config.config.credentials.access_key_id,
+            AccessKey = credentials.access_key_id,
             ShortDate = short_date,
             Region = self.request.client_info.signing_region,
             Service = self.request.client_info.service_name.as_str(),
@@ -297,10 +325,14 @@ impl SendRequest for Send {
         );
 
         // Add the authorization header to the request.
-        let request_builder_with_headers = request_builder.header("Authorization", authorization);
-
-        // Return the request builder with the authorization header.
-        Ok(request_builder_with_headers)
+        let authorization = reqwest::header::HeaderValue::from_str(&authorization)
+            .map_err(|_| error::Error::ErrRequestBuildFailed)?;
+        reqwest_request
+            .headers_mut()
+            .insert(reqwest::header::AUTHORIZATION, authorization);
+
+        // Return the request with the authorization header.
+        Ok(reqwest_request)
     }
 
     /// Builds the request.
@@ -314,13 +346,17 @@ impl SendRequest for Send {
         &self,
         request: &T,
     ) -> Result<RequestBuilder, error::Error> {
-        let client = Client::new();
+        // Use the pooled client shared by the session.
+        let client = self.request.config.transport.client();
 
-        // Format the request parameters into a query string.
+        // Format the request parameters into a query string, sorted by key so that the same
+        // request always yields the same URL.
         let query_string = self
             .format_request_hashmap(request)
+            .into_iter()
+            .collect::<BTreeMap<String, String>>()
             .iter()
-            .map(|(k, v)| format!("{}={}", k, urlencoding::encode(v)))
+            .map(|(k, v)| format!("{}={}", urlencoding::encode(k), urlencoding::encode(v)))
             .collect::<Vec<String>>()
             .join("&");
 
@@ -335,11 +371,22 @@ impl SendRequest for Send {
         // Build the request based on the HTTP method.
         let request_builder = match self.request.operation.http_method {
             operation_config::operation_http_method::OperationHttpMethod::GET => {
+                // A GET request sends its parameters in the query only; refuse to drop a body.
+                let body = request.to_body();
+                if !body.is_empty() {
+                    return Err(error::Error::ErrRequestGetBody(body.len()));
+                }
                 client.get(&request_addr)
             }
             operation_config::operation_http_method::OperationHttpMethod::POST => {
                 let body = request.to_body();
-                client.post(&request_addr).body(body)
+                let request_builder = client.post(&request_addr).body(body);
+                match request.content_type() {
+                    Some(content_type) => {
+                        request_builder.header(reqwest::header::CONTENT_TYPE, content_type)
+                    }
+                    None => request_builder,
+                }
             }
         };
 
@@ -382,6 +429,213 @@ impl SendRequest for Send {
     /// # Returns
     /// Returns the formatted date and time as a String.
     fn get_x_date(&self) -> String {
-        Utc::now().format("%Y%m%dT%H%M%SZ").to_string()
+        // Read the configured clock, corrected by the skew learnt from the server.
+        let config = &self.request.config.config;
+        config
+            .clock_skew
+            .adjust(config.clock.now())
+            .format("%Y%m%dT%H%M%SZ")
+            .to_string()
+    }
+}
+
+/**
+ * @description: Helpers used by `Send::send` to run a single attempt.
+ * @author: Jerry.Yang
+ * @date: 2025-03-06 11:20:48
+ * @return {*}
+ */
+impl Send {
+    /// Builds, signs and sends the request once.
+    ///
+    /// # Arguments
+    /// - `request`: The request object to be sent.
+    ///
+    /// # Returns
+    /// Returns a `Result` containing either the `reqwest::Response` or an error.
+    async fn send_attempt<T: request::ApiRequest>(
+        &self,
+        request: &T,
+    ) -> Result<reqwest::Response, error::Error> {
+        // Get the current date and time in UTC.
+        let now_date_string = self.get_x_date();
+        let now_date = now_date_string.as_str();
+
+        // Build the request.
+        let request_builder = self.build_request(request)?;
+
+        // Build the request headers.
+        let request_builder = self.build_request_headers(now_date, request_builder, request)?;
+
+        // Build the HTTP request so that the handlers can inspect and modify it.
+        let mut reqwest_request = request_builder.build().map_err(error::Error::ErrRequest)?;
+
+        // Run the before-send handlers.
+        self.request
+            .handles
+            .run_before_send(&self.request, &mut reqwest_request)?;
+
+        // Resolve the credentials lazily from the configured provider.
+        let credentials = self
+            .request
+            .config
+            .config
+            .credentials_provider
+            .provide_credentials()
+            .await?;
+
+        // Add the authorization header.
+        let reqwest_request =
+            self.build_request_headers_authorization(now_date, reqwest_request, &credentials)?;
+
+        // Send the request through the shared transport.
+        self.request.config.transport.execute(reqwest_request).await
+    }
+
+    /// Presigns the request into a URL carrying its authentication in the query string.
+    ///
+    /// The URL is built like the URL of `send_attempt`, with `Action`, `Version` and the
+    /// parameters of the request, then presigned by `sign::presign_url`. The before-send
+    /// handlers are not run, since no request is sent. A URL carries no body, so requests
+    /// sending their parameters as a body are refused rather than silently dropping them.
+    ///
+    /// # Arguments
+    /// - `request`: The request object to presign.
+    /// - `expires`: How long the URL stays valid.
+    ///
+    /// # Returns
+    /// Returns a `Result` containing either the presigned URL or an error,
+    /// `error::Error::ErrRequestPresignBody` if the request has a body.
+    pub(crate) async fn presign<T: request::ApiRequest>(
+        &self,
+        request: &T,
+        expires: Duration,
+    ) -> Result<String, error::Error> {
+        // The parameters of a presigned URL can only be in its query.
+        let body = request.to_body();
+        if !body.is_empty() {
+            return Err(error::Error::ErrRequestPresignBody(body.len()));
+        }
+
+        // Get the current date and time in UTC.
+        let now_date = self.get_x_date();
+
+        // Build the request to obtain its URL.
+        let reqwest_request = self
+            .build_request(request)?
+            .build()
+            .map_err(error::Error::ErrRequest)?;
+
+        // Resolve the credentials lazily from the configured provider.
+        let credentials = self
+            .request
+            .config
+            .config
+            .credentials_provider
+            .provide_credentials()
+            .await?;
+
+        // Append the query-string authentication.
+        let presigned_url = sign::presign_url(
+            reqwest_request.url(),
+            reqwest_request.method().as_str(),
+            &now_date,
+            expires,
+            &self.request.client_info.signing_region,
+            self.request.client_info.service_name.as_str(),
+            &credentials,
+        )?;
+        Ok(presigned_url.to_string())
+    }
+
+    /// Checks whether a response should be retried.
+    ///
+    /// HTTP 429 and 5xx responses are always retryable. For other error statuses the body is
+    /// read to look for a throttling code in `ResponseMetadata.Error.Code`; since reading
+    /// consumes the body, an equivalent response is rebuilt from the buffered bytes.
+    ///
+    /// # Arguments
+    /// - `response`: The response received from the server.
+    ///
+    /// # Returns
+    /// Returns the response, possibly rebuilt, whether it should be retried, and the error
+    /// code of the body when it was read.
+    async fn inspect_response(
+        &self,
+        response: reqwest::Response,
+    ) -> Result<(reqwest::Response, bool, Option<String>), error::Error> {
+        let retry_policy = &self.request.config.config.retry_policy;
+        let status = response.status();
+
+        // Successful responses are never retried.
+        if status.is_success() {
+            return Ok((response, false, None));
+        }
+
+        // Throttled and server side errors are retried regardless of the body.
+        if retry_policy.is_retryable_status(status) {
+            return Ok((response, true, None));
+        }
+
+        // Buffer the body to read the Volcengine error code.
+        let version = response.version();
+        let headers = response.headers().clone();
+        let body = response
+            .bytes()
+            .await
+            .map_err(error::Error::ErrParseResponse)?;
+
+        let error_code = serde_json::from_slice::<serde_json::Value>(&body)
+            .ok()
+            .and_then(|value| {
+                value
+                    .pointer("/ResponseMetadata/Error/Code")
+                    .and_then(|code| code.as_str())
+                    .map(|code| code.to_string())
+            });
+        let retryable = error_code
+            .as_deref()
+            .is_some_and(|code| retry_policy.is_retryable_error_code(code));
+
+        // Rebuild the response from the buffered body.
+        let mut http_response = http::Response::new(body);
+        *http_response.status_mut() = status;
+        *http_response.version_mut() = version;
+        *http_response.headers_mut() = headers;
+
+        Ok((
+            reqwest::Response::from(http_response),
+            retryable,
+            error_code,
+        ))
+    }
+
+    /// Learns the skew of the local clock from a response rejecting the `X-Date`.
+    ///
+    /// # Arguments
+    /// - `response`: The response received from the server.
+    /// - `error_code`: The code from `ResponseMetadata.Error.Code`, if any.
+    ///
+    /// # Returns
+    /// Returns `true` if a skew was measured from the `Date` header and stored, in which case
+    /// the request should be signed again.
+    fn correct_clock_skew(&self, response: &reqwest::Response, error_code: Option<&str>) -> bool {
+        if !error_code.is_some_and(clock::is_clock_skew_error_code) {
+            return false;
+        }
+
+        let config = &self.request.config.config;
+        let offset = response
+            .headers()
+            .get(reqwest::header::DATE)
+            .and_then(|server_date| server_date.to_str().ok())
+            .and_then(|server_date| clock::skew_from_server_date(server_date, config.clock.now()));
+        match offset {
+            Some(offset) => {
+                config.clock_skew.set_offset(offset);
+                true
+            }
+            None => false,
+        }
     }
 }
@@ -2,17 +2,17 @@
  * @Author: Jerry.Yang
  * @Date: 2024-10-17 14:58:16
  * @LastEditors: Jerry.Yang
- * @LastEditTime: 2025-02-19 11:09:32
+ * @LastEditTime: 2025-03-30 10:30:00
  * @Description: sign
  */
+use crate::volcengine::credentials::credentials;
 use crate::volcengine::error::error;
 use crate::volcengine::request::request;
 use hex::encode;
 use hmac::{Hmac, Mac};
-use reqwest::RequestBuilder;
 use reqwest::Url;
 use sha2::{Digest, Sha256};
-use std::collections::BTreeMap;
+use std::time::Duration;
 
 /// Trait that defines methods for generating HMAC-SHA256 signatures and constructing canonical requests
 /// for signing HTTP requests with AWS Signature Version 4 or a similar signing scheme.
@@ -56,7 +56,7 @@ pub trait SignRequest {
     ///
     /// # Returns
     /// - A `Vec<&str>` containing the header keys that should be signed.
-    fn get_sign_header_keys(&self, reqwest_request: &reqwest::Request) -> Vec<&str>;
+    fn get_sign_header_keys<'a>(&self, reqwest_request: &'a reqwest::Request) -> Vec<&'a str>;
 
     /// Builds the final signature string for the request, which includes the canonical request and string to sign.
     /// The result is an HMAC-SHA256 signature used to authorize the request.
@@ -64,29 +64,53 @@ pub trait SignRequest {
     /// # Parameters
     /// - `now_date`: The current date (formatted as "yyyyMMdd").
     /// - `request`: The request object that contains configuration and client info.
-    /// - `api_request`: The API request that is being made (generic over `ApiRequest` trait).
-    /// - `request_builder`: The builder used to construct the request (used to extract method, headers, etc.).
+    /// - `reqwest_request`: The final HTTP request (used to extract method, URL, headers and body).
+    /// - `credentials`: The credentials resolved for this request.
     ///
     /// # Returns
     /// - A `Result<String, error::Error>` where `Ok(String)` is the final signature, and `Err(error)` indicates failure.
-    fn build_signature<T: request::ApiRequest>(
+    fn build_signature(
         &self,
         now_date: &str,
         request: &request::Request,
-        api_request: &T,
-        request_builder: &RequestBuilder,
+        reqwest_request: &reqwest::Request,
+        credentials: &credentials::Credentials,
+    ) -> Result<String, error::Error>;
+
+    /// Builds the canonical request, the text whose hash is signed.
+    ///
+    /// # Parameters
+    /// - `reqwest_request`: The HTTP request being signed.
+    ///
+    /// # Returns
+    /// - A `Result<String, error::Error>` containing the canonical request or an error.
+    fn build_canonical_request(
+        &self,
+        reqwest_request: &reqwest::Request,
+    ) -> Result<String, error::Error>;
+
+    /// Builds the canonical URI, the encoded path of the request.
+    ///
+    /// # Parameters
+    /// - `reqwest_request`: The HTTP request whose URL carries the path.
+    ///
+    /// # Returns
+    /// - A `Result<String, error::Error>` containing the canonical URI or an error.
+    fn build_sign_canonical_uri(
+        &self,
+        reqwest_request: &reqwest::Request,
     ) -> Result<String, error::Error>;
 
     /// Builds the query string for the request, which is used in the canonical request.
     ///
     /// # Parameters
-    /// - `request_builder`: The builder used to construct the request.
+    /// - `reqwest_request`: The HTTP request whose URL carries the query parameters.
     ///
     /// # Returns
     /// - A `Result<String, error::Error>` containing the query string or an error.
     fn build_sign_request_query_str(
         &self,
-        request_builder: &RequestBuilder,
+        reqwest_request: &reqwest::Request,
     ) -> Result<String, error::Error>;
 
     /// Builds the string representation of the request headers to be included in the canonical request.
@@ -117,13 +141,13 @@ pub trait SignRequest {
     /// the body of the request, hashed in the case of POST requests.
     ///
     /// # Parameters
-    /// - `api_request`: The API request object from which the payload is extracted.
+    /// - `reqwest_request`: The HTTP request from which the payload is extracted.
     ///
     /// # Returns
     /// - A `Result<String, error::Error>` containing the hashed payload or an error.
-    fn build_sign_payload<T: request::ApiRequest>(
+    fn build_sign_payload(
         &self,
-        api_request: &T,
+        reqwest_request: &reqwest::Request,
     ) -> Result<String, error::Error>;
 }
 
@@ -137,6 +161,65 @@ pub trait SignRequest {
 #[derive(Debug, Default, Clone)]
 pub struct Sign;
 
+impl Sign {
+    /// Signs a canonical request.
+    ///
+    /// Hashes the canonical request, builds the string to sign within the credential scope
+    /// `date/region/service/request` and signs it with the key derived from the secret key.
+    ///
+    /// # Arguments
+    /// - `canonical_request`: The canonical request, see `build_canonical_request`.
+    /// - `now_date`: The signing time in `YYYYMMDD'T'HHMMSS'Z'` format, as sent in `X-Date`.
+    /// - `region`: The signing region, e.g. `cn-beijing`.
+    /// - `service`: The signing service, e.g. `ecs`.
+    /// - `secret_access_key`: The secret access key deriving the signing key.
+    ///
+    /// # Returns
+    /// - `String`: The signature as a hexadecimal string.
+    pub(crate) fn sign_canonical_request(
+        &self,
+        canonical_request: &str,
+        now_date: &str,
+        region: &str,
+        service: &str,
+        secret_access_key: &str,
+    ) -> String {
+        // Compute the SHA256 hash of the canonical request
+        let hash_canonical_request = hex::encode(self.hash_sha256(canonical_request.as_bytes()));
+
+        // Construct the credential scope for the signing process (date/region/service/request)
+        let sign_credential_scope_date = &now_date[..8]; // Extract date from `now_date`
+        let sign_credential_scope = format!(
+            "{}/{}/{}/request",
+            sign_credential_scope_date, // Date (YYYYMMDD)
+            region,                     // Signing region (e.g., "us-east-1")
+            service                     // Service name (e.g., "s3")
+        );
+
+        // Create the StringToSign, which includes the signing algorithm, timestamp, credential scope, and hashed canonical request
+        let sign_string = format!(
+            "HMAC-SHA256\n{}\n{}\n{}",
+            now_date,               // The current timestamp
+            sign_credential_scope,  // Credential scope (date/region/service/request)
+            hash_canonical_request  // Hash of the canonical request
+        );
+
+        // Extract the date part from `now_date` to use for signing
+        let sign_key_date_str = &now_date[..8];
+
+        // Generate the signing key using the secret key, date, region, and service
+        let signed_key = self.get_signed_key(
+            secret_access_key,
+            sign_key_date_str, // Date (YYYYMMDD)
+            region,            // Region (e.g., "us-east-1")
+            service,           // Service (e.g., "s3")
+        );
+
+        // Generate the signature using HMAC-SHA256 and the signing key
+        encode(self.hmac_sha256(&signed_key, &sign_string))
+    }
+}
+
 /// Implementation of the `SignRequest` trait for the `Sign` struct.
 ///
 /// This block provides the actual logic for each method declared in the `SignRequest` trait,
@@ -163,112 +246,128 @@ impl SignRequest for Sign {
     /// - `&self`: A reference to the `Sign` struct that implements the `SignRequest` trait.
     /// - `now_date`: A string representing the current date in `YYYYMMDD'T'HHMMSS'Z'` format.
     /// - `request`: A reference to the `request::Request` object, which contains client configuration and request-specific information.
-    /// - `api_request`: A reference to a trait object (`Box<dyn request::ApiRequest>`), representing the actual API request being made.
-    /// - `request_builder`: A reference to the `RequestBuilder` used to build the actual HTTP request.
+    /// - `reqwest_request`: A reference to the final `reqwest::Request` that will be sent.
+    /// - `credentials`: The credentials whose secret access key derives the signing key.
     ///
     /// # Returns
     /// - `Ok(String)`: The generated signature as a hexadecimal string.
     /// - `Err(error::Error)`: Returns an error if any step in the signing process fails.
-    fn build_signature<T: request::ApiRequest>(
+    fn build_signature(
         &self,
         now_date: &str,
         request: &request::Request,
-        api_request: &T,
-        request_builder: &RequestBuilder,
+        reqwest_request: &reqwest::Request,
+        credentials: &credentials::Credentials,
     ) -> Result<String, error::Error> {
-        // Clone the request_builder to avoid modifying the original
-        let request_builder_clone = request_builder
-            .try_clone()
-            .ok_or_else(|| error::Error::ErrRequestBuilderIsNone)?;
+        // Build the canonical request from the method, path, query, headers and payload
+        let canonical_request = self.build_canonical_request(reqwest_request)?;
+
+        // Sign the canonical request within the scope of the region and service
+        let signature = self.sign_canonical_request(
+            &canonical_request,
+            now_date,
+            request.client_info.signing_region.as_str(),
+            request.client_info.service_name.as_str(),
+            credentials.secret_access_key.as_str(),
+        );
 
-        // Build the actual request using the cloned builder
-        let reqwest_request = request_builder_clone
-            .build()
-            .map_err(|e| error::Error::ErrRequest(e))?;
+        // Return the final signature
+        Ok(signature)
+    }
 
+    /// Builds the canonical request of an API request.
+    ///
+    /// The canonical request joins, one per line, the HTTP method, the canonical URI, the
+    /// canonical query string, the signed headers, the list of signed header keys and the
+    /// hash of the payload.
+    ///
+    /// # Arguments
+    /// - `&self`: A reference to the `Sign` struct that implements the `SignRequest` trait.
+    /// - `reqwest_request`: A reference to the `reqwest::Request` being signed.
+    ///
+    /// # Returns
+    /// - `Ok(String)`: The canonical request.
+    /// - `Err(error::Error)`: Returns an error if a signed header is missing or invalid.
+    fn build_canonical_request(
+        &self,
+        reqwest_request: &reqwest::Request,
+    ) -> Result<String, error::Error> {
         // Retrieve the HTTP method (e.g., GET, POST) for the request
         let method = reqwest_request.method().as_str();
 
+        // Build the encoded path of the request
+        let canonical_uri = self.build_sign_canonical_uri(reqwest_request)?;
+
         // Build the query string for the request (used for signing)
-        let api_request_query_str = self.build_sign_request_query_str(request_builder)?;
+        let api_request_query_str = self.build_sign_request_query_str(reqwest_request)?;
 
         // Build the string for signing the headers (used for canonical headers)
-        let sign_headers_str = self.build_sign_headers_str(&reqwest_request)?;
+        let sign_headers_str = self.build_sign_headers_str(reqwest_request)?;
 
         // Get the keys of the headers that need to be signed
-        let sign_header_keys_str = self.build_sign_header_keys_str(&reqwest_request)?;
+        let sign_header_keys_str = self.build_sign_header_keys_str(reqwest_request)?;
 
         // Build the payload string that needs to be signed
-        let sign_payload = self.build_sign_payload(api_request)?;
+        let sign_payload = self.build_sign_payload(reqwest_request)?;
 
-        // Construct the CanonicalRequest string by concatenating the method, URL, query string, headers, and payload
-        let canonical_request = format!(
+        // Concatenate the method, path, query string, headers and payload
+        Ok(format!(
             "{}\n{}\n{}\n{}\n{}\n{}",
             method,                // HTTP method (e.g., GET)
-            "/",                   // Assuming root URL (this can be customized if needed)
+            canonical_uri,         // Encoded path of the request
             api_request_query_str, // Query string for the request
             sign_headers_str,      // Headers to be signed
             sign_header_keys_str,  // Header keys to be signed
             sign_payload           // Payload to be signed
-        );
-
-        // Compute the SHA256 hash of the canonical request
-        let hash_canonical_request = hex::encode(self.hash_sha256(canonical_request.as_bytes()));
-
-        // Construct the credential scope for the signing process (date/region/service/request)
-        let sign_credential_scope_date = &now_date[..8]; // Extract date from `now_date`
-        let sign_credential_scope = format!(
-            "{}/{}/{}/request",
-            sign_credential_scope_date,                // Date (YYYYMMDD)
-            request.client_info.signing_region,        // Signing region (e.g., "us-east-1")
-            request.client_info.service_name.as_str()  // Service name (e.g., "s3")
-        );
-
-        // Create the StringToSign, which includes the signing algorithm, timestamp, credential scope, and hashed canonical request
-        let sign_string = format!(
-            "HMAC-SHA256\n{}\n{}\n{}",
-            now_date,               // The current timestamp
-            sign_credential_scope,  // Credential scope (date/region/service/request)
-            hash_canonical_request  // Hash of the canonical request
-        );
-
-        // Extract the date part from `now_date` to use for signing
-        let sign_key_date_str = &now_date[..8];
-
-        // Generate the signing key using the secret key, date, region, and service
-        let signed_key = self.get_signed_key(
-            request.config.config.credentials.secret_access_key.as_str(),
-            sign_key_date_str,                           // Date (YYYYMMDD)
-            request.client_info.signing_region.as_str(), // Region (e.g., "us-east-1")
-            request.client_info.service_name.as_str(),   // Service (e.g., "s3")
-        );
-
-        // Generate the signature using HMAC-SHA256 and the signing key
-        let signature = encode(self.hmac_sha256(&signed_key, &sign_string));
+        ))
+    }
 
-        // Return the final signature
-        Ok(signature)
+    /// Builds the canonical URI of an API request.
+    ///
+    /// The path is taken from the request URL, decoded, and every segment encoded again with
+    /// `uri_encode`, so that a path is encoded exactly once whatever the caller passed. An
+    /// empty path is signed as `/`.
+    ///
+    /// # Arguments
+    /// - `&self`: A reference to the `Sign` struct that implements the `SignRequest` trait.
+    /// - `reqwest_request`: A reference to the `reqwest::Request` whose path is signed.
+    ///
+    /// # Returns
+    /// - `Ok(String)`: The canonical URI, e.g. `/bucket/object%20name`.
+    /// - `Err(error::Error)`: Returns an error if the URL cannot be parsed.
+    fn build_sign_canonical_uri(
+        &self,
+        reqwest_request: &reqwest::Request,
+    ) -> Result<String, error::Error> {
+        Ok(canonical_uri(reqwest_request.url().path()))
     }
 
     /// Builds the payload hash for signing an API request.
     ///
-    /// This function generates the hash of the request payload. It first converts the request's body
-    /// to a byte slice, hashes it using SHA256, and then encodes the hash in hexadecimal format.
-    /// The resulting payload hash is used in the canonical request for signing the API request.
+    /// This function generates the hash of the request payload. It takes the request's body
+    /// as a byte slice (an empty slice when there is no body), hashes it using SHA256, and then
+    /// encodes the hash in hexadecimal format. The resulting payload hash is used in the
+    /// canonical request for signing the API request.
     ///
     /// # Arguments
     /// - `&self`: A reference to the `Sign` struct that implements the `SignRequest` trait.
-    /// - `api_request`: A reference to a trait object (`Box<dyn request::ApiRequest>`), representing the actual API request being made.
+    /// - `reqwest_request`: A reference to the `reqwest::Request` whose body is hashed.
     ///
     /// # Returns
     /// - `Ok(String)`: The resulting payload hash encoded in hexadecimal format.
     /// - `Err(error::Error)`: Returns an error if any issue occurs during hashing or encoding.
-    fn build_sign_payload<T: request::ApiRequest>(
+    fn build_sign_payload(
         &self,
-        api_request: &T,
+        reqwest_request: &reqwest::Request,
     ) -> Result<String, error::Error> {
+        // Retrieve the buffered request body, treating a missing body as empty
+        let body = reqwest_request
+            .body()
+            .and_then(|body| body.as_bytes())
+            .unwrap_or_default();
+
         // Generate the SHA256 hash of the request body (payload)
-        let payload_hash = encode(self.hash_sha256(api_request.to_body().as_ref()));
+        let payload_hash = encode(self.hash_sha256(body));
 
         // Return the resulting payload hash as a hexadecimal string
         Ok(payload_hash)
@@ -336,18 +435,8 @@ impl SignRequest for Sign {
         for sign_header_key in sign_header_keys {
             // Check if the current header key is "Host".
             if sign_header_key == "Host" {
-                // Get the URL of the request.
-                let url = reqwest_request.url().as_str();
-                let parse_url =
-                    Url::parse(url).map_err(|e| error::Error::ErrRequestSignGetHost(e))?;
-
-                // Check if the URL contains a host.
-                if parse_url.host_str().is_none() {
-                    return Err(error::Error::ErrRequestSignGetHostNone);
-                }
-
-                // Retrieve the host string from the URL.
-                let host_str = parse_url.host_str().unwrap();
+                // Retrieve the host of the request URL, as sent in the `Host` header.
+                let host_str = canonical_host(reqwest_request.url())?;
 
                 // Convert the "Host" header key to lowercase and add it to the signed headers.
                 let lower_sign_header_key = sign_header_key.to_lowercase();
@@ -373,7 +462,11 @@ impl SignRequest for Sign {
 
             // Convert the header key to lowercase and add it to the signed headers string.
             let lower_sign_header_key = sign_header_key.to_lowercase();
-            sign_headers.push(format!("{}:{}", lower_sign_header_key, header_value_str));
+            sign_headers.push(format!(
+                "{}:{}",
+                lower_sign_header_key,
+                header_value_str.trim()
+            ));
         }
 
         // Optionally, add an empty string if the HTTP method is "GET" (commented out).
@@ -394,53 +487,24 @@ impl SignRequest for Sign {
 
     /// Builds the query string for signing the request.
     ///
-    /// This function processes the query parameters of the provided `request_builder`, extracts them,
-    /// encodes the values, and constructs a query string that can be used in signing the request.
+    /// This function decodes the query parameters of the request URL, sorts them by key and encodes
+    /// both keys and values with `uri_encode`. Repeated keys are all kept, in the order they were sent.
     ///
     /// # Arguments
     /// - `&self`: A reference to the `Sign` struct that implements the `SignRequest` trait.
-    /// - `request_builder`: A reference to the `RequestBuilder` object, which is used to construct the request.
-    ///   It contains the URL and query parameters that will be used for signing.
+    /// - `reqwest_request`: A reference to the `reqwest::Request` object whose URL contains
+    ///   the query parameters that will be used for signing.
     ///
     /// # Returns
     /// - `Ok(String)`: Returns the formatted query string for signing the request. This string consists of
     ///   key-value pairs from the URL query parameters, encoded appropriately.
-    /// - `Err(error::Error)`: Returns an error if there are any issues during the request building or query extraction.
+    /// - `Err(error::Error)`: Returns an error if there are any issues during the query extraction.
     fn build_sign_request_query_str(
         &self,
-        request_builder: &RequestBuilder,
+        reqwest_request: &reqwest::Request,
     ) -> Result<String, error::Error> {
-        // Clone the `request_builder` to allow mutation and prevent borrowing issues.
-        let request_builder_clone = request_builder
-            .try_clone()
-            .ok_or_else(|| error::Error::ErrRequestBuilderIsNone)?;
-
-        // Build the final request from the cloned `request_builder`.
-        let reqwest_request = request_builder_clone
-            .build()
-            .map_err(|e| error::Error::ErrRequest(e))?;
-
-        // Retrieve the URL of the request.
-        let url = reqwest_request.url().as_str();
-
-        // Parse the URL into a `Url` object to easily extract the query parameters.
-        let url_parse = Url::parse(url).map_err(|e| error::Error::ErrParse(e))?;
-
-        // Extract the query parameters from the URL and convert them into a HashMap.
-        // The `query_pairs()` function returns an iterator of key-value pairs.
-        let query_pairs = url_parse.query_pairs();
-        let request_hashmap: BTreeMap<String, String> = query_pairs.into_owned().collect();
-
-        // Create a vector to hold the query parameter strings in the format "key=value".
-        let mut request_vec = Vec::new();
-        for (key, value) in request_hashmap.iter() {
-            // Encode the value of each query parameter using `urlencoding` to ensure special characters
-            // are safely included in the query string.
-            request_vec.push(format!("{}={}", key, urlencoding::encode(value)));
-        }
-
-        // Join the elements of the `request_vec` into a single query string with "&" separating each key-value pair.
-        let request_query_str = request_vec.join("&");
+        // Return the canonical form of the query of the request URL.
+        let request_query_str = canonical_query(reqwest_request.url().query().unwrap_or_default());
 
         // Return the generated query string.
         Ok(request_query_str)
@@ -449,9 +513,11 @@ impl SignRequest for Sign {
     /// Retrieves the headers that need to be signed based on the HTTP request method.
     ///
     /// This function returns the list of HTTP headers that should be included in the signing process.
-    /// The headers to be signed are determined by the HTTP method. For a `POST` request, additional headers
+    /// The headers to be signed are determined by the HTTP method and listed in the sorted order
+    /// the canonical request requires. For a `POST` request, additional headers
     /// like `"X-Content-Sha256"` are included, while for other methods like `GET`, only `"Host"` and `"X-Date"`
-    /// are included in the list.
+    /// are included in the list. Every other `X-*` header of the request, such as a header added
+    /// by a before-send handler, is signed as well.
     ///
     /// # Arguments
     /// - `&self`: A reference to the `Sign` struct that implements this method.
@@ -461,20 +527,37 @@ impl SignRequest for Sign {
     /// # Returns
     /// - `Vec<&str>`: A vector of string slices representing the header keys that need to be signed.
     ///   The function returns a different set of headers depending on the HTTP method.
-    ///   - For `POST` requests: `["Host", "X-Date", "X-Content-Sha256"]`
+    ///   - For `POST` requests: `["Host", "X-Content-Sha256", "X-Date"]`
     ///   - For other methods (like `GET`): `["Host", "X-Date"]`
-    fn get_sign_header_keys(&self, reqwest_request: &reqwest::Request) -> Vec<&str> {
+    ///   - `"X-Security-Token"`, or any other `X-*` header, is added when the request carries it.
+    fn get_sign_header_keys<'a>(&self, reqwest_request: &'a reqwest::Request) -> Vec<&'a str> {
         // Get the HTTP method of the request (e.g., "POST", "GET", etc.)
         let method = reqwest_request.method().as_str();
 
-        // For POST requests, return a list of headers that include "X-Content-Sha256"
-        if method == "POST" {
-            let result = vec!["Host", "X-Date", "X-Content-Sha256"];
-            return result;
+        // For POST requests, sign the headers that include "X-Content-Sha256"
+        let mut sign_header_keys = if method == "POST" {
+            vec!["Host", "X-Content-Sha256", "X-Date"]
+        } else {
+            // For other HTTP methods (such as GET), only sign "Host" and "X-Date"
+            vec!["Host", "X-Date"]
+        };
+
+        // The other `X-*` headers, e.g. the session token of temporary credentials or a header
+        // added by a before-send handler, must be signed as well
+        for header_name in reqwest_request.headers().keys() {
+            let header_name = header_name.as_str();
+            if header_name.starts_with("x-")
+                && !sign_header_keys
+                    .iter()
+                    .any(|sign_header_key| sign_header_key.eq_ignore_ascii_case(header_name))
+            {
+                sign_header_keys.push(header_name);
+            }
         }
 
-        // For other HTTP methods (such as GET), only return "Host" and "X-Date"
-        vec!["Host", "X-Date"]
+        // The canonical request lists the signed headers in lowercase alphabetical order
+        sign_header_keys.sort_by_key(|sign_header_key| sign_header_key.to_ascii_lowercase());
+        sign_header_keys
     }
 
     /// Computes the HMAC-SHA256 hash of the given content using the provided key.
@@ -582,3 +665,167 @@ impl SignRequest for Sign {
         hasher.finalize().to_vec()
     }
 }
+
+/// Percent-encodes a string for the canonical request.
+///
+/// Only the unreserved characters of RFC 3986 (`A-Z a-z 0-9 - _ . ~`) are kept; every other
+/// byte of the UTF-8 encoding is written `%XX` with uppercase hex digits, so that a space
+/// becomes `%20` and `+` becomes `%2B`.
+///
+/// # Arguments
+/// - `value`: The string to encode.
+/// - `encode_slash`: Whether `/` is encoded, `false` for paths.
+///
+/// # Returns
+/// - `String`: The encoded string.
+pub(crate) fn uri_encode(value: &str, encode_slash: bool) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                encoded.push(byte as char)
+            }
+            b'/' if !encode_slash => encoded.push('/'),
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
+
+/// Builds the canonical URI of a path.
+///
+/// # Arguments
+/// - `path`: The path of the URL, percent-encoded or not.
+///
+/// # Returns
+/// - `String`: The path with every segment encoded once, `/` for an empty path.
+pub(crate) fn canonical_uri(path: &str) -> String {
+    if path.is_empty() {
+        return String::from("/");
+    }
+
+    // Decode first so that an already encoded path is not encoded twice.
+    let decoded = urlencoding::decode_binary(path.as_bytes());
+    uri_encode(&String::from_utf8_lossy(&decoded), false)
+}
+
+/// Builds the canonical query string of a URL query.
+///
+/// # Arguments
+/// - `query`: The query of the URL, without the leading `?`.
+///
+/// # Returns
+/// - `String`: The decoded pairs sorted by key, encoded with `uri_encode` and joined with `&`.
+pub(crate) fn canonical_query(query: &str) -> String {
+    let mut pairs: Vec<(String, String)> = url::form_urlencoded::parse(query.as_bytes())
+        .into_owned()
+        .collect();
+
+    // A stable sort keeps the values of a repeated key in the order they were sent.
+    pairs.sort_by(|(left, _), (right, _)| left.cmp(right));
+
+    pairs
+        .iter()
+        .map(|(key, value)| format!("{}={}", uri_encode(key, true), uri_encode(value, true)))
+        .collect::<Vec<String>>()
+        .join("&")
+}
+
+/// Returns the host of a URL as sent in the `Host` header.
+///
+/// # Arguments
+/// - `url`: The request URL.
+///
+/// # Returns
+/// - `Ok(String)`: The host, with the port unless it is the default one of the scheme.
+/// - `Err(error::Error::ErrRequestSignGetHostNone)`: If the URL has no host.
+pub(crate) fn canonical_host(url: &Url) -> Result<String, error::Error> {
+    let host = url
+        .host_str()
+        .ok_or(error::Error::ErrRequestSignGetHostNone)?;
+    Ok(match url.port() {
+        Some(port) => format!("{}:{}", host, port),
+        None => host.to_string(),
+    })
+}
+
+/// Presigns a URL with query-string authentication.
+///
+/// Appends `X-Algorithm`, `X-Credential`, `X-Date`, `X-Expires`, `X-SignedHeaders`, and
+/// `X-Security-Token` for temporary credentials, to the query of `url`, then the `X-Signature`
+/// computed over the method, the path, the whole query and the `host` header. The payload is
+/// signed as empty: the URL is requested without a body, so callers refuse requests with one
+/// (see `Send::presign`).
+///
+/// # Arguments
+/// - `url`: The request URL, carrying the parameters of the operation.
+/// - `method`: The HTTP method the URL will be requested with.
+/// - `now_date`: The signing time in `YYYYMMDD'T'HHMMSS'Z'` format.
+/// - `expires`: How long the URL stays valid after `now_date`, at least one second.
+/// - `region`: The signing region, e.g. `cn-beijing`.
+/// - `service`: The signing service, e.g. `ecs`.
+/// - `credentials`: The credentials signing the URL.
+///
+/// # Returns
+/// - `Ok(Url)`: The presigned URL.
+/// - `Err(error::Error::ErrRequestSignInvalidExpires)`: If `expires` is under one second.
+/// - `Err(error::Error)`: If the URL has no host.
+pub(crate) fn presign_url(
+    url: &Url,
+    method: &str,
+    now_date: &str,
+    expires: Duration,
+    region: &str,
+    service: &str,
+    credentials: &credentials::Credentials,
+) -> Result<Url, error::Error> {
+    if expires.as_secs() == 0 {
+        return Err(error::Error::ErrRequestSignInvalidExpires(expires));
+    }
+
+    let mut presigned_url = url.clone();
+    {
+        let mut query_pairs = presigned_url.query_pairs_mut();
+        query_pairs
+            .append_pair("X-Algorithm", "HMAC-SHA256")
+            .append_pair(
+                "X-Credential",
+                &format!(
+                    "{}/{}/{}/{}/request",
+                    credentials.access_key_id,
+                    &now_date[..8],
+                    region,
+                    service
+                ),
+            )
+            .append_pair("X-Date", now_date)
+            .append_pair("X-Expires", &expires.as_secs().to_string())
+            .append_pair("X-SignedHeaders", "host");
+        if let Some(session_token) = &credentials.session_token {
+            query_pairs.append_pair("X-Security-Token", session_token);
+        }
+    }
+
+    // Only the host is signed, and the payload is signed as empty.
+    let request_sign = Sign;
+    let canonical_request = format!(
+        "{}\n{}\n{}\nhost:{}\n\nhost\n{}",
+        method,
+        canonical_uri(presigned_url.path()),
+        canonical_query(presigned_url.query().unwrap_or_default()),
+        canonical_host(&presigned_url)?,
+        encode(request_sign.hash_sha256(b""))
+    );
+    let signature = request_sign.sign_canonical_request(
+        &canonical_request,
+        now_date,
+        region,
+        service,
+        &credentials.secret_access_key,
+    );
+
+    presigned_url
+        .query_pairs_mut()
+        .append_pair("X-Signature", &signature);
+    Ok(presigned_url)
+}
@@ -0,0 +1,577 @@
+/*
+ * @Author: Jerry.Yang
+ * @Date: 2025-03-15 10:12:37
+ * @LastEditors: Jerry.Yang
+ * @LastEditTime: 2025-03-30 10:30:00
+ * @Description: sign and query test
+ */
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::time::Duration;
+
+    use serde::{Deserialize, Serialize};
+
+    use crate::volcengine::{
+        credentials::credentials,
+        error::error,
+        mock::mock,
+        request::handles,
+        request::operation_config::operation_http_method::OperationHttpMethod,
+        request::query,
+        request::response,
+        request::sign::{self, SignRequest},
+        session::session,
+        universal::universal,
+    };
+    use std::sync::{Arc, Mutex};
+
+    /// A typed response, as returned by the describe requests.
+    #[derive(Deserialize, Debug, Default)]
+    #[serde(default)]
+    struct DescribeResp {
+        #[serde(rename = "ResponseMetadata")]
+        response_metadata: response::ApiResponseMetadata,
+        #[serde(rename = "Result")]
+        result: Option<DescribeResult>,
+    }
+
+    /// The result of `DescribeResp`.
+    #[derive(Deserialize, Debug, Default)]
+    #[serde(default)]
+    struct DescribeResult {
+        #[serde(rename = "TotalCount")]
+        total_count: i32,
+    }
+
+    /// Builds an HTTP response with `status` and `body`.
+    fn http_response(status: u16, body: &str) -> reqwest::Response {
+        http::Response::builder()
+            .status(status)
+            .body(body.to_string())
+            .unwrap()
+            .into()
+    }
+
+    /// A tag filter, as found in the describe requests.
+    #[derive(Serialize)]
+    struct TagFilter {
+        #[serde(rename = "Key")]
+        key: String,
+        #[serde(rename = "Values")]
+        values: Vec<String>,
+    }
+
+    /// A request exercising the shapes flattened into query parameters.
+    #[derive(Serialize, Default)]
+    struct QueryReq {
+        #[serde(rename = "InstanceName")]
+        instance_name: Option<String>,
+        #[serde(rename = "Description")]
+        description: String,
+        #[serde(rename = "MaxResults")]
+        max_results: Option<i32>,
+        #[serde(rename = "DryRun")]
+        dry_run: Option<bool>,
+        #[serde(rename = "PolicyDocument")]
+        policy_document: String,
+        #[serde(rename = "Tags")]
+        tags: Vec<TagFilter>,
+        #[serde(rename = "Labels")]
+        labels: HashMap<String, String>,
+    }
+
+    // Signing time shared by the test vectors.
+    const X_DATE: &str = "20250314T080000Z";
+
+    #[test]
+    fn test_uri_encode() {
+        // Unreserved characters are kept, `~` included.
+        assert_eq!(sign::uri_encode("AZaz09-_.~", true), "AZaz09-_.~");
+
+        // Spaces are `%20`, never `+`, and `+` itself is encoded.
+        assert_eq!(sign::uri_encode("a b+c", true), "a%20b%2Bc");
+
+        // Reserved characters and UTF-8 bytes use uppercase hex digits.
+        assert_eq!(sign::uri_encode("*'()!=&", true), "%2A%27%28%29%21%3D%26");
+        assert_eq!(sign::uri_encode("中", true), "%E4%B8%AD");
+
+        // `/` is only kept in paths.
+        assert_eq!(sign::uri_encode("a/b", true), "a%2Fb");
+        assert_eq!(sign::uri_encode("a/b", false), "a/b");
+    }
+
+    #[test]
+    fn test_canonical_uri() {
+        assert_eq!(sign::canonical_uri(""), "/");
+        assert_eq!(sign::canonical_uri("/"), "/");
+        assert_eq!(sign::canonical_uri("/v1/objects"), "/v1/objects");
+
+        // Encoded paths are not encoded twice.
+        assert_eq!(
+            sign::canonical_uri("/bucket/my%20file.txt"),
+            "/bucket/my%20file.txt"
+        );
+        assert_eq!(sign::canonical_uri("/a+b/c~d"), "/a%2Bb/c~d");
+        assert_eq!(sign::canonical_uri("/%E4%B8%AD"), "/%E4%B8%AD");
+    }
+
+    #[test]
+    fn test_canonical_query() {
+        assert_eq!(sign::canonical_query(""), "");
+
+        // Keys are sorted, repeated keys keep the order they were sent in.
+        assert_eq!(
+            sign::canonical_query("Version=2020-04-01&Tag=b&Action=Run&Tag=a"),
+            "Action=Run&Tag=b&Tag=a&Version=2020-04-01"
+        );
+
+        // Keys and values are both decoded then encoded again.
+        assert_eq!(
+            sign::canonical_query("Tag%20Key=a+b&Name=x%2By~z&Policy=%7B%22a%22%3A1%7D"),
+            "Name=x%2By~z&Policy=%7B%22a%22%3A1%7D&Tag%20Key=a%20b"
+        );
+    }
+
+    #[test]
+    fn test_sign_get_request() {
+        let reqwest_request = reqwest::Client::new()
+            .get("https://ecs.cn-beijing.volcengineapi.com/?Version=2020-04-01&Action=DescribeInstances&InstanceIds.1=i-1")
+            .header("X-Date", X_DATE)
+            .build()
+            .unwrap();
+
+        let request_sign = sign::Sign;
+        let canonical_request = request_sign
+            .build_canonical_request(&reqwest_request)
+            .unwrap();
+        assert_eq!(
+            canonical_request,
+            "GET\n\
+             /\n\
+             Action=DescribeInstances&InstanceIds.1=i-1&Version=2020-04-01\n\
+             host:ecs.cn-beijing.volcengineapi.com\n\
+             x-date:20250314T080000Z\n\
+             \n\
+             host;x-date\n\
+             e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+
+        let signature = request_sign.sign_canonical_request(
+            &canonical_request,
+            X_DATE,
+            "cn-beijing",
+            "ecs",
+            "secret",
+        );
+        assert_eq!(
+            signature,
+            "47100329f0209fce1e1e154bd5fbb11c58c07cd0bd99b1b2c3630a12cd5f3893"
+        );
+    }
+
+    #[test]
+    fn test_sign_post_request_with_path() {
+        let body = r#"{"Name":"a b"}"#;
+        let payload_hash = "9b70b1839dff3f380a547937c7c0393cfda82653ad66481a6f3fbf1c53d26de0";
+        let reqwest_request = reqwest::Client::new()
+            .post("http://127.0.0.1:8080/v1/objects/my file.txt?Action=PutObject&Version=2025-01-01&Tag=b&Tag=a")
+            .header("X-Date", X_DATE)
+            .header("X-Content-Sha256", payload_hash)
+            .header("X-Security-Token", "token")
+            .body(body)
+            .build()
+            .unwrap();
+
+        let request_sign = sign::Sign;
+        let canonical_request = request_sign
+            .build_canonical_request(&reqwest_request)
+            .unwrap();
+        assert_eq!(
+            canonical_request,
+            format!(
+                "POST\n\
+                 /v1/objects/my%20file.txt\n\
+                 Action=PutObject&Tag=b&Tag=a&Version=2025-01-01\n\
+                 host:127.0.0.1:8080\n\
+                 x-content-sha256:{payload_hash}\n\
+                 x-date:20250314T080000Z\n\
+                 x-security-token:token\n\
+                 \n\
+                 host;x-content-sha256;x-date;x-security-token\n\
+                 {payload_hash}"
+            )
+        );
+
+        let signature = request_sign.sign_canonical_request(
+            &canonical_request,
+            X_DATE,
+            "cn-shanghai",
+            "tos",
+            "secret",
+        );
+        assert_eq!(
+            signature,
+            "a58f967387ba7ae6e25709d07cbebdacbc49c6e7d5a0e0a1bb596a168ab3218a"
+        );
+    }
+
+    #[test]
+    fn test_presign_url() {
+        let url = reqwest::Url::parse(
+            "https://ecs.cn-beijing.volcengineapi.com/?Action=DescribeInstances&Version=2020-04-01",
+        )
+        .unwrap();
+        let mut credentials = credentials::Credentials::new("AKLTtest", "secret");
+        credentials.session_token = Some("tok en".to_string());
+
+        let presigned_url = sign::presign_url(
+            &url,
+            "GET",
+            X_DATE,
+            Duration::from_secs(300),
+            "cn-beijing",
+            "ecs",
+            &credentials,
+        )
+        .unwrap();
+        assert_eq!(
+            presigned_url.as_str(),
+            "https://ecs.cn-beijing.volcengineapi.com/?Action=DescribeInstances&Version=2020-04-01\
+             &X-Algorithm=HMAC-SHA256\
+             &X-Credential=AKLTtest%2F20250314%2Fcn-beijing%2Fecs%2Frequest\
+             &X-Date=20250314T080000Z\
+             &X-Expires=300\
+             &X-SignedHeaders=host\
+             &X-Security-Token=tok+en\
+             &X-Signature=9d2dd204b47800bf94c857ca606ce9dd24099601cf0f552d4d51fca9be327d16"
+        );
+
+        // A URL valid for no time at all is refused.
+        let result = sign::presign_url(
+            &url,
+            "GET",
+            X_DATE,
+            Duration::from_millis(500),
+            "cn-beijing",
+            "ecs",
+            &credentials,
+        );
+        assert!(matches!(
+            result,
+            Err(error::Error::ErrRequestSignInvalidExpires(_))
+        ));
+    }
+
+    #[test]
+    fn test_query_pairs_nesting() {
+        let request = QueryReq {
+            tags: vec![
+                TagFilter {
+                    key: "env".to_string(),
+                    values: vec!["prod".to_string(), "staging".to_string()],
+                },
+                TagFilter {
+                    key: "team".to_string(),
+                    values: vec!["db".to_string()],
+                },
+            ],
+            labels: HashMap::from([("app".to_string(), "web".to_string())]),
+            ..Default::default()
+        };
+        let pairs = query::to_query_pairs(&request).unwrap();
+
+        // lists are numbered from 1 at every level, maps are addressed by their keys
+        assert_eq!(pairs["Tags.1.Key"], "env");
+        assert_eq!(pairs["Tags.1.Values.1"], "prod");
+        assert_eq!(pairs["Tags.1.Values.2"], "staging");
+        assert_eq!(pairs["Tags.2.Key"], "team");
+        assert_eq!(pairs["Tags.2.Values.1"], "db");
+        assert_eq!(pairs["Labels.app"], "web");
+        assert_eq!(pairs.len(), 6);
+    }
+
+    #[test]
+    fn test_query_pairs_scalars() {
+        let request = QueryReq {
+            instance_name: Some("web \"1\"".to_string()),
+            max_results: Some(20),
+            dry_run: Some(false),
+            ..Default::default()
+        };
+        let pairs = query::to_query_pairs(&request).unwrap();
+
+        // strings are kept verbatim, quotes included
+        assert_eq!(pairs["InstanceName"], "web \"1\"");
+        assert_eq!(pairs["MaxResults"], "20");
+        assert_eq!(pairs["DryRun"], "false");
+
+        // `None`, empty strings and empty lists or maps are left out
+        assert!(!pairs.contains_key("Description"));
+        assert!(!pairs.contains_key("PolicyDocument"));
+        assert_eq!(pairs.len(), 3);
+        assert!(query::to_query_pairs(&QueryReq::default())
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_query_pairs_policy_document() {
+        // a JSON document is a string like any other, sent without escaping or reformatting
+        let policy_document =
+            r#"{"Statement":[{"Effect":"Allow","Action":["ecs:Describe*"],"Resource":["*"]}]}"#;
+        let request = QueryReq {
+            policy_document: policy_document.to_string(),
+            ..Default::default()
+        };
+        let pairs = query::to_query_pairs(&request).unwrap();
+
+        assert_eq!(pairs["PolicyDocument"], policy_document);
+        assert_eq!(pairs.len(), 1);
+    }
+
+    #[test]
+    fn test_query_pairs_errors() {
+        // only structs and maps have parameter names
+        assert!(matches!(
+            query::to_query_pairs(&"DescribeInstances"),
+            Err(error::Error::ErrSerializeQuery(_))
+        ));
+        assert!(matches!(
+            query::to_query_pairs(&vec![1, 2]),
+            Err(error::Error::ErrSerializeQuery(_))
+        ));
+
+        // map keys must be written in a parameter name
+        let labels = HashMap::from([(vec![1], "web".to_string())]);
+        assert!(matches!(
+            query::to_query_pairs(&labels),
+            Err(error::Error::ErrSerializeQuery(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_parse_response() {
+        let body = r#"{
+            "ResponseMetadata": { "RequestId": "req-1", "Action": "DescribeVpcs" },
+            "Result": { "TotalCount": 2 }
+        }"#;
+        let response: DescribeResp = response::parse_response(http_response(200, body))
+            .await
+            .unwrap();
+
+        assert_eq!(response.response_metadata.request_id, "req-1");
+        assert_eq!(response.result.unwrap().total_count, 2);
+    }
+
+    #[tokio::test]
+    async fn test_parse_response_api_error() {
+        let body = r#"{
+            "ResponseMetadata": {
+                "RequestId": "req-1",
+                "Action": "DescribeVpcs",
+                "Version": "2020-04-01",
+                "Service": "vpc",
+                "Region": "cn-beijing",
+                "Error": { "CodeN": 100009, "Code": "InvalidVpc.NotFound", "Message": "the vpc does not exist" }
+            }
+        }"#;
+        let result = response::parse_response::<DescribeResp>(http_response(404, body)).await;
+
+        match result {
+            Err(error::Error::Api {
+                code,
+                code_n,
+                message,
+                request_id,
+                http_status,
+                action,
+                service,
+            }) => {
+                assert_eq!(code, "InvalidVpc.NotFound");
+                assert_eq!(code_n, Some(100009));
+                assert_eq!(message, "the vpc does not exist");
+                assert_eq!(request_id, "req-1");
+                assert_eq!(http_status, reqwest::StatusCode::NOT_FOUND);
+                assert_eq!(action, "DescribeVpcs");
+                assert_eq!(service, "vpc");
+            }
+            other => panic!("expected an API error, got {other:?}"),
+        }
+
+        // The error metadata wins over a success status.
+        let result = response::parse_response::<DescribeResp>(http_response(200, body)).await;
+        assert!(matches!(
+            result,
+            Err(error::Error::Api { code, http_status, .. })
+                if code == "InvalidVpc.NotFound" && http_status == reqwest::StatusCode::OK
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_parse_response_non_json() {
+        // A gateway error page is reported by its status.
+        let body = "<html><body>502 Bad Gateway</body></html>";
+        let result = response::parse_response::<DescribeResp>(http_response(502, body)).await;
+        assert!(matches!(
+            result,
+            Err(error::Error::ErrResponseStatus(status)) if status == reqwest::StatusCode::BAD_GATEWAY
+        ));
+
+        // A successful answer which is not JSON cannot be parsed.
+        let result = response::parse_response::<DescribeResp>(http_response(200, body)).await;
+        assert!(matches!(result, Err(error::Error::ErrParseJson(_))));
+    }
+
+    /// Calls `ListBill` on the mock server through a session with `handles`.
+    async fn call_with_handles(
+        server: &mock::MockServer,
+        handles: handles::Handles,
+    ) -> Result<serde_json::Value, error::Error> {
+        let session = session::Session::builder()
+            .with_config(server.config_builder().build().unwrap())
+            .with_handles(handles)
+            .build()
+            .unwrap();
+        universal::UniversalClient::new(session)
+            .call(
+                "billing",
+                "2022-01-01",
+                "ListBill",
+                OperationHttpMethod::GET,
+                serde_json::json!({ "BillPeriod": "2025-02" }),
+            )
+            .await
+    }
+
+    /// Returns a before-send handler recording `name` into `calls`.
+    fn record_before_send(
+        calls: &Arc<Mutex<Vec<&'static str>>>,
+        name: &'static str,
+    ) -> handles::BeforeSendHandler {
+        let calls = calls.clone();
+        Arc::new(move |_, _| {
+            calls.lock().unwrap().push(name);
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_handler_list() {
+        let mut handler_list = handles::HandlerList::default();
+        assert!(handler_list.is_empty());
+
+        // `push_front` runs before the handlers pushed back
+        handler_list.push_back("second", 2);
+        handler_list.push_back("third", 3);
+        handler_list.push_front("first", 1);
+        assert_eq!(handler_list.iter().copied().collect::<Vec<_>>(), [1, 2, 3]);
+        assert_eq!(
+            format!("{:?}", handler_list),
+            r#"["first", "second", "third"]"#
+        );
+
+        // `remove` drops every handler of a name, unknown names are ignored
+        handler_list.push_back("second", 4);
+        handler_list.remove("second");
+        handler_list.remove("unknown");
+        assert_eq!(handler_list.iter().copied().collect::<Vec<_>>(), [1, 3]);
+        assert_eq!(handler_list.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_handles_before_send() {
+        let server = mock::MockServer::start().await;
+        server.mock("ListBill", "2022-01-01", mock::MockResponse::empty());
+
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let mut handles = handles::Handles::default();
+        handles.before_send.push_back(
+            "trace-id",
+            Arc::new(|_, reqwest_request| {
+                reqwest_request.headers_mut().insert(
+                    "X-Trace-Id",
+                    reqwest::header::HeaderValue::from_static("trace-1"),
+                );
+                Ok(())
+            }),
+        );
+        handles
+            .before_send
+            .push_back("second", record_before_send(&calls, "second"));
+        handles
+            .before_send
+            .push_front("first", record_before_send(&calls, "first"));
+        handles
+            .before_send
+            .push_back("removed", record_before_send(&calls, "removed"));
+        handles.before_send.remove("removed");
+        call_with_handles(&server, handles).await.unwrap();
+
+        // the handlers ran in order, the removed one did not run
+        assert_eq!(*calls.lock().unwrap(), ["first", "second"]);
+
+        // the added header is sent and covered by the signature
+        let request = server.single_request("ListBill", "billing");
+        assert!(request.signature_valid);
+        assert_eq!(request.headers["X-Trace-Id"], "trace-1");
+        let authorization = request.headers["Authorization"].to_str().unwrap();
+        assert!(authorization.contains("SignedHeaders=host;x-date;x-trace-id,"));
+    }
+
+    #[tokio::test]
+    async fn test_handles_before_send_error() {
+        let server = mock::MockServer::start().await;
+        server.mock("ListBill", "2022-01-01", mock::MockResponse::empty());
+
+        // a failing handler aborts the call before it is sent
+        let mut handles = handles::Handles::default();
+        handles.before_send.push_back(
+            "reject",
+            Arc::new(|_, _| Err(error::Error::ErrRequestBuildFailed)),
+        );
+        let result = call_with_handles(&server, handles).await;
+
+        assert!(matches!(result, Err(error::Error::ErrRequestBuildFailed)));
+        assert!(server.requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_handles_after_receive() {
+        let server = mock::MockServer::start().await;
+        server.mock(
+            "ListBill",
+            "2022-01-01",
+            mock::MockResponse::empty().with_header("X-Tt-Logid", "log-1"),
+        );
+
+        // the handler sees the status and headers of the response
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let mut handles = handles::Handles::default();
+        handles.after_receive.push_back("log-id", {
+            let received = received.clone();
+            Arc::new(move |request, response| {
+                received.lock().unwrap().push((
+                    request.operation.name.to_string(),
+                    response.status(),
+                    response.headers()["X-Tt-Logid"]
+                        .to_str()
+                        .unwrap()
+                        .to_string(),
+                ));
+                Ok(())
+            })
+        });
+        call_with_handles(&server, handles).await.unwrap();
+
+        assert_eq!(
+            *received.lock().unwrap(),
+            [(
+                "ListBill".to_string(),
+                reqwest::StatusCode::OK,
+                "log-1".to_string()
+            )]
+        );
+        server.single_request("ListBill", "billing");
+    }
+}
@@ -0,0 +1,11 @@
+/*
+ * @Author: Jerry.Yang
+ * @Date: 2025-03-06 10:02:11
+ * @LastEditors: Jerry.Yang
+ * @LastEditTime: 2025-03-29 18:58:00
+ * @Description: mod
+ */
+pub mod retry;
+
+// Test module for the retry policy.
+mod tests;
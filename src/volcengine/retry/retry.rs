@@ -0,0 +1,261 @@
+/*
+ * @Author: Jerry.Yang
+ * @Date: 2025-03-06 10:02:11
+ * @LastEditors: Jerry.Yang
+ * @LastEditTime: 2025-03-30 11:10:00
+ * @Description: Retry policy with exponential backoff and jitter
+ */
+use crate::volcengine::error::error;
+use rand::Rng;
+use std::time::Duration;
+
+/// The default maximum number of attempts, the first attempt included.
+pub const DEFAULT_MAX_ATTEMPTS: u32 = 3;
+
+/// The default delay before the first retry.
+pub const DEFAULT_BASE_DELAY: Duration = Duration::from_millis(300);
+
+/// The default upper bound of a single retry delay.
+pub const DEFAULT_MAX_DELAY: Duration = Duration::from_secs(20);
+
+/// Volcengine error codes (`ResponseMetadata.Error.Code`) treated as throttling.
+pub const DEFAULT_RETRYABLE_ERROR_CODES: [&str; 5] = [
+    "Throttling",
+    "ThrottlingException",
+    "RequestLimitExceeded",
+    "TooManyRequests",
+    "FlowLimitExceeded",
+];
+
+/// Describes when and how often a failed request is retried.
+///
+/// A request is retried when:
+/// - the connection to the endpoint could not be established;
+/// - the server answered with HTTP 429 or any 5xx status;
+/// - the response carries a throttling code in `ResponseMetadata.Error.Code`.
+///
+/// The delay before retry `n` (starting at 1) is `min(max_delay, base_delay * 2^(n - 1))`.
+/// With `jitter` enabled ("full jitter") a random delay between zero and that value is used
+/// instead, which spreads out the retries of concurrent callers hitting the same limit.
+///
+/// # Fields
+/// - `max_attempts` (`u32`): Maximum number of attempts, the first one included. `1` disables retries.
+/// - `base_delay` (`Duration`): Delay before the first retry.
+/// - `max_delay` (`Duration`): Upper bound of a single delay.
+/// - `jitter` (`bool`): Whether to use full jitter.
+/// - `retryable_error_codes` (`Vec<String>`): Error codes that trigger a retry.
+///
+/// # Example
+/// ```rust
+/// use std::time::Duration;
+/// use volcengine_rust_sdk::volcengine::retry::retry::RetryPolicy;
+///
+/// let retry_policy = RetryPolicy::builder()
+///     .with_max_attempts(5)
+///     .with_base_delay(Duration::from_millis(500))
+///     .with_max_delay(Duration::from_secs(30))
+///     .build();
+/// ```
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    pub max_attempts: u32,                  // Maximum number of attempts.
+    pub base_delay: Duration,               // Delay before the first retry.
+    pub max_delay: Duration,                // Upper bound of a single delay.
+    pub jitter: bool,                       // Whether to use full jitter.
+    pub retryable_error_codes: Vec<String>, // Error codes that trigger a retry.
+}
+
+/// Provides the default policy: 3 attempts, 300ms base delay, 20s max delay, full jitter.
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+            base_delay: DEFAULT_BASE_DELAY,
+            max_delay: DEFAULT_MAX_DELAY,
+            jitter: true,
+            retryable_error_codes: DEFAULT_RETRYABLE_ERROR_CODES
+                .iter()
+                .map(|code| code.to_string())
+                .collect(),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Creates a new `RetryPolicyBuilder` starting from the default policy.
+    ///
+    /// # Returns
+    /// - `RetryPolicyBuilder`: A builder used to configure the policy step by step.
+    pub fn builder() -> RetryPolicyBuilder {
+        RetryPolicyBuilder {
+            retry_policy: RetryPolicy::default(),
+        }
+    }
+
+    /// Returns a policy which never retries.
+    ///
+    /// # Returns
+    /// - `RetryPolicy`: A policy with a single attempt.
+    pub fn disabled() -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: 1,
+            ..RetryPolicy::default()
+        }
+    }
+
+    /// Checks whether another attempt may follow the given one.
+    ///
+    /// # Arguments
+    /// - `attempt`: The number of the attempt that just finished, starting at 1.
+    ///
+    /// # Returns
+    /// - `bool`: `true` if the attempt budget is not exhausted.
+    pub fn has_attempts_left(&self, attempt: u32) -> bool {
+        attempt < self.max_attempts
+    }
+
+    /// Computes the delay to wait before the retry following the given attempt.
+    ///
+    /// # Arguments
+    /// - `attempt`: The number of the attempt that just failed, starting at 1.
+    ///
+    /// # Returns
+    /// - `Duration`: The time to sleep before sending the request again.
+    pub fn delay(&self, attempt: u32) -> Duration {
+        // Double the base delay for every attempt, without overflowing.
+        let exponent = attempt.saturating_sub(1).min(31);
+        let backoff = self
+            .base_delay
+            .checked_mul(1u32 << exponent)
+            .unwrap_or(self.max_delay)
+            .min(self.max_delay);
+
+        if !self.jitter || backoff.is_zero() {
+            return backoff;
+        }
+
+        // Full jitter: pick a random delay in [0, backoff].
+        let millis = rand::thread_rng().gen_range(0..=backoff.as_millis() as u64);
+        Duration::from_millis(millis)
+    }
+
+    /// Checks whether an HTTP status should be retried.
+    ///
+    /// # Arguments
+    /// - `status`: The status returned by the server.
+    ///
+    /// # Returns
+    /// - `bool`: `true` for 429 Too Many Requests and every 5xx status.
+    pub fn is_retryable_status(&self, status: reqwest::StatusCode) -> bool {
+        status == reqwest::StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+    }
+
+    /// Checks whether a Volcengine error code should be retried.
+    ///
+    /// # Arguments
+    /// - `code`: The value of `ResponseMetadata.Error.Code`.
+    ///
+    /// # Returns
+    /// - `bool`: `true` if the code is one of `retryable_error_codes`.
+    pub fn is_retryable_error_code(&self, code: &str) -> bool {
+        self.retryable_error_codes
+            .iter()
+            .any(|retryable_code| retryable_code == code)
+    }
+
+    /// Checks whether an error raised while sending a request should be retried.
+    ///
+    /// Only connection failures are retried: the request never reached the server, so
+    /// sending it again cannot create a resource twice.
+    ///
+    /// # Arguments
+    /// - `err`: The error returned by the transport.
+    ///
+    /// # Returns
+    /// - `bool`: `true` if the error is a connection error.
+    pub fn is_retryable_error(&self, err: &error::Error) -> bool {
+        match err {
+            error::Error::ErrRequest(reqwest_err) => reqwest_err.is_connect(),
+            _ => false,
+        }
+    }
+}
+
+/// A builder for constructing a `RetryPolicy` instance.
+///
+/// Options which are never set keep the values of `RetryPolicy::default()`.
+pub struct RetryPolicyBuilder {
+    retry_policy: RetryPolicy, // The policy being built.
+}
+
+impl RetryPolicyBuilder {
+    /// Sets the maximum number of attempts, the first one included.
+    ///
+    /// # Arguments
+    /// - `max_attempts` (`u32`): Maximum number of attempts. Values below 1 are treated as 1.
+    ///
+    /// # Returns
+    /// - `Self`: The updated builder.
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.retry_policy.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Sets the delay before the first retry.
+    ///
+    /// # Arguments
+    /// - `base_delay` (`Duration`): The base delay, doubled after every attempt.
+    ///
+    /// # Returns
+    /// - `Self`: The updated builder.
+    pub fn with_base_delay(mut self, base_delay: Duration) -> Self {
+        self.retry_policy.base_delay = base_delay;
+        self
+    }
+
+    /// Sets the upper bound of a single delay.
+    ///
+    /// # Arguments
+    /// - `max_delay` (`Duration`): The maximum delay between two attempts.
+    ///
+    /// # Returns
+    /// - `Self`: The updated builder.
+    pub fn with_max_delay(mut self, max_delay: Duration) -> Self {
+        self.retry_policy.max_delay = max_delay;
+        self
+    }
+
+    /// Enables or disables full jitter.
+    ///
+    /// # Arguments
+    /// - `jitter` (`bool`): `true` to randomize every delay between zero and the backoff.
+    ///
+    /// # Returns
+    /// - `Self`: The updated builder.
+    pub fn with_jitter(mut self, jitter: bool) -> Self {
+        self.retry_policy.jitter = jitter;
+        self
+    }
+
+    /// Adds an error code which triggers a retry.
+    ///
+    /// # Arguments
+    /// - `code` (`&str`): A value of `ResponseMetadata.Error.Code`.
+    ///
+    /// # Returns
+    /// - `Self`: The updated builder.
+    pub fn with_retryable_error_code(mut self, code: &str) -> Self {
+        self.retry_policy
+            .retryable_error_codes
+            .push(code.to_string());
+        self
+    }
+
+    /// Finalizes the builder.
+    ///
+    /// # Returns
+    /// - `RetryPolicy`: The configured retry policy.
+    pub fn build(self) -> RetryPolicy {
+        self.retry_policy
+    }
+}
@@ -0,0 +1,236 @@
+/*
+ * @Author: Jerry.Yang
+ * @Date: 2025-03-29 18:40:27
+ * @LastEditors: Jerry.Yang
+ * @LastEditTime: 2025-03-29 18:58:00
+ * @Description: retry test
+ */
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use volcengine_sdk_protobuf::protobuf::rds_instance;
+
+    use crate::service::rds::{self, RdsService};
+    use crate::volcengine::error::error;
+    use crate::volcengine::mock::mock;
+    use crate::volcengine::retry::retry;
+    use crate::volcengine::session::session;
+
+    /// Returns a policy of `max_attempts` whose retries are not delayed.
+    fn immediate_retry_policy(max_attempts: u32) -> retry::RetryPolicy {
+        retry::RetryPolicy::builder()
+            .with_max_attempts(max_attempts)
+            .with_base_delay(Duration::ZERO)
+            .with_jitter(false)
+            .build()
+    }
+
+    /// Returns an `Rds` calling the mock server with `retry_policy`.
+    fn rds_with_retry_policy(
+        server: &mock::MockServer,
+        retry_policy: retry::RetryPolicy,
+    ) -> rds::Rds {
+        let config = server
+            .config_builder()
+            .with_retry_policy(retry_policy)
+            .build()
+            .unwrap();
+        let session = session::Session::builder()
+            .with_config(config)
+            .build()
+            .unwrap();
+        rds::Rds::new_rds(session).unwrap()
+    }
+
+    /// Sends a `DescribeDBInstanceDetail` request.
+    async fn describe_db_instance_detail(
+        rds: &rds::Rds,
+    ) -> Result<rds_instance::DescribeDbInstanceDetailResp, error::Error> {
+        rds.new_describe_db_instance_detail(rds_instance::DescribeDbInstanceDetailReq {
+            instance_id: Some("mysql-1".to_string()),
+        })
+        .await
+    }
+
+    #[test]
+    fn test_delay() {
+        // doubled from the base delay up to the max delay
+        let retry_policy = retry::RetryPolicy::builder()
+            .with_base_delay(Duration::from_millis(100))
+            .with_max_delay(Duration::from_secs(1))
+            .with_jitter(false)
+            .build();
+        let delays: Vec<_> = (1..=6).map(|attempt| retry_policy.delay(attempt)).collect();
+        assert_eq!(
+            delays,
+            [100, 200, 400, 800, 1000, 1000].map(Duration::from_millis)
+        );
+        // without overflowing for large attempts
+        assert_eq!(retry_policy.delay(40), Duration::from_secs(1));
+        assert_eq!(retry_policy.delay(u32::MAX), Duration::from_secs(1));
+        assert_eq!(retry_policy.delay(0), Duration::from_millis(100));
+
+        // full jitter stays within the same bounds
+        let retry_policy = retry::RetryPolicy::builder()
+            .with_base_delay(Duration::from_millis(100))
+            .with_max_delay(Duration::from_secs(1))
+            .build();
+        for attempt in 1..=6 {
+            let bound =
+                Duration::from_millis(100 * (1 << (attempt - 1))).min(Duration::from_secs(1));
+            for _ in 0..50 {
+                assert!(retry_policy.delay(attempt) <= bound);
+            }
+        }
+
+        // a zero base delay never sleeps
+        assert_eq!(immediate_retry_policy(3).delay(5), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_has_attempts_left() {
+        let retry_policy = immediate_retry_policy(3);
+        assert!(retry_policy.has_attempts_left(1));
+        assert!(retry_policy.has_attempts_left(2));
+        assert!(!retry_policy.has_attempts_left(3));
+        assert!(!retry::RetryPolicy::disabled().has_attempts_left(1));
+    }
+
+    #[test]
+    fn test_is_retryable_status() {
+        let retry_policy = retry::RetryPolicy::default();
+        for status in [429, 500, 502, 503, 504] {
+            let status = reqwest::StatusCode::from_u16(status).unwrap();
+            assert!(retry_policy.is_retryable_status(status), "{}", status);
+        }
+        for status in [200, 400, 401, 403, 404, 409] {
+            let status = reqwest::StatusCode::from_u16(status).unwrap();
+            assert!(!retry_policy.is_retryable_status(status), "{}", status);
+        }
+    }
+
+    #[test]
+    fn test_is_retryable_error_code() {
+        let retry_policy = retry::RetryPolicy::default();
+        for code in retry::DEFAULT_RETRYABLE_ERROR_CODES {
+            assert!(retry_policy.is_retryable_error_code(code), "{}", code);
+        }
+        // codes are compared exactly
+        assert!(!retry_policy.is_retryable_error_code("throttling"));
+        assert!(!retry_policy.is_retryable_error_code("InvalidParameter"));
+        assert!(!retry_policy.is_retryable_error_code(""));
+
+        // codes can be added to the defaults
+        let retry_policy = retry::RetryPolicy::builder()
+            .with_retryable_error_code("OperationDenied.Busy")
+            .build();
+        assert!(retry_policy.is_retryable_error_code("OperationDenied.Busy"));
+        assert!(retry_policy.is_retryable_error_code("Throttling"));
+    }
+
+    #[tokio::test]
+    async fn test_is_retryable_error() {
+        let retry_policy = retry::RetryPolicy::default();
+
+        // a connection never established is retried
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        drop(listener);
+        let connect_error = reqwest::get(format!("http://{}", address))
+            .await
+            .unwrap_err();
+        assert!(retry_policy.is_retryable_error(&error::Error::ErrRequest(connect_error)));
+
+        // other errors are not, the request may have reached the server
+        let builder_error = reqwest::get("http://").await.unwrap_err();
+        assert!(!retry_policy.is_retryable_error(&error::Error::ErrRequest(builder_error)));
+        assert!(!retry_policy.is_retryable_error(&error::Error::ErrRequestBuilderIsNone));
+    }
+
+    #[tokio::test]
+    async fn test_retry_server_error_then_success() {
+        let server = mock::MockServer::start().await;
+        server.mock(
+            "DescribeDBInstanceDetail",
+            "2022-01-01",
+            mock::MockResponse::error(503, "ServiceUnavailable", "try again later"),
+        );
+        server.mock(
+            "DescribeDBInstanceDetail",
+            "2022-01-01",
+            mock::MockResponse::json(&rds_instance::DescribeDbInstanceDetailResp::default()),
+        );
+
+        let rds = rds_with_retry_policy(&server, immediate_retry_policy(3));
+        describe_db_instance_detail(&rds).await.unwrap();
+
+        // every attempt is signed again
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert!(requests.iter().all(|request| request.signature_valid));
+    }
+
+    #[tokio::test]
+    async fn test_retry_throttling_then_success() {
+        let server = mock::MockServer::start().await;
+        server.mock(
+            "DescribeDBInstanceDetail",
+            "2022-01-01",
+            mock::MockResponse::error(400, "Throttling", "request was denied due to throttling"),
+        );
+        server.mock(
+            "DescribeDBInstanceDetail",
+            "2022-01-01",
+            mock::MockResponse::json(&rds_instance::DescribeDbInstanceDetailResp::default()),
+        );
+
+        let rds = rds_with_retry_policy(&server, immediate_retry_policy(3));
+        describe_db_instance_detail(&rds).await.unwrap();
+        assert_eq!(server.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_no_retry_client_error() {
+        let server = mock::MockServer::start().await;
+        server.mock(
+            "DescribeDBInstanceDetail",
+            "2022-01-01",
+            mock::MockResponse::error(400, "InvalidParameter", "the instance id is invalid"),
+        );
+        server.mock(
+            "DescribeDBInstanceDetail",
+            "2022-01-01",
+            mock::MockResponse::json(&rds_instance::DescribeDbInstanceDetailResp::default()),
+        );
+
+        let rds = rds_with_retry_policy(&server, immediate_retry_policy(3));
+        let result = describe_db_instance_detail(&rds).await;
+        assert!(matches!(
+            result,
+            Err(error::Error::Api { code, http_status, .. })
+                if code == "InvalidParameter" && http_status == reqwest::StatusCode::BAD_REQUEST
+        ));
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_retry_attempts_exhausted() {
+        let server = mock::MockServer::start().await;
+        server.mock(
+            "DescribeDBInstanceDetail",
+            "2022-01-01",
+            mock::MockResponse::error(503, "ServiceUnavailable", "try again later"),
+        );
+
+        // the last answer is returned once the attempts are exhausted
+        let rds = rds_with_retry_policy(&server, immediate_retry_policy(3));
+        let result = describe_db_instance_detail(&rds).await;
+        assert!(matches!(
+            result,
+            Err(error::Error::Api { code, http_status, .. })
+                if code == "ServiceUnavailable"
+                    && http_status == reqwest::StatusCode::SERVICE_UNAVAILABLE
+        ));
+        assert_eq!(server.requests().len(), 3);
+    }
+}
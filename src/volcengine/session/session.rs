@@ -10,16 +10,18 @@ use crate::volcengine::config; // Importing the main configuration
 use crate::volcengine::endpoint::endpoint; // Importing endpoint handling
 use crate::volcengine::error::error; // Importing custom error types
 use crate::volcengine::request::handles; // Importing request handling
-use crate::volcengine::util::url; // Importing URL utilities
+use crate::volcengine::transport::transport; // Importing the shared HTTP transport
 
 /// Represents a session for interacting with Volcengine services.
 ///
-/// A `Session` contains the configuration and request handles required to communicate
-/// with Volcengine services.
+/// A `Session` contains the configuration, request handles and the HTTP transport required
+/// to communicate with Volcengine services. The transport is created once per session and
+/// shared by every service client built from it.
 #[derive(Debug, Clone)]
 pub struct Session {
-    config: config::Config,    // Configuration for the session
-    handles: handles::Handles, // Handles for managing requests
+    config: config::Config,          // Configuration for the session
+    handles: handles::Handles,       // Handles for managing requests
+    transport: transport::Transport, // Pooled HTTP transport shared by all clients
 }
 
 /// Represents a session for managing the state and context of an ongoing process or interaction.
@@ -38,66 +40,74 @@ impl Session {
         SessionBuilder {
             config: None,
             handles: None,
+            transport: None,
         } // Initialize builder with None values
     }
 
     /// Creates a new `client_config::Config` for the specified service name.
     ///
     /// This method configures a new client by cloning the session's base configuration and
-    /// setting specific endpoint and signing details for the given service name.
+    /// setting specific endpoint and signing details for the given service name. The endpoint
+    /// is the one set for the service with `with_service_endpoint`, else the one set with
+    /// `with_endpoint`, else the one returned by the configured `EndpointResolver`.
     ///
     /// # Arguments
     /// - `client_service_name`: The name of the client service.
     ///
     /// # Returns
-    /// A `client_config::Config` instance ready to be used for API requests.
+    /// - `Ok(client_config::Config)`: A configuration ready to be used for API requests.
+    /// - `Err(error::Error)`: If the configured endpoint is invalid or its scheme and port conflict.
     pub fn new_client_config(
         &self,
         client_service_name: client_config::ClientServiceName, // The name of the client service
-    ) -> client_config::Config {
+    ) -> Result<client_config::Config, error::Error> {
         // Initialize client_config::Config explicitly without using Default
         let mut client_config = client_config::Config {
-            config: self.config.clone(),   // Clone the session's config
-            endpoint: String::new(),       // Initialize endpoint as an empty string
-            signing_region: String::new(), // Initialize signing region as empty
-            signing_name: String::new(),   // Initialize signing name as empty
-            signing_name_derived: false,   // Initialize derived signing name flag
-            handles: self.handles.clone(), // Clone the session's handles
+            config: self.config.clone(),       // Clone the session's config
+            endpoint: String::new(),           // Initialize endpoint as an empty string
+            signing_region: String::new(),     // Initialize signing region as empty
+            signing_name: String::new(),       // Initialize signing name as empty
+            signing_name_derived: false,       // Initialize derived signing name flag
+            handles: self.handles.clone(),     // Clone the session's handles
+            transport: self.transport.clone(), // Share the session's transport
         };
 
-        // Set endpoint if not provided
-        if client_config.config.endpoint.is_empty() {
-            // Generate endpoint URL based on service name and region
-            let endpoint_url = url::Url {
-                service_name: client_service_name,  // Service name
-                region: self.config.region.clone(), // Region from session config
-            }
-            .get_endpoint();
-            client_config.config.endpoint = endpoint_url.to_string(); // Assign generated endpoint
+        // Pick the endpoint: the service override, the global override, then the resolver
+        if let Some(service_endpoint) = self.config.service_endpoints.get(&client_service_name) {
+            client_config.config.endpoint = service_endpoint.clone(); // Service-specific endpoint
+        } else if client_config.config.endpoint.is_empty() {
+            // Resolve the endpoint from the service name and region
+            client_config.config.endpoint = self
+                .config
+                .endpoint_resolver
+                .resolve_endpoint(&client_service_name, &self.config.region)?;
         }
 
         // Resolve the endpoint and signing information
-        let resolved_endpoint = self.resolve_endpoint(&client_config.config.endpoint);
+        let resolved_endpoint = self.resolve_endpoint(&client_config.config.endpoint)?;
         client_config.endpoint = resolved_endpoint.url; // Set the resolved URL
         client_config.signing_region = resolved_endpoint.signing_region; // Set signing region
         client_config.signing_name = resolved_endpoint.signing_name; // Set signing name
         client_config.signing_name_derived = resolved_endpoint.signing_name_derived; // Set derived signing name flag
 
         // Return the configured client
-        client_config
+        Ok(client_config)
     }
 
     /// Resolves the endpoint URL and signing details for the given endpoint.
     ///
-    /// This method ensures that the endpoint URL is properly formatted and includes the correct
-    /// signing details required for secure API interactions.
+    /// This method validates the endpoint with `url::Url`, adds the configured scheme when the
+    /// endpoint has none and attaches the signing details required for secure API interactions.
     ///
     /// # Arguments
     /// - `endpoint`: The endpoint URL to be resolved.
     ///
     /// # Returns
-    /// A `ResolvedEndpoint` containing the fully resolved URL, signing region, and signing name.
-    fn resolve_endpoint(&self, endpoint: &str) -> endpoint::ResolvedEndpoint {
+    /// - `Ok(ResolvedEndpoint)`: The resolved URL, signing region, and signing name.
+    /// - `Err(error::Error::ErrEndpointInvalid)`: If the endpoint is not a valid `http`/`https` origin.
+    /// - `Err(error::Error::ErrEndpointSchemeConflict)`: If the scheme and the port conflict,
+    ///   e.g. `http://host:443`.
+    fn resolve_endpoint(&self, endpoint: &str) -> Result<endpoint::ResolvedEndpoint, error::Error> {
         // Initialize a new ResolvedEndpoint with empty values
         let mut resolved_endpoint = endpoint::ResolvedEndpoint {
             url: String::new(),            // URL placeholder
@@ -108,13 +118,13 @@ impl Session {
 
         // If the endpoint is not empty, resolve it
         if !endpoint.is_empty() {
-            let endpoint_url = endpoint::add_scheme(endpoint, self.config.disable_ssl); // Add scheme to URL
+            let endpoint_url = endpoint::normalize_endpoint(endpoint, self.config.scheme)?; // Validate and add scheme
             resolved_endpoint.url = endpoint_url; // Set resolved URL
             resolved_endpoint.signing_region = self.config.region.clone(); // Set signing region from session config
         }
 
         // Return the resolved endpoint
-        resolved_endpoint
+        Ok(resolved_endpoint)
     }
 }
 
@@ -125,6 +135,7 @@ impl Session {
 pub struct SessionBuilder {
     config: Option<config::Config>, // Optional configuration for the session
     handles: Option<handles::Handles>, // Optional handles for requests
+    transport: Option<transport::Transport>, // Optional prebuilt transport
 }
 
 /// Builder for constructing a `Session` instance.
@@ -175,10 +186,27 @@ impl SessionBuilder {
         self
     }
 
+    /// Sets a prebuilt transport for the session builder.
+    ///
+    /// By default the session builds its own transport from `Config.transport_config`.
+    /// Passing a transport here allows several sessions (for example one per region)
+    /// to share a single connection pool.
+    ///
+    /// # Arguments
+    /// - `transport`: The transport to be used for sending requests.
+    ///
+    /// # Returns
+    /// The modified `SessionBuilder` instance with the specified transport.
+    pub fn with_transport(mut self, transport: transport::Transport) -> Self {
+        self.transport = Some(transport); // Store the provided transport
+        self
+    }
+
     /// Builds the final `Session` object.
     ///
     /// This method checks if all required fields have been provided and creates
     /// the `Session` instance. If any required fields are missing, an error is returned.
+    /// When no transport was provided, one is built from the configuration.
     ///
     /// # Returns
     /// - `Ok(Session)`: The successfully built `Session`.
@@ -189,10 +217,20 @@ impl SessionBuilder {
             return Err(error::Error::ErrUtilSessionBuildSessionNoConfig); // Error for missing config
         }
 
+        // Unwrap config (guaranteed to be Some)
+        let config = self.config.unwrap();
+
+        // Build the transport from the configuration unless one was provided
+        let transport = match self.transport {
+            Some(transport) => transport,
+            None => transport::Transport::new(&config.transport_config)?,
+        };
+
         // Create and return a Session instance, using default handles if not provided
         Ok(Session {
-            config: self.config.unwrap(), // Unwrap config (guaranteed to be Some)
-            handles: handles::Handles {}, // Use default handles if None
+            config,                                    // Session configuration
+            handles: self.handles.unwrap_or_default(), // Use default handles if None
+            transport,                                 // Shared HTTP transport
         })
     }
 }
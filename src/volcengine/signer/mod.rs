@@ -0,0 +1,11 @@
+/*
+ * @Author: Jerry.Yang
+ * @Date: 2025-03-16 10:03:41
+ * @LastEditors: Jerry.Yang
+ * @LastEditTime: 2025-03-16 10:03:41
+ * @Description: mod
+ */
+pub mod signer;
+
+// Test module for the signer.
+mod tests;
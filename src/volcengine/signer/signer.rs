@@ -0,0 +1,506 @@
+/*
+ * @Author: Jerry.Yang
+ * @Date: 2025-03-16 10:03:41
+ * @LastEditors: Jerry.Yang
+ * @LastEditTime: 2025-03-30 11:10:00
+ * @Description: Standalone signer for Volcengine V4 signatures
+ */
+use crate::volcengine::credentials::credentials;
+use crate::volcengine::error::error;
+use crate::volcengine::request::sign;
+use bytes::Bytes;
+use chrono::{DateTime, Utc};
+use http::header::{HeaderMap, HeaderName, HeaderValue, AUTHORIZATION, HOST};
+use sha2::{Digest, Sha256};
+
+/// The algorithm named in the `Authorization` header.
+pub const SIGNING_ALGORITHM: &str = "HMAC-SHA256";
+
+/// The format of the `X-Date` header, e.g. `20250316T080000Z`.
+pub const X_DATE_FORMAT: &str = "%Y%m%dT%H%M%SZ";
+
+/// The header carrying the signing time.
+pub const X_DATE: &str = "x-date";
+
+/// The header carrying the hash of the payload.
+pub const X_CONTENT_SHA256: &str = "x-content-sha256";
+
+/// The header carrying the session token of temporary credentials.
+pub const X_SECURITY_TOKEN: &str = "x-security-token";
+
+/// Signs and verifies Volcengine V4 signatures on plain `http` requests.
+///
+/// The service clients sign their requests on their own; `Signer` exposes the same algorithm
+/// for code that builds its requests by hand, such as a reverse proxy or interop tests. The
+/// `host`, `x-date` and `x-content-sha256` headers, and `x-security-token` for temporary
+/// credentials, are always signed; `with_signed_header` adds others, e.g. `content-type`.
+///
+/// # Example
+/// ```rust,no_run
+/// # use volcengine_rust_sdk::volcengine::error::error;
+/// use bytes::Bytes;
+/// use chrono::Utc;
+/// use volcengine_rust_sdk::volcengine::credentials::credentials;
+/// use volcengine_rust_sdk::volcengine::signer::signer::Signer;
+///
+/// # fn example() -> Result<(), error::Error> {
+/// let signer = Signer::builder()
+///     .with_credentials(credentials::Credentials::new("AKLT...", "secret"))
+///     .with_region("cn-beijing")
+///     .with_service("ecs")
+///     .with_signed_header("content-type")
+///     .build()?;
+///
+/// let mut request = http::Request::builder()
+///     .method("POST")
+///     .uri("https://ecs.cn-beijing.volcengineapi.com/?Action=RunInstances&Version=2020-04-01")
+///     .header("content-type", "application/json")
+///     .body(Bytes::from_static(b"{}"))
+///     .expect("a valid request");
+/// let added_headers = signer.sign(&mut request, Utc::now())?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Signer {
+    credentials: credentials::Credentials, // Credentials signing the requests.
+    region: String,                        // Signing region, e.g. `cn-beijing`.
+    service: String,                       // Signing service, e.g. `ecs`.
+    signed_headers: Vec<HeaderName>,       // Headers signed on top of the default ones.
+}
+
+impl Signer {
+    /// Creates a new `SignerBuilder`.
+    ///
+    /// # Returns
+    /// - `SignerBuilder`: A builder used to configure the signer.
+    pub fn builder() -> SignerBuilder {
+        SignerBuilder {
+            credentials: None,
+            region: None,
+            service: None,
+            signed_headers: Vec::new(),
+        }
+    }
+
+    /// Signs a request.
+    ///
+    /// Sets `X-Date`, `X-Content-Sha256`, `X-Security-Token` for temporary credentials and
+    /// `Authorization` on the request, replacing any previous value.
+    ///
+    /// # Arguments
+    /// - `request`: The request to sign; its URI or `Host` header must name the host.
+    /// - `timestamp`: The signing time.
+    ///
+    /// # Returns
+    /// - `Ok(HeaderMap)`: The headers added to the request, `Authorization` included.
+    /// - `Err(error::Error)`: If a signed header is missing or is not valid text.
+    pub fn sign(
+        &self,
+        request: &mut http::Request<Bytes>,
+        timestamp: DateTime<Utc>,
+    ) -> Result<HeaderMap, error::Error> {
+        let x_date = timestamp.format(X_DATE_FORMAT).to_string();
+        let payload_hash = hex::encode(Sha256::digest(request.body()));
+
+        let mut added_headers = HeaderMap::new();
+        added_headers.insert(X_DATE, header_value(X_DATE, &x_date)?);
+        added_headers.insert(
+            X_CONTENT_SHA256,
+            header_value(X_CONTENT_SHA256, &payload_hash)?,
+        );
+        if let Some(session_token) = &self.credentials.session_token {
+            added_headers.insert(
+                X_SECURITY_TOKEN,
+                header_value(X_SECURITY_TOKEN, session_token)?,
+            );
+        }
+
+        // The added headers take part in the signature.
+        for (name, value) in added_headers.iter() {
+            request.headers_mut().insert(name.clone(), value.clone());
+        }
+
+        let signed_headers = self.signed_header_names(&added_headers);
+        let signature = self.signature(request, &signed_headers)?;
+        let authorization = format!(
+            "{} Credential={}/{}, SignedHeaders={}, Signature={}",
+            SIGNING_ALGORITHM,
+            self.credentials.access_key_id,
+            self.credential_scope(&x_date),
+            join_header_names(&signed_headers),
+            signature
+        );
+        let authorization = header_value(AUTHORIZATION.as_str(), &authorization)?;
+        request
+            .headers_mut()
+            .insert(AUTHORIZATION, authorization.clone());
+        added_headers.insert(AUTHORIZATION, authorization);
+
+        Ok(added_headers)
+    }
+
+    /// Verifies the `Authorization` header of a request.
+    ///
+    /// The signature is recomputed from the headers listed in `SignedHeaders` and the body
+    /// of the request. The age of `X-Date` is not checked; callers reject stale requests
+    /// themselves.
+    ///
+    /// # Arguments
+    /// - `request`: The signed request.
+    ///
+    /// # Returns
+    /// - `Ok(true)`: If the request was signed by the access key, region and service of the
+    ///   signer and was not modified since.
+    /// - `Ok(false)`: If the credential or the signature does not match.
+    /// - `Err(error::Error::ErrSignerInvalidAuthorization)`: If the `Authorization` header is
+    ///   missing or malformed.
+    /// - `Err(error::Error)`: If a signed header is missing.
+    pub fn verify(&self, request: &http::Request<Bytes>) -> Result<bool, error::Error> {
+        let authorization = request
+            .headers()
+            .get(AUTHORIZATION)
+            .ok_or_else(|| {
+                error::Error::ErrSignerInvalidAuthorization("no Authorization header".to_string())
+            })?
+            .to_str()?;
+        let authorization = parse_authorization(authorization)?;
+
+        let x_date = request_header(request, X_DATE)?;
+        let expected_credential = format!(
+            "{}/{}",
+            self.credentials.access_key_id,
+            self.credential_scope(&x_date)
+        );
+        if authorization.credential != expected_credential {
+            return Ok(false);
+        }
+
+        let signature = self.signature(request, &authorization.signed_headers)?;
+        Ok(constant_time_eq(
+            signature.as_bytes(),
+            authorization.signature.as_bytes(),
+        ))
+    }
+
+    /// Computes the signature of a request.
+    ///
+    /// # Arguments
+    /// - `request`: The request, carrying its `X-Date` header.
+    /// - `signed_headers`: The headers to sign.
+    ///
+    /// # Returns
+    /// - `Ok(String)`: The signature as a hexadecimal string.
+    /// - `Err(error::Error)`: If a signed header is missing or is not valid text.
+    pub fn signature(
+        &self,
+        request: &http::Request<Bytes>,
+        signed_headers: &[HeaderName],
+    ) -> Result<String, error::Error> {
+        let x_date = request_header(request, X_DATE)?;
+        if x_date.len() < 8 {
+            return Err(error::Error::ErrSignerInvalidHeader(X_DATE.to_string()));
+        }
+
+        let canonical_request = canonical_request(request, signed_headers)?;
+        Ok(sign::Sign.sign_canonical_request(
+            &canonical_request,
+            &x_date,
+            &self.region,
+            &self.service,
+            &self.credentials.secret_access_key,
+        ))
+    }
+
+    /// Returns the credential scope of a signing time.
+    ///
+    /// # Arguments
+    /// - `x_date`: The signing time in `X_DATE_FORMAT`.
+    ///
+    /// # Returns
+    /// - `String`: `date/region/service/request`.
+    fn credential_scope(&self, x_date: &str) -> String {
+        format!(
+            "{}/{}/{}/request",
+            x_date.get(..8).unwrap_or(x_date),
+            self.region,
+            self.service
+        )
+    }
+
+    /// Returns the sorted names of the headers signed by `sign`.
+    ///
+    /// # Arguments
+    /// - `added_headers`: The headers added by `sign`.
+    ///
+    /// # Returns
+    /// - `Vec<HeaderName>`: `host`, the added headers and the configured ones.
+    fn signed_header_names(&self, added_headers: &HeaderMap) -> Vec<HeaderName> {
+        let mut signed_headers: Vec<HeaderName> = std::iter::once(HOST)
+            .chain(added_headers.keys().cloned())
+            .chain(self.signed_headers.iter().cloned())
+            .collect();
+        signed_headers.sort_by(|left, right| left.as_str().cmp(right.as_str()));
+        signed_headers.dedup();
+        signed_headers
+    }
+}
+
+/// A builder for constructing a `Signer`.
+pub struct SignerBuilder {
+    credentials: Option<credentials::Credentials>, // Credentials signing the requests.
+    region: Option<String>,                        // Signing region.
+    service: Option<String>,                       // Signing service.
+    signed_headers: Vec<String>,                   // Extra headers to sign.
+}
+
+impl SignerBuilder {
+    /// Sets the credentials signing the requests.
+    ///
+    /// # Arguments
+    /// - `credentials`: The credentials; a session token is sent in `X-Security-Token`.
+    ///
+    /// # Returns
+    /// - `Self`: The updated builder.
+    pub fn with_credentials(mut self, credentials: credentials::Credentials) -> Self {
+        self.credentials = Some(credentials);
+        self
+    }
+
+    /// Sets the signing region.
+    ///
+    /// # Arguments
+    /// - `region`: The region, e.g. `cn-beijing`.
+    ///
+    /// # Returns
+    /// - `Self`: The updated builder.
+    pub fn with_region(mut self, region: &str) -> Self {
+        self.region = Some(region.to_string());
+        self
+    }
+
+    /// Sets the signing service.
+    ///
+    /// # Arguments
+    /// - `service`: The service, e.g. `ecs`.
+    ///
+    /// # Returns
+    /// - `Self`: The updated builder.
+    pub fn with_service(mut self, service: &str) -> Self {
+        self.service = Some(service.to_string());
+        self
+    }
+
+    /// Adds a header to sign on top of the default ones.
+    ///
+    /// # Arguments
+    /// - `header`: The header name, e.g. `content-type`, in any case.
+    ///
+    /// # Returns
+    /// - `Self`: The updated builder.
+    pub fn with_signed_header(mut self, header: &str) -> Self {
+        self.signed_headers.push(header.to_string());
+        self
+    }
+
+    /// Builds the `Signer`.
+    ///
+    /// # Returns
+    /// - `Ok(Signer)`: The signer.
+    /// - `Err(error::Error::ErrSignerBuildSignerNo)`: If the credentials, region or service is
+    ///   missing.
+    /// - `Err(error::Error::ErrSignerInvalidHeader)`: If a signed header is not a valid name.
+    pub fn build(self) -> Result<Signer, error::Error> {
+        let credentials = self
+            .credentials
+            .ok_or_else(|| error::Error::ErrSignerBuildSignerNo("credentials".to_string()))?;
+        let region = self
+            .region
+            .filter(|region| !region.is_empty())
+            .ok_or_else(|| error::Error::ErrSignerBuildSignerNo("region".to_string()))?;
+        let service = self
+            .service
+            .filter(|service| !service.is_empty())
+            .ok_or_else(|| error::Error::ErrSignerBuildSignerNo("service".to_string()))?;
+
+        let signed_headers = self
+            .signed_headers
+            .iter()
+            .map(|header| {
+                HeaderName::from_bytes(header.as_bytes())
+                    .map_err(|_| error::Error::ErrSignerInvalidHeader(header.clone()))
+            })
+            .collect::<Result<Vec<HeaderName>, error::Error>>()?;
+
+        Ok(Signer {
+            credentials,
+            region,
+            service,
+            signed_headers,
+        })
+    }
+}
+
+/// Builds the canonical request of an `http` request.
+///
+/// # Arguments
+/// - `request`: The request.
+/// - `signed_headers`: The headers to sign, in any order.
+///
+/// # Returns
+/// - `Ok(String)`: The canonical request.
+/// - `Err(error::Error)`: If a signed header is missing or is not valid text.
+pub fn canonical_request(
+    request: &http::Request<Bytes>,
+    signed_headers: &[HeaderName],
+) -> Result<String, error::Error> {
+    let mut signed_headers = signed_headers.to_vec();
+    signed_headers.sort_by(|left, right| left.as_str().cmp(right.as_str()));
+    signed_headers.dedup();
+
+    let mut canonical_headers = String::new();
+    for name in signed_headers.iter() {
+        let value = if *name == HOST {
+            request_host(request)?
+        } else {
+            request_header(request, name.as_str())?
+        };
+        canonical_headers.push_str(&format!("{}:{}\n", name.as_str(), value.trim()));
+    }
+
+    Ok(format!(
+        "{}\n{}\n{}\n{}\n{}\n{}",
+        request.method().as_str(),
+        sign::canonical_uri(request.uri().path()),
+        sign::canonical_query(request.uri().query().unwrap_or_default()),
+        canonical_headers,
+        join_header_names(&signed_headers),
+        hex::encode(Sha256::digest(request.body()))
+    ))
+}
+
+/// The parts of an `Authorization` header.
+struct Authorization {
+    credential: String,              // `access_key_id/date/region/service/request`.
+    signed_headers: Vec<HeaderName>, // Names listed in `SignedHeaders`.
+    signature: String,               // Hexadecimal signature.
+}
+
+/// Parses an `Authorization` header.
+///
+/// # Arguments
+/// - `authorization`: The header value.
+///
+/// # Returns
+/// - `Ok(Authorization)`: The credential, signed headers and signature.
+/// - `Err(error::Error::ErrSignerInvalidAuthorization)`: If the header is malformed.
+fn parse_authorization(authorization: &str) -> Result<Authorization, error::Error> {
+    let invalid = |reason: &str| error::Error::ErrSignerInvalidAuthorization(reason.to_string());
+
+    let fields = authorization
+        .strip_prefix(SIGNING_ALGORITHM)
+        .ok_or_else(|| invalid("unsupported algorithm"))?;
+
+    let (mut credential, mut signed_headers, mut signature) = (None, None, None);
+    for field in fields.split(',') {
+        match field.trim().split_once('=') {
+            Some(("Credential", value)) => credential = Some(value.to_string()),
+            Some(("SignedHeaders", value)) => signed_headers = Some(value),
+            Some(("Signature", value)) => signature = Some(value.to_string()),
+            _ => return Err(invalid(field.trim())),
+        }
+    }
+
+    let signed_headers = signed_headers
+        .ok_or_else(|| invalid("no SignedHeaders"))?
+        .split(';')
+        .map(|name| HeaderName::from_bytes(name.as_bytes()).map_err(|_| invalid(name)))
+        .collect::<Result<Vec<HeaderName>, error::Error>>()?;
+
+    Ok(Authorization {
+        credential: credential.ok_or_else(|| invalid("no Credential"))?,
+        signed_headers,
+        signature: signature.ok_or_else(|| invalid("no Signature"))?,
+    })
+}
+
+/// Returns the host a request is sent to.
+///
+/// # Arguments
+/// - `request`: The request.
+///
+/// # Returns
+/// - `Ok(String)`: The `Host` header, or else the host of the URI as the request signer of the
+///   clients signs it: without userinfo, with the port unless it is the default one.
+/// - `Err(error::Error::ErrRequestSignGetHostNone)`: If neither is set.
+fn request_host(request: &http::Request<Bytes>) -> Result<String, error::Error> {
+    if let Some(host) = request.headers().get(HOST) {
+        return Ok(host.to_str()?.to_string());
+    }
+
+    let url = url::Url::parse(&request.uri().to_string())
+        .map_err(|_| error::Error::ErrRequestSignGetHostNone)?;
+    sign::canonical_host(&url)
+}
+
+/// Returns the value of a request header.
+///
+/// # Arguments
+/// - `request`: The request.
+/// - `name`: The header name.
+///
+/// # Returns
+/// - `Ok(String)`: The value of the header.
+/// - `Err(error::Error::ErrRequestSignGetHeaderNone)`: If the header is missing.
+fn request_header(request: &http::Request<Bytes>, name: &str) -> Result<String, error::Error> {
+    let value = request
+        .headers()
+        .get(name)
+        .ok_or_else(|| error::Error::ErrRequestSignGetHeaderNone(name.to_string()))?;
+    Ok(value.to_str()?.to_string())
+}
+
+/// Converts text into a header value.
+///
+/// # Arguments
+/// - `name`: The header name, used in errors.
+/// - `value`: The text.
+///
+/// # Returns
+/// - `Ok(HeaderValue)`: The header value.
+/// - `Err(error::Error::ErrSignerInvalidHeader)`: If the text is not a valid header value.
+fn header_value(name: &str, value: &str) -> Result<HeaderValue, error::Error> {
+    HeaderValue::from_str(value).map_err(|_| error::Error::ErrSignerInvalidHeader(name.to_string()))
+}
+
+/// Joins header names with `;`, as in `SignedHeaders`.
+///
+/// # Arguments
+/// - `names`: The header names.
+///
+/// # Returns
+/// - `String`: The joined names.
+fn join_header_names(names: &[HeaderName]) -> String {
+    names
+        .iter()
+        .map(|name| name.as_str())
+        .collect::<Vec<&str>>()
+        .join(";")
+}
+
+/// Compares two byte strings in time independent of where they differ.
+///
+/// # Arguments
+/// - `left`: The first byte string.
+/// - `right`: The second byte string.
+///
+/// # Returns
+/// - `bool`: `true` if both are equal.
+fn constant_time_eq(left: &[u8], right: &[u8]) -> bool {
+    left.len() == right.len()
+        && left
+            .iter()
+            .zip(right.iter())
+            .fold(0u8, |diff, (left, right)| diff | (left ^ right))
+            == 0
+}
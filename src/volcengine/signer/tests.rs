@@ -0,0 +1,208 @@
+/*
+ * @Author: Jerry.Yang
+ * @Date: 2025-03-16 14:25:10
+ * @LastEditors: Jerry.Yang
+ * @LastEditTime: 2025-03-30 10:50:00
+ * @Description: signer test
+ */
+#[cfg(test)]
+mod tests {
+    use bytes::Bytes;
+    use chrono::{DateTime, TimeZone, Utc};
+
+    use crate::volcengine::{credentials::credentials, error::error, signer::signer};
+
+    // Signing time of the test vectors, `20250314T080000Z`.
+    fn timestamp() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 3, 14, 8, 0, 0).unwrap()
+    }
+
+    fn new_signer(
+        credentials: credentials::Credentials,
+        region: &str,
+        service: &str,
+    ) -> signer::SignerBuilder {
+        signer::Signer::builder()
+            .with_credentials(credentials)
+            .with_region(region)
+            .with_service(service)
+    }
+
+    #[test]
+    fn test_sign_get_request() {
+        let signer = new_signer(
+            credentials::Credentials::new("AKLTtest", "secret"),
+            "cn-north-1",
+            "iam",
+        )
+        .build()
+        .unwrap();
+
+        let mut request = http::Request::builder()
+            .uri("https://iam.volcengineapi.com/?Version=2018-01-01&Action=ListUsers&Limit=10")
+            .body(Bytes::new())
+            .unwrap();
+        let added_headers = signer.sign(&mut request, timestamp()).unwrap();
+
+        assert_eq!(added_headers.len(), 3);
+        assert_eq!(added_headers["x-date"], "20250314T080000Z");
+        assert_eq!(
+            added_headers["x-content-sha256"],
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+        assert_eq!(
+            request.headers()["authorization"],
+            "HMAC-SHA256 Credential=AKLTtest/20250314/cn-north-1/iam/request, \
+             SignedHeaders=host;x-content-sha256;x-date, \
+             Signature=d1dfe3f8dbf6397267669c536920015d7cae7acc1561aa8c30193c81b26f6f70"
+        );
+        assert!(signer.verify(&request).unwrap());
+    }
+
+    #[test]
+    fn test_sign_post_request_with_extra_headers() {
+        let mut credentials = credentials::Credentials::new("AKLTtest", "secret");
+        credentials.session_token = Some("token".to_string());
+        let signer = new_signer(credentials, "cn-beijing", "ecs")
+            .with_signed_header("Content-Type")
+            .build()
+            .unwrap();
+
+        let mut request = http::Request::builder()
+            .method("POST")
+            .uri("https://ecs.cn-beijing.volcengineapi.com/v1/run?Action=RunInstances&Version=2020-04-01")
+            .header("content-type", "application/json")
+            .body(Bytes::from_static(br#"{"InstanceName":"web 1"}"#))
+            .unwrap();
+        let added_headers = signer.sign(&mut request, timestamp()).unwrap();
+
+        assert_eq!(added_headers["x-security-token"], "token");
+        assert_eq!(
+            added_headers["authorization"],
+            "HMAC-SHA256 Credential=AKLTtest/20250314/cn-beijing/ecs/request, \
+             SignedHeaders=content-type;host;x-content-sha256;x-date;x-security-token, \
+             Signature=675bea005df64430798bc83c160474243e60e6cdb5808198959c6f4fef062122"
+        );
+        assert!(signer.verify(&request).unwrap());
+    }
+
+    #[test]
+    fn test_sign_default_port() {
+        let signer = new_signer(
+            credentials::Credentials::new("AKLTtest", "secret"),
+            "cn-north-1",
+            "iam",
+        )
+        .build()
+        .unwrap();
+
+        // The default port and userinfo are not part of the signed host.
+        for uri in [
+            "https://iam.volcengineapi.com:443/?Version=2018-01-01&Action=ListUsers&Limit=10",
+            "https://user@iam.volcengineapi.com/?Version=2018-01-01&Action=ListUsers&Limit=10",
+        ] {
+            let mut request = http::Request::builder()
+                .uri(uri)
+                .body(Bytes::new())
+                .unwrap();
+            signer.sign(&mut request, timestamp()).unwrap();
+
+            assert_eq!(
+                request.headers()["authorization"],
+                "HMAC-SHA256 Credential=AKLTtest/20250314/cn-north-1/iam/request, \
+                 SignedHeaders=host;x-content-sha256;x-date, \
+                 Signature=d1dfe3f8dbf6397267669c536920015d7cae7acc1561aa8c30193c81b26f6f70",
+                "{uri}"
+            );
+            assert!(signer.verify(&request).unwrap());
+        }
+
+        // Another port is signed.
+        let request = http::Request::builder()
+            .uri("http://localhost:8080/?Action=ListUsers")
+            .body(Bytes::new())
+            .unwrap();
+        let canonical_request = signer::canonical_request(&request, &[http::header::HOST]).unwrap();
+        assert!(canonical_request.contains("\nhost:localhost:8080\n"));
+    }
+
+    #[test]
+    fn test_verify_rejects_modified_request() {
+        let signer = new_signer(
+            credentials::Credentials::new("AKLTtest", "secret"),
+            "cn-beijing",
+            "ecs",
+        )
+        .build()
+        .unwrap();
+
+        let mut request = http::Request::builder()
+            .method("POST")
+            .uri("https://ecs.cn-beijing.volcengineapi.com/?Action=RunInstances&Version=2020-04-01")
+            .body(Bytes::from_static(b"{}"))
+            .unwrap();
+        signer.sign(&mut request, timestamp()).unwrap();
+
+        // A modified body no longer matches the signature.
+        let mut tampered = http::Request::new(Bytes::from_static(b"{ }"));
+        *tampered.method_mut() = request.method().clone();
+        *tampered.uri_mut() = request.uri().clone();
+        *tampered.headers_mut() = request.headers().clone();
+        assert!(!signer.verify(&tampered).unwrap());
+
+        // Neither does a signature for another region.
+        let other_signer = new_signer(
+            credentials::Credentials::new("AKLTtest", "secret"),
+            "cn-shanghai",
+            "ecs",
+        )
+        .build()
+        .unwrap();
+        assert!(signer.verify(&request).unwrap());
+        assert!(!other_signer.verify(&request).unwrap());
+    }
+
+    #[test]
+    fn test_verify_unsigned_request() {
+        let signer = new_signer(
+            credentials::Credentials::new("AKLTtest", "secret"),
+            "cn-beijing",
+            "ecs",
+        )
+        .build()
+        .unwrap();
+
+        let request = http::Request::builder()
+            .uri("https://ecs.cn-beijing.volcengineapi.com/")
+            .body(Bytes::new())
+            .unwrap();
+        assert!(matches!(
+            signer.verify(&request),
+            Err(error::Error::ErrSignerInvalidAuthorization(_))
+        ));
+    }
+
+    #[test]
+    fn test_build_signer() {
+        let result = signer::Signer::builder()
+            .with_credentials(credentials::Credentials::new("AKLTtest", "secret"))
+            .with_region("cn-beijing")
+            .build();
+        assert!(matches!(
+            result,
+            Err(error::Error::ErrSignerBuildSignerNo(field)) if field == "service"
+        ));
+
+        let result = new_signer(
+            credentials::Credentials::new("AKLTtest", "secret"),
+            "cn-beijing",
+            "ecs",
+        )
+        .with_signed_header("bad header")
+        .build();
+        assert!(matches!(
+            result,
+            Err(error::Error::ErrSignerInvalidHeader(_))
+        ));
+    }
+}
@@ -0,0 +1,559 @@
+/*
+ * @Author: Jerry.Yang
+ * @Date: 2025-03-20 10:18:46
+ * @LastEditors: Jerry.Yang
+ * @LastEditTime: 2025-03-30 11:10:00
+ * @Description: Record/replay cassettes of HTTP interactions
+ */
+use crate::volcengine::error::error;
+use bytes::Bytes;
+use http::header::{HeaderName, HeaderValue};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+/// Headers whose values are replaced by `REDACTED` when an interaction is recorded.
+///
+/// They carry the signature, the signing time and temporary credentials, none of which
+/// belong in a file checked into a repository.
+pub const REDACTED_HEADERS: [&str; 3] = ["authorization", "x-date", "x-security-token"];
+
+/// Query parameters, form fields and JSON fields whose values are replaced by `REDACTED`
+/// when an interaction is recorded, unless `Cassette::with_redacted_fields` changes them.
+///
+/// They carry instance passwords sent by `ModifyInstanceAttribute` or `ReplaceSystemVolume`,
+/// and the temporary credentials returned by STS `AssumeRole`.
+pub const REDACTED_FIELDS: [&str; 3] = ["Password", "SecretAccessKey", "SessionToken"];
+
+/// The value written in place of a redacted header or field.
+pub const REDACTED: &str = "<redacted>";
+
+/// Whether a `Cassette` records or replays interactions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CassetteMode {
+    Record, // Send requests and write every interaction to the cassette.
+    Replay, // Serve responses from the cassette without sending anything.
+}
+
+/// A request as written to a cassette.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RecordedRequest {
+    pub method: String,                    // HTTP method of the request.
+    pub path: String,                      // Path of the request.
+    pub action: String,                    // `Action` query parameter.
+    pub version: String,                   // `Version` query parameter.
+    pub query: BTreeMap<String, String>,   // Decoded query parameters, with the fields redacted.
+    pub headers: BTreeMap<String, String>, // Headers, with `REDACTED_HEADERS` redacted.
+    pub body: String,                      // Body of the request, with the fields redacted.
+}
+
+/// A response as written to a cassette.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RecordedResponse {
+    pub status: u16,                       // HTTP status of the response.
+    pub headers: BTreeMap<String, String>, // Headers of the response.
+    pub body: String,                      // Body of the response, with the fields redacted.
+}
+
+/// A request and the response it received.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Interaction {
+    pub request: RecordedRequest,   // The request sent.
+    pub response: RecordedResponse, // The response received.
+}
+
+/// The JSON document stored in a cassette file.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct CassetteFile {
+    interactions: Vec<Interaction>, // Interactions in the order they happened.
+}
+
+/// The interactions of a cassette and which of them were replayed.
+#[derive(Debug, Default)]
+struct CassetteState {
+    interactions: Vec<Interaction>, // Recorded or loaded interactions.
+    replayed: Vec<bool>,            // Whether each interaction was served already.
+}
+
+/// A VCR-style cassette of the HTTP interactions of a transport.
+///
+/// Set on a `TransportConfig` with `with_cassette`, a cassette sees every attempt sent by
+/// `Send::send`, retries included:
+///
+/// - In `CassetteMode::Record` requests are sent as usual, and each request and response is
+///   appended to the cassette file, which is rewritten after every interaction. The headers
+///   in `REDACTED_HEADERS` are redacted, and so are the fields named in `REDACTED_FIELDS`
+///   wherever they appear: in the query, in a form or JSON request body, and in a JSON
+///   response body.
+/// - In `CassetteMode::Replay` nothing is sent. Each request is answered with the first
+///   interaction not replayed yet whose `Action`, `Version`, query and body match; JSON
+///   bodies are compared as documents, so their key order and whitespace do not matter.
+///   A request without such an interaction fails with `ErrCassetteNoInteraction`.
+///
+/// Replayed requests are still signed, so any credentials do. The fields are redacted in the
+/// replayed requests too, so requests carrying secrets still match their interactions.
+///
+/// Redaction goes by name only: a secret sent or returned under another name, or in a body
+/// which is neither JSON nor a form, is written to the file as it is. Add such names with
+/// `with_redacted_fields`, and review a cassette before checking it into a repository.
+///
+/// # Example
+/// ```rust,no_run
+/// # use volcengine_rust_sdk::volcengine::error::error;
+/// use volcengine_rust_sdk::volcengine::transport::{cassette, transport};
+///
+/// # fn example() -> Result<(), error::Error> {
+/// // Capture real responses once...
+/// let cassette = cassette::Cassette::record("tests/cassettes/rds.json")
+///     .with_redacted_fields(["Password", "SecretAccessKey", "SessionToken", "UserData"]);
+/// // ...then run against them offline.
+/// let cassette = cassette::Cassette::replay("tests/cassettes/rds.json")?;
+///
+/// let transport_config = transport::TransportConfig::builder()
+///     .with_cassette(cassette)
+///     .build();
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Cassette {
+    path: PathBuf,                    // File the cassette is read from or written to.
+    mode: CassetteMode,               // Whether the cassette records or replays.
+    redacted_fields: Vec<String>,     // Names of the fields redacted from interactions.
+    state: Arc<Mutex<CassetteState>>, // Interactions shared by the clones.
+}
+
+impl Cassette {
+    /// Creates an empty cassette recording to `path`.
+    ///
+    /// The file, and its missing parent directories, are created on the first interaction;
+    /// an existing file is overwritten.
+    ///
+    /// # Arguments
+    /// - `path`: The cassette file.
+    ///
+    /// # Returns
+    /// - `Cassette`: The cassette, in `CassetteMode::Record`.
+    pub fn record(path: impl AsRef<Path>) -> Cassette {
+        Cassette {
+            path: path.as_ref().to_path_buf(),
+            mode: CassetteMode::Record,
+            redacted_fields: default_redacted_fields(),
+            state: Arc::new(Mutex::new(CassetteState::default())),
+        }
+    }
+
+    /// Loads a cassette to replay from `path`.
+    ///
+    /// # Arguments
+    /// - `path`: The cassette file.
+    ///
+    /// # Returns
+    /// - `Ok(Cassette)`: The cassette, in `CassetteMode::Replay`.
+    /// - `Err(error::Error)`: If the file cannot be read or is not a cassette.
+    pub fn replay(path: impl AsRef<Path>) -> Result<Cassette, error::Error> {
+        let content = std::fs::read(path.as_ref()).map_err(error::Error::ErrCassetteIo)?;
+        let cassette_file: CassetteFile =
+            serde_json::from_slice(&content).map_err(error::Error::ErrCassetteFormat)?;
+
+        let replayed = vec![false; cassette_file.interactions.len()];
+        Ok(Cassette {
+            path: path.as_ref().to_path_buf(),
+            mode: CassetteMode::Replay,
+            redacted_fields: default_redacted_fields(),
+            state: Arc::new(Mutex::new(CassetteState {
+                interactions: cassette_file.interactions,
+                replayed,
+            })),
+        })
+    }
+
+    /// Sets the fields redacted from the recorded interactions, in place of `REDACTED_FIELDS`.
+    ///
+    /// A field is matched by name, ignoring case, in the query and the form bodies, where the
+    /// last segment of a flattened name counts (`Tags.1.Password`), and at any depth of the
+    /// JSON bodies. A replaying cassette needs the fields it was recorded with.
+    ///
+    /// # Arguments
+    /// - `fields`: The names of the fields, e.g. `["Password", "UserData"]`.
+    ///
+    /// # Returns
+    /// - `Cassette`: The cassette, redacting the fields.
+    pub fn with_redacted_fields<I, S>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.redacted_fields = fields.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Returns the file of the cassette.
+    ///
+    /// # Returns
+    /// - `&Path`: The cassette file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns whether the cassette records or replays.
+    ///
+    /// # Returns
+    /// - `CassetteMode`: The mode.
+    pub fn mode(&self) -> CassetteMode {
+        self.mode
+    }
+
+    /// Returns the interactions recorded or loaded so far, in order.
+    ///
+    /// # Returns
+    /// - `Vec<Interaction>`: The interactions.
+    pub fn interactions(&self) -> Vec<Interaction> {
+        self.lock().interactions.clone()
+    }
+
+    /// Sends a request through the cassette.
+    ///
+    /// # Arguments
+    /// - `client`: The client used to send the request when recording.
+    /// - `reqwest_request`: The signed request.
+    ///
+    /// # Returns
+    /// - `Ok(reqwest::Response)`: The response, received or replayed.
+    /// - `Err(error::Error)`: If the request fails, the cassette cannot be written, or no
+    ///   interaction matches when replaying.
+    pub(crate) async fn execute(
+        &self,
+        client: &reqwest::Client,
+        reqwest_request: reqwest::Request,
+    ) -> Result<reqwest::Response, error::Error> {
+        let recorded_request = record_request(&reqwest_request, &self.redacted_fields);
+        match self.mode {
+            CassetteMode::Record => {
+                let response = client
+                    .execute(reqwest_request)
+                    .await
+                    .map_err(error::Error::ErrRequest)?;
+
+                // Buffer the body to record it, then hand back an equivalent response.
+                let status = response.status();
+                let version = response.version();
+                let headers = response.headers().clone();
+                let body = response
+                    .bytes()
+                    .await
+                    .map_err(error::Error::ErrParseResponse)?;
+
+                self.append(Interaction {
+                    request: recorded_request,
+                    response: RecordedResponse {
+                        status: status.as_u16(),
+                        headers: headers
+                            .iter()
+                            .filter_map(|(name, value)| {
+                                Some((name.to_string(), value.to_str().ok()?.to_string()))
+                            })
+                            .collect(),
+                        body: redact_body(
+                            &String::from_utf8_lossy(&body),
+                            false,
+                            &self.redacted_fields,
+                        ),
+                    },
+                })?;
+
+                let mut http_response = http::Response::new(body);
+                *http_response.status_mut() = status;
+                *http_response.version_mut() = version;
+                *http_response.headers_mut() = headers;
+                Ok(reqwest::Response::from(http_response))
+            }
+            CassetteMode::Replay => {
+                let recorded_response = self.find(&recorded_request)?;
+                replay_response(recorded_response)
+            }
+        }
+    }
+
+    /// Appends an interaction and rewrites the cassette file.
+    ///
+    /// # Arguments
+    /// - `interaction`: The interaction to record.
+    ///
+    /// # Returns
+    /// - `Ok(())`: If the file was written.
+    /// - `Err(error::Error)`: If the file cannot be written.
+    fn append(&self, interaction: Interaction) -> Result<(), error::Error> {
+        let mut state = self.lock();
+        state.interactions.push(interaction);
+        state.replayed.push(false);
+
+        let content = serde_json::to_vec_pretty(&CassetteFile {
+            interactions: state.interactions.clone(),
+        })
+        .map_err(error::Error::ErrCassetteFormat)?;
+        if let Some(parent) = self
+            .path
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+        {
+            std::fs::create_dir_all(parent).map_err(error::Error::ErrCassetteIo)?;
+        }
+        std::fs::write(&self.path, content).map_err(error::Error::ErrCassetteIo)
+    }
+
+    /// Takes the first interaction not replayed yet which matches a request.
+    ///
+    /// # Arguments
+    /// - `request`: The request to answer.
+    ///
+    /// # Returns
+    /// - `Ok(RecordedResponse)`: The response of the interaction.
+    /// - `Err(error::Error)`: If no interaction is left for the request.
+    fn find(&self, request: &RecordedRequest) -> Result<RecordedResponse, error::Error> {
+        let mut state = self.lock();
+        let CassetteState {
+            interactions,
+            replayed,
+        } = &mut *state;
+
+        let position = interactions
+            .iter()
+            .zip(replayed.iter())
+            .position(|(interaction, replayed)| {
+                !*replayed && matches_request(&interaction.request, request)
+            })
+            .ok_or_else(|| error::Error::ErrCassetteNoInteraction {
+                action: request.action.clone(),
+                version: request.version.clone(),
+            })?;
+
+        replayed[position] = true;
+        Ok(interactions[position].response.clone())
+    }
+
+    /// Locks the shared state, ignoring a poisoned lock.
+    fn lock(&self) -> std::sync::MutexGuard<'_, CassetteState> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// Returns `REDACTED_FIELDS` as the fields of a new cassette.
+fn default_redacted_fields() -> Vec<String> {
+    REDACTED_FIELDS
+        .iter()
+        .map(|field| field.to_string())
+        .collect()
+}
+
+/// Converts a signed request into its recorded form.
+///
+/// # Arguments
+/// - `reqwest_request`: The request.
+/// - `redacted_fields`: The fields to redact from the query and the body.
+///
+/// # Returns
+/// - `RecordedRequest`: The request with its query decoded and its secrets redacted.
+fn record_request(
+    reqwest_request: &reqwest::Request,
+    redacted_fields: &[String],
+) -> RecordedRequest {
+    let query: BTreeMap<String, String> = reqwest_request
+        .url()
+        .query_pairs()
+        .into_owned()
+        .map(|(name, value)| redact_pair(name, value, redacted_fields))
+        .collect();
+    let headers = reqwest_request
+        .headers()
+        .iter()
+        .filter_map(|(name, value)| {
+            let value = if REDACTED_HEADERS.contains(&name.as_str()) {
+                REDACTED.to_string()
+            } else {
+                value.to_str().ok()?.to_string()
+            };
+            Some((name.to_string(), value))
+        })
+        .collect();
+    let is_form = reqwest_request
+        .headers()
+        .get(http::header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.starts_with("application/x-www-form-urlencoded"));
+    let body = reqwest_request
+        .body()
+        .and_then(|body| body.as_bytes())
+        .map(|body| redact_body(&String::from_utf8_lossy(body), is_form, redacted_fields))
+        .unwrap_or_default();
+
+    RecordedRequest {
+        method: reqwest_request.method().to_string(),
+        path: reqwest_request.url().path().to_string(),
+        action: query.get("Action").cloned().unwrap_or_default(),
+        version: query.get("Version").cloned().unwrap_or_default(),
+        query,
+        headers,
+        body,
+    }
+}
+
+/// Checks whether a parameter or field is to be redacted.
+///
+/// # Arguments
+/// - `name`: The name of the parameter, possibly flattened (`Tags.1.Password`).
+/// - `redacted_fields`: The fields to redact.
+///
+/// # Returns
+/// - `bool`: Whether the last segment of the name is one of the fields, ignoring case.
+fn is_redacted(name: &str, redacted_fields: &[String]) -> bool {
+    let name = name.rsplit('.').next().unwrap_or(name);
+    redacted_fields
+        .iter()
+        .any(|field| field.eq_ignore_ascii_case(name))
+}
+
+/// Redacts the value of a query parameter or form field.
+///
+/// # Arguments
+/// - `name`: The name of the parameter.
+/// - `value`: The value of the parameter.
+/// - `redacted_fields`: The fields to redact.
+///
+/// # Returns
+/// - `(String, String)`: The parameter, its value replaced by `REDACTED` if redacted.
+fn redact_pair(name: String, value: String, redacted_fields: &[String]) -> (String, String) {
+    if is_redacted(&name, redacted_fields) {
+        (name, REDACTED.to_string())
+    } else {
+        (name, value)
+    }
+}
+
+/// Redacts the fields of a body.
+///
+/// A JSON body has the matching fields redacted at any depth, and a form body its matching
+/// fields; other bodies, and bodies without such fields, are kept as they are.
+///
+/// # Arguments
+/// - `body`: The body.
+/// - `is_form`: Whether the body is `application/x-www-form-urlencoded`.
+/// - `redacted_fields`: The fields to redact.
+///
+/// # Returns
+/// - `String`: The body, redacted.
+fn redact_body(body: &str, is_form: bool, redacted_fields: &[String]) -> String {
+    if is_form {
+        let pairs: Vec<(String, String)> = url::form_urlencoded::parse(body.as_bytes())
+            .into_owned()
+            .collect();
+        if !pairs
+            .iter()
+            .any(|(name, _)| is_redacted(name, redacted_fields))
+        {
+            return body.to_string();
+        }
+        return url::form_urlencoded::Serializer::new(String::new())
+            .extend_pairs(
+                pairs
+                    .into_iter()
+                    .map(|(name, value)| redact_pair(name, value, redacted_fields)),
+            )
+            .finish();
+    }
+
+    match serde_json::from_str::<serde_json::Value>(body) {
+        Ok(mut document) => {
+            if redact_json(&mut document, redacted_fields) {
+                document.to_string()
+            } else {
+                body.to_string()
+            }
+        }
+        Err(_) => body.to_string(),
+    }
+}
+
+/// Redacts the fields of a JSON document, at any depth.
+///
+/// # Arguments
+/// - `value`: The document.
+/// - `redacted_fields`: The fields to redact.
+///
+/// # Returns
+/// - `bool`: Whether a field was redacted.
+fn redact_json(value: &mut serde_json::Value, redacted_fields: &[String]) -> bool {
+    match value {
+        serde_json::Value::Object(object) => {
+            let mut redacted = false;
+            for (name, field) in object.iter_mut() {
+                if is_redacted(name, redacted_fields) {
+                    *field = serde_json::Value::String(REDACTED.to_string());
+                    redacted = true;
+                } else {
+                    redacted |= redact_json(field, redacted_fields);
+                }
+            }
+            redacted
+        }
+        serde_json::Value::Array(items) => items.iter_mut().fold(false, |redacted, item| {
+            redact_json(item, redacted_fields) | redacted
+        }),
+        _ => false,
+    }
+}
+
+/// Checks whether a recorded request answers a request.
+///
+/// # Arguments
+/// - `recorded`: The request of an interaction.
+/// - `request`: The request to answer.
+///
+/// # Returns
+/// - `bool`: Whether the `Action`, `Version`, query and normalized body are equal.
+fn matches_request(recorded: &RecordedRequest, request: &RecordedRequest) -> bool {
+    recorded.action == request.action
+        && recorded.version == request.version
+        && recorded.query == request.query
+        && normalize_body(&recorded.body) == normalize_body(&request.body)
+}
+
+/// Normalizes a body for matching.
+///
+/// # Arguments
+/// - `body`: The body.
+///
+/// # Returns
+/// - `serde_json::Value`: The parsed document for a JSON body, the raw text otherwise.
+fn normalize_body(body: &str) -> serde_json::Value {
+    serde_json::from_str(body).unwrap_or_else(|_| serde_json::Value::String(body.to_string()))
+}
+
+/// Rebuilds the response of an interaction.
+///
+/// # Arguments
+/// - `recorded_response`: The recorded response.
+///
+/// # Returns
+/// - `Ok(reqwest::Response)`: The response.
+/// - `Err(error::Error)`: If the recorded status or a header is invalid.
+fn replay_response(recorded_response: RecordedResponse) -> Result<reqwest::Response, error::Error> {
+    let mut http_response = http::Response::new(Bytes::from(recorded_response.body));
+    *http_response.status_mut() =
+        http::StatusCode::from_u16(recorded_response.status).map_err(|_| {
+            error::Error::ErrCassetteInvalid(format!("status {}", recorded_response.status))
+        })?;
+    for (name, value) in &recorded_response.headers {
+        let header_name = HeaderName::from_bytes(name.as_bytes())
+            .map_err(|_| error::Error::ErrCassetteInvalid(format!("header {}", name)))?;
+        let header_value = HeaderValue::from_str(value)
+            .map_err(|_| error::Error::ErrCassetteInvalid(format!("header {}", name)))?;
+        http_response
+            .headers_mut()
+            .append(header_name, header_value);
+    }
+    Ok(reqwest::Response::from(http_response))
+}
@@ -0,0 +1,12 @@
+/*
+ * @Author: Jerry.Yang
+ * @Date: 2025-03-03 10:12:40
+ * @LastEditors: Jerry.Yang
+ * @LastEditTime: 2025-03-20 11:02:15
+ * @Description: mod
+ */
+pub mod cassette;
+pub mod transport;
+
+// Test module for cassette recording and replay.
+mod tests;
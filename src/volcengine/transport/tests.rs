@@ -0,0 +1,355 @@
+/*
+ * @Author: Jerry.Yang
+ * @Date: 2025-03-20 14:31:02
+ * @LastEditors: Jerry.Yang
+ * @LastEditTime: 2025-03-29 17:10:00
+ * @Description: cassette test
+ */
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+    use volcengine_sdk_protobuf::protobuf::rds_instance;
+
+    use crate::service::ecs::{self, api_instance_lifecycle_model, EcsService};
+    use crate::service::rds::{self, RdsService};
+    use crate::service::sts::{self, api_assume_role_model, StsService};
+    use crate::volcengine::config;
+    use crate::volcengine::credentials::credentials;
+    use crate::volcengine::error::error;
+    use crate::volcengine::mock::mock;
+    use crate::volcengine::retry::retry;
+    use crate::volcengine::session::session;
+    use crate::volcengine::transport::{cassette, transport};
+
+    /// Returns a cassette file path unique to the test.
+    fn cassette_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!(
+            "volcengine-cassette-{}-{}.json",
+            std::process::id(),
+            name
+        ))
+    }
+
+    /// Returns a session recording its requests to the mock server in `cassette`.
+    fn recording_session(
+        server: &mock::MockServer,
+        cassette: cassette::Cassette,
+    ) -> session::Session {
+        let config = server
+            .config_builder()
+            .with_transport_config(
+                transport::TransportConfig::builder()
+                    .with_cassette(cassette)
+                    .build(),
+            )
+            .build()
+            .unwrap();
+        session::Session::builder()
+            .with_config(config)
+            .build()
+            .unwrap()
+    }
+
+    /// Returns a session replaying `cassette` against an endpoint nothing listens on.
+    fn replay_session(cassette: cassette::Cassette) -> session::Session {
+        let config = config::Config::builder()
+            .with_region(mock::MOCK_REGION)
+            .with_endpoint("http://127.0.0.1:9")
+            .with_credentials(credentials::Credentials::new("AKLTreplay", "replay"))
+            .with_retry_policy(retry::RetryPolicy::disabled())
+            .with_transport_config(
+                transport::TransportConfig::builder()
+                    .with_cassette(cassette)
+                    .build(),
+            )
+            .build()
+            .unwrap();
+        session::Session::builder()
+            .with_config(config)
+            .build()
+            .unwrap()
+    }
+
+    /// Returns a `DescribeDBInstanceDetail` request for `instance_id`.
+    fn describe_db_instance_detail_request(
+        instance_id: &str,
+    ) -> rds_instance::DescribeDbInstanceDetailReq {
+        rds_instance::DescribeDbInstanceDetailReq {
+            instance_id: Some(instance_id.to_string()),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_record_then_replay() {
+        let path = cassette_path("record_then_replay");
+
+        // record one interaction with the mock server
+        {
+            let server = mock::MockServer::start().await;
+            server.mock(
+                "DescribeDBInstanceDetail",
+                "2022-01-01",
+                mock::MockResponse::json(&rds_instance::DescribeDbInstanceDetailResp {
+                    result: Some(rds_instance::DescribeDbInstanceDetailResultResp {
+                        basic_info: Some(
+                            rds_instance::DescribeDbInstanceDetailResultBasicInfoResp {
+                                instance_status: Some("Running".to_string()),
+                                ..Default::default()
+                            },
+                        ),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                }),
+            );
+
+            let recorder = cassette::Cassette::record(&path);
+            let rds = rds::Rds::new_rds(recording_session(&server, recorder.clone())).unwrap();
+            rds.new_describe_db_instance_detail(describe_db_instance_detail_request("mysql-1"))
+                .await
+                .unwrap();
+
+            // the secrets are redacted
+            let interactions = recorder.interactions();
+            assert_eq!(interactions.len(), 1);
+            let request = &interactions[0].request;
+            assert_eq!(request.action, "DescribeDBInstanceDetail");
+            assert_eq!(request.headers["authorization"], cassette::REDACTED);
+            assert_eq!(request.headers["x-date"], cassette::REDACTED);
+            assert_eq!(interactions[0].response.status, 200);
+        }
+
+        // replay it with no server
+        let rds =
+            rds::Rds::new_rds(replay_session(cassette::Cassette::replay(&path).unwrap())).unwrap();
+        let response = rds
+            .new_describe_db_instance_detail(describe_db_instance_detail_request("mysql-1"))
+            .await
+            .unwrap();
+        let basic_info = response.result.unwrap().basic_info.unwrap();
+        assert_eq!(basic_info.instance_status.as_deref(), Some("Running"));
+
+        // every interaction is replayed once
+        let result = rds
+            .new_describe_db_instance_detail(describe_db_instance_detail_request("mysql-1"))
+            .await;
+        assert!(matches!(
+            result,
+            Err(error::Error::ErrCassetteNoInteraction { action, .. })
+                if action == "DescribeDBInstanceDetail"
+        ));
+
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[tokio::test]
+    async fn test_replay_matches_normalized_body() {
+        let path = cassette_path("replay_matches_normalized_body");
+        let create_db_instance_request = |vpc_id: &str| rds_instance::CreateDbInstanceReq {
+            db_engine_version: Some("MySQL_8_0".to_string()),
+            vpc_id: Some(vpc_id.to_string()),
+            ..Default::default()
+        };
+
+        // record a request carrying a JSON body
+        {
+            let server = mock::MockServer::start().await;
+            server.mock(
+                "CreateDBInstance",
+                "2022-01-01",
+                mock::MockResponse::json(&rds_instance::CreateDbInstanceResp {
+                    result: Some(rds_instance::CreateDbInstanceResultResp {
+                        instance_id: Some("mysql-1".to_string()),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                }),
+            );
+
+            let recorder = cassette::Cassette::record(&path);
+            let rds = rds::Rds::new_rds(recording_session(&server, recorder)).unwrap();
+            rds.new_create_db_instance(create_db_instance_request("vpc-1"))
+                .await
+                .unwrap();
+        }
+
+        // hand-edit the cassette, pretty printing the body
+        let mut cassette_file: serde_json::Value =
+            serde_json::from_slice(&std::fs::read(&path).unwrap()).unwrap();
+        let body = &mut cassette_file["interactions"][0]["request"]["body"];
+        let document: serde_json::Value = serde_json::from_str(body.as_str().unwrap()).unwrap();
+        *body = serde_json::Value::String(serde_json::to_string_pretty(&document).unwrap());
+        std::fs::write(&path, cassette_file.to_string()).unwrap();
+
+        let rds =
+            rds::Rds::new_rds(replay_session(cassette::Cassette::replay(&path).unwrap())).unwrap();
+
+        // another body does not match
+        let result = rds
+            .new_create_db_instance(create_db_instance_request("vpc-2"))
+            .await;
+        assert!(matches!(
+            result,
+            Err(error::Error::ErrCassetteNoInteraction { .. })
+        ));
+
+        // the same document matches whatever its formatting
+        let response = rds
+            .new_create_db_instance(create_db_instance_request("vpc-1"))
+            .await
+            .unwrap();
+        assert_eq!(
+            response.result.unwrap().instance_id.as_deref(),
+            Some("mysql-1")
+        );
+
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[tokio::test]
+    async fn test_record_redacts_fields() {
+        let path = cassette_path("record_redacts_fields");
+        let modify_instance_attribute_request =
+            || api_instance_lifecycle_model::ModifyInstanceAttributeReq {
+                instance_id: "i-1".to_string(),
+                password: Some("Secret-1".to_string()),
+                ..Default::default()
+            };
+
+        // record a password sent in a form body, and credentials returned by STS
+        {
+            let server = mock::MockServer::start().await;
+            server.mock(
+                "ModifyInstanceAttribute",
+                "2020-04-01",
+                mock::MockResponse::empty(),
+            );
+            server.mock(
+                "AssumeRole",
+                "2018-01-01",
+                mock::MockResponse::json(&serde_json::json!({
+                    "Result": {
+                        "Credentials": {
+                            "AccessKeyId": "AKLTsts",
+                            "SecretAccessKey": "Secret-2",
+                            "SessionToken": "Secret-3",
+                        },
+                    },
+                })),
+            );
+
+            let recorder = cassette::Cassette::record(&path);
+            let session = recording_session(&server, recorder.clone());
+            let ecs = ecs::Ecs::new_ecs(session.clone()).unwrap();
+            ecs.new_modify_instance_attribute(modify_instance_attribute_request())
+                .await
+                .unwrap();
+            let sts = sts::Sts::new_sts(session).unwrap();
+            let response = sts
+                .new_assume_role(api_assume_role_model::AssumeRoleReq {
+                    role_trn: "trn:iam::2100000000:role/ops".to_string(),
+                    role_session_name: "record".to_string(),
+                    ..Default::default()
+                })
+                .await
+                .unwrap();
+            // the caller still gets the secrets
+            assert_eq!(
+                response.result.unwrap().credentials.secret_access_key,
+                "Secret-2"
+            );
+
+            let interactions = recorder.interactions();
+            assert!(interactions[0]
+                .request
+                .body
+                .contains("Password=%3Credacted%3E"));
+            let credentials: serde_json::Value =
+                serde_json::from_str(&interactions[1].response.body).unwrap();
+            let credentials = &credentials["Result"]["Credentials"];
+            assert_eq!(credentials["AccessKeyId"], "AKLTsts");
+            assert_eq!(credentials["SecretAccessKey"], cassette::REDACTED);
+            assert_eq!(credentials["SessionToken"], cassette::REDACTED);
+        }
+
+        // no secret is written to the file
+        let content = std::fs::read_to_string(&path).unwrap();
+        for secret in ["Secret-1", "Secret-2", "Secret-3"] {
+            assert!(!content.contains(secret), "{} is recorded", secret);
+        }
+
+        // the request carrying the password still matches its interaction
+        let ecs =
+            ecs::Ecs::new_ecs(replay_session(cassette::Cassette::replay(&path).unwrap())).unwrap();
+        ecs.new_modify_instance_attribute(modify_instance_attribute_request())
+            .await
+            .unwrap();
+
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[tokio::test]
+    async fn test_record_redacts_configured_fields() {
+        let path = cassette_path("record_redacts_configured_fields");
+
+        {
+            let server = mock::MockServer::start().await;
+            server.mock(
+                "DescribeDBInstanceDetail",
+                "2022-01-01",
+                mock::MockResponse::json(&rds_instance::DescribeDbInstanceDetailResp::default()),
+            );
+
+            let recorder = cassette::Cassette::record(&path).with_redacted_fields(["InstanceId"]);
+            let rds = rds::Rds::new_rds(recording_session(&server, recorder.clone())).unwrap();
+            rds.new_describe_db_instance_detail(describe_db_instance_detail_request("mysql-1"))
+                .await
+                .unwrap();
+
+            let interactions = recorder.interactions();
+            assert_eq!(
+                interactions[0].request.query["InstanceId"],
+                cassette::REDACTED
+            );
+        }
+        assert!(!std::fs::read_to_string(&path).unwrap().contains("mysql-1"));
+
+        // replaying needs the same fields
+        let cassette = cassette::Cassette::replay(&path)
+            .unwrap()
+            .with_redacted_fields(["InstanceId"]);
+        let rds = rds::Rds::new_rds(replay_session(cassette)).unwrap();
+        rds.new_describe_db_instance_detail(describe_db_instance_detail_request("mysql-2"))
+            .await
+            .unwrap();
+
+        let rds =
+            rds::Rds::new_rds(replay_session(cassette::Cassette::replay(&path).unwrap())).unwrap();
+        let result = rds
+            .new_describe_db_instance_detail(describe_db_instance_detail_request("mysql-1"))
+            .await;
+        assert!(matches!(
+            result,
+            Err(error::Error::ErrCassetteNoInteraction { .. })
+        ));
+
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn test_replay_invalid_cassette() {
+        let path = cassette_path("replay_invalid_cassette");
+        assert!(matches!(
+            cassette::Cassette::replay(&path),
+            Err(error::Error::ErrCassetteIo(_))
+        ));
+
+        std::fs::write(&path, "not a cassette").unwrap();
+        assert!(matches!(
+            cassette::Cassette::replay(&path),
+            Err(error::Error::ErrCassetteFormat(_))
+        ));
+
+        std::fs::remove_file(&path).ok();
+    }
+}
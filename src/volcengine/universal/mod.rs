@@ -0,0 +1,11 @@
+/*
+ * @Author: Jerry.Yang
+ * @Date: 2025-03-21 09:36:12
+ * @LastEditors: Jerry.Yang
+ * @LastEditTime: 2025-03-21 09:36:12
+ * @Description: mod
+ */
+pub mod universal;
+
+// Test module for raw API calls.
+mod tests;
@@ -0,0 +1,326 @@
+/*
+ * @Author: Jerry.Yang
+ * @Date: 2025-03-21 13:05:27
+ * @LastEditors: Jerry.Yang
+ * @LastEditTime: 2025-03-30 09:40:00
+ * @Description: universal test
+ */
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::time::Duration;
+
+    use crate::volcengine::error::error;
+    use crate::volcengine::mock::mock;
+    use crate::volcengine::registry::registry;
+    use crate::volcengine::request::operation_config::operation_http_method::OperationHttpMethod;
+    use crate::volcengine::universal::universal;
+
+    #[tokio::test]
+    async fn test_call_get() {
+        let server = mock::MockServer::start().await;
+        server.mock(
+            "StartInstances",
+            "2020-04-01",
+            mock::MockResponse::json(&serde_json::json!({
+                "Result": { "OperationDetails": [] },
+            })),
+        );
+
+        let client = universal::UniversalClient::new(server.session());
+        let response = client
+            .call(
+                "ecs",
+                "2020-04-01",
+                "StartInstances",
+                OperationHttpMethod::GET,
+                serde_json::json!({ "InstanceIds": ["i-1", "i-2"] }),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response["ResponseMetadata"]["Action"], "StartInstances");
+        assert!(response["Result"]["OperationDetails"].is_array());
+
+        // the parameters are flattened into the signed query
+        let requests = server.requests();
+        assert!(requests[0].signature_valid);
+        assert_eq!(requests[0].method, http::Method::GET);
+        assert_eq!(requests[0].service, "ecs");
+        assert_eq!(requests[0].version, "2020-04-01");
+        assert_eq!(requests[0].query["InstanceIds.1"], "i-1");
+        assert_eq!(requests[0].query["InstanceIds.2"], "i-2");
+    }
+
+    #[tokio::test]
+    async fn test_call_post() {
+        let server = mock::MockServer::start().await;
+        server.mock(
+            "DescribeDBInstanceParameters",
+            "2022-01-01",
+            mock::MockResponse::empty(),
+        );
+
+        let client = universal::UniversalClient::new(server.session());
+        client
+            .call(
+                "rds_mysql",
+                "2022-01-01",
+                "DescribeDBInstanceParameters",
+                OperationHttpMethod::POST,
+                serde_json::json!({ "InstanceId": "mysql-1" }),
+            )
+            .await
+            .unwrap();
+
+        // the parameters are sent as the signed JSON body
+        let requests = server.requests();
+        assert!(requests[0].signature_valid);
+        assert_eq!(requests[0].method, http::Method::POST);
+        assert_eq!(requests[0].service, "rds_mysql");
+        assert!(!requests[0].query.contains_key("InstanceId"));
+        let body: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+        assert_eq!(body["InstanceId"], "mysql-1");
+    }
+
+    #[tokio::test]
+    async fn test_call_registry_content_type() {
+        let server = mock::MockServer::start().await;
+        server.mock("ModifyAllowList", "2022-01-01", mock::MockResponse::empty());
+
+        // `ModifyAllowList` is a `POST` operation declared with its parameters in the query
+        let client = universal::UniversalClient::new(server.session());
+        client
+            .call(
+                "rds_mysql",
+                "2022-01-01",
+                "ModifyAllowList",
+                OperationHttpMethod::POST,
+                serde_json::json!({ "AllowListId": "acl-1", "AllowList": "10.0.0.0/8" }),
+            )
+            .await
+            .unwrap();
+
+        let requests = server.requests();
+        assert!(requests[0].signature_valid);
+        assert_eq!(requests[0].method, http::Method::POST);
+        assert_eq!(requests[0].query["AllowListId"], "acl-1");
+        assert_eq!(requests[0].query["AllowList"], "10.0.0.0/8");
+        assert!(requests[0].body.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_call_get_registry_json() {
+        let server = mock::MockServer::start().await;
+        server.mock(
+            "DescribeDBInstanceParameters",
+            "2022-01-01",
+            mock::MockResponse::empty(),
+        );
+
+        // `DescribeDBInstanceParameters` is registered as a `POST` with a JSON body, called
+        // with `GET` its parameters fall back to the query
+        let client = universal::UniversalClient::new(server.session());
+        client
+            .call(
+                "rds_mysql",
+                "2022-01-01",
+                "DescribeDBInstanceParameters",
+                OperationHttpMethod::GET,
+                serde_json::json!({ "InstanceId": "mysql-1" }),
+            )
+            .await
+            .unwrap();
+
+        let requests = server.requests();
+        assert!(requests[0].signature_valid);
+        assert_eq!(requests[0].method, http::Method::GET);
+        assert_eq!(requests[0].query["InstanceId"], "mysql-1");
+        assert!(requests[0].body.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_call_get_with_body() {
+        let server = mock::MockServer::start().await;
+        server.mock("ListBill", "2022-01-01", mock::MockResponse::empty());
+
+        // a `GET` cannot carry the body, the call fails instead of dropping the parameters
+        let client = universal::UniversalClient::new(server.session());
+        for content_type in [registry::ContentType::Json, registry::ContentType::Form] {
+            let result = client
+                .call_with_content_type(
+                    "billing",
+                    "2022-01-01",
+                    "ListBill",
+                    OperationHttpMethod::GET,
+                    content_type,
+                    serde_json::json!({ "BillPeriod": "2025-02" }),
+                )
+                .await;
+            assert!(matches!(result, Err(error::Error::ErrRequestGetBody(_))));
+        }
+        assert!(server.requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_call_with_content_type() {
+        let server = mock::MockServer::start().await;
+        server.mock("ListBill", "2022-01-01", mock::MockResponse::empty());
+
+        let client = universal::UniversalClient::new(server.session());
+        client
+            .call_with_content_type(
+                "billing",
+                "2022-01-01",
+                "ListBill",
+                OperationHttpMethod::POST,
+                registry::ContentType::Query,
+                serde_json::json!({ "BillPeriod": "2025-02" }),
+            )
+            .await
+            .unwrap();
+
+        let requests = server.requests();
+        assert!(requests[0].signature_valid);
+        assert_eq!(requests[0].method, http::Method::POST);
+        assert_eq!(requests[0].query["BillPeriod"], "2025-02");
+        assert!(requests[0].body.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_call_custom_service() {
+        let server = mock::MockServer::start().await;
+        server.mock("ListBill", "2022-01-01", mock::MockResponse::empty());
+
+        let client = universal::UniversalClient::new(server.session());
+        client
+            .call(
+                "billing",
+                "2022-01-01",
+                "ListBill",
+                OperationHttpMethod::GET,
+                serde_json::Value::Null,
+            )
+            .await
+            .unwrap();
+
+        let requests = server.requests();
+        assert!(requests[0].signature_valid);
+        assert_eq!(requests[0].service, "billing");
+    }
+
+    #[tokio::test]
+    async fn test_call_api_error() {
+        let server = mock::MockServer::start().await;
+        server.mock(
+            "DeleteInstances",
+            "2020-04-01",
+            mock::MockResponse::error(404, "InvalidInstance.NotFound", "not found"),
+        );
+
+        let client = universal::UniversalClient::new(server.session());
+        let result = client
+            .call(
+                "ecs",
+                "2020-04-01",
+                "DeleteInstances",
+                OperationHttpMethod::GET,
+                serde_json::json!({ "InstanceIds": ["i-1"] }),
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(error::Error::Api { code, .. }) if code == "InvalidInstance.NotFound"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_call_invalid_params() {
+        let server = mock::MockServer::start().await;
+
+        let client = universal::UniversalClient::new(server.session());
+        let result = client
+            .call(
+                "vpc",
+                "2020-04-01",
+                "CreateSecurityGroup",
+                OperationHttpMethod::GET,
+                serde_json::json!(["not", "an", "object"]),
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(error::Error::ErrRequestInvalidParams(_))
+        ));
+        assert!(server.requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_presign() {
+        let server = mock::MockServer::start().await;
+
+        let client = universal::UniversalClient::new(server.session());
+        let url = client
+            .presign(
+                "ecs",
+                "2020-04-01",
+                "DescribeInstances",
+                OperationHttpMethod::GET,
+                serde_json::json!({ "InstanceIds": ["i-1"] }),
+                Duration::from_secs(300),
+            )
+            .await
+            .unwrap();
+
+        // the parameters and the authentication are in the query
+        let url = reqwest::Url::parse(&url).unwrap();
+        let query: HashMap<_, _> = url.query_pairs().into_owned().collect();
+        assert_eq!(query["Action"], "DescribeInstances");
+        assert_eq!(query["Version"], "2020-04-01");
+        assert_eq!(query["InstanceIds.1"], "i-1");
+        assert_eq!(query["X-Expires"], "300");
+        assert!(query["X-Credential"].ends_with("/ecs/request"));
+        assert!(query.contains_key("X-Signature"));
+
+        // nothing is sent
+        assert!(server.requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_presign_body() {
+        let server = mock::MockServer::start().await;
+
+        // the parameters of a JSON operation cannot be carried by a URL
+        let client = universal::UniversalClient::new(server.session());
+        let result = client
+            .presign(
+                "rds_mysql",
+                "2022-01-01",
+                "DescribeDBInstanceParameters",
+                OperationHttpMethod::POST,
+                serde_json::json!({ "InstanceId": "mysql-1" }),
+                Duration::from_secs(300),
+            )
+            .await;
+        assert!(matches!(
+            result,
+            Err(error::Error::ErrRequestPresignBody(_))
+        ));
+
+        // without parameters there is no body
+        let url = client
+            .presign(
+                "rds_mysql",
+                "2022-01-01",
+                "DescribeDBInstanceParameters",
+                OperationHttpMethod::POST,
+                serde_json::Value::Null,
+                Duration::from_secs(300),
+            )
+            .await
+            .unwrap();
+        assert!(url.contains("X-Signature="));
+    }
+}
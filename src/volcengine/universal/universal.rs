@@ -0,0 +1,284 @@
+/*
+ * @Author: Jerry.Yang
+ * @Date: 2025-03-21 09:36:12
+ * @LastEditors: Jerry.Yang
+ * @LastEditTime: 2025-03-30 11:10:00
+ * @Description: Raw calls of the operations the SDK does not model
+ */
+use crate::volcengine::client::client_info;
+use crate::volcengine::client::config as client_config;
+use crate::volcengine::error::error;
+use crate::volcengine::registry::registry;
+use crate::volcengine::request::operation;
+use crate::volcengine::request::operation_config;
+use crate::volcengine::request::request;
+use crate::volcengine::request::request::RequestVolcengine;
+use crate::volcengine::request::response;
+use crate::volcengine::session::session;
+use std::time::Duration;
+
+/// A client calling any Volcengine API by its service, version and action.
+///
+/// `UniversalClient` covers the operations for which the SDK has no typed wrapper yet. Calls
+/// go through the same path as the typed services: the endpoint is resolved from the session
+/// (endpoint overrides included), the request is signed and retried per the configuration,
+/// and a `ResponseMetadata.Error` is returned as `error::Error::Api`.
+///
+/// The parameters are a JSON object, laid out like the typed requests: flattened into the
+/// query string (`InstanceIds.1=i-1`) or sent as the JSON body. Operations declared in the
+/// registry use the content type they are declared with, e.g. the `POST` operation
+/// `ModifyAllowList` takes its parameters in the query; other `GET` operations use the query
+/// and other `POST` operations the JSON body. `call_with_content_type` picks the layout
+/// explicitly.
+///
+/// # Example
+/// ```rust,no_run
+/// # use volcengine_rust_sdk::volcengine::error::error;
+/// # use volcengine_rust_sdk::volcengine::session::session;
+/// use volcengine_rust_sdk::volcengine::request::operation_config::operation_http_method::OperationHttpMethod;
+/// use volcengine_rust_sdk::volcengine::universal::universal;
+///
+/// # async fn example(session: session::Session) -> Result<(), error::Error> {
+/// let client = universal::UniversalClient::new(session);
+/// let response = client
+///     .call(
+///         "ecs",
+///         "2020-04-01",
+///         "StartInstances",
+///         OperationHttpMethod::GET,
+///         serde_json::json!({ "InstanceIds": ["i-1", "i-2"] }),
+///     )
+///     .await?;
+/// println!("{}", response["ResponseMetadata"]["RequestId"]);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct UniversalClient {
+    session: session::Session, // Session the client configuration is derived from.
+}
+
+impl UniversalClient {
+    /// Creates a client calling APIs with the configuration of a session.
+    ///
+    /// # Arguments
+    /// - `session`: The session providing the credentials, region and transport.
+    ///
+    /// # Returns
+    /// - `UniversalClient`: The client.
+    pub fn new(session: session::Session) -> UniversalClient {
+        UniversalClient { session }
+    }
+
+    /// Calls an API and returns its raw JSON response.
+    ///
+    /// The parameters are laid out per the content type of the operation in the registry,
+    /// if declared there with `method`, or else per `method`: in the query for `GET` and as
+    /// a JSON body for `POST`.
+    ///
+    /// # Arguments
+    /// - `service`: The service, as named in its signing scope, e.g. `ecs` or `rds_mysql`.
+    /// - `version`: The API version, e.g. `2020-04-01`.
+    /// - `action`: The action, e.g. `StartInstances`.
+    /// - `method`: The HTTP method of the action.
+    /// - `params`: The parameters, a JSON object; `null` for none.
+    ///
+    /// # Returns
+    /// - `Ok(serde_json::Value)`: The whole response, `ResponseMetadata` and `Result`.
+    /// - `Err(error::Error::Api)`: If the API returned an error.
+    /// - `Err(error::Error::ErrRequestInvalidParams)`: If the parameters are not an object.
+    /// - `Err(error::Error)`: If the endpoint cannot be resolved or the request fails.
+    pub async fn call(
+        &self,
+        service: &str,
+        version: &str,
+        action: &str,
+        method: operation_config::operation_http_method::OperationHttpMethod,
+        params: serde_json::Value,
+    ) -> Result<serde_json::Value, error::Error> {
+        let content_type = content_type_of(service, action, &method);
+        self.call_with_content_type(service, version, action, method, content_type, params)
+            .await
+    }
+
+    /// Calls an API with the parameters laid out as `content_type`, and returns its raw JSON
+    /// response.
+    ///
+    /// # Arguments
+    /// - `service`: The service, as named in its signing scope, e.g. `ecs` or `rds_mysql`.
+    /// - `version`: The API version, e.g. `2020-04-01`.
+    /// - `action`: The action, e.g. `StartInstances`.
+    /// - `method`: The HTTP method of the action.
+    /// - `content_type`: How the parameters are sent, in the query or as the JSON body.
+    /// - `params`: The parameters, a JSON object; `null` for none.
+    ///
+    /// # Returns
+    /// - `Ok(serde_json::Value)`: The whole response, `ResponseMetadata` and `Result`.
+    /// - `Err(error::Error::Api)`: If the API returned an error.
+    /// - `Err(error::Error::ErrRequestInvalidParams)`: If the parameters are not an object.
+    /// - `Err(error::Error::ErrRequestGetBody)`: If a `GET` call has parameters laid out as a body.
+    /// - `Err(error::Error)`: If the endpoint cannot be resolved or the request fails.
+    pub async fn call_with_content_type(
+        &self,
+        service: &str,
+        version: &str,
+        action: &str,
+        method: operation_config::operation_http_method::OperationHttpMethod,
+        content_type: registry::ContentType,
+        params: serde_json::Value,
+    ) -> Result<serde_json::Value, error::Error> {
+        let (request, operation_params) =
+            self.build_request(service, version, action, method, content_type, params)?;
+
+        // Send the request and read the response.
+        let response = request.send(operation_params).await?;
+        response::parse_response(response).await
+    }
+
+    /// Presigns a call of an API into a URL usable without credentials.
+    ///
+    /// The URL carries the parameters with `X-Algorithm`, `X-Credential`, `X-Date`, `X-Expires`,
+    /// `X-SignedHeaders` and `X-Signature`, so that it can be handed to a browser or a job
+    /// holding no access key. It must be requested with `method`, without a body, before it
+    /// expires. The parameters are laid out like `call` does; since a URL carries no body,
+    /// operations sending their parameters as a JSON body can only be presigned without
+    /// parameters.
+    ///
+    /// # Arguments
+    /// - `service`: The service, as named in its signing scope, e.g. `ecs`.
+    /// - `version`: The API version, e.g. `2020-04-01`.
+    /// - `action`: The action, e.g. `DescribeInstances`.
+    /// - `method`: The HTTP method the URL will be requested with.
+    /// - `params`: The parameters, a JSON object; `null` for none.
+    /// - `expires`: How long the URL stays valid, at least one second.
+    ///
+    /// # Returns
+    /// - `Ok(String)`: The presigned URL.
+    /// - `Err(error::Error::ErrRequestPresignBody)`: If the parameters are sent as a body.
+    /// - `Err(error::Error::ErrRequestSignInvalidExpires)`: If `expires` is under one second.
+    /// - `Err(error::Error)`: If the parameters are not an object, the endpoint cannot be
+    ///   resolved or the credentials cannot be provided.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let client = universal::UniversalClient::new(session);
+    /// let url = client
+    ///     .presign(
+    ///         "ecs",
+    ///         "2020-04-01",
+    ///         "DescribeInstances",
+    ///         OperationHttpMethod::GET,
+    ///         serde_json::json!({ "InstanceIds": ["i-1"] }),
+    ///         Duration::from_secs(300),
+    ///     )
+    ///     .await?;
+    /// ```
+    pub async fn presign(
+        &self,
+        service: &str,
+        version: &str,
+        action: &str,
+        method: operation_config::operation_http_method::OperationHttpMethod,
+        params: serde_json::Value,
+        expires: Duration,
+    ) -> Result<String, error::Error> {
+        let content_type = content_type_of(service, action, &method);
+        let (request, operation_params) =
+            self.build_request(service, version, action, method, content_type, params)?;
+        request.presign(operation_params, expires).await
+    }
+
+    /// Builds the request of a call and lays its parameters out.
+    ///
+    /// # Arguments
+    /// - `service`: The service, as named in its signing scope.
+    /// - `version`: The API version.
+    /// - `action`: The action.
+    /// - `method`: The HTTP method of the action.
+    /// - `content_type`: How the parameters are sent.
+    /// - `params`: The parameters, a JSON object; `null` for none.
+    ///
+    /// # Returns
+    /// - `Ok((request::Request, registry::OperationParams))`: The request and its parameters.
+    /// - `Err(error::Error::ErrRequestInvalidParams)`: If the parameters are not an object.
+    /// - `Err(error::Error)`: If the endpoint cannot be resolved.
+    fn build_request(
+        &self,
+        service: &str,
+        version: &str,
+        action: &str,
+        method: operation_config::operation_http_method::OperationHttpMethod,
+        content_type: registry::ContentType,
+        params: serde_json::Value,
+    ) -> Result<(request::Request, registry::OperationParams), error::Error> {
+        // Resolve the endpoint and signing region of the service.
+        let service_name = client_config::ClientServiceName::from_name(service);
+        let client_config = self.session.new_client_config(service_name.clone())?;
+        let client_info = client_info::ClientInfo::builder()
+            .with_service_name(service_name)
+            .with_api_version(version)
+            .with_signing_region(&client_config.signing_region)
+            .build()?;
+
+        // Lay the parameters out for the content type.
+        let operation_params = if params.is_null() {
+            registry::OperationParams::default()
+        } else if params.is_object() {
+            registry::OperationParams::new(content_type, &params)?
+        } else {
+            return Err(error::Error::ErrRequestInvalidParams(params.to_string()));
+        };
+
+        // Define the operation.
+        let request_operation = operation::Operation::builder()
+            .with_operation_name(
+                operation_config::operation_name::OperationName::CustomOperation(
+                    action.to_string(),
+                ),
+            )
+            .with_operation_http_method(method)
+            .with_operation_http_path(
+                operation_config::operation_http_path::OperationHttpPath::Default,
+            )
+            .build()?;
+
+        // Build the request.
+        let request = request::Request::builder()
+            .with_client_info(&client_info)
+            .with_config(&client_config)
+            .with_handles(&client_config.handles)
+            .with_operation(&request_operation)
+            .build()?;
+        Ok((request, operation_params))
+    }
+}
+
+/// Returns how the parameters of an operation are sent.
+///
+/// # Arguments
+/// - `service`: The service, as named in its signing scope.
+/// - `action`: The action.
+/// - `method`: The HTTP method of the action.
+///
+/// # Returns
+/// - `registry::ContentType`: The content type the operation is declared with in the
+///   registry when it is registered with `method`, or else `Query` for `GET` and `Json`
+///   for `POST`.
+fn content_type_of(
+    service: &str,
+    action: &str,
+    method: &operation_config::operation_http_method::OperationHttpMethod,
+) -> registry::ContentType {
+    // A content type declared for another method would put GET parameters in a body.
+    if let Some(spec) = registry::find(service, action).filter(|spec| spec.http_method == *method) {
+        return spec.content_type;
+    }
+    match method {
+        operation_config::operation_http_method::OperationHttpMethod::GET => {
+            registry::ContentType::Query
+        }
+        operation_config::operation_http_method::OperationHttpMethod::POST => {
+            registry::ContentType::Json
+        }
+    }
+}
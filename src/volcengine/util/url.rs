@@ -2,7 +2,7 @@
  * @Author: Jerry.Yang
  * @Date: 2024-10-18 10:49:00
  * @LastEditors: Jerry.Yang
- * @LastEditTime: 2025-02-05 11:42:12
+ * @LastEditTime: 2025-03-27 11:06:42
  * @Description: Utility for constructing service URLs
  */
 use crate::volcengine::client::config;
@@ -83,6 +83,9 @@ impl Url {
             config::ClientServiceName::Rds => endpoint::Endpoint::RdsEndpoint,
             config::ClientServiceName::Redis => endpoint::Endpoint::RedisEndpoint,
             config::ClientServiceName::Clb => endpoint::Endpoint::ClbEndpoint,
+            config::ClientServiceName::Sts => endpoint::Endpoint::StsEndpoint,
+            config::ClientServiceName::NatGateway => endpoint::Endpoint::NatGatewayEndpoint,
+            config::ClientServiceName::Custom(_) => endpoint::Endpoint::OpenEndpoint,
         };
 
         // Region-related configuration is not yet implemented, so this is left blank for future expansion.
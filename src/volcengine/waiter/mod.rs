@@ -0,0 +1,8 @@
+/*
+ * @Author: Jerry.Yang
+ * @Date: 2025-03-12 10:14:52
+ * @LastEditors: Jerry.Yang
+ * @LastEditTime: 2025-03-12 10:14:52
+ * @Description: mod
+ */
+pub mod waiter;
@@ -0,0 +1,308 @@
+/*
+ * @Author: Jerry.Yang
+ * @Date: 2025-03-12 10:14:52
+ * @LastEditors: Jerry.Yang
+ * @LastEditTime: 2025-03-30 11:10:00
+ * @Description: Waiters polling a resource until it reaches a desired state
+ */
+use crate::volcengine::error::error;
+use std::future::Future;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::{Duration, Instant};
+
+/// The default delay before the first poll is repeated.
+pub const DEFAULT_DELAY: Duration = Duration::from_secs(5);
+
+/// The default upper bound of the delay between two polls.
+pub const DEFAULT_MAX_DELAY: Duration = Duration::from_secs(60);
+
+/// The default time after which the waiter gives up.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30 * 60);
+
+/// The default factor applied to the delay after every poll.
+pub const DEFAULT_BACKOFF: f64 = 1.5;
+
+/// The default number of consecutive polls a resource may be missing from a Describe answer.
+pub const DEFAULT_MAX_NOT_FOUND: u32 = 3;
+
+/// The state reported when a resource is missing from a Describe answer.
+pub const STATE_NOT_FOUND: &str = "NOT_FOUND";
+
+/// Describes how often and how long a waiter polls.
+///
+/// The first poll is sent immediately. While the resource is still transitioning the
+/// waiter sleeps `delay`, then `delay * backoff`, `delay * backoff^2`... capped at
+/// `max_delay`, and gives up with `Error::ErrWaiterTimeout` once `timeout` would be exceeded.
+///
+/// # Fields
+/// - `delay` (`Duration`): Delay after the first poll.
+/// - `max_delay` (`Duration`): Upper bound of a single delay.
+/// - `timeout` (`Duration`): Total time after which the waiter gives up.
+/// - `backoff` (`f64`): Factor applied to the delay after every poll, `1.0` for a fixed delay.
+/// - `max_not_found` (`u32`): Consecutive polls a resource may be missing before the waiter fails.
+///
+/// # Example
+/// ```rust,no_run
+/// # use volcengine_rust_sdk::volcengine::error::error;
+/// use std::time::Duration;
+/// use volcengine_rust_sdk::service::ecs::{self, EcsService};
+/// use volcengine_rust_sdk::volcengine::waiter::waiter::WaiterConfig;
+///
+/// # async fn example(ecs: ecs::Ecs) -> Result<(), error::Error> {
+/// let waiter_config = WaiterConfig::builder()
+///     .with_delay(Duration::from_secs(10))
+///     .with_timeout(Duration::from_secs(15 * 60))
+///     .build();
+/// ecs.wait_until_instance_running("i-3tiefmkskq3vj0hk3asp", &waiter_config).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct WaiterConfig {
+    pub delay: Duration,     // Delay after the first poll.
+    pub max_delay: Duration, // Upper bound of a single delay.
+    pub timeout: Duration,   // Total time after which the waiter gives up.
+    pub backoff: f64,        // Factor applied to the delay after every poll.
+    pub max_not_found: u32,  // Consecutive polls a resource may be missing.
+}
+
+impl Default for WaiterConfig {
+    /// Polls after 5 seconds, backing off by 1.5 up to one minute, for at most 30 minutes,
+    /// and fails once a resource was missing from 3 consecutive polls.
+    fn default() -> Self {
+        WaiterConfig {
+            delay: DEFAULT_DELAY,
+            max_delay: DEFAULT_MAX_DELAY,
+            timeout: DEFAULT_TIMEOUT,
+            backoff: DEFAULT_BACKOFF,
+            max_not_found: DEFAULT_MAX_NOT_FOUND,
+        }
+    }
+}
+
+impl WaiterConfig {
+    /// Creates a new `WaiterConfigBuilder` starting from the default values.
+    ///
+    /// # Returns
+    /// - `WaiterConfigBuilder`: A builder used to configure the waiter.
+    pub fn builder() -> WaiterConfigBuilder {
+        WaiterConfigBuilder {
+            waiter_config: WaiterConfig::default(),
+        }
+    }
+
+    /// Returns the delay following `delay`.
+    ///
+    /// # Arguments
+    /// - `delay`: The delay just waited.
+    ///
+    /// # Returns
+    /// - `Duration`: The next delay, capped at `max_delay`.
+    fn next_delay(&self, delay: Duration) -> Duration {
+        delay.mul_f64(self.backoff.max(1.0)).min(self.max_delay)
+    }
+}
+
+/// A builder for constructing a `WaiterConfig`.
+pub struct WaiterConfigBuilder {
+    waiter_config: WaiterConfig, // The configuration being built.
+}
+
+impl WaiterConfigBuilder {
+    /// Sets the delay after the first poll.
+    ///
+    /// # Arguments
+    /// - `delay` (`Duration`): The initial delay.
+    ///
+    /// # Returns
+    /// - `Self`: The updated builder.
+    pub fn with_delay(mut self, delay: Duration) -> Self {
+        self.waiter_config.delay = delay;
+        self
+    }
+
+    /// Sets the upper bound of a single delay.
+    ///
+    /// # Arguments
+    /// - `max_delay` (`Duration`): The maximum delay.
+    ///
+    /// # Returns
+    /// - `Self`: The updated builder.
+    pub fn with_max_delay(mut self, max_delay: Duration) -> Self {
+        self.waiter_config.max_delay = max_delay;
+        self
+    }
+
+    /// Sets the total time after which the waiter gives up.
+    ///
+    /// # Arguments
+    /// - `timeout` (`Duration`): The timeout.
+    ///
+    /// # Returns
+    /// - `Self`: The updated builder.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.waiter_config.timeout = timeout;
+        self
+    }
+
+    /// Sets the factor applied to the delay after every poll.
+    ///
+    /// # Arguments
+    /// - `backoff` (`f64`): The factor; values below `1.0` are treated as `1.0`.
+    ///
+    /// # Returns
+    /// - `Self`: The updated builder.
+    pub fn with_backoff(mut self, backoff: f64) -> Self {
+        self.waiter_config.backoff = backoff;
+        self
+    }
+
+    /// Sets the number of consecutive polls a resource may be missing from a Describe answer.
+    ///
+    /// A resource is often not listed right after its creation. Once it was missing from
+    /// `max_not_found` consecutive polls the waiter fails with the state `NOT_FOUND`
+    /// instead of polling until the timeout.
+    ///
+    /// # Arguments
+    /// - `max_not_found` (`u32`): The number of polls; values below `1` are treated as `1`.
+    ///
+    /// # Returns
+    /// - `Self`: The updated builder.
+    pub fn with_max_not_found(mut self, max_not_found: u32) -> Self {
+        self.waiter_config.max_not_found = max_not_found;
+        self
+    }
+
+    /// Builds the `WaiterConfig`.
+    ///
+    /// # Returns
+    /// - `WaiterConfig`: The configuration.
+    pub fn build(self) -> WaiterConfig {
+        self.waiter_config
+    }
+}
+
+/// The outcome of a single poll.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WaitState<T> {
+    Success(T),      // The desired state was reached; the waiter returns the value.
+    Pending(String), // The resource is still transitioning, in the given state.
+    Failure(String), // The resource reached a state from which it cannot recover.
+}
+
+impl<T> WaitState<T> {
+    /// Classifies a resource status.
+    ///
+    /// # Arguments
+    /// - `status`: The status reported by the Describe API.
+    /// - `target`: The status waited for.
+    /// - `failures`: Terminal statuses ending the wait with an error.
+    /// - `value`: The value returned when `status` is `target`.
+    ///
+    /// # Returns
+    /// - `WaitState<T>`: `Success`, `Failure` or `Pending` depending on `status`.
+    pub fn from_status(status: &str, target: &str, failures: &[&str], value: T) -> Self {
+        if status == target {
+            WaitState::Success(value)
+        } else if failures.contains(&status) {
+            WaitState::Failure(status.to_string())
+        } else {
+            WaitState::Pending(status.to_string())
+        }
+    }
+}
+
+/// Counts the consecutive polls a resource was missing from a Describe answer.
+///
+/// The counter is shared by the polls of one wait; it is atomic so that the future of the
+/// wait stays `Send` and can be spawned.
+#[derive(Debug, Default)]
+pub struct NotFoundCounter {
+    count: AtomicU32, // Consecutive polls the resource was missing.
+}
+
+impl NotFoundCounter {
+    /// Classifies a poll that did or did not find the resource.
+    ///
+    /// # Arguments
+    /// - `found`: The state of the resource if the Describe answer listed it.
+    /// - `waiter_config`: The configuration holding `max_not_found`.
+    ///
+    /// # Returns
+    /// - `WaitState<T>`: `found` itself, resetting the count, or `Pending("NOT_FOUND")` until
+    ///   the resource was missing from `max_not_found` consecutive polls, then `Failure("NOT_FOUND")`.
+    pub fn classify<T>(
+        &self,
+        found: Option<WaitState<T>>,
+        waiter_config: &WaiterConfig,
+    ) -> WaitState<T> {
+        match found {
+            Some(state) => {
+                self.count.store(0, Ordering::Relaxed);
+                state
+            }
+            None => {
+                let count = self.count.fetch_add(1, Ordering::Relaxed) + 1;
+                if count >= waiter_config.max_not_found.max(1) {
+                    WaitState::Failure(STATE_NOT_FOUND.to_string())
+                } else {
+                    WaitState::Pending(STATE_NOT_FOUND.to_string())
+                }
+            }
+        }
+    }
+}
+
+/// Polls until `poll` reports success, a failure state, or the timeout is reached.
+///
+/// Errors returned by `poll`, such as an `Error::Api` for a missing resource, end the
+/// wait immediately.
+///
+/// # Arguments
+/// - `waiter`: The name of the waiter, used in errors, e.g. `InstanceRunning`.
+/// - `waiter_config`: The delay, backoff and timeout.
+/// - `poll`: Sends the Describe request and classifies the response.
+///
+/// # Returns
+/// - `Ok(T)`: The value of the successful poll.
+/// - `Err(error::Error::ErrWaiterFailure)`: If the resource reached a failure state.
+/// - `Err(error::Error::ErrWaiterTimeout)`: If the resource is still pending at the timeout.
+/// - `Err(error::Error)`: If a poll fails.
+pub async fn wait_until<T, F, Fut>(
+    waiter: &str,
+    waiter_config: &WaiterConfig,
+    mut poll: F,
+) -> Result<T, error::Error>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<WaitState<T>, error::Error>>,
+{
+    let started_at = Instant::now();
+    let mut delay = waiter_config.delay;
+
+    loop {
+        let state = match poll().await? {
+            WaitState::Success(value) => return Ok(value),
+            WaitState::Failure(state) => {
+                return Err(error::Error::ErrWaiterFailure {
+                    waiter: waiter.to_string(),
+                    state,
+                })
+            }
+            WaitState::Pending(state) => state,
+        };
+
+        // Give up rather than sleep past the timeout.
+        let elapsed = started_at.elapsed();
+        if elapsed + delay > waiter_config.timeout {
+            return Err(error::Error::ErrWaiterTimeout {
+                waiter: waiter.to_string(),
+                state,
+                elapsed,
+            });
+        }
+
+        tokio::time::sleep(delay).await;
+        delay = waiter_config.next_delay(delay);
+    }
+}